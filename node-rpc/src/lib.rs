@@ -95,11 +95,12 @@ pub struct FullDeps<C, P, SC, B> {
 /// Instantiate all Full RPC extensions.
 pub fn create_full<C, P, SC, B>(
     deps: FullDeps<C, P, SC, B>,
-    _backend: Arc<B>,
+    backend: Arc<B>,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
     C: ProvideRuntimeApi<Block>
         + sc_client_api::BlockBackend<Block>
+        + sc_client_api::BlockchainEvents<Block>
         + HeaderBackend<Block>
         + AuxStore
         + HeaderMetadata<Block, Error = BlockChainError>
@@ -118,6 +119,13 @@ where
     C::Api: BlockBuilder<Block>,
     C::Api: node_rpc::nft::NFTRuntimeApi<Block>,
     C::Api: node_rpc::settlement::SettlementRuntimeApi<Block>,
+    C::Api: node_rpc::statistics::StatisticsRuntimeApi<Block>,
+    C::Api: node_rpc::portfolio::PortfolioRuntimeApi<Block>,
+    C::Api: node_rpc::corporate_actions::CorporateActionsRuntimeApi<Block>,
+    C::Api: node_rpc::events::EventsRuntimeApi<Block>,
+    C::Api: sp_api::Core<Block>,
+    C::Api: node_rpc::dry_run::DryRunRuntimeApi<Block>,
+    C::Api: sp_api::ApiExt<Block, StateBackend = B::State>,
     P: TransactionPool + 'static,
     SC: SelectChain<Block> + 'static,
     B: sc_client_api::Backend<Block> + Send + Sync + 'static,
@@ -125,10 +133,15 @@ where
 {
     use node_rpc::{
         asset::{Asset, AssetApiServer},
+        corporate_actions::{CorporateActions, CorporateActionsApiServer},
+        dry_run::{DryRun, DryRunApiServer},
+        events::{Events, EventsApiServer},
         identity::{Identity, IdentityApiServer},
         nft::{NFTApiServer, NFT},
         pips::{Pips, PipsApiServer},
+        portfolio::{Portfolio, PortfolioApiServer},
         settlement::{Settlement, SettlementApiServer},
+        statistics::{Statistics, StatisticsApiServer},
         transaction_payment::{TransactionPayment, TransactionPaymentApiServer},
     };
     use pallet_group_rpc::{Group, GroupApiServer};
@@ -189,7 +202,7 @@ where
     )?;
     io.merge(
         Grandpa::new(
-            subscription_executor,
+            subscription_executor.clone(),
             shared_authority_set.clone(),
             shared_voter_state,
             justification_stream,
@@ -217,7 +230,12 @@ where
     io.merge(Asset::new(client.clone()).into_rpc())?;
     io.merge(Group::from(client.clone()).into_rpc())?;
     io.merge(NFT::new(client.clone()).into_rpc())?;
-    io.merge(Settlement::new(client).into_rpc())?;
+    io.merge(Settlement::new(client.clone()).into_rpc())?;
+    io.merge(Statistics::new(client.clone()).into_rpc())?;
+    io.merge(Portfolio::new(client.clone()).into_rpc())?;
+    io.merge(CorporateActions::new(client.clone()).into_rpc())?;
+    io.merge(Events::new(client.clone(), subscription_executor).into_rpc())?;
+    io.merge(DryRun::new(client, backend, deny_unsafe).into_rpc())?;
 
     Ok(io)
 }
@@ -17,6 +17,7 @@ use frame_benchmarking::benchmarks;
 use frame_support::StorageValue;
 use frame_system::RawOrigin;
 use scale_info::prelude::format;
+use scale_info::prelude::string::String;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::{convert::TryInto, iter, prelude::*};
 
@@ -30,8 +31,9 @@ use polymesh_primitives::agent::AgentGroup;
 use polymesh_primitives::asset::{AssetName, NonFungibleType};
 use polymesh_primitives::asset_metadata::{
     AssetMetadataDescription, AssetMetadataKey, AssetMetadataName, AssetMetadataSpec,
-    AssetMetadataValue, AssetMetadataValueDetail,
+    AssetMetadataTypeDef, AssetMetadataValue, AssetMetadataValueDetail,
 };
+use polymesh_primitives::calendar::{CalendarPeriod, CalendarUnit};
 use polymesh_primitives::ticker::TICKER_LEN;
 use polymesh_primitives::{
     AuthorizationData, Fund, FundDescription, IdentityId, NFTCollectionKeys, PortfolioKind,
@@ -62,8 +64,27 @@ fn make_metadata_name<T: Config>() -> AssetMetadataName {
 }
 
 /// Make metadata value for benchmarking.
+///
+/// The value is a sequence of `bool`, so its items are decoded one by one when it is validated.
 fn make_metadata_value<T: Config>() -> AssetMetadataValue {
-    AssetMetadataValue(vec![b'v'; T::AssetMetadataValueMaxLength::get() as usize])
+    // Leave room for the compact encoded length of the sequence.
+    let len = T::AssetMetadataValueMaxLength::get() as usize - 4;
+    AssetMetadataValue(vec![true; len].encode())
+}
+
+/// Make metadata type definition for benchmarking.
+///
+/// Defines the type of [`make_metadata_value`], padded with docs up to the maximum length.
+fn make_metadata_type_def<T: Config>() -> Vec<u8> {
+    let mut type_def = AssetMetadataTypeDef::new_from_type::<Vec<bool>>();
+    // Leave room for the compact encoded lengths of the docs.
+    let padding = (T::AssetMetadataTypeDefMaxLength::get() as usize)
+        .saturating_sub(type_def.encode().len() + 8);
+    type_def.types.types[0]
+        .ty
+        .docs
+        .push(iter::repeat('d').take(padding).collect::<String>());
+    type_def.encode()
 }
 
 /// Make metadata spec for benchmarking.
@@ -74,7 +95,7 @@ fn make_metadata_spec<T: Config>() -> AssetMetadataSpec {
             b'd';
             T::MaxLen::get() as usize
         ])),
-        type_def: Some(vec![b'x'; T::AssetMetadataTypeDefMaxLength::get() as usize]),
+        type_def: Some(make_metadata_type_def::<T>()),
     }
 }
 
@@ -538,6 +559,7 @@ benchmarks! {
         let asset_id = create_sample_asset::<T>(&alice, true);
 
         let key = register_metadata_global_name::<T>();
+        Module::<T>::set_asset_metadata_validation(alice.origin().into(), asset_id, key, true).unwrap();
         let value = make_metadata_value::<T>();
         let details = AssetMetadataValueDetail::default();
     }: _(alice.origin, asset_id, key, value, Some(details))
@@ -765,4 +787,135 @@ benchmarks! {
         )
         .unwrap();
     }: _(alice.origin, ticker, asset_id)
+
+    freeze_identity {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+    }: _(alice.origin, asset_id, bob.did(), None)
+    verify {
+        assert_eq!(FrozenIdentityBalances::get(&asset_id, bob.did()), Balance::MAX);
+    }
+
+    unfreeze_identity {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        Module::<T>::freeze_identity(alice.origin().into(), asset_id, bob.did(), None).unwrap();
+    }: _(alice.origin, asset_id, bob.did())
+    verify {
+        assert!(!FrozenIdentityBalances::contains_key(&asset_id, bob.did()));
+    }
+
+    freeze_portfolio {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let bob_portfolio = create_portfolio::<T>(&bob, "BobPortfolio");
+    }: _(alice.origin, asset_id, bob_portfolio, None)
+    verify {
+        assert_eq!(FrozenPortfolioBalances::get(&asset_id, bob_portfolio), Balance::MAX);
+    }
+
+    unfreeze_portfolio {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let bob_portfolio = create_portfolio::<T>(&bob, "BobPortfolio");
+        Module::<T>::freeze_portfolio(alice.origin().into(), asset_id, bob_portfolio, None).unwrap();
+    }: _(alice.origin, asset_id, bob_portfolio)
+    verify {
+        assert!(!FrozenPortfolioBalances::contains_key(&asset_id, bob_portfolio));
+    }
+
+    approve {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let alice_portfolio = create_portfolio::<T>(&alice, "AlicePortfolio");
+    }: _(alice.origin, alice_portfolio, asset_id, bob.did(), ONE_UNIT)
+    verify {
+        assert_eq!(Allowances::get((alice_portfolio, asset_id), bob.did()), ONE_UNIT);
+    }
+
+    transfer_from {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let (sender_portfolio, receiver_portfolio, _, asset_id) =
+            setup_asset_transfer::<T>(&alice, &bob, None, None, true, true, 0);
+        Module::<T>::approve(
+            alice.origin().into(),
+            sender_portfolio,
+            asset_id,
+            bob.did(),
+            ONE_UNIT,
+        )
+        .unwrap();
+    }: _(bob.origin, sender_portfolio, receiver_portfolio, asset_id, ONE_UNIT)
+    verify {
+        assert!(!Allowances::contains_key((sender_portfolio, asset_id), bob.did()));
+    }
+
+    create_issuance_schedule {
+        <pallet_timestamp::Now<T>>::set(1000u32.into());
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let period = CalendarPeriod { unit: CalendarUnit::Month, amount: 1 };
+        checkpoint::Module::<T>::set_schedules_max_complexity(RawOrigin::Root.into(), period.complexity()).unwrap();
+    }: _(alice.origin, asset_id, PortfolioKind::Default, ONE_UNIT, 12 * ONE_UNIT, 2000, period)
+    verify {
+        assert!(IssuanceSchedules::contains_key(asset_id, IssuanceScheduleId(1)));
+    }
+
+    remove_issuance_schedule {
+        <pallet_timestamp::Now<T>>::set(1000u32.into());
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let period = CalendarPeriod { unit: CalendarUnit::Month, amount: 1 };
+        checkpoint::Module::<T>::set_schedules_max_complexity(RawOrigin::Root.into(), period.complexity()).unwrap();
+        Module::<T>::create_issuance_schedule(
+            alice.origin().into(),
+            asset_id,
+            PortfolioKind::Default,
+            ONE_UNIT,
+            12 * ONE_UNIT,
+            2000,
+            period,
+        )
+        .unwrap();
+    }: _(alice.origin, asset_id, IssuanceScheduleId(1))
+    verify {
+        assert!(!IssuanceSchedules::contains_key(asset_id, IssuanceScheduleId(1)));
+    }
+
+    execute_issuance_schedule {
+        <pallet_timestamp::Now<T>>::set(1000u32.into());
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let period = CalendarPeriod { unit: CalendarUnit::Month, amount: 1 };
+        checkpoint::Module::<T>::set_schedules_max_complexity(RawOrigin::Root.into(), period.complexity()).unwrap();
+        Module::<T>::create_issuance_schedule(
+            alice.origin().into(),
+            asset_id,
+            PortfolioKind::Default,
+            ONE_UNIT,
+            12 * ONE_UNIT,
+            2000,
+            period,
+        )
+        .unwrap();
+        <pallet_timestamp::Now<T>>::set(3000u32.into());
+    }: _(alice.origin, asset_id, IssuanceScheduleId(1))
+    verify {
+        assert_eq!(Module::<T>::balance_of(asset_id, alice.did()), ONE_UNIT);
+    }
+
+    set_asset_metadata_validation {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let key = register_metadata_global_name::<T>();
+    }: _(alice.origin, asset_id, key, true)
+    verify {
+        assert!(Module::<T>::validated_asset_metadata_keys(asset_id, key));
+    }
 }
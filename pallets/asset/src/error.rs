@@ -103,6 +103,30 @@ decl_error! {
         /// The given asset is already linked to a ticker.
         AssetIsAlreadyLinkedToATicker,
         /// The given ticker is not linked to the given asset.
-        TickerIsNotLinkedToTheAsset
+        TickerIsNotLinkedToTheAsset,
+        /// The asset metadata value doesn't match the type definition of its key.
+        InvalidAssetMetadataValue,
+        /// The asset metadata key has no valid type definition.
+        AssetMetadataTypeDefIsMissing,
+        /// The supply of the asset can't be changed, since it represents the fractions of an NFT.
+        AssetSupplyIsFixed,
+        /// The transfer would use the frozen holdings of the sender's identity.
+        IdentityBalanceFrozen,
+        /// The transfer would use the frozen holdings of the sender's portfolio.
+        PortfolioBalanceFrozen,
+        /// The frozen amount must be greater than zero.
+        InvalidFrozenAmount,
+        /// The spender's allowance is lower than the amount being transferred.
+        InsufficientAllowance,
+        /// The receiving portfolio is not in custody of the spender and its owner has not pre-approved the asset.
+        TransferReceiverNotPreApproved,
+        /// The asset has mandatory mediators and can only be transferred through settlement.
+        AssetRequiresMediation,
+        /// The issuance schedule must issue a non-zero amount, up to its cap, starting in the future.
+        InvalidIssuanceSchedule,
+        /// The issuance schedule does not exist for the asset.
+        NoSuchIssuanceSchedule,
+        /// The issuance schedule has no due issuances.
+        IssuanceNotDue
     }
 }
@@ -110,16 +110,19 @@ use polymesh_common_utilities::traits::nft::NFTTrait;
 use polymesh_primitives::agent::AgentGroup;
 use polymesh_primitives::asset::{
     AssetId, AssetName, AssetType, CheckpointId, CustomAssetTypeId, FundingRoundName,
+    IssuanceSchedule, IssuanceScheduleId,
 };
 use polymesh_primitives::asset_metadata::{
-    AssetMetadataGlobalKey, AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataName,
-    AssetMetadataSpec, AssetMetadataValue, AssetMetadataValueDetail,
+    AssetMetadataDecodedValue, AssetMetadataGlobalKey, AssetMetadataKey, AssetMetadataLocalKey,
+    AssetMetadataName, AssetMetadataSpec, AssetMetadataTypeDef, AssetMetadataValue,
+    AssetMetadataValueDetail,
 };
+use polymesh_primitives::calendar::CalendarPeriod;
 use polymesh_primitives::settlement::InstructionId;
 use polymesh_primitives::{
     extract_auth, storage_migrate_on, storage_migration_ver, AssetIdentifier, Balance, Document,
-    DocumentId, IdentityId, Memo, PortfolioId, PortfolioKind, PortfolioUpdateReason, SecondaryKey,
-    Ticker, WeightMeter,
+    DocumentId, IdentityId, Memo, Moment, PortfolioId, PortfolioKind, PortfolioUpdateReason,
+    SecondaryKey, Ticker, WeightMeter,
 };
 
 pub use error::Error;
@@ -167,6 +170,24 @@ decl_storage! {
         pub IssuedInFundingRound get(fn issued_in_funding_round): map hasher(blake2_128_concat) (AssetId, FundingRoundName) => Balance;
         /// Returns `true` if transfers for the token associated to [`AssetId`] are frozen. Otherwise, returns `false`.
         pub Frozen get(fn frozen): map hasher(blake2_128_concat) AssetId => bool;
+        /// The [`Balance`] of [`AssetId`] that can't be transferred out of an [`IdentityId`]. `Balance::MAX` freezes all holdings.
+        pub FrozenIdentityBalances get(fn frozen_identity_balance):
+            double_map hasher(blake2_128_concat) AssetId, hasher(identity) IdentityId => Balance;
+        /// The [`Balance`] of [`AssetId`] that can't be transferred out of a [`PortfolioId`]. `Balance::MAX` freezes all holdings.
+        pub FrozenPortfolioBalances get(fn frozen_portfolio_balance):
+            double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) PortfolioId => Balance;
+        /// The [`Balance`] of [`AssetId`] that a spender [`IdentityId`] is allowed to transfer out of a [`PortfolioId`].
+        pub Allowances get(fn allowance):
+            double_map hasher(blake2_128_concat) (PortfolioId, AssetId), hasher(identity) IdentityId => Balance;
+        /// All active [`IssuanceSchedule`] of an asset.
+        pub IssuanceSchedules get(fn issuance_schedules):
+            double_map hasher(blake2_128_concat) AssetId, hasher(twox_64_concat) IssuanceScheduleId => Option<IssuanceSchedule>;
+        /// The last [`IssuanceScheduleId`] used for [`AssetId`].
+        pub IssuanceScheduleIdSequence get(fn issuance_schedule_id_sequence):
+            map hasher(blake2_128_concat) AssetId => IssuanceScheduleId;
+        /// The total complexity of the active issuance schedules of an asset.
+        pub IssuanceSchedulesComplexity get(fn issuance_schedules_complexity):
+            map hasher(blake2_128_concat) AssetId => u64;
         /// All [`Document`] attached to an asset.
         pub AssetDocuments get(fn asset_documents):
             double_map hasher(blake2_128_concat) AssetId, hasher(twox_64_concat) DocumentId => Option<Document>;
@@ -205,6 +226,9 @@ decl_storage! {
         /// Asset Metadata Global Key specs.
         pub AssetMetadataGlobalSpecs get(fn asset_metadata_global_specs):
             map hasher(twox_64_concat) AssetMetadataGlobalKey => Option<AssetMetadataSpec>;
+        /// Metadata keys of an asset whose values must match the type definition of their spec.
+        pub ValidatedAssetMetadataKeys get(fn validated_asset_metadata_keys):
+            double_map hasher(blake2_128_concat) AssetId, hasher(twox_64_concat) AssetMetadataKey => bool;
 
         /// A list of assets that exempt all users from affirming its receivement.
         pub AssetsExemptFromAffirmation get(fn assets_exempt_from_affirmation):
@@ -594,6 +618,9 @@ decl_module! {
 
         /// Set asset metadata value.
         ///
+        /// If `key` has been opted into validation with [`Module::set_asset_metadata_validation`],
+        /// `value` must be the SCALE encoding of the [`AssetMetadataTypeDef`] of its spec.
+        ///
         /// # Arguments
         /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
         /// * `asset_id`: the [`AssetId`] associated to the token.
@@ -857,6 +884,175 @@ decl_module! {
         pub fn unlink_ticker_from_asset_id(origin, ticker: Ticker, asset_id: AssetId) {
             Self::base_unlink_ticker_from_asset_id(origin, ticker, asset_id)?;
         }
+
+        /// Freezes the holdings of `asset_id` of a single identity, without halting the whole asset.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `identity`: the [`IdentityId`] whose holdings will be frozen.
+        /// * `amount`: the [`Balance`] that can't be transferred out of the identity. `None` freezes all holdings.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::freeze_identity()]
+        pub fn freeze_identity(origin, asset_id: AssetId, identity: IdentityId, amount: Option<Balance>) {
+            Self::base_freeze_identity(origin, asset_id, identity, amount)?;
+        }
+
+        /// Removes the freeze of the holdings of `asset_id` of `identity`.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `identity`: the [`IdentityId`] whose holdings will be unfrozen.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::unfreeze_identity()]
+        pub fn unfreeze_identity(origin, asset_id: AssetId, identity: IdentityId) {
+            Self::base_unfreeze_identity(origin, asset_id, identity)?;
+        }
+
+        /// Freezes the holdings of `asset_id` of a single portfolio, without halting the whole asset.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `portfolio_id`: the [`PortfolioId`] whose holdings will be frozen.
+        /// * `amount`: the [`Balance`] that can't be transferred out of the portfolio. `None` freezes all holdings.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::freeze_portfolio()]
+        pub fn freeze_portfolio(origin, asset_id: AssetId, portfolio_id: PortfolioId, amount: Option<Balance>) {
+            Self::base_freeze_portfolio(origin, asset_id, portfolio_id, amount)?;
+        }
+
+        /// Removes the freeze of the holdings of `asset_id` of `portfolio_id`.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `portfolio_id`: the [`PortfolioId`] whose holdings will be unfrozen.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::unfreeze_portfolio()]
+        pub fn unfreeze_portfolio(origin, asset_id: AssetId, portfolio_id: PortfolioId) {
+            Self::base_unfreeze_portfolio(origin, asset_id, portfolio_id)?;
+        }
+
+        /// Sets the [`Balance`] of `asset_id` that `spender` is allowed to transfer out of `portfolio`.
+        /// An `amount` of zero removes the allowance.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has custody of `portfolio`.
+        /// * `portfolio`: the [`PortfolioId`] the allowance is drawn from.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `spender`: the [`IdentityId`] allowed to spend the tokens.
+        /// * `amount`: the [`Balance`] the spender is allowed to transfer.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::approve()]
+        pub fn approve(origin, portfolio: PortfolioId, asset_id: AssetId, spender: IdentityId, amount: Balance) {
+            Self::base_approve(origin, portfolio, asset_id, spender, amount)?;
+        }
+
+        /// Transfers `amount` of `asset_id` from `from` to `to`, consuming the allowance granted to the caller.
+        /// All compliance, statistics and freeze checks of a regular transfer apply.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer whose identity has been granted an allowance on `from`.
+        /// * `from`: the [`PortfolioId`] the tokens are transferred from.
+        /// * `to`: the [`PortfolioId`] receiving the tokens. Must be in custody of the caller, or its owner
+        /// must have pre-approved receiving `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `amount`: the [`Balance`] to be transferred.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::transfer_from()]
+        pub fn transfer_from(origin, from: PortfolioId, to: PortfolioId, asset_id: AssetId, amount: Balance) {
+            Self::base_transfer_from(origin, from, to, asset_id, amount)?;
+        }
+
+        /// Creates a schedule that issues `amount` of `asset_id` into the caller's `portfolio_kind` on each `period`,
+        /// starting at `start`, until `cap` tokens have been issued.
+        /// The schedule counts against the maximum schedules complexity of the asset.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `portfolio_kind`: the [`PortfolioKind`] of the portfolio that will receive the issued tokens.
+        /// * `amount`: the [`Balance`] issued on each period.
+        /// * `cap`: the maximum [`Balance`] issued by the schedule.
+        /// * `start`: the time of the first issuance.
+        /// * `period`: the [`CalendarPeriod`] between two issuances. A non-recurring period issues only once.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::create_issuance_schedule()]
+        pub fn create_issuance_schedule(
+            origin,
+            asset_id: AssetId,
+            portfolio_kind: PortfolioKind,
+            amount: Balance,
+            cap: Balance,
+            start: Moment,
+            period: CalendarPeriod,
+        ) {
+            Self::base_create_issuance_schedule(origin, asset_id, portfolio_kind, amount, cap, start, period)?;
+        }
+
+        /// Removes the issuance schedule `schedule_id` of `asset_id`. Tokens already issued are not affected.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `schedule_id`: the [`IssuanceScheduleId`] of the schedule being removed.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::remove_issuance_schedule()]
+        pub fn remove_issuance_schedule(origin, asset_id: AssetId, schedule_id: IssuanceScheduleId) {
+            Self::base_remove_issuance_schedule(origin, asset_id, schedule_id)?;
+        }
+
+        /// Issues all due periods of the issuance schedule `schedule_id` of `asset_id`.
+        /// Can be called by any identity.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has a valid identity.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `schedule_id`: the [`IssuanceScheduleId`] of the schedule being executed.
+        #[weight = <T as Config>::WeightInfo::execute_issuance_schedule()]
+        pub fn execute_issuance_schedule(origin, asset_id: AssetId, schedule_id: IssuanceScheduleId) {
+            Self::base_execute_issuance_schedule(origin, asset_id, schedule_id)?;
+        }
+
+        /// Enables or disables the validation of the values set for the metadata `key` of `asset_id`.
+        /// Once enabled, values are checked against the [`AssetMetadataTypeDef`] of the key's spec.
+        ///
+        /// # Arguments
+        /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id`: the [`AssetId`] associated to the token.
+        /// * `key`: the [`AssetMetadataKey`] being updated.
+        /// * `validate`: `true` to validate the values of `key`, `false` to stop validating them.
+        ///
+        /// # Errors
+        /// * `AssetMetadataKeyIsMissing` if `key` doesn't exist.
+        /// * `AssetMetadataTypeDefIsMissing` if validation is enabled for a key without a type definition.
+        ///
+        /// # Permissions
+        /// * Agent
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::set_asset_metadata_validation()]
+        pub fn set_asset_metadata_validation(origin, asset_id: AssetId, key: AssetMetadataKey, validate: bool) {
+            Self::base_set_asset_metadata_validation(origin, asset_id, key, validate)?;
+        }
     }
 }
 
@@ -994,6 +1190,338 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Freezes `amount` (or all holdings if `None`) of `asset_id` held by `identity`.
+    fn base_freeze_identity(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        identity: IdentityId,
+        amount: Option<Balance>,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+
+        Self::ensure_asset_exists(&asset_id)?;
+        Identity::<T>::ensure_id_record_exists(identity)?;
+        let frozen_balance = Self::ensure_valid_frozen_amount(amount)?;
+
+        FrozenIdentityBalances::insert(asset_id, identity, frozen_balance);
+        Self::deposit_event(RawEvent::IdentityBalanceFrozen(
+            caller_did, asset_id, identity, amount,
+        ));
+        Ok(())
+    }
+
+    /// Removes the freeze of the holdings of `asset_id` held by `identity`.
+    fn base_unfreeze_identity(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        identity: IdentityId,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+
+        ensure!(
+            FrozenIdentityBalances::contains_key(asset_id, identity),
+            Error::<T>::NotFrozen
+        );
+        FrozenIdentityBalances::remove(asset_id, identity);
+        Self::deposit_event(RawEvent::IdentityBalanceUnfrozen(
+            caller_did, asset_id, identity,
+        ));
+        Ok(())
+    }
+
+    /// Freezes `amount` (or all holdings if `None`) of `asset_id` held by `portfolio_id`.
+    fn base_freeze_portfolio(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_id: PortfolioId,
+        amount: Option<Balance>,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+
+        Self::ensure_asset_exists(&asset_id)?;
+        Portfolio::<T>::ensure_portfolio_validity(&portfolio_id)?;
+        let frozen_balance = Self::ensure_valid_frozen_amount(amount)?;
+
+        FrozenPortfolioBalances::insert(asset_id, portfolio_id, frozen_balance);
+        Self::deposit_event(RawEvent::PortfolioBalanceFrozen(
+            caller_did,
+            asset_id,
+            portfolio_id,
+            amount,
+        ));
+        Ok(())
+    }
+
+    /// Removes the freeze of the holdings of `asset_id` held by `portfolio_id`.
+    fn base_unfreeze_portfolio(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_id: PortfolioId,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+
+        ensure!(
+            FrozenPortfolioBalances::contains_key(asset_id, portfolio_id),
+            Error::<T>::NotFrozen
+        );
+        FrozenPortfolioBalances::remove(asset_id, portfolio_id);
+        Self::deposit_event(RawEvent::PortfolioBalanceUnfrozen(
+            caller_did,
+            asset_id,
+            portfolio_id,
+        ));
+        Ok(())
+    }
+
+    /// Sets the allowance of `spender` over the `asset_id` holdings of `portfolio` to `amount`.
+    fn base_approve(
+        origin: T::RuntimeOrigin,
+        portfolio: PortfolioId,
+        asset_id: AssetId,
+        spender: IdentityId,
+        amount: Balance,
+    ) -> DispatchResult {
+        let caller_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        let caller_did = caller_data.primary_did;
+
+        Self::ensure_asset_exists(&asset_id)?;
+        Portfolio::<T>::ensure_portfolio_custody_and_permission(
+            portfolio,
+            caller_did,
+            caller_data.secondary_key.as_ref(),
+        )?;
+
+        if amount == 0 {
+            Allowances::remove((portfolio, asset_id), spender);
+        } else {
+            Allowances::insert((portfolio, asset_id), spender, amount);
+        }
+        Self::deposit_event(RawEvent::AllowanceUpdated(
+            caller_did, portfolio, asset_id, spender, amount,
+        ));
+        Ok(())
+    }
+
+    /// Transfers `amount` of `asset_id` from `from` to `to` on behalf of the owner of `from`.
+    fn base_transfer_from(
+        origin: T::RuntimeOrigin,
+        from: PortfolioId,
+        to: PortfolioId,
+        asset_id: AssetId,
+        amount: Balance,
+    ) -> DispatchResult {
+        let caller_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        let spender = caller_data.primary_did;
+
+        // The receiver must either be controlled by the spender or accept the asset without affirming
+        let receiver_in_custody = Portfolio::<T>::ensure_portfolio_custody_and_permission(
+            to,
+            spender,
+            caller_data.secondary_key.as_ref(),
+        )
+        .is_ok();
+        ensure!(
+            receiver_in_custody || Self::skip_asset_affirmation(&to.did, &asset_id),
+            Error::<T>::TransferReceiverNotPreApproved
+        );
+        // Mediated assets can only be moved through settlement
+        ensure!(
+            MandatoryMediators::<T>::get(asset_id).is_empty(),
+            Error::<T>::AssetRequiresMediation
+        );
+
+        Self::consume_allowance(from, asset_id, spender, amount)?;
+        Self::base_transfer(
+            from,
+            to,
+            asset_id,
+            amount,
+            None,
+            None,
+            spender,
+            &mut WeightMeter::max_limit_no_minimum(),
+        )
+    }
+
+    /// Creates an [`IssuanceSchedule`] for `asset_id` issuing into the caller's `portfolio_kind`.
+    fn base_create_issuance_schedule(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_kind: PortfolioKind,
+        amount: Balance,
+        cap: Balance,
+        start: Moment,
+        period: CalendarPeriod,
+    ) -> DispatchResult {
+        let portfolio = Self::ensure_origin_asset_and_portfolio_permissions(
+            origin,
+            asset_id,
+            portfolio_kind,
+            false,
+        )?;
+        ensure!(
+            !T::NFTFn::is_nft_fraction(&asset_id),
+            Error::<T>::AssetSupplyIsFixed
+        );
+        let asset_details = Self::try_get_asset_details(&asset_id)?;
+        ensure!(
+            asset_details.asset_type.is_fungible(),
+            Error::<T>::UnexpectedNonFungibleToken
+        );
+        ensure!(
+            amount > 0 && cap >= amount && start >= <Checkpoint<T>>::now_unix(),
+            Error::<T>::InvalidIssuanceSchedule
+        );
+        Self::ensure_token_granular(&asset_details, &amount)?;
+        Self::ensure_token_granular(&asset_details, &cap)?;
+
+        // Issuance schedules are limited by the same maximum complexity as checkpoint schedules
+        let complexity =
+            IssuanceSchedulesComplexity::get(asset_id).saturating_add(period.complexity());
+        ensure!(
+            complexity <= <Checkpoint<T>>::schedules_max_complexity(),
+            checkpoint::Error::<T>::SchedulesOverMaxComplexity
+        );
+
+        let schedule_id = try_next_pre::<T, _>(&mut IssuanceScheduleIdSequence::get(asset_id))?;
+        let schedule = IssuanceSchedule::new(portfolio, amount, cap, start, period);
+        IssuanceScheduleIdSequence::insert(asset_id, schedule_id);
+        IssuanceSchedulesComplexity::insert(asset_id, complexity);
+        IssuanceSchedules::insert(asset_id, schedule_id, &schedule);
+        Self::deposit_event(RawEvent::IssuanceScheduleCreated(
+            portfolio.did,
+            asset_id,
+            schedule_id,
+            schedule,
+        ));
+        Ok(())
+    }
+
+    /// Removes the [`IssuanceSchedule`] of the given `schedule_id`.
+    fn base_remove_issuance_schedule(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        schedule_id: IssuanceScheduleId,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+
+        let schedule = IssuanceSchedules::get(asset_id, schedule_id)
+            .ok_or(Error::<T>::NoSuchIssuanceSchedule)?;
+        Self::remove_issuance_schedule_storage(asset_id, schedule_id, &schedule);
+        Self::deposit_event(RawEvent::IssuanceScheduleRemoved(
+            caller_did,
+            asset_id,
+            schedule_id,
+        ));
+        Ok(())
+    }
+
+    /// Issues all due periods of the [`IssuanceSchedule`] of the given `schedule_id`, up to [`MAX_ISSUANCES_PER_EXECUTION`].
+    fn base_execute_issuance_schedule(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        schedule_id: IssuanceScheduleId,
+    ) -> DispatchResult {
+        let caller_did = Identity::<T>::ensure_perms(origin)?;
+
+        let mut schedule = IssuanceSchedules::get(asset_id, schedule_id)
+            .ok_or(Error::<T>::NoSuchIssuanceSchedule)?;
+        let now = <Checkpoint<T>>::now_unix();
+        ensure!(schedule.is_due(now), Error::<T>::IssuanceNotDue);
+
+        let mut amount_to_issue: Balance = 0;
+        let mut n_issuances = 0;
+        while schedule.is_due(now) && n_issuances < MAX_ISSUANCES_PER_EXECUTION {
+            amount_to_issue = amount_to_issue.saturating_add(schedule.advance());
+            n_issuances += 1;
+        }
+
+        Portfolio::<T>::ensure_portfolio_validity(&schedule.portfolio)?;
+        let mut asset_details = Self::try_get_asset_details(&asset_id)?;
+        Self::validate_issuance_rules(&asset_details, amount_to_issue)?;
+        Self::unverified_issue_tokens(
+            asset_id,
+            &mut asset_details,
+            schedule.portfolio,
+            amount_to_issue,
+            false,
+            &mut WeightMeter::max_limit_no_minimum(),
+        )?;
+
+        if schedule.is_finished() {
+            Self::remove_issuance_schedule_storage(asset_id, schedule_id, &schedule);
+        } else {
+            IssuanceSchedules::insert(asset_id, schedule_id, schedule);
+        }
+        Self::deposit_event(RawEvent::ScheduledIssuanceExecuted(
+            caller_did,
+            asset_id,
+            schedule_id,
+            amount_to_issue,
+        ));
+        Ok(())
+    }
+
+    /// Removes the [`IssuanceSchedule`] from storage and releases its complexity.
+    fn remove_issuance_schedule_storage(
+        asset_id: AssetId,
+        schedule_id: IssuanceScheduleId,
+        schedule: &IssuanceSchedule,
+    ) {
+        IssuanceSchedules::remove(asset_id, schedule_id);
+        IssuanceSchedulesComplexity::mutate(asset_id, |complexity| {
+            *complexity = complexity.saturating_sub(schedule.period.complexity())
+        });
+    }
+
+    /// Returns `Ok` if `spender` has an allowance of at least `amount` of `asset_id` over `portfolio`.
+    /// Otherwise, returns [`Error::InsufficientAllowance`].
+    pub fn ensure_sufficient_allowance(
+        portfolio: PortfolioId,
+        asset_id: AssetId,
+        spender: IdentityId,
+        amount: Balance,
+    ) -> DispatchResult {
+        ensure!(
+            Allowances::get((portfolio, asset_id), spender) >= amount,
+            Error::<T>::InsufficientAllowance
+        );
+        Ok(())
+    }
+
+    /// Decreases the allowance of `spender` over the `asset_id` holdings of `portfolio` by `amount`.
+    pub fn consume_allowance(
+        portfolio: PortfolioId,
+        asset_id: AssetId,
+        spender: IdentityId,
+        amount: Balance,
+    ) -> DispatchResult {
+        let remaining = Allowances::get((portfolio, asset_id), spender)
+            .checked_sub(amount)
+            .ok_or(Error::<T>::InsufficientAllowance)?;
+
+        if remaining == 0 {
+            Allowances::remove((portfolio, asset_id), spender);
+        } else {
+            Allowances::insert((portfolio, asset_id), spender, remaining);
+        }
+        Self::deposit_event(RawEvent::AllowanceUpdated(
+            spender, portfolio, asset_id, spender, remaining,
+        ));
+        Ok(())
+    }
+
+    /// Returns the [`Balance`] to be frozen for `amount`, where `None` stands for all holdings.
+    fn ensure_valid_frozen_amount(amount: Option<Balance>) -> Result<Balance, DispatchError> {
+        match amount {
+            Some(amount) => {
+                ensure!(amount > 0, Error::<T>::InvalidFrozenAmount);
+                Ok(amount)
+            }
+            None => Ok(Balance::MAX),
+        }
+    }
+
     /// If `asset_name` is valid, updates the [`AssetName`] of the underling token given by `asset_id`.
     fn base_rename_asset(
         origin: T::RuntimeOrigin,
@@ -1023,6 +1551,10 @@ impl<T: Config> Module<T> {
             portfolio_kind,
             false,
         )?;
+        ensure!(
+            !T::NFTFn::is_nft_fraction(&asset_id),
+            Error::<T>::AssetSupplyIsFixed
+        );
         let mut weight_meter = WeightMeter::max_limit_no_minimum();
         let mut asset_details = Self::try_get_asset_details(&asset_id)?;
         Self::validate_issuance_rules(&asset_details, amount_to_issue)?;
@@ -1051,7 +1583,20 @@ impl<T: Config> Module<T> {
             portfolio_kind,
             true,
         )?;
+        ensure!(
+            !T::NFTFn::is_nft_fraction(&asset_id),
+            Error::<T>::AssetSupplyIsFixed
+        );
+        Self::unverified_redeem(asset_id, value, portfolio, weight_meter)
+    }
 
+    /// Burns `value` tokens of `asset_id` from `portfolio`, without checking the caller's permissions.
+    pub fn unverified_redeem(
+        asset_id: AssetId,
+        value: Balance,
+        portfolio: PortfolioId,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
         let mut asset_details = Self::try_get_asset_details(&asset_id)?;
         Self::ensure_token_granular(&asset_details, &value)?;
 
@@ -1430,12 +1975,44 @@ impl<T: Config> Module<T> {
         AssetMetadataLocalNameToKey::remove(&asset_id, &name);
         AssetMetadataLocalKeyToName::remove(&asset_id, &local_key);
         AssetMetadataLocalSpecs::remove(&asset_id, &local_key);
+        ValidatedAssetMetadataKeys::remove(&asset_id, &metadata_key);
         Self::deposit_event(RawEvent::LocalMetadataKeyDeleted(
             caller_did, asset_id, local_key,
         ));
         Ok(())
     }
 
+    fn base_set_asset_metadata_validation(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        key: AssetMetadataKey,
+        validate: bool,
+    ) -> DispatchResult {
+        // Verifies if the caller has the correct permissions for this asset
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+        // Verifies if the key exists.
+        ensure!(
+            Self::check_asset_metadata_key_exists(&asset_id, &key),
+            Error::<T>::AssetMetadataKeyIsMissing
+        );
+
+        if validate {
+            // Values can only be validated against a type definition.
+            ensure!(
+                Self::asset_metadata_type_def(&asset_id, &key).is_some(),
+                Error::<T>::AssetMetadataTypeDefIsMissing
+            );
+            ValidatedAssetMetadataKeys::insert(&asset_id, &key, true);
+        } else {
+            ValidatedAssetMetadataKeys::remove(&asset_id, &key);
+        }
+
+        Self::deposit_event(RawEvent::AssetMetadataValidationUpdated(
+            caller_did, asset_id, key, validate,
+        ));
+        Ok(())
+    }
+
     fn base_remove_metadata_value(
         origin: T::RuntimeOrigin,
         asset_id: AssetId,
@@ -1907,6 +2484,51 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Returns the [`AssetMetadataSpec`] of the given metadata `key`.
+    pub fn asset_metadata_spec(
+        asset_id: &AssetId,
+        key: &AssetMetadataKey,
+    ) -> Option<AssetMetadataSpec> {
+        match key {
+            AssetMetadataKey::Global(key) => AssetMetadataGlobalSpecs::get(key),
+            AssetMetadataKey::Local(key) => AssetMetadataLocalSpecs::get(asset_id, key),
+        }
+    }
+
+    /// Returns the [`AssetMetadataTypeDef`] declared by the spec of the given metadata `key`.
+    ///
+    /// Type definitions that can't be decoded are treated as opaque, so their values are not typed.
+    fn asset_metadata_type_def(
+        asset_id: &AssetId,
+        key: &AssetMetadataKey,
+    ) -> Option<AssetMetadataTypeDef> {
+        Self::asset_metadata_spec(asset_id, key)?
+            .decode_type_def()
+            .ok()
+            .flatten()
+    }
+
+    /// Returns the metadata value of `key` decoded with the type definition of its spec,
+    /// or `None` if no value has been set.
+    pub fn decoded_asset_metadata_value(
+        asset_id: AssetId,
+        key: AssetMetadataKey,
+    ) -> Result<Option<AssetMetadataDecodedValue>, DispatchError> {
+        ensure!(
+            Self::check_asset_metadata_key_exists(&asset_id, &key),
+            Error::<T>::AssetMetadataKeyIsMissing
+        );
+        let type_def = Self::asset_metadata_type_def(&asset_id, &key)
+            .ok_or(Error::<T>::AssetMetadataTypeDefIsMissing)?;
+        AssetMetadataValues::get(&asset_id, &key)
+            .map(|value| {
+                type_def
+                    .decode_value(&value)
+                    .map_err(|_| Error::<T>::InvalidAssetMetadataValue.into())
+            })
+            .transpose()
+    }
+
     fn is_asset_metadata_locked(asset_id: &AssetId, key: AssetMetadataKey) -> bool {
         AssetMetadataValueDetails::<T>::get(asset_id, key).map_or(false, |details| {
             details.is_locked(<pallet_timestamp::Pallet<T>>::get())
@@ -2009,6 +2631,10 @@ impl<T: Config> Module<T> {
             Error::<T>::InvalidTransferFrozenAsset
         );
 
+        // Verifies that the frozen holdings of the sender are not being transferred
+        Self::ensure_identity_balance_unfrozen(&asset_id, &sender_portfolio.did, transfer_value)?;
+        Self::ensure_portfolio_balance_unfrozen(&asset_id, sender_portfolio, transfer_value)?;
+
         ensure!(
             Identity::<T>::has_valid_cdd(receiver_portfolio.did),
             Error::<T>::InvalidTransferInvalidReceiverCDD
@@ -2123,6 +2749,18 @@ impl<T: Config> Module<T> {
             asset_transfer_errors.push(Error::<T>::InvalidTransferFrozenAsset.into());
         }
 
+        if let Err(e) =
+            Self::ensure_identity_balance_unfrozen(asset_id, &sender_portfolio.did, transfer_value)
+        {
+            asset_transfer_errors.push(e);
+        }
+
+        if let Err(e) =
+            Self::ensure_portfolio_balance_unfrozen(asset_id, sender_portfolio, transfer_value)
+        {
+            asset_transfer_errors.push(e);
+        }
+
         if let Err(e) = Statistics::<T>::verify_transfer_restrictions(
             *asset_id,
             &sender_portfolio.did,
@@ -2155,6 +2793,39 @@ impl<T: Config> Module<T> {
         asset_transfer_errors
     }
 
+    /// Returns `Ok` if `transfer_value` can leave `identity` without using its frozen holdings of `asset_id`.
+    fn ensure_identity_balance_unfrozen(
+        asset_id: &AssetId,
+        identity: &IdentityId,
+        transfer_value: Balance,
+    ) -> DispatchResult {
+        let frozen_balance = FrozenIdentityBalances::get(asset_id, identity);
+        if frozen_balance > 0 {
+            ensure!(
+                BalanceOf::get(asset_id, identity).saturating_sub(frozen_balance) >= transfer_value,
+                Error::<T>::IdentityBalanceFrozen
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns `Ok` if `transfer_value` can leave `portfolio_id` without using its frozen holdings of `asset_id`.
+    pub fn ensure_portfolio_balance_unfrozen(
+        asset_id: &AssetId,
+        portfolio_id: &PortfolioId,
+        transfer_value: Balance,
+    ) -> DispatchResult {
+        let frozen_balance = FrozenPortfolioBalances::get(asset_id, portfolio_id);
+        if frozen_balance > 0 {
+            ensure!(
+                PortfolioAssetBalances::get(portfolio_id, asset_id).saturating_sub(frozen_balance)
+                    >= transfer_value,
+                Error::<T>::PortfolioBalanceFrozen
+            );
+        }
+        Ok(())
+    }
+
     /// Returns [`AssetDetails::total_supply`] for the given `asset_id`.
     pub fn total_supply(asset_id: &AssetId) -> Balance {
         Assets::get(asset_id)
@@ -2516,6 +3187,16 @@ impl<T: Config> Module<T> {
             Error::<T>::AssetMetadataValueIsLocked
         );
 
+        // Check the value against the type definition of the key, if validation was enabled.
+        if ValidatedAssetMetadataKeys::get(&asset_id, &key) {
+            let type_def = Self::asset_metadata_type_def(&asset_id, &key)
+                .ok_or(Error::<T>::AssetMetadataTypeDefIsMissing)?;
+            ensure!(
+                type_def.decode_value(&value).is_ok(),
+                Error::<T>::InvalidAssetMetadataValue
+            );
+        }
+
         // Set asset metadata value for asset.
         AssetMetadataValues::insert(asset_id, key, &value);
 
@@ -2635,6 +3316,14 @@ impl<T: Config> AssetFnTrait<T::AccountId, T::RuntimeOrigin> for Module<T> {
         Self::generate_asset_id(caller_acc, false)
     }
 
+    fn ensure_portfolio_balance_unfrozen(
+        asset_id: &AssetId,
+        portfolio_id: &PortfolioId,
+        amount: Balance,
+    ) -> DispatchResult {
+        Self::ensure_portfolio_balance_unfrozen(asset_id, portfolio_id, amount)
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     fn register_unique_ticker(origin: T::RuntimeOrigin, ticker: Ticker) -> DispatchResult {
         Self::register_unique_ticker(origin, ticker)
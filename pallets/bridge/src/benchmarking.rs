@@ -0,0 +1,202 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::benchmarks;
+use frame_support::BoundedVec;
+use sp_std::convert::TryInto;
+
+use polymesh_common_utilities::benchs::{user, AccountIdOf, User, UserBuilder};
+use polymesh_common_utilities::TestUtilsFn;
+use polymesh_primitives::Signatory;
+
+use crate::*;
+
+const MAX_TXS: u32 = 100;
+const AMOUNT: Balance = 1_000;
+
+fn setup_admin<T: Config + TestUtilsFn<AccountIdOf<T>>>() -> User<T> {
+    let admin = user::<T>("admin", 0);
+    <Admin<T>>::put(admin.account());
+    admin
+}
+
+fn bridge_txs<T: Config + TestUtilsFn<AccountIdOf<T>>>(n: u32) -> Vec<BridgeTx<T::AccountId>> {
+    let recipient = user::<T>("recipient", 0);
+    (0..n)
+        .map(|nonce| BridgeTx {
+            nonce,
+            recipient: recipient.account(),
+            amount: AMOUNT,
+            tx_hash: H256::zero(),
+        })
+        .collect()
+}
+
+/// Stores `bridge_txs` as accepted transactions with the given `status`.
+fn store_txs<T: Config>(
+    bridge_txs: &[BridgeTx<T::AccountId>],
+    status: BridgeTxStatus,
+    execution_block: T::BlockNumber,
+) {
+    for bridge_tx in bridge_txs {
+        let detail = BridgeTxDetail {
+            amount: bridge_tx.amount,
+            status,
+            execution_block,
+            tx_hash: bridge_tx.tx_hash,
+        };
+        <BridgeTxDetails<T>>::insert(&bridge_tx.recipient, bridge_tx.nonce, detail);
+    }
+}
+
+/// Creates a 1-of-1 controller multisig with `relayer` as its only signer.
+fn setup_controller<T: Config + TestUtilsFn<AccountIdOf<T>>>(relayer: &User<T>) {
+    let creator = UserBuilder::<T>::default().generate_did().build("creator");
+    let controller = MultiSig::<T>::get_next_multisig_address(creator.account()).unwrap();
+    let signers: BoundedVec<_, _> = vec![relayer.account()].try_into().unwrap();
+    MultiSig::<T>::create_multisig(creator.origin().into(), signers, 1, None).unwrap();
+    let auth_id = <pallet_identity::Authorizations<T>>::iter_prefix_values(Signatory::Account(
+        relayer.account(),
+    ))
+    .map(|auth| auth.auth_id)
+    .max()
+    .unwrap();
+    MultiSig::<T>::accept_multisig_signer(relayer.origin().into(), auth_id).unwrap();
+    <Controller<T>>::put(controller);
+}
+
+benchmarks! {
+    where_clause { where T: TestUtilsFn<AccountIdOf<T>> }
+
+    change_controller {
+        let admin = setup_admin::<T>();
+        let controller = user::<T>("controller", 0);
+    }: _(admin.origin(), controller.account())
+    verify {
+        assert_eq!(Module::<T>::controller(), Some(controller.account()));
+    }
+
+    change_admin {
+        let admin = setup_admin::<T>();
+        let new_admin = user::<T>("new_admin", 0);
+    }: _(admin.origin(), new_admin.account())
+    verify {
+        assert_eq!(Module::<T>::admin(), Some(new_admin.account()));
+    }
+
+    change_timelock {
+        let admin = setup_admin::<T>();
+    }: _(admin.origin(), 100u32.into())
+
+    freeze {
+        let admin = setup_admin::<T>();
+    }: _(admin.origin())
+    verify {
+        assert!(Module::<T>::frozen());
+    }
+
+    unfreeze {
+        let admin = setup_admin::<T>();
+        Frozen::put(true);
+    }: _(admin.origin())
+    verify {
+        assert!(!Module::<T>::frozen());
+    }
+
+    change_bridge_limit {
+        let admin = setup_admin::<T>();
+    }: _(admin.origin(), AMOUNT, 100u32.into())
+
+    change_bridge_exempted {
+        let n in 0..MAX_TXS;
+
+        let admin = setup_admin::<T>();
+        let exempted = (0..n).map(|i| (IdentityId::from(i as u128), true)).collect();
+    }: _(admin.origin(), exempted)
+
+    propose_bridge_tx {
+        let relayer = UserBuilder::<T>::default().build("relayer");
+        setup_controller::<T>(&relayer);
+        let bridge_tx = bridge_txs::<T>(1).remove(0);
+    }: _(relayer.origin(), bridge_tx.clone())
+    verify {
+        assert!(Module::<T>::bridge_tx_proposals(&bridge_tx.recipient, bridge_tx.proposal_hash()).is_some());
+    }
+
+    handle_bridge_tx {
+        let controller = user::<T>("controller", 0);
+        <Controller<T>>::put(controller.account());
+        <Timelock<T>>::put(T::BlockNumber::from(10u32));
+        let bridge_tx = bridge_txs::<T>(1).remove(0);
+    }: _(controller.origin(), bridge_tx.clone())
+    verify {
+        let detail = Module::<T>::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+        assert_eq!(detail.status, BridgeTxStatus::Timelocked);
+    }
+
+    handle_scheduled_bridge_tx {
+        let bridge_tx = bridge_txs::<T>(1).remove(0);
+        store_txs::<T>(&[bridge_tx.clone()], BridgeTxStatus::Timelocked, Zero::zero());
+    }: _(RawOrigin::Root, bridge_tx.clone())
+    verify {
+        let detail = Module::<T>::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+        assert_eq!(detail.status, BridgeTxStatus::Handled);
+    }
+
+    freeze_txs {
+        let n in 1..MAX_TXS;
+
+        let admin = setup_admin::<T>();
+        let bridge_txs = bridge_txs::<T>(n);
+        store_txs::<T>(&bridge_txs, BridgeTxStatus::Timelocked, 10u32.into());
+    }: _(admin.origin(), bridge_txs)
+
+    unfreeze_txs {
+        let n in 1..MAX_TXS;
+
+        let admin = setup_admin::<T>();
+        let bridge_txs = bridge_txs::<T>(n);
+        store_txs::<T>(&bridge_txs, BridgeTxStatus::Frozen, Zero::zero());
+    }: _(admin.origin(), bridge_txs)
+
+    remove_txs {
+        let n in 1..MAX_TXS;
+
+        let admin = setup_admin::<T>();
+        let bridge_txs = bridge_txs::<T>(n);
+        store_txs::<T>(&bridge_txs, BridgeTxStatus::Frozen, Zero::zero());
+    }: _(admin.origin(), bridge_txs)
+
+    add_freeze_admin {
+        let admin = setup_admin::<T>();
+        let freeze_admin = user::<T>("freeze_admin", 0);
+    }: _(admin.origin(), freeze_admin.account())
+    verify {
+        assert!(Module::<T>::freeze_admins(freeze_admin.account()));
+    }
+
+    remove_freeze_admin {
+        let admin = setup_admin::<T>();
+        let freeze_admin = user::<T>("freeze_admin", 0);
+        <FreezeAdmins<T>>::insert(freeze_admin.account(), true);
+    }: _(admin.origin(), freeze_admin.account())
+    verify {
+        assert!(!Module::<T>::freeze_admins(freeze_admin.account()));
+    }
+
+    burn_for_bridge {
+        let caller = user::<T>("caller", 0);
+    }: _(caller.origin(), AMOUNT, H160::zero())
+}
@@ -13,19 +13,112 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-//! # Bridge from Ethereum to Polymesh
-//! Removed.
+//! # Bridge Module
+//!
+//! The bridge module implements a lock-and-mint bridge between Polymesh and another chain.
+//!
+//! ## Overview
+//!
+//! Tokens locked on the other chain are credited as POLYX on Polymesh by bridge transactions.
+//! Bridge transactions are proposed by relayers, which are the signers of the controller
+//! multisig. Once the multisig reaches its quorum, the transaction is accepted and executed after
+//! the timelock expires. During the timelock the admin can freeze the transaction. Each identity
+//! can only receive a limited amount of POLYX per block interval unless it is exempted from the
+//! bridge limit.
+//!
+//! POLYX can be returned to the other chain with `burn_for_bridge`, which burns the tokens and
+//! emits an event that relayers watch to release the locked tokens.
+//!
+//! ## Dispatchable Functions
+//!
+//! - `change_controller` - Changes the controller multisig account.
+//! - `change_admin` - Changes the bridge admin key.
+//! - `change_timelock` - Changes the timelock period of bridge transactions.
+//! - `freeze` - Freezes the bridge.
+//! - `unfreeze` - Unfreezes the bridge.
+//! - `change_bridge_limit` - Changes the amount of POLYX an identity can receive per block interval.
+//! - `change_bridge_exempted` - Exempts identities from the bridge limit or removes their exemption.
+//! - `propose_bridge_tx` - Proposes or approves a bridge transaction as a controller signer.
+//! - `batch_propose_bridge_tx` - Proposes or approves a batch of bridge transactions.
+//! - `handle_bridge_tx` - Accepts a bridge transaction as the controller or retries a pending one.
+//! - `handle_scheduled_bridge_tx` - Executes a bridge transaction whose timelock has expired.
+//! - `freeze_txs` - Freezes bridge transactions.
+//! - `unfreeze_txs` - Unfreezes bridge transactions.
+//! - `remove_txs` - Removes frozen bridge transactions.
+//! - `add_freeze_admin` - Adds an account that can freeze the bridge.
+//! - `remove_freeze_admin` - Removes an account that can freeze the bridge.
+//! - `burn_for_bridge` - Burns POLYX to be released on the other chain.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
 use codec::{Decode, Encode};
-use frame_support::{decl_module, decl_storage};
-use polymesh_primitives::{storage_migration_ver, Balance, IdentityId};
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::traits::schedule::{DispatchTime, Named};
+use frame_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
+use frame_support::weights::Weight;
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
+use frame_system::{ensure_root, ensure_signed, RawOrigin};
 use scale_info::TypeInfo;
-use sp_core::H256;
+use sp_core::hashing::blake2_256;
+use sp_core::{H160, H256};
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::{fmt::Debug, prelude::*, vec};
 
-pub trait Config: frame_system::Config {}
+use pallet_balances::Config as BalancesConfig;
+use pallet_identity::PermissionedCallOriginData;
+use pallet_multisig::Config as MultiSigConfig;
+use polymesh_common_utilities::constants::queue_priority::BRIDGE_TX_EXECUTION_PRIORITY;
+use polymesh_common_utilities::traits::identity::Config as IdentityConfig;
+use polymesh_common_utilities::GC_DID;
+use polymesh_primitives::constants::BRIDGE_TX_EXECUTION;
+use polymesh_primitives::{storage_migration_ver, Balance, IdentityId};
+
+type Balances<T> = pallet_balances::Module<T>;
+type Identity<T> = pallet_identity::Module<T>;
+type MultiSig<T> = pallet_multisig::Pallet<T>;
+type System<T> = frame_system::Pallet<T>;
+
+pub trait WeightInfo {
+    fn change_controller() -> Weight;
+    fn change_admin() -> Weight;
+    fn change_timelock() -> Weight;
+    fn freeze() -> Weight;
+    fn unfreeze() -> Weight;
+    fn change_bridge_limit() -> Weight;
+    fn change_bridge_exempted(n: u32) -> Weight;
+    fn propose_bridge_tx() -> Weight;
+    fn handle_bridge_tx() -> Weight;
+    fn handle_scheduled_bridge_tx() -> Weight;
+    fn freeze_txs(n: u32) -> Weight;
+    fn unfreeze_txs(n: u32) -> Weight;
+    fn remove_txs(n: u32) -> Weight;
+    fn add_freeze_admin() -> Weight;
+    fn remove_freeze_admin() -> Weight;
+    fn burn_for_bridge() -> Weight;
+
+    fn batch_propose_bridge_tx(n: u32) -> Weight {
+        Self::propose_bridge_tx().saturating_mul(n.into())
+    }
+}
+
+pub trait Config: frame_system::Config + BalancesConfig + MultiSigConfig {
+    /// The overarching event type.
+    type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
+
+    /// A call type used by the controller multisig and the scheduler.
+    type Proposal: From<Call<Self>>
+        + Into<<Self as IdentityConfig>::Proposal>
+        + Into<<Self as MultiSigConfig>::Proposal>;
+
+    /// Scheduler of timelocked bridge transactions.
+    type Scheduler: Named<Self::BlockNumber, <Self as Config>::Proposal, Self::SchedulerOrigin>;
+
+    /// Weight information for extrinsics of the bridge pallet.
+    type WeightInfo: WeightInfo;
+}
 
 /// The status of a bridge transaction.
 #[derive(Encode, Decode, TypeInfo)]
@@ -33,9 +126,9 @@ pub trait Config: frame_system::Config {}
 pub enum BridgeTxStatus {
     /// No such transaction in the system.
     Absent,
-    /// The transaction is missing a CDD or the bridge module is frozen.  The `u8` parameter is the
-    /// capped number of times the module tried processing this transaction.  It will be retried
-    /// automatically. Anyone can retry these manually.
+    /// The transaction is missing a CDD, exceeds the bridge limit or the bridge module is frozen.
+    /// The `u8` parameter is the capped number of times the module tried processing this
+    /// transaction. Anyone can retry these manually.
     Pending(u8),
     /// The transaction is frozen by the admin. It will not be retried automatically.
     Frozen,
@@ -67,6 +160,18 @@ pub struct BridgeTx<Account> {
     pub tx_hash: H256,
 }
 
+impl<Account: Encode> BridgeTx<Account> {
+    /// Converts a bridge transaction into a scheduler name for its timelocked execution.
+    pub fn execution_name(&self) -> Vec<u8> {
+        (BRIDGE_TX_EXECUTION, &self.recipient, self.nonce).encode()
+    }
+
+    /// Returns the hash of all fields of the bridge transaction, identifying its proposal.
+    pub fn proposal_hash(&self) -> H256 {
+        H256(self.using_encoded(blake2_256))
+    }
+}
+
 /// Additional details of a bridge transaction.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
@@ -86,9 +191,8 @@ storage_migration_ver!(0);
 
 decl_storage! {
     trait Store for Module<T: Config> as Bridge {
-        /// The multisig account of the bridge controller. The genesis signers accept their
-        /// authorizations and are able to get their proposals delivered. The bridge creator
-        /// transfers some POLY to their identity.
+        /// The multisig account of the bridge controller. Its signers are the relayers, which
+        /// propose bridge transactions until the multisig quorum is reached.
         pub Controller get(fn controller): Option<T::AccountId>;
 
         /// Details of bridge transactions identified with pairs of the recipient account and the
@@ -114,6 +218,7 @@ decl_storage! {
 
         /// The maximum number of bridged POLYX per identity within a set interval of
         /// blocks. Fields: POLYX amount and the block interval duration.
+        /// An interval duration of zero disables the limit.
         BridgeLimit get(fn bridge_limit) config(): (Balance, T::BlockNumber);
 
         /// Amount of POLYX bridged by the identity in last block interval. Fields: the bridged
@@ -123,12 +228,583 @@ decl_storage! {
         /// Identities not constrained by the bridge limit.
         BridgeLimitExempted get(fn bridge_exempted): map hasher(twox_64_concat) IdentityId => bool;
 
+        /// Controller multisig proposals of bridge transactions identified with pairs of the
+        /// recipient account and the [`BridgeTx::proposal_hash`] of the transaction.
+        /// Fields: the controller multisig and the proposal id.
+        pub BridgeTxProposals get(fn bridge_tx_proposals): double_map
+                hasher(blake2_128_concat) T::AccountId,
+                hasher(blake2_128_concat) H256
+            =>
+                Option<(T::AccountId, u64)>;
+
         /// Storage version.
         StorageVersion get(fn storage_version) build(|_| Version::new(0)): Version;
     }
 }
 
+decl_event! {
+    pub enum Event<T>
+    where
+        AccountId = <T as frame_system::Config>::AccountId,
+        BlockNumber = <T as frame_system::Config>::BlockNumber,
+    {
+        /// The controller multisig has been changed.
+        /// (caller DID, new controller)
+        ControllerChanged(IdentityId, AccountId),
+        /// The bridge admin key has been changed.
+        /// (caller DID, new admin)
+        AdminChanged(IdentityId, AccountId),
+        /// The timelock of bridge transactions has been changed.
+        /// (caller DID, new timelock)
+        TimelockChanged(IdentityId, BlockNumber),
+        /// POLYX has been credited to the recipient of a bridge transaction.
+        /// (recipient DID, bridge transaction)
+        Bridged(IdentityId, BridgeTx<AccountId>),
+        /// The bridge has been frozen.
+        /// (caller DID)
+        Frozen(IdentityId),
+        /// The bridge has been unfrozen.
+        /// (caller DID)
+        Unfrozen(IdentityId),
+        /// A bridge transaction has been frozen.
+        /// (caller DID, bridge transaction)
+        FrozenTx(IdentityId, BridgeTx<AccountId>),
+        /// A bridge transaction has been unfrozen.
+        /// (caller DID, bridge transaction)
+        UnfrozenTx(IdentityId, BridgeTx<AccountId>),
+        /// The bridge limit exemption of an identity has been updated.
+        /// (caller DID, identity, exempted)
+        ExemptedUpdated(IdentityId, IdentityId, bool),
+        /// The bridge limit has been updated.
+        /// (caller DID, POLYX amount, block interval duration)
+        BridgeLimitUpdated(IdentityId, Balance, BlockNumber),
+        /// A bridge transaction has been accepted and will be executed after the timelock.
+        /// (bridge transaction, execution block)
+        BridgeTxScheduled(BridgeTx<AccountId>, BlockNumber),
+        /// A bridge transaction could not be credited and has been left pending.
+        /// (bridge transaction, error)
+        BridgeTxFailed(BridgeTx<AccountId>, DispatchError),
+        /// A freeze admin has been added.
+        /// (caller DID, freeze admin)
+        FreezeAdminAdded(IdentityId, AccountId),
+        /// A freeze admin has been removed.
+        /// (caller DID, freeze admin)
+        FreezeAdminRemoved(IdentityId, AccountId),
+        /// A frozen bridge transaction has been removed.
+        /// (caller DID, bridge transaction)
+        TxRemoved(IdentityId, BridgeTx<AccountId>),
+        /// POLYX has been burnt to be released on the other chain.
+        /// (caller DID, account, amount, recipient on the other chain)
+        BridgeBurned(IdentityId, AccountId, Balance, H160),
+    }
+}
+
+decl_error! {
+    pub enum Error for Module<T: Config> {
+        /// The controller multisig has not been set.
+        ControllerNotSet,
+        /// The origin is not the controller or the admin.
+        BadCaller,
+        /// The origin is not the admin.
+        BadAdmin,
+        /// The recipient DID has no valid CDD.
+        NoValidCdd,
+        /// The bridge transaction has already been handled.
+        ProposalAlreadyHandled,
+        /// The bridge is frozen.
+        Frozen,
+        /// The bridge is not frozen.
+        NotFrozen,
+        /// The bridge transaction is frozen.
+        FrozenTx,
+        /// The bridge transaction does not exist.
+        NoSuchTx,
+        /// The identity's bridge limit has been reached.
+        BridgeLimitReached,
+        /// The bridged amount overflowed.
+        Overflow,
+        /// The timelocked bridge transaction could not be scheduled.
+        FailedToSchedule,
+    }
+}
+
 decl_module! {
     pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// Changes the controller multisig account. Only the admin or root can do that.
+        ///
+        /// # Arguments
+        /// * `controller` - The new controller account.
+        #[weight = <T as Config>::WeightInfo::change_controller()]
+        pub fn change_controller(origin, controller: T::AccountId) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            <Controller<T>>::put(&controller);
+            Self::deposit_event(RawEvent::ControllerChanged(did, controller));
+            Ok(())
+        }
+
+        /// Changes the bridge admin key. Only the admin or root can do that.
+        ///
+        /// # Arguments
+        /// * `admin` - The new admin key.
+        #[weight = <T as Config>::WeightInfo::change_admin()]
+        pub fn change_admin(origin, admin: T::AccountId) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            <Admin<T>>::put(&admin);
+            Self::deposit_event(RawEvent::AdminChanged(did, admin));
+            Ok(())
+        }
+
+        /// Changes the timelock period of new bridge transactions.
+        ///
+        /// # Arguments
+        /// * `timelock` - The number of blocks a bridge transaction waits before being executed.
+        #[weight = <T as Config>::WeightInfo::change_timelock()]
+        pub fn change_timelock(origin, timelock: T::BlockNumber) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            <Timelock<T>>::put(timelock);
+            Self::deposit_event(RawEvent::TimelockChanged(did, timelock));
+            Ok(())
+        }
+
+        /// Freezes the bridge. Bridge transactions are left pending while the bridge is frozen.
+        /// The admin and the freeze admins can do that.
+        #[weight = <T as Config>::WeightInfo::freeze()]
+        pub fn freeze(origin) -> DispatchResult {
+            let did = Self::ensure_freeze_admin(origin)?;
+            ensure!(!Self::frozen(), Error::<T>::Frozen);
+            Frozen::put(true);
+            Self::deposit_event(RawEvent::Frozen(did));
+            Ok(())
+        }
+
+        /// Unfreezes the bridge.
+        #[weight = <T as Config>::WeightInfo::unfreeze()]
+        pub fn unfreeze(origin) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            ensure!(Self::frozen(), Error::<T>::NotFrozen);
+            Frozen::put(false);
+            Self::deposit_event(RawEvent::Unfrozen(did));
+            Ok(())
+        }
+
+        /// Changes the amount of POLYX an identity can receive per block interval.
+        ///
+        /// # Arguments
+        /// * `amount` - The maximum amount of POLYX per interval.
+        /// * `duration` - The interval duration in blocks. Zero disables the limit.
+        #[weight = <T as Config>::WeightInfo::change_bridge_limit()]
+        pub fn change_bridge_limit(origin, amount: Balance, duration: T::BlockNumber) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            <BridgeLimit<T>>::put((amount, duration));
+            Self::deposit_event(RawEvent::BridgeLimitUpdated(did, amount, duration));
+            Ok(())
+        }
+
+        /// Exempts identities from the bridge limit or removes their exemption.
+        ///
+        /// # Arguments
+        /// * `exempted` - Pairs of identities and whether they are exempted.
+        #[weight = <T as Config>::WeightInfo::change_bridge_exempted(exempted.len() as u32)]
+        pub fn change_bridge_exempted(origin, exempted: Vec<(IdentityId, bool)>) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            for (exempted_did, exempt) in exempted {
+                if exempt {
+                    BridgeLimitExempted::insert(exempted_did, true);
+                } else {
+                    BridgeLimitExempted::remove(exempted_did);
+                }
+                Self::deposit_event(RawEvent::ExemptedUpdated(did, exempted_did, exempt));
+            }
+            Ok(())
+        }
+
+        /// Proposes a bridge transaction to the controller multisig or approves the existing
+        /// proposal of the same transaction. The caller must be a signer of the controller.
+        /// The transaction is accepted once the multisig reaches its quorum.
+        ///
+        /// # Arguments
+        /// * `bridge_tx` - The bridge transaction.
+        #[weight = <T as Config>::WeightInfo::propose_bridge_tx()]
+        pub fn propose_bridge_tx(origin, bridge_tx: BridgeTx<T::AccountId>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::base_propose_bridge_tx(sender, bridge_tx)
+        }
+
+        /// Proposes or approves a batch of bridge transactions. See `propose_bridge_tx`.
+        ///
+        /// # Arguments
+        /// * `bridge_txs` - The bridge transactions.
+        #[weight = <T as Config>::WeightInfo::batch_propose_bridge_tx(bridge_txs.len() as u32)]
+        pub fn batch_propose_bridge_tx(origin, bridge_txs: Vec<BridgeTx<T::AccountId>>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            for bridge_tx in bridge_txs {
+                Self::base_propose_bridge_tx(sender.clone(), bridge_tx)?;
+            }
+            Ok(())
+        }
+
+        /// Accepts a bridge transaction when called by the controller. The transaction is
+        /// executed after the timelock, or immediately if there is no timelock.
+        ///
+        /// Any other caller can only retry a pending bridge transaction.
+        ///
+        /// # Arguments
+        /// * `bridge_tx` - The bridge transaction.
+        #[weight = <T as Config>::WeightInfo::handle_bridge_tx()]
+        pub fn handle_bridge_tx(origin, bridge_tx: BridgeTx<T::AccountId>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            if Self::controller().as_ref() == Some(&sender) {
+                return Self::base_accept_bridge_tx(bridge_tx);
+            }
+            let detail = Self::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+            ensure!(
+                matches!(detail.status, BridgeTxStatus::Pending(_)),
+                Error::<T>::BadCaller
+            );
+            Self::handle_bridge_tx_now(bridge_tx, detail);
+            Ok(())
+        }
+
+        /// Executes a bridge transaction whose timelock has expired.
+        /// Only called by the scheduler.
+        ///
+        /// # Arguments
+        /// * `bridge_tx` - The bridge transaction.
+        #[weight = <T as Config>::WeightInfo::handle_scheduled_bridge_tx()]
+        pub fn handle_scheduled_bridge_tx(origin, bridge_tx: BridgeTx<T::AccountId>) -> DispatchResult {
+            ensure_root(origin)?;
+            let detail = Self::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+            ensure!(
+                detail.status == BridgeTxStatus::Timelocked,
+                Error::<T>::ProposalAlreadyHandled
+            );
+            Self::handle_bridge_tx_now(bridge_tx, detail);
+            Ok(())
+        }
+
+        /// Freezes timelocked or pending bridge transactions.
+        ///
+        /// # Arguments
+        /// * `bridge_txs` - The bridge transactions.
+        #[weight = <T as Config>::WeightInfo::freeze_txs(bridge_txs.len() as u32)]
+        pub fn freeze_txs(origin, bridge_txs: Vec<BridgeTx<T::AccountId>>) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            for bridge_tx in bridge_txs {
+                let mut detail = Self::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+                match detail.status {
+                    BridgeTxStatus::Absent => return Err(Error::<T>::NoSuchTx.into()),
+                    BridgeTxStatus::Handled => return Err(Error::<T>::ProposalAlreadyHandled.into()),
+                    BridgeTxStatus::Frozen => return Err(Error::<T>::FrozenTx.into()),
+                    BridgeTxStatus::Timelocked => {
+                        let _ = T::Scheduler::cancel_named(bridge_tx.execution_name());
+                    }
+                    BridgeTxStatus::Pending(_) => {}
+                }
+                detail.status = BridgeTxStatus::Frozen;
+                let bridge_tx = Self::stored_bridge_tx(bridge_tx, &detail);
+                <BridgeTxDetails<T>>::insert(&bridge_tx.recipient, bridge_tx.nonce, detail);
+                Self::deposit_event(RawEvent::FrozenTx(did, bridge_tx));
+            }
+            Ok(())
+        }
+
+        /// Unfreezes bridge transactions. Transactions whose timelock has not expired yet are
+        /// scheduled again, the others are executed immediately.
+        ///
+        /// # Arguments
+        /// * `bridge_txs` - The bridge transactions.
+        #[weight = <T as Config>::WeightInfo::unfreeze_txs(bridge_txs.len() as u32)]
+        pub fn unfreeze_txs(origin, bridge_txs: Vec<BridgeTx<T::AccountId>>) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            let current_block = System::<T>::block_number();
+            for bridge_tx in bridge_txs {
+                let mut detail = Self::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+                ensure!(detail.status == BridgeTxStatus::Frozen, Error::<T>::NotFrozen);
+                let bridge_tx = Self::stored_bridge_tx(bridge_tx, &detail);
+                Self::deposit_event(RawEvent::UnfrozenTx(did, bridge_tx.clone()));
+                if detail.execution_block > current_block {
+                    Self::schedule_bridge_tx(bridge_tx, detail.execution_block)?;
+                } else {
+                    detail.status = BridgeTxStatus::Pending(0);
+                    Self::handle_bridge_tx_now(bridge_tx, detail);
+                }
+            }
+            Ok(())
+        }
+
+        /// Removes frozen bridge transactions.
+        ///
+        /// # Arguments
+        /// * `bridge_txs` - The bridge transactions.
+        #[weight = <T as Config>::WeightInfo::remove_txs(bridge_txs.len() as u32)]
+        pub fn remove_txs(origin, bridge_txs: Vec<BridgeTx<T::AccountId>>) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            for bridge_tx in bridge_txs {
+                let detail = Self::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+                ensure!(detail.status == BridgeTxStatus::Frozen, Error::<T>::NotFrozen);
+                <BridgeTxDetails<T>>::remove(&bridge_tx.recipient, bridge_tx.nonce);
+                let bridge_tx = Self::stored_bridge_tx(bridge_tx, &detail);
+                <BridgeTxProposals<T>>::remove(&bridge_tx.recipient, bridge_tx.proposal_hash());
+                Self::deposit_event(RawEvent::TxRemoved(did, bridge_tx));
+            }
+            Ok(())
+        }
+
+        /// Adds an account that can freeze the bridge.
+        ///
+        /// # Arguments
+        /// * `freeze_admin` - The new freeze admin.
+        #[weight = <T as Config>::WeightInfo::add_freeze_admin()]
+        pub fn add_freeze_admin(origin, freeze_admin: T::AccountId) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            <FreezeAdmins<T>>::insert(&freeze_admin, true);
+            Self::deposit_event(RawEvent::FreezeAdminAdded(did, freeze_admin));
+            Ok(())
+        }
+
+        /// Removes an account that can freeze the bridge.
+        ///
+        /// # Arguments
+        /// * `freeze_admin` - The freeze admin to remove.
+        #[weight = <T as Config>::WeightInfo::remove_freeze_admin()]
+        pub fn remove_freeze_admin(origin, freeze_admin: T::AccountId) -> DispatchResult {
+            let did = Self::ensure_admin(origin)?;
+            <FreezeAdmins<T>>::remove(&freeze_admin);
+            Self::deposit_event(RawEvent::FreezeAdminRemoved(did, freeze_admin));
+            Ok(())
+        }
+
+        /// Burns POLYX from the caller's free balance. Relayers watch the emitted event to
+        /// release the same amount to `recipient` on the other chain.
+        ///
+        /// # Arguments
+        /// * `amount` - The amount of POLYX to burn.
+        /// * `recipient` - The recipient address on the other chain.
+        #[weight = <T as Config>::WeightInfo::burn_for_bridge()]
+        pub fn burn_for_bridge(origin, amount: Balance, recipient: H160) -> DispatchResult {
+            let PermissionedCallOriginData {
+                sender,
+                primary_did,
+                ..
+            } = Identity::<T>::ensure_origin_call_permissions(origin)?;
+            ensure!(!Self::frozen(), Error::<T>::Frozen);
+            // Withdraw the account balance and burn the resulting imbalance by dropping it.
+            let _ = <Balances<T> as Currency<T::AccountId>>::withdraw(
+                &sender,
+                amount,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            Self::deposit_event(RawEvent::BridgeBurned(primary_did, sender, amount, recipient));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    /// Ensures that `origin` is root or the admin, returning the DID used in events.
+    fn ensure_admin(origin: T::RuntimeOrigin) -> Result<IdentityId, DispatchError> {
+        Self::ensure_admin_or(origin, |_| false)
+    }
+
+    /// Ensures that `origin` is root, the admin or a freeze admin, returning the DID used in events.
+    fn ensure_freeze_admin(origin: T::RuntimeOrigin) -> Result<IdentityId, DispatchError> {
+        Self::ensure_admin_or(origin, |sender| Self::freeze_admins(sender))
+    }
+
+    fn ensure_admin_or(
+        origin: T::RuntimeOrigin,
+        is_allowed: impl FnOnce(&T::AccountId) -> bool,
+    ) -> Result<IdentityId, DispatchError> {
+        if ensure_root(origin.clone()).is_ok() {
+            return Ok(GC_DID);
+        }
+        let PermissionedCallOriginData {
+            sender,
+            primary_did,
+            ..
+        } = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        ensure!(
+            Self::admin().as_ref() == Some(&sender) || is_allowed(&sender),
+            Error::<T>::BadAdmin
+        );
+        Ok(primary_did)
+    }
+
+    /// Creates a controller multisig proposal for `bridge_tx`, or approves it if it already exists.
+    ///
+    /// Proposals are identified by all fields of `bridge_tx`, so a proposal with a different
+    /// amount or hash for the same recipient and nonce is never approved in its place.
+    fn base_propose_bridge_tx(
+        signer: T::AccountId,
+        bridge_tx: BridgeTx<T::AccountId>,
+    ) -> DispatchResult {
+        let controller = Self::controller().ok_or(Error::<T>::ControllerNotSet)?;
+        let detail = Self::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+        ensure!(
+            detail.status == BridgeTxStatus::Absent,
+            Error::<T>::ProposalAlreadyHandled
+        );
+        let origin: T::RuntimeOrigin = RawOrigin::Signed(signer).into();
+        let max_weight = Some(<T as Config>::WeightInfo::handle_bridge_tx());
+        let proposal_hash = bridge_tx.proposal_hash();
+        // Proposals of a previous controller and expired, rejected or executed proposals are replaced.
+        let active_proposal = Self::bridge_tx_proposals(&bridge_tx.recipient, proposal_hash)
+            .filter(|(proposal_controller, proposal_id)| {
+                *proposal_controller == controller
+                    && MultiSig::<T>::ensure_proposal_is_active(&controller, *proposal_id).is_ok()
+            });
+        match active_proposal {
+            Some((_, proposal_id)) => {
+                MultiSig::<T>::approve(origin, controller, proposal_id, max_weight)
+            }
+            None => {
+                let proposal_id = MultiSig::<T>::next_proposal_id(&controller);
+                <BridgeTxProposals<T>>::insert(
+                    &bridge_tx.recipient,
+                    proposal_hash,
+                    (controller.clone(), proposal_id),
+                );
+                let proposal =
+                    <T as Config>::Proposal::from(Call::<T>::handle_bridge_tx { bridge_tx }).into();
+                MultiSig::<T>::create_proposal(origin, controller, Box::new(proposal), None)
+            }
+        }
+        .map(|_| ())
+        .map_err(|e| e.error)
+    }
+
+    /// Accepts a new bridge transaction from the controller.
+    fn base_accept_bridge_tx(bridge_tx: BridgeTx<T::AccountId>) -> DispatchResult {
+        let detail = Self::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce);
+        ensure!(
+            detail.status == BridgeTxStatus::Absent,
+            Error::<T>::ProposalAlreadyHandled
+        );
+        let timelock = Self::timelock();
+        if timelock.is_zero() {
+            let detail = BridgeTxDetail {
+                amount: bridge_tx.amount,
+                tx_hash: bridge_tx.tx_hash,
+                ..detail
+            };
+            Self::handle_bridge_tx_now(bridge_tx, detail);
+            return Ok(());
+        }
+        let execution_block = System::<T>::block_number().saturating_add(timelock);
+        Self::schedule_bridge_tx(bridge_tx, execution_block)
+    }
+
+    /// Schedules the execution of `bridge_tx` at `execution_block` and marks it as timelocked.
+    fn schedule_bridge_tx(
+        bridge_tx: BridgeTx<T::AccountId>,
+        execution_block: T::BlockNumber,
+    ) -> DispatchResult {
+        let call = Call::<T>::handle_scheduled_bridge_tx {
+            bridge_tx: bridge_tx.clone(),
+        }
+        .into();
+        T::Scheduler::schedule_named(
+            bridge_tx.execution_name(),
+            DispatchTime::At(execution_block),
+            None,
+            BRIDGE_TX_EXECUTION_PRIORITY,
+            RawOrigin::Root.into(),
+            call,
+        )
+        .map_err(|_| Error::<T>::FailedToSchedule)?;
+        let detail = BridgeTxDetail {
+            amount: bridge_tx.amount,
+            status: BridgeTxStatus::Timelocked,
+            execution_block,
+            tx_hash: bridge_tx.tx_hash,
+        };
+        <BridgeTxDetails<T>>::insert(&bridge_tx.recipient, bridge_tx.nonce, detail);
+        Self::deposit_event(RawEvent::BridgeTxScheduled(bridge_tx, execution_block));
+        Ok(())
+    }
+
+    /// Returns `bridge_tx` with the amount and hash stored in `detail`, so callers can not alter them.
+    fn stored_bridge_tx(
+        bridge_tx: BridgeTx<T::AccountId>,
+        detail: &BridgeTxDetail<T::BlockNumber>,
+    ) -> BridgeTx<T::AccountId> {
+        BridgeTx {
+            amount: detail.amount,
+            tx_hash: detail.tx_hash,
+            ..bridge_tx
+        }
+    }
+
+    /// Credits `bridge_tx` now. If it can not be credited, the transaction is left pending.
+    fn handle_bridge_tx_now(
+        bridge_tx: BridgeTx<T::AccountId>,
+        mut detail: BridgeTxDetail<T::BlockNumber>,
+    ) {
+        let bridge_tx = Self::stored_bridge_tx(bridge_tx, &detail);
+        let current_block = System::<T>::block_number();
+        match Self::credit(&bridge_tx, current_block) {
+            Ok(did) => {
+                detail.status = BridgeTxStatus::Handled;
+                detail.execution_block = current_block;
+                <BridgeTxDetails<T>>::insert(&bridge_tx.recipient, bridge_tx.nonce, detail);
+                Self::deposit_event(RawEvent::Bridged(did, bridge_tx));
+            }
+            Err(error) => {
+                let attempts = match detail.status {
+                    BridgeTxStatus::Pending(attempts) => attempts.saturating_add(1),
+                    _ => 1,
+                };
+                detail.status = BridgeTxStatus::Pending(attempts);
+                <BridgeTxDetails<T>>::insert(&bridge_tx.recipient, bridge_tx.nonce, detail);
+                Self::deposit_event(RawEvent::BridgeTxFailed(bridge_tx, error));
+            }
+        }
+    }
+
+    /// Mints the POLYX of `bridge_tx` into the recipient's account, returning the recipient's DID.
+    fn credit(
+        bridge_tx: &BridgeTx<T::AccountId>,
+        current_block: T::BlockNumber,
+    ) -> Result<IdentityId, DispatchError> {
+        ensure!(!Self::frozen(), Error::<T>::Frozen);
+        let did = Identity::<T>::get_identity(&bridge_tx.recipient)
+            .filter(|did| Identity::<T>::has_valid_cdd(*did))
+            .ok_or(Error::<T>::NoValidCdd)?;
+        let bridged = Self::bridged_within_limit(did, bridge_tx.amount, current_block)?;
+        let _ = <Balances<T> as Currency<T::AccountId>>::deposit_creating(
+            &bridge_tx.recipient,
+            bridge_tx.amount,
+        );
+        if let Some(bridged) = bridged {
+            PolyxBridged::<T>::insert(did, bridged);
+        }
+        Ok(did)
+    }
+
+    /// Returns the amount bridged by `did` in the current interval after adding `amount`, or
+    /// `None` if `did` is not constrained by the bridge limit.
+    fn bridged_within_limit(
+        did: IdentityId,
+        amount: Balance,
+        current_block: T::BlockNumber,
+    ) -> Result<Option<(Balance, T::BlockNumber)>, DispatchError> {
+        let (limit, interval) = Self::bridge_limit();
+        if interval.is_zero() || Self::bridge_exempted(did) {
+            return Ok(None);
+        }
+        let current_interval = current_block / interval;
+        let (bridged, last_interval) = Self::polyx_bridged(did);
+        let total = if last_interval == current_interval {
+            bridged.checked_add(amount).ok_or(Error::<T>::Overflow)?
+        } else {
+            amount
+        };
+        ensure!(total <= limit, Error::<T>::BridgeLimitReached);
+        Ok(Some((total, current_interval)))
     }
 }
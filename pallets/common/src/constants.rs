@@ -45,6 +45,9 @@ pub mod queue_priority {
 
     /// Queue priority for the settlement instruction execution.
     pub const SETTLEMENT_INSTRUCTION_EXECUTION_PRIORITY: Priority = 100;
+
+    /// Queue priority for the execution of timelocked bridge transactions.
+    pub const BRIDGE_TX_EXECUTION_PRIORITY: Priority = 100;
 }
 
 // ERC1400 transfer status codes
@@ -80,3 +83,6 @@ pub const FIAT_TICKERS_RESERVATION_PALLET_ID: PalletId = PalletId(*b"pm/ftres");
 
 /// Base module constants
 pub const ENSURED_MAX_LEN: u32 = 2048;
+
+/// The maximum number of due periods of an issuance schedule that are issued in a single call.
+pub const MAX_ISSUANCES_PER_EXECUTION: u32 = 50;
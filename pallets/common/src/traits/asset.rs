@@ -24,7 +24,8 @@ use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::Vec;
 
 use polymesh_primitives::asset::{
-    AssetId, AssetName, AssetType, CustomAssetTypeId, FundingRoundName,
+    AssetId, AssetName, AssetType, CustomAssetTypeId, FundingRoundName, IssuanceSchedule,
+    IssuanceScheduleId,
 };
 use polymesh_primitives::asset_metadata::{
     AssetMetadataGlobalKey, AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataName,
@@ -185,6 +186,34 @@ decl_event! {
         /// An identity has unlinked a ticker from an asset.
         /// Parameters: [`IdentityId`] of caller, unlinked [`Ticker`], the asset identifier [`AssetId`].
         TickerUnlinkedFromAsset(IdentityId, Ticker, AssetId),
+        /// The holdings of an identity have been frozen.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the frozen [`IdentityId`], the frozen amount (`None` for all holdings).
+        IdentityBalanceFrozen(IdentityId, AssetId, IdentityId, Option<Balance>),
+        /// The holdings of an identity have been unfrozen.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the unfrozen [`IdentityId`].
+        IdentityBalanceUnfrozen(IdentityId, AssetId, IdentityId),
+        /// The holdings of a portfolio have been frozen.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the frozen [`PortfolioId`], the frozen amount (`None` for all holdings).
+        PortfolioBalanceFrozen(IdentityId, AssetId, PortfolioId, Option<Balance>),
+        /// The holdings of a portfolio have been unfrozen.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the unfrozen [`PortfolioId`].
+        PortfolioBalanceUnfrozen(IdentityId, AssetId, PortfolioId),
+        /// The allowance of a spender over the holdings of a portfolio has been set or spent.
+        /// Parameters: [`IdentityId`] of caller, [`PortfolioId`] of the holder, [`AssetId`] of the asset,
+        /// [`IdentityId`] of the spender and the remaining allowance.
+        AllowanceUpdated(IdentityId, PortfolioId, AssetId, IdentityId, Balance),
+        /// An issuance schedule has been created.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, [`IssuanceScheduleId`] and the [`IssuanceSchedule`].
+        IssuanceScheduleCreated(IdentityId, AssetId, IssuanceScheduleId, IssuanceSchedule),
+        /// An issuance schedule has been removed.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset and the [`IssuanceScheduleId`].
+        IssuanceScheduleRemoved(IdentityId, AssetId, IssuanceScheduleId),
+        /// The due issuances of a schedule have been executed.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, [`IssuanceScheduleId`] and the issued amount.
+        ScheduledIssuanceExecuted(IdentityId, AssetId, IssuanceScheduleId, Balance),
+        /// The validation of the values of a metadata key has been enabled or disabled.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the [`AssetMetadataKey`] and whether its values are validated.
+        AssetMetadataValidationUpdated(IdentityId, AssetId, AssetMetadataKey, bool),
     }
 }
 
@@ -222,6 +251,16 @@ pub trait WeightInfo {
     fn remove_mandatory_mediators(n: u32) -> Weight;
     fn link_ticker_to_asset_id() -> Weight;
     fn unlink_ticker_from_asset_id() -> Weight;
+    fn freeze_identity() -> Weight;
+    fn unfreeze_identity() -> Weight;
+    fn freeze_portfolio() -> Weight;
+    fn unfreeze_portfolio() -> Weight;
+    fn approve() -> Weight;
+    fn transfer_from() -> Weight;
+    fn create_issuance_schedule() -> Weight;
+    fn remove_issuance_schedule() -> Weight;
+    fn execute_issuance_schedule() -> Weight;
+    fn set_asset_metadata_validation() -> Weight;
 }
 
 pub trait AssetFnTrait<Account, Origin> {
@@ -243,6 +282,13 @@ pub trait AssetFnTrait<Account, Origin> {
     /// Returns the next [`AssetID`] for the `caller_acc`.
     fn generate_asset_id(caller_acc: Account) -> AssetId;
 
+    /// Returns `Ok` if `amount` can leave `portfolio_id` without using its frozen holdings of `asset_id`.
+    fn ensure_portfolio_balance_unfrozen(
+        asset_id: &AssetId,
+        portfolio_id: &PortfolioId,
+        amount: Balance,
+    ) -> DispatchResult;
+
     #[cfg(feature = "runtime-benchmarks")]
     fn register_unique_ticker(origin: Origin, ticker: Ticker) -> DispatchResult;
 
@@ -26,8 +26,8 @@ use polymesh_primitives::identity::limits::{
     MAX_ASSETS, MAX_EXTRINSICS, MAX_PALLETS, MAX_PORTFOLIOS,
 };
 use polymesh_primitives::{
-    secondary_key::SecondaryKey, AuthorizationData, Balance, CustomClaimTypeId, IdentityClaim,
-    IdentityId, Permissions, Ticker,
+    secondary_key::SecondaryKey, AuthorizationData, Balance, Claim, ClaimType, CustomClaimTypeId,
+    IdentityClaim, IdentityId, Permissions, Scope, Ticker,
 };
 
 use crate::traits::group::GroupTrait;
@@ -129,6 +129,9 @@ pub trait WeightInfo {
     fn add_secondary_keys_with_authorization(n: u32) -> Weight;
     fn revoke_claim_by_index() -> Weight;
     fn register_custom_claim_type(n: u32) -> Weight;
+    fn initiate_identity_recovery() -> Weight;
+    fn cancel_identity_recovery() -> Weight;
+    fn complete_identity_recovery(p: u32, c: u32, a: u32) -> Weight;
 
     /// Add complexity cost of Permissions to `add_secondary_keys_with_authorization` extrinsic.
     fn add_secondary_keys_full<AccountId>(
@@ -212,6 +215,13 @@ pub trait Config: CommonConfig + pallet_timestamp::Config + crate::traits::base:
 
     /// Maximum number of authorizations an identity can give.
     type MaxGivenAuths: Get<u32>;
+
+    /// Time that must pass between a CDD provider attesting an identity recovery
+    /// and the successor identity being able to complete it.
+    type IdentityRecoveryDelay: Get<Self::Moment>;
+
+    /// Notified of the claims added, updated or revoked. Used to keep claim based statistics up to date.
+    type ClaimHook: ClaimChangeHook;
 }
 
 decl_event!(
@@ -332,9 +342,57 @@ decl_event!(
         ///
         /// (Caller DID, Parent DID, Child DID)
         ChildDidUnlinked(IdentityId, IdentityId, IdentityId),
+
+        /// A CDD provider attested that an identity is recovered by a successor identity.
+        ///
+        /// (Old DID, successor DID, CDD provider DID, moment from which the recovery can be completed)
+        IdentityRecoveryInitiated(IdentityId, IdentityId, IdentityId, Moment),
+
+        /// A pending identity recovery was cancelled by the primary key of the old identity.
+        ///
+        /// (Old DID, successor DID)
+        IdentityRecoveryCancelled(IdentityId, IdentityId),
+
+        /// The portfolios, asset claims and pending authorizations of an identity were migrated to its successor.
+        ///
+        /// (Old DID, successor DID)
+        IdentityRecovered(IdentityId, IdentityId),
     }
 );
 
+/// Hook called when a claim is added, updated or revoked.
+pub trait ClaimChangeHook {
+    /// Called after the `claim_type` claim issued by `issuer` to `target` in `scope` has changed.
+    /// `old` and `new` are the unexpired claims before and after the change.
+    fn on_claim_change(
+        target: IdentityId,
+        issuer: IdentityId,
+        claim_type: ClaimType,
+        scope: Option<&Scope>,
+        old: Option<Claim>,
+        new: Option<Claim>,
+    );
+
+    /// Returns the maximum weight of `on_claim_change`.
+    fn on_claim_change_weight() -> Weight;
+}
+
+impl ClaimChangeHook for () {
+    fn on_claim_change(
+        _: IdentityId,
+        _: IdentityId,
+        _: ClaimType,
+        _: Option<&Scope>,
+        _: Option<Claim>,
+        _: Option<Claim>,
+    ) {
+    }
+
+    fn on_claim_change_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 pub trait IdentityFnTrait<AccountId> {
     fn get_identity(key: &AccountId) -> Option<IdentityId>;
     fn current_payer() -> Option<AccountId>;
@@ -9,7 +9,7 @@ use frame_support::decl_event;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use polymesh_primitives::asset::AssetId;
-use polymesh_primitives::asset_metadata::AssetMetadataKey;
+use polymesh_primitives::asset_metadata::{AssetMetadataKey, AssetMetadataValue};
 use polymesh_primitives::nft::{NFTCollectionId, NFTs};
 use polymesh_primitives::{IdentityId, NFTId, PortfolioId, PortfolioUpdateReason};
 
@@ -44,6 +44,25 @@ decl_event!(
             Option<PortfolioId>,
             PortfolioUpdateReason,
         ),
+        /// Emitted when the metadata value of an NFT is updated.
+        /// Contains the [`IdentityId`] of the caller, the [`AssetId`] of the collection, the [`NFTId`], the updated [`AssetMetadataKey`],
+        /// the previous [`AssetMetadataValue`] and the new [`AssetMetadataValue`].
+        NFTMetadataUpdated(
+            IdentityId,
+            AssetId,
+            NFTId,
+            AssetMetadataKey,
+            AssetMetadataValue,
+            AssetMetadataValue,
+        ),
+        /// Emitted when an NFT is locked in a custody portfolio and a fungible asset representing its fractions is issued.
+        /// Contains the [`IdentityId`] of the caller, the [`AssetId`] of the collection, the [`NFTId`], the [`AssetId`] of the fractions
+        /// and the [`PortfolioId`] of the custody portfolio.
+        NFTFractionalized(IdentityId, AssetId, NFTId, AssetId, PortfolioId),
+        /// Emitted when all fractions of an NFT are redeemed in exchange for the NFT.
+        /// Contains the [`IdentityId`] of the redeemer, the [`AssetId`] of the fractions, the [`AssetId`] of the collection, the [`NFTId`]
+        /// and the [`PortfolioId`] that received the NFT.
+        NFTFractionRedeemed(IdentityId, AssetId, AssetId, NFTId, PortfolioId),
     }
 );
 
@@ -53,6 +72,9 @@ pub trait WeightInfo {
     fn redeem_nft(n: u32) -> Weight;
     fn base_nft_transfer(n: u32) -> Weight;
     fn controller_transfer(n: u32) -> Weight;
+    fn update_nft_metadata() -> Weight;
+    fn fractionalize_nft() -> Weight;
+    fn redeem_nft_fraction() -> Weight;
 }
 
 pub trait NFTTrait<Origin> {
@@ -60,6 +82,8 @@ pub trait NFTTrait<Origin> {
     fn is_collection_key(asset_id: &AssetId, metadata_key: &AssetMetadataKey) -> bool;
     /// Updates the NFTOwner storage after moving funds.
     fn move_portfolio_owner(asset_id: AssetId, nft_id: NFTId, new_owner_portfolio: PortfolioId);
+    /// Returns `true` if `asset_id` represents the fractions of a locked NFT.
+    fn is_nft_fraction(asset_id: &AssetId) -> bool;
 
     #[cfg(feature = "runtime-benchmarks")]
     fn create_nft_collection(
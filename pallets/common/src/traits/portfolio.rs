@@ -97,6 +97,17 @@ pub trait PortfolioSubTrait<AccountId> {
 
     /// Returns `true` if the portfolio has pre-approved the receivement of `asset_id`, otherwise returns `false`.
     fn skip_portfolio_affirmation(portfolio_id: &PortfolioId, asset_id: &AssetId) -> bool;
+
+    /// Returns all portfolios in custody of `did`.
+    fn portfolios_in_custody(did: IdentityId) -> Vec<PortfolioId>;
+
+    /// Gives `to` the custody of `portfolios`, which are in custody of `from`.
+    ///
+    /// # Arguments
+    /// * `from` - Identity losing the custody of the portfolios.
+    /// * `to` - Identity taking over the custody.
+    /// * `portfolios` - Portfolios returned by [`Self::portfolios_in_custody`] for `from`.
+    fn transfer_custody(from: IdentityId, to: IdentityId, portfolios: &[PortfolioId]);
 }
 
 pub trait WeightInfo {
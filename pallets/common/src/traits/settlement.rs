@@ -6,8 +6,8 @@ use sp_std::vec::Vec;
 
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::settlement::{
-    AffirmationCount, AssetCount, InstructionId, Leg, LegId, ReceiptMetadata, SettlementType,
-    VenueDetails, VenueId, VenueType,
+    AffirmationCount, AssetCount, AutoAffirmRule, AutoAffirmRuleId, InstructionId, Leg, LegId,
+    ReceiptMetadata, SettlementType, VenueDetails, VenueId, VenueType,
 };
 use polymesh_primitives::{IdentityId, Memo, PortfolioId};
 
@@ -60,7 +60,7 @@ decl_event!(
         VenueSignersUpdated(IdentityId, VenueId, Vec<AccountId>, bool),
         /// Settlement manually executed (did, id)
         SettlementManuallyExecuted(IdentityId, InstructionId),
-        /// A new instruction has been created
+        /// A new instruction has been created, with its legs as stored (netted if the venue nets its instructions)
         /// (did, venue_id, instruction_id, settlement_type, trade_date, value_date, legs, memo)
         InstructionCreated(
             IdentityId,
@@ -86,6 +86,41 @@ decl_event!(
         /// An instruction with mediators has been created.
         /// Parameters: [`InstructionId`] of the instruction and the [`IdentityId`] of all mediators.
         InstructionMediators(InstructionId, BTreeSet<IdentityId>),
+        /// Netting has been enabled or disabled for a venue (did, venue_id, netting_enabled)
+        VenueNetting(IdentityId, VenueId, bool),
+        /// The fungible legs of an instruction have been netted.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction and the netted legs, in [`LegId`] order.
+        InstructionLegsNetted(IdentityId, InstructionId, Vec<Leg>),
+        /// An instruction with an affirmation deadline has been created.
+        /// Parameters: [`InstructionId`] of the instruction and the block number of the deadline.
+        InstructionAffirmationDeadline(InstructionId, BlockNumber),
+        /// An instruction has been rejected because not all affirmations were received before its deadline.
+        /// Parameters: [`InstructionId`] of the instruction.
+        InstructionAffirmationDeadlineReached(InstructionId),
+        /// The preimage of a hash-locked leg has been revealed, affirming the leg.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction, [`LegId`] of the leg and the preimage.
+        HashLockPreimageRevealed(IdentityId, InstructionId, LegId, Vec<u8>),
+        /// An instruction has been amended.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction, the amendment version,
+        /// trade_date, value_date, the new legs and memo.
+        InstructionAmended(
+            IdentityId,
+            InstructionId,
+            u32,
+            Option<Moment>,
+            Option<Moment>,
+            Vec<Leg>,
+            Option<Memo>,
+        ),
+        /// An auto-affirm rule has been added to a portfolio.
+        /// Parameters: [`IdentityId`] of the caller, [`PortfolioId`] of the portfolio, [`AutoAffirmRuleId`] of the new rule and the rule.
+        AutoAffirmRuleAdded(IdentityId, PortfolioId, AutoAffirmRuleId, AutoAffirmRule),
+        /// An auto-affirm rule has been removed from a portfolio.
+        /// Parameters: [`IdentityId`] of the caller, [`PortfolioId`] of the portfolio and [`AutoAffirmRuleId`] of the removed rule.
+        AutoAffirmRuleRemoved(IdentityId, PortfolioId, AutoAffirmRuleId),
+        /// A portfolio has automatically affirmed an instruction because of its auto-affirm rules.
+        /// Parameters: [`PortfolioId`] of the portfolio, [`InstructionId`] of the instruction and the rules that matched its legs.
+        AutoAffirmRulesApplied(PortfolioId, InstructionId, BTreeSet<AutoAffirmRuleId>),
     }
 );
 
@@ -115,26 +150,88 @@ pub trait WeightInfo {
     fn affirm_instruction_as_mediator() -> Weight;
     fn withdraw_affirmation_as_mediator() -> Weight;
     fn reject_instruction_as_mediator(f: u32, n: u32, o: u32) -> Weight;
+    fn set_venue_netting() -> Weight;
+    fn schedule_affirmation_deadline() -> Weight;
+    fn affirm_instruction_as_spender(f: u32) -> Weight;
+    fn affirm_with_preimage() -> Weight;
+    fn amend_instruction(f: u32, n: u32, o: u32) -> Weight;
+    fn add_auto_affirm_rule(c: u32) -> Weight;
+    fn remove_auto_affirm_rule() -> Weight;
+    fn auto_affirm_rules(l: u32, r: u32) -> Weight;
 
     fn add_and_affirm_with_mediators_legs(
         legs: &[Leg],
         portfolios: u32,
         n_mediators: u32,
+        max_rules: u32,
     ) -> Weight {
         let (f, n, o) = Self::get_transfer_by_asset(legs, portfolios);
         Self::add_and_affirm_with_mediators(f, n, o, n_mediators)
+            .saturating_add(Self::auto_affirm_rules_legs(legs, max_rules))
     }
-    fn add_instruction_with_mediators_legs(legs: &[Leg], n_mediators: u32) -> Weight {
+    fn add_instruction_with_mediators_legs(
+        legs: &[Leg],
+        n_mediators: u32,
+        max_rules: u32,
+    ) -> Weight {
         let (f, n, o) = Self::get_transfer_by_asset(legs, 0);
         Self::add_instruction_with_mediators(f, n, o, n_mediators)
+            .saturating_add(Self::auto_affirm_rules_legs(legs, max_rules))
+    }
+    fn add_instruction_with_deadline_legs(legs: &[Leg], max_rules: u32) -> Weight {
+        Self::add_instruction_legs(legs, max_rules)
+            .saturating_add(Self::schedule_affirmation_deadline())
+    }
+    fn add_and_affirm_with_deadline_legs(legs: &[Leg], portfolios: u32, max_rules: u32) -> Weight {
+        Self::add_and_affirm_instruction_legs(legs, portfolios, max_rules)
+            .saturating_add(Self::schedule_affirmation_deadline())
+    }
+    fn add_instruction_with_mediators_and_deadline_legs(
+        legs: &[Leg],
+        n_mediators: u32,
+        max_rules: u32,
+    ) -> Weight {
+        Self::add_instruction_with_mediators_legs(legs, n_mediators, max_rules)
+            .saturating_add(Self::schedule_affirmation_deadline())
     }
-    fn add_instruction_legs(legs: &[Leg]) -> Weight {
+    fn add_and_affirm_with_mediators_and_deadline_legs(
+        legs: &[Leg],
+        portfolios: u32,
+        n_mediators: u32,
+        max_rules: u32,
+    ) -> Weight {
+        Self::add_and_affirm_with_mediators_legs(legs, portfolios, n_mediators, max_rules)
+            .saturating_add(Self::schedule_affirmation_deadline())
+    }
+    fn add_instruction_legs(legs: &[Leg], max_rules: u32) -> Weight {
         let (f, n, o) = Self::get_transfer_by_asset(legs, 0);
-        Self::add_instruction(f, n, o)
+        Self::add_instruction(f, n, o).saturating_add(Self::auto_affirm_rules_legs(legs, max_rules))
     }
-    fn add_and_affirm_instruction_legs(legs: &[Leg], portfolios: u32) -> Weight {
+    fn add_and_affirm_instruction_legs(legs: &[Leg], portfolios: u32, max_rules: u32) -> Weight {
         let (f, n, o) = Self::get_transfer_by_asset(legs, portfolios);
         Self::add_and_affirm_instruction(f, n, o)
+            .saturating_add(Self::auto_affirm_rules_legs(legs, max_rules))
+    }
+    fn amend_instruction_input(
+        legs: &[Leg],
+        asset_count: Option<AssetCount>,
+        max_rules: u32,
+    ) -> Weight {
+        // The legs being replaced are also read, unlocked and removed
+        let (f, n, o) = Self::get_transfer_by_asset(legs, 0);
+        let amend_weight = match asset_count {
+            Some(asset_count) => Self::amend_instruction(
+                f.max(asset_count.fungible()),
+                n.max(asset_count.non_fungible()),
+                o.max(asset_count.off_chain()),
+            ),
+            None => Self::amend_instruction(f.max(10), n.max(100), o.max(10)),
+        };
+        amend_weight.saturating_add(Self::auto_affirm_rules_legs(legs, max_rules))
+    }
+    /// Each portfolio in `legs` can have at most `max_rules` auto-affirm rules.
+    fn auto_affirm_rules_legs(legs: &[Leg], max_rules: u32) -> Weight {
+        Self::auto_affirm_rules(legs.len() as u32, max_rules)
     }
     fn execute_manual_weight_limit(
         weight_limit: &Option<Weight>,
@@ -22,6 +22,8 @@ pub trait Config:
     type MaxStatsPerAsset: Get<u32>;
     /// Maximum transfer conditions that can be enabled for an Asset.
     type MaxTransferConditionsPerAsset: Get<u32>;
+    /// Maximum assets that can have stats on the custom claims of the same claim issuer.
+    type MaxAssetsPerCustomClaimIssuer: Get<u32>;
     /// Weights for extrinsics.
     type WeightInfo: WeightInfo;
 }
@@ -41,6 +43,8 @@ pub trait WeightInfo {
     fn update_asset_balance_stats(a: u32) -> Weight;
     fn active_asset_statistics_load(_a: u32) -> Weight;
     fn is_exempt() -> Weight;
+    fn update_asset_volume_stats() -> Weight;
+    fn volume_restriction() -> Weight;
     fn verify_requirements(i: u32) -> Weight;
     fn verify_requirements_loop(i: u32) -> Weight {
         Self::verify_requirements(i)
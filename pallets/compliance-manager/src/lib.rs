@@ -82,6 +82,7 @@ use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_module, decl_storage, ensure};
+use sp_runtime::SaturatedConversion;
 use sp_std::{convert::From, prelude::*};
 
 use pallet_base::ensure_length_ok;
@@ -94,13 +95,16 @@ use polymesh_primitives::compliance_manager::{
     AssetCompliance, AssetComplianceResult, ComplianceReport, ComplianceRequirement,
     ConditionReport, ConditionResult, RequirementReport,
 };
+use polymesh_primitives::condition::MAX_CONDITION_EXPRESSION_DEPTH;
 use polymesh_primitives::{
-    proposition, storage_migrate_on, storage_migration_ver, Claim, Condition, ConditionType,
-    Context, IdentityId, TargetIdentity, TrustedFor, TrustedIssuer, WeightMeter,
+    proposition, storage_migrate_on, storage_migration_ver, Claim, ClaimDatePredicate, ClaimType,
+    Condition, ConditionType, Context, IdentityId, Moment, Scope, TargetIdentity, TrustedFor,
+    TrustedIssuer, WeightMeter,
 };
 
 type ExternalAgents<T> = pallet_external_agents::Module<T>;
 type Identity<T> = pallet_identity::Module<T>;
+type Timestamp<T> = pallet_timestamp::Pallet<T>;
 
 storage_migration_ver!(1);
 
@@ -130,7 +134,11 @@ decl_error! {
         /// The worst case scenario of the compliance requirement is too complex.
         ComplianceRequirementTooComplex,
         /// The maximum weight limit for executing the function was exceeded.
-        WeightLimitExceeded
+        WeightLimitExceeded,
+        /// A condition expression tree is deeper than the maximum depth allowed.
+        ConditionExpressionTooDeep,
+        /// An `IsJurisdictionIn` condition has no country.
+        EmptyJurisdictionSet
     }
 }
 
@@ -440,16 +448,28 @@ impl<T: Config> Module<T> {
         claim: &'a Claim,
         issuers: &'a [TrustedIssuer],
     ) -> impl 'a + Iterator<Item = Claim> {
-        let claim_type = claim.claim_type();
-        let scope = claim.as_scope();
+        Self::fetch_claims_with(target, claim.claim_type(), claim.as_scope(), issuers, None)
+    }
 
+    /// Fetches all claims of `target` identity with `claim_type` and `scope`, generated by any of `issuers`
+    /// and whose dates satisfy `predicate` at the given time, if any.
+    fn fetch_claims_with<'a>(
+        target: IdentityId,
+        claim_type: ClaimType,
+        scope: Option<&'a Scope>,
+        issuers: &'a [TrustedIssuer],
+        predicate: Option<(ClaimDatePredicate, Moment)>,
+    ) -> impl 'a + Iterator<Item = Claim> {
         issuers
             .iter()
             .filter(move |issuer| issuer.is_trusted_for(claim_type))
             .filter_map(move |issuer| {
                 Identity::<T>::fetch_claim(target, claim_type, issuer.issuer, scope.cloned())
-                    .map(|id_claim| id_claim.claim)
             })
+            .filter(move |id_claim| {
+                predicate.map_or(true, |(predicate, now)| predicate.evaluate(id_claim, now))
+            })
+            .map(|id_claim| id_claim.claim)
     }
 
     /// Returns trusted issuers specified in `condition` if any,
@@ -477,9 +497,10 @@ impl<T: Config> Module<T> {
         weight_meter: &mut WeightMeter,
     ) -> Result<proposition::Context<impl 'a + Iterator<Item = Claim>>, DispatchError> {
         // Because of `-> impl Iterator`, we need to return a **single type** in each of the branches below.
-        // To do this, we use `Either<Either<MatchArm1, MatchArm2>, MatchArm3>`,
-        // equivalent to a 3-variant enum with iterators in each variant corresponding to the branches below.
-        // `Left(Left(arm1))`, `Left(Right(arm2))` and `Right(arm3)` correspond to arms 1, 2 and 3 respectively.
+        // To do this, we use `Either<Either<MatchArm1, MatchArm2>, Either<MatchArm3, MatchArm4>>`,
+        // equivalent to a 4-variant enum with iterators in each variant corresponding to the branches below.
+        // `Left(Left(arm1))`, `Left(Right(arm2))`, `Right(Left(arm3))` and `Right(Right(arm4))`
+        // correspond to arms 1, 2, 3 and 4 respectively.
         use either::Either::{Left, Right};
 
         let claims = match &condition.condition_type {
@@ -493,7 +514,50 @@ impl<T: Config> Module<T> {
                         condition.issuers.is_empty() as u32,
                     ),
                 )?;
-                Left(Left(Self::fetch_claims(id, claim, trusted_issuers)))
+                Left(Left(Self::fetch_claims_with(
+                    id,
+                    claim.claim_type(),
+                    claim.as_scope(),
+                    trusted_issuers,
+                    None,
+                )))
+            }
+            ConditionType::IsPresentWith(claim, predicate) => {
+                let trusted_issuers = Self::issuers_for(asset_id, condition, slot);
+                // Consumes the weight for this condition
+                Self::consume_weight_meter(
+                    weight_meter,
+                    <T as Config>::WeightInfo::is_condition_satisfied(
+                        trusted_issuers.len() as u32,
+                        condition.issuers.is_empty() as u32,
+                    ),
+                )?;
+                let now = Timestamp::<T>::get().saturated_into::<Moment>();
+                Left(Left(Self::fetch_claims_with(
+                    id,
+                    claim.claim_type(),
+                    claim.as_scope(),
+                    trusted_issuers,
+                    Some((*predicate, now)),
+                )))
+            }
+            ConditionType::IsJurisdictionIn(_, scope) => {
+                let trusted_issuers = Self::issuers_for(asset_id, condition, slot);
+                // Consumes the weight for this condition
+                Self::consume_weight_meter(
+                    weight_meter,
+                    <T as Config>::WeightInfo::is_condition_satisfied(
+                        trusted_issuers.len() as u32,
+                        condition.issuers.is_empty() as u32,
+                    ),
+                )?;
+                Left(Left(Self::fetch_claims_with(
+                    id,
+                    ClaimType::Jurisdiction,
+                    Some(scope),
+                    trusted_issuers,
+                    None,
+                )))
             }
             ConditionType::IsAnyOf(claims) | ConditionType::IsNoneOf(claims) => {
                 let trusted_issuers = Self::issuers_for(asset_id, condition, slot);
@@ -509,13 +573,28 @@ impl<T: Config> Module<T> {
                     Self::fetch_claims(id, claim, trusted_issuers)
                 })))
             }
+            ConditionType::Expression(expression) => {
+                let trusted_issuers = Self::issuers_for(asset_id, condition, slot);
+                let claims = expression.claims();
+                // Consumes the weight for this condition
+                Self::consume_weight_meter(
+                    weight_meter,
+                    <T as Config>::WeightInfo::is_condition_satisfied(
+                        (trusted_issuers.len() * claims.len()) as u32,
+                        condition.issuers.is_empty() as u32,
+                    ),
+                )?;
+                Right(Right(claims.into_iter().flat_map(move |claim| {
+                    Self::fetch_claims(id, claim, trusted_issuers)
+                })))
+            }
             ConditionType::IsIdentity(TargetIdentity::ExternalAgent) => {
                 // Consumes the weight for this condition
                 Self::consume_weight_meter(
                     weight_meter,
                     <T as Config>::WeightInfo::is_identity_condition(1),
                 )?;
-                Right(core::iter::empty())
+                Right(Left(core::iter::empty()))
             }
             ConditionType::IsIdentity(TargetIdentity::Specific(_)) => {
                 // Consumes the weight for this condition
@@ -523,7 +602,7 @@ impl<T: Config> Module<T> {
                     weight_meter,
                     <T as Config>::WeightInfo::is_identity_condition(0),
                 )?;
-                Right(core::iter::empty())
+                Right(Left(core::iter::empty()))
             }
         };
 
@@ -618,10 +697,30 @@ impl<T: Config> Module<T> {
 
     /// Verify that `asset_compliance`, with `default_issuer_count`,
     /// is within the maximum condition complexity allowed.
+    ///
+    /// Condition expression trees must also be within the maximum depth allowed,
+    /// and `IsJurisdictionIn` conditions must have at least one country.
     pub fn base_verify_compliance_complexity(
         asset_compliance: &[ComplianceRequirement],
         default_issuer_count: usize,
     ) -> DispatchResult {
+        ensure!(
+            asset_compliance
+                .iter()
+                .flat_map(|req| req.conditions())
+                .all(|condition| condition.depth() <= MAX_CONDITION_EXPRESSION_DEPTH),
+            Error::<T>::ConditionExpressionTooDeep
+        );
+        ensure!(
+            asset_compliance
+                .iter()
+                .flat_map(|req| req.conditions())
+                .all(|condition| !matches!(
+                    &condition.condition_type,
+                    ConditionType::IsJurisdictionIn(countries, _) if countries.is_empty()
+                )),
+            Error::<T>::EmptyJurisdictionSet
+        );
         let complexity = asset_compliance
             .iter()
             .flat_map(|req| req.conditions())
@@ -642,8 +741,8 @@ impl<T: Config> Module<T> {
         condition: impl Iterator<Item = &'a Condition>,
     ) -> DispatchResult {
         condition
-            .flat_map(|c| c.claims())
-            .try_for_each(Identity::<T>::ensure_custom_scopes_limited)
+            .flat_map(|c| c.scopes())
+            .try_for_each(Identity::<T>::ensure_custom_scope_limited)
     }
 
     fn dedup_and_ensure_requirement_limited(req: &mut ComplianceRequirement) -> DispatchResult {
@@ -842,16 +941,35 @@ impl<T: Config> Module<T> {
     ) -> Result<Vec<ConditionReport>, DispatchError> {
         let mut conditions_report = Vec::new();
         for condition in conditions {
-            let is_condition_satisfied = Self::is_condition_satisfied(
-                asset_id,
-                identity,
-                &condition,
-                &mut None,
-                weight_meter,
-            )?;
-            conditions_report.push(ConditionReport::new(condition, is_condition_satisfied));
-            *requirement_satisfied = *requirement_satisfied && is_condition_satisfied;
+            let condition_report =
+                Self::get_condition_report(asset_id, identity, condition, weight_meter)?;
+            *requirement_satisfied =
+                *requirement_satisfied && condition_report.is_condition_satisfied();
+            conditions_report.push(condition_report);
         }
         Ok(conditions_report)
     }
+
+    /// Returns the [`ConditionReport`] for the given `condition`.
+    /// The report of an expression tree contains the result of each node.
+    fn get_condition_report(
+        asset_id: &AssetId,
+        identity: IdentityId,
+        condition: Condition,
+        weight_meter: &mut WeightMeter,
+    ) -> Result<ConditionReport, DispatchError> {
+        if let ConditionType::Expression(expression) = &condition.condition_type {
+            let mut slot = None;
+            let context =
+                Self::fetch_context(identity, asset_id, &mut slot, &condition, weight_meter)?;
+            let expression_report = proposition::report(expression, context);
+            return Ok(ConditionReport::new_with_expression(
+                condition,
+                expression_report,
+            ));
+        }
+        let is_condition_satisfied =
+            Self::is_condition_satisfied(asset_id, identity, &condition, &mut None, weight_meter)?;
+        Ok(ConditionReport::new(condition, is_condition_satisfied))
+    }
 }
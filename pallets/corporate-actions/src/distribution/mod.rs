@@ -60,13 +60,18 @@
 //! - `push_benefit` pushes a benefit of an active capital distribution to a holder.
 //! - `reclaim` reclaims forfeited benefits of a capital distribution that has expired.
 //! - `remove_distribution` removes a capital distribution which hasn't reached its payment date yet.
+//!
+//! ### Public Functions
+//!
+//! - `entitlement` returns the record date balance, benefit and withholding tax of a holder in a distribution.
+//! - `unclaimed_distributions` returns the entitlements of a holder in the distributions of an asset it has yet to be paid for.
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 mod migrations;
 
 use crate as ca;
-use ca::{CAId, Config, Tax};
+use ca::{CAId, Config, CorporateAction, CorporateActions, Tax};
 use codec::{Decode, Encode};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
@@ -74,6 +79,7 @@ use frame_support::{
     ensure,
     traits::Get,
     weights::Weight,
+    IterableStorageDoubleMap,
 };
 use pallet_asset::{self as asset, checkpoint};
 use pallet_identity::{self as identity, PermissionedCallOriginData};
@@ -129,6 +135,26 @@ pub struct Distribution {
     pub expires_at: Option<Moment>,
 }
 
+/// The entitlement of a holder in a capital distribution.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct DistributionEntitlement {
+    /// The holder's balance of the asset at the record date,
+    /// or its current balance if no checkpoint has been recorded yet.
+    pub balance: Balance,
+    /// The benefit before withholding tax, i.e. `balance * per_share`.
+    pub gross: Balance,
+    /// The withholding tax % applied to the holder.
+    pub tax: Tax,
+    /// The amount of `gross` withheld as tax.
+    pub withheld: Balance,
+    /// The benefit transferred to the holder.
+    /// Rounded down to a whole unit for indivisible currencies.
+    pub net: Balance,
+    /// Has the holder been paid yet?
+    pub paid: bool,
+}
+
 /// Has the distribution expired?
 fn expired(expiry: Option<Moment>, now: Moment) -> bool {
     expiry.filter(|&e| e <= now).is_some()
@@ -527,16 +553,14 @@ impl<T: Config> Module<T> {
         // Ensure we have an active distribution.
         let mut dist = Self::ensure_active_distribution(ca_id)?;
 
-        // Fetch the CA data (cannot fail) + ensure CA targets DID.
+        // Fetch the CA data (cannot fail) + compute DID's benefit, tax, and gain.
         let ca = <CA<T>>::ensure_ca_exists(ca_id)?;
-        <CA<T>>::ensure_ca_targets(&ca, &holder)?;
-
-        // Extract CP + total supply at the record date.
-        let cp_id = <CA<T>>::record_date_cp(&ca, ca_id)?;
-
-        // Compute `balance * amount / supply`, i.e. DID's benefit.
-        let balance = <CA<T>>::balance_at_cp(holder, ca_id, cp_id);
-        let benefit = Self::benefit_of(balance, dist.per_share)?;
+        let DistributionEntitlement {
+            gross: benefit,
+            tax,
+            net: gain,
+            ..
+        } = Self::entitlement_of(holder, ca_id, &ca, &dist)?;
 
         // Ensure we have enough remaining.
         dist.remaining = dist
@@ -544,17 +568,6 @@ impl<T: Config> Module<T> {
             .checked_sub(benefit)
             .ok_or(Error::<T>::InsufficientRemainingAmount)?;
 
-        // Compute withholding tax + gain.
-        let tax = ca.tax_of(&holder);
-        let gain = benefit - tax * benefit;
-
-        // Round down to unit multiple if indivisible.
-        let gain = if Asset::<T>::is_divisible(&dist.currency) {
-            gain
-        } else {
-            gain / ONE_UNIT * ONE_UNIT
-        };
-
         with_transaction(|| {
             // Unlock `benefit` of `currency` from the calling agent's portfolio.
             Self::unlock(&dist, benefit)?;
@@ -589,6 +602,78 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Returns the entitlement of `holder` in the capital distribution attached to `ca_id`.
+    ///
+    /// Before the record date, the entitlement is computed from the current balance of `holder`.
+    pub fn entitlement(
+        holder: IdentityId,
+        ca_id: CAId,
+    ) -> Result<DistributionEntitlement, DispatchError> {
+        let dist = Self::ensure_distribution_exists(ca_id)?;
+        let ca = <CA<T>>::ensure_ca_exists(ca_id)?;
+        Self::entitlement_of(holder, ca_id, &ca, &dist)
+    }
+
+    /// Returns the entitlements of `holder` in the capital distributions of the CAs of `asset_id`
+    /// that haven't expired, that target `holder`, and for which `holder` has a non-zero benefit
+    /// that wasn't paid yet.
+    pub fn unclaimed_distributions(
+        holder: IdentityId,
+        asset_id: AssetId,
+    ) -> Vec<(CAId, DistributionEntitlement)> {
+        let now = <Checkpoint<T>>::now_unix();
+        CorporateActions::iter_prefix(asset_id)
+            .filter_map(|(local_id, ca)| {
+                let ca_id = CAId { asset_id, local_id };
+                let dist = Distributions::get(ca_id)?;
+                if expired(dist.expires_at, now) {
+                    return None;
+                }
+                let entitlement = Self::entitlement_of(holder, ca_id, &ca, &dist).ok()?;
+                (!entitlement.paid && entitlement.gross > 0).then_some((ca_id, entitlement))
+            })
+            .collect()
+    }
+
+    /// Computes the entitlement of `holder` in `dist`, attached to `ca`, identified by `ca_id`.
+    fn entitlement_of(
+        holder: IdentityId,
+        ca_id: CAId,
+        ca: &CorporateAction,
+        dist: &Distribution,
+    ) -> Result<DistributionEntitlement, DispatchError> {
+        // Ensure CA targets DID.
+        <CA<T>>::ensure_ca_targets(ca, &holder)?;
+
+        // Extract CP at the record date.
+        let cp_id = <CA<T>>::record_date_cp(ca, ca_id)?;
+
+        // Compute `balance * per_share`, i.e. DID's benefit.
+        let balance = <CA<T>>::balance_at_cp(holder, ca_id, cp_id);
+        let gross = Self::benefit_of(balance, dist.per_share)?;
+
+        // Compute withholding tax + gain.
+        let tax = ca.tax_of(&holder);
+        let withheld = tax * gross;
+        let net = gross - withheld;
+
+        // Round down to unit multiple if indivisible.
+        let net = if Asset::<T>::is_divisible(&dist.currency) {
+            net
+        } else {
+            net / ONE_UNIT * ONE_UNIT
+        };
+
+        Ok(DistributionEntitlement {
+            balance,
+            gross,
+            tax,
+            withheld,
+            net,
+            paid: HolderPaid::get((ca_id, holder)),
+        })
+    }
+
     /// Unlock `amount` of `dist.currency` in the `dist.from` portfolio.
     fn unlock(dist: &Distribution, amount: Balance) -> DispatchResult {
         <Portfolio<T>>::unlock_tokens(&dist.from, &dist.currency, amount)
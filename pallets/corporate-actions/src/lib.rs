@@ -117,6 +117,8 @@ use polymesh_primitives::{
 use polymesh_primitives_derive::VecU8StrongTyped;
 use scale_info::TypeInfo;
 use sp_arithmetic::Permill;
+#[cfg(feature = "std")]
+use sp_runtime::{Deserialize, Serialize};
 use sp_std::prelude::*;
 
 /// Representation of a % to tax, with 10^6 precision.
@@ -278,11 +280,13 @@ impl CorporateAction {
 /// A `AssetId`-local CA ID.
 /// By *local*, we mean that the same number might be used for a different `AssetId`
 /// to uniquely identify a different CA.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, Default, Debug)]
 pub struct LocalCAId(pub u32);
 impl_checked_inc!(LocalCAId);
 
 /// A unique global identifier for a CA.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, Debug)]
 pub struct CAId {
     /// The `[`AssetId`]` component used to disambiguate the `local` one.
@@ -23,6 +23,7 @@ use sp_std::prelude::*;
 use polymesh_common_utilities::benchs::{
     cdd_provider, user, user_without_did, AccountIdOf, UserBuilder,
 };
+use polymesh_common_utilities::portfolio::PortfolioSubTrait;
 use polymesh_common_utilities::traits::{identity::TargetIdAuthorization, TestUtilsFn};
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::identity::limits::{
@@ -404,4 +405,84 @@ benchmarks! {
         assert_ne!(id, Module::<T>::custom_claim_id_seq());
     }
 
+    initiate_identity_recovery {
+        let cdd = cdd_provider::<T>("cdd", 0);
+        let old = user::<T>("old", 0);
+        let successor = user::<T>("successor", 0);
+
+        let cdd_auth_id = Module::<T>::add_auth(
+            cdd.did(),
+            Signatory::Account(successor.account()),
+            AuthorizationData::AttestPrimaryKeyRotation(old.did()),
+            None,
+        )
+        .unwrap();
+    }: _(successor.origin, cdd_auth_id)
+    verify {
+        assert!(PendingRecoveries::<T>::contains_key(old.did()));
+    }
+
+    cancel_identity_recovery {
+        let cdd = cdd_provider::<T>("cdd", 0);
+        let old = user::<T>("old", 0);
+        let successor = user::<T>("successor", 0);
+
+        let recovery = IdentityRecovery {
+            successor: successor.did(),
+            attested_by: cdd.did(),
+            executable_at: T::Moment::default(),
+        };
+        PendingRecoveries::<T>::insert(old.did(), recovery);
+    }: _(old.origin)
+    verify {
+        assert!(!PendingRecoveries::<T>::contains_key(old.did()));
+    }
+
+    complete_identity_recovery {
+        // Number of portfolios held by the recovered identity, besides its default portfolio.
+        let p in 0 .. 100;
+        // Number of asset scoped claims.
+        let c in 0 .. 100;
+        // Number of pending authorizations.
+        let a in 0 .. 100;
+
+        let cdd = cdd_provider::<T>("cdd", 0);
+        let old = user::<T>("old", 0);
+        let successor = user::<T>("successor", 0);
+
+        let portfolios: Vec<_> = (0..p)
+            .map(|i| PortfolioId::default_portfolio(IdentityId::from(u128::from(i) + 1_000)))
+            .collect();
+        for pid in &portfolios {
+            T::Portfolio::transfer_custody(pid.did, old.did(), &[*pid]);
+        }
+        for i in 0..c {
+            let scope = Scope::Asset(AssetId::new((i as u128).to_be_bytes()));
+            Module::<T>::unverified_add_claim_with_scope(
+                old.did(),
+                Claim::KnowYourCustomer(scope.clone()),
+                Some(scope),
+                cdd.did(),
+                None,
+            );
+        }
+        for _ in 0..a {
+            Module::<T>::add_auth(
+                cdd.did(),
+                Signatory::Identity(old.did()),
+                AuthorizationData::RotatePrimaryKey,
+                None,
+            )
+            .unwrap();
+        }
+        let recovery = IdentityRecovery {
+            successor: successor.did(),
+            attested_by: cdd.did(),
+            executable_at: T::Moment::default(),
+        };
+        PendingRecoveries::<T>::insert(old.did(), recovery);
+    }: _(successor.origin, old.did(), p + 1, c, a)
+    verify {
+        assert!(!PendingRecoveries::<T>::contains_key(old.did()));
+    }
 }
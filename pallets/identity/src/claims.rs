@@ -28,7 +28,7 @@ use polymesh_common_utilities::{
     protocol_fee::ProtocolOp,
     traits::{
         group::{GroupTrait, InactiveMember},
-        identity::{Config, RawEvent},
+        identity::{ClaimChangeHook, Config, RawEvent},
     },
     SystematicIssuers,
 };
@@ -136,7 +136,14 @@ impl<T: Config> CddClaimChecker<T> {
 impl<T: Config> Module<T> {
     /// Ensure that any `Scope::Custom(data)` is limited to 32 characters.
     pub fn ensure_custom_scopes_limited(claim: &Claim) -> DispatchResult {
-        if let Some(Scope::Custom(data)) = claim.as_scope() {
+        claim
+            .as_scope()
+            .map_or(Ok(()), Self::ensure_custom_scope_limited)
+    }
+
+    /// Ensure that `scope`, if it is a `Scope::Custom(data)`, is limited to 32 characters.
+    pub fn ensure_custom_scope_limited(scope: &Scope) -> DispatchResult {
+        if let Scope::Custom(data) = scope {
             ensure!(data.len() <= 32, Error::<T>::CustomScopeTooLong);
         }
         Ok(())
@@ -289,7 +296,9 @@ impl<T: Config> Module<T> {
     ) {
         let claim_type = claim.claim_type();
         let last_update_date = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
-        let issuance_date = Self::fetch_claim(target, claim_type, issuer, scope.clone())
+        let old_claim = Self::fetch_claim(target, claim_type, issuer, scope.clone());
+        let issuance_date = old_claim
+            .as_ref()
             .map_or(last_update_date, |id_claim| id_claim.issuance_date);
 
         let expiry = expiry.map(|m| m.saturated_into::<u64>());
@@ -303,6 +312,14 @@ impl<T: Config> Module<T> {
         };
 
         Claims::insert(&pk, &sk, id_claim.clone());
+        T::ClaimHook::on_claim_change(
+            target,
+            issuer,
+            claim_type,
+            sk.scope.as_ref(),
+            old_claim.map(|id_claim| id_claim.claim),
+            Some(id_claim.claim.clone()),
+        );
         Self::deposit_event(RawEvent::ClaimAdded(target, id_claim));
     }
 
@@ -340,9 +357,18 @@ impl<T: Config> Module<T> {
         issuer: IdentityId,
         scope: Option<Scope>,
     ) -> DispatchResult {
+        let old_claim = Self::fetch_claim(target, claim_type, issuer, scope.clone());
         let (pk, sk) = Self::get_claim_keys(target, claim_type, issuer, scope);
         // Remove the claim.
         let claim = Claims::take(&pk, &sk).ok_or(Error::<T>::ClaimDoesNotExist)?;
+        T::ClaimHook::on_claim_change(
+            target,
+            issuer,
+            claim_type,
+            sk.scope.as_ref(),
+            old_claim.map(|id_claim| id_claim.claim),
+            None,
+        );
         // Emit claim revoked event.
         Self::deposit_event(RawEvent::ClaimRevoked(target, claim));
         Ok(())
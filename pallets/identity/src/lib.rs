@@ -44,6 +44,16 @@
 //! see [freeze_secondary_keys](./struct.Module.html#method.freeze_secondary_keys)
 //! see [unfreeze_secondary_keys](./struct.Module.html#method.unfreeze_secondary_keys)
 //!
+//! ## Identity recovery
+//!
+//! When the keys of an identity are lost, a CDD provider can attest that another identity is its
+//! successor. After a delay, during which the primary key of the old identity can cancel it, the
+//! successor takes over the custody of the old portfolios and its pending authorizations, and receives
+//! a copy of its asset scoped claims, without any token being transferred.
+//!
+//! see [initiate_identity_recovery](./struct.Module.html#method.initiate_identity_recovery)
+//! see [complete_identity_recovery](./struct.Module.html#method.complete_identity_recovery)
+//!
 //! ## Claim Unique Index
 //!
 //! Each claim is identified by a unique index, which is composed by two keys in order to optimise
@@ -74,6 +84,9 @@
 //! - `add_authorization` - Adds an authorization.
 //! - `remove_authorization` - Removes an authorization.
 //! - `add_secondary_keys_with_authorization` - Adds secondary keys to target identity `id`.
+//! - `initiate_identity_recovery` - Starts the recovery of an identity attested by a CDD provider.
+//! - `cancel_identity_recovery` - Cancels the pending recovery of the caller's identity.
+//! - `complete_identity_recovery` - Migrates a recovered identity to its successor.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
@@ -84,11 +97,15 @@ pub mod benchmarking;
 mod auth;
 mod claims;
 mod keys;
+mod recovery;
 mod ticker_migrations;
 pub mod types;
 
 pub use polymesh_common_utilities::traits::identity::WeightInfo;
-pub use types::{Claim1stKey, Claim2ndKey, DidStatus, PermissionedCallOriginData, RpcDidRecords};
+pub use types::{
+    Claim1stKey, Claim2ndKey, DidStatus, IdentityRecovery, PermissionedCallOriginData,
+    RpcDidRecords,
+};
 
 use core::convert::From;
 
@@ -102,8 +119,8 @@ use frame_support::traits::{ChangeMembers, Currency, EnsureOrigin, Get, Initiali
 use frame_support::{decl_error, decl_module, decl_storage};
 use polymesh_common_utilities::protocol_fee::{ChargeProtocolFee, ProtocolOp};
 use polymesh_common_utilities::traits::identity::{
-    AuthorizationNonce, Config, CreateChildIdentityWithAuth, IdentityFnTrait, RawEvent,
-    SecondaryKeyWithAuth,
+    AuthorizationNonce, ClaimChangeHook, Config, CreateChildIdentityWithAuth, IdentityFnTrait,
+    RawEvent, SecondaryKeyWithAuth,
 };
 use polymesh_common_utilities::{SystematicIssuers, GC_DID};
 use polymesh_primitives::{
@@ -207,7 +224,11 @@ decl_storage! {
             map hasher(blake2_128_concat) Signatory<T::AccountId> => Option<u64>;
 
         /// Controls the authorization id.
-        pub CurrentAuthId get(fn current_auth_id): u64
+        pub CurrentAuthId get(fn current_auth_id): u64;
+
+        /// Pending recoveries of identities, keyed by the identity being recovered.
+        pub PendingRecoveries get(fn pending_recovery):
+            map hasher(identity) IdentityId => Option<IdentityRecovery<T::Moment>>;
     }
     add_extra_genesis {
         // Identities at genesis.
@@ -281,6 +302,8 @@ decl_module! {
 
         const InitialPOLYX: <T::Balances as Currency<T::AccountId>>::Balance = T::InitialPOLYX::get();
 
+        const IdentityRecoveryDelay: T::Moment = T::IdentityRecoveryDelay::get();
+
         /// Register `target_account` with a new Identity.
         ///
         /// # Failure
@@ -357,7 +380,7 @@ decl_module! {
         /// Adds a new claim record or edits an existing one.
         ///
         /// Only called by did_issuer's secondary key.
-        #[weight = <T as Config>::WeightInfo::add_claim()]
+        #[weight = <T as Config>::WeightInfo::add_claim().saturating_add(T::ClaimHook::on_claim_change_weight())]
         pub fn add_claim(
             origin,
             target: IdentityId,
@@ -377,7 +400,10 @@ decl_module! {
         }
 
         /// Marks the specified claim as revoked.
-        #[weight = (<T as Config>::WeightInfo::revoke_claim(), revoke_claim_class(claim.claim_type()))]
+        #[weight = (
+            <T as Config>::WeightInfo::revoke_claim().saturating_add(T::ClaimHook::on_claim_change_weight()),
+            revoke_claim_class(claim.claim_type())
+        )]
         pub fn revoke_claim(origin, target: IdentityId, claim: Claim) -> DispatchResult {
             let issuer = Self::ensure_perms(origin)?;
             let claim_type = claim.claim_type();
@@ -445,7 +471,10 @@ decl_module! {
         /// `claim_type`, and `scope`.
         ///
         /// Please note that `origin` must be the issuer of the target claim.
-        #[weight = (<T as Config>::WeightInfo::revoke_claim_by_index(), revoke_claim_class(*claim_type))]
+        #[weight = (
+            <T as Config>::WeightInfo::revoke_claim_by_index().saturating_add(T::ClaimHook::on_claim_change_weight()),
+            revoke_claim_class(*claim_type)
+        )]
         pub fn revoke_claim_by_index(origin, target: IdentityId, claim_type: ClaimType, scope: Option<Scope>) -> DispatchResult {
             let issuer = Self::ensure_perms(origin)?;
             Self::base_revoke_claim(target, claim_type, issuer, scope)
@@ -597,6 +626,67 @@ decl_module! {
         pub fn unlink_child_identity(origin, child_did: IdentityId) {
             Self::base_unlink_child_identity(origin, child_did)?;
         }
+
+        /// Starts the recovery of an identity whose keys were lost, using the caller's identity as its successor.
+        ///
+        /// The caller must be the primary key of the successor identity and accepts an
+        /// `AttestPrimaryKeyRotation(old_did)` authorization given to it by a CDD provider.
+        /// The recovery can be completed once `IdentityRecoveryDelay` has passed,
+        /// and until then it can be cancelled by the primary key of the old identity.
+        ///
+        /// # Arguments
+        /// - `cdd_auth_id` the authorization from the CDD provider attesting the recovery.
+        ///
+        /// # Errors
+        /// - `KeyNotAllowed` only the primary key of the successor can initiate a recovery.
+        /// - `TargetHasNoCdd` the successor identity has no valid CDD claim.
+        /// - `NotCddProviderAttestation` the authorization was not given by a CDD provider.
+        /// - `CannotRecoverToSameIdentity` the attested identity is the successor itself.
+        /// - `IdentityRecoveryAlreadyPending` the attested identity already has a pending recovery.
+        #[weight = <T as Config>::WeightInfo::initiate_identity_recovery()]
+        pub fn initiate_identity_recovery(origin, cdd_auth_id: u64) {
+            Self::base_initiate_identity_recovery(origin, cdd_auth_id)?;
+        }
+
+        /// Cancels the pending recovery of the caller's identity.
+        ///
+        /// # Errors
+        /// - `KeyNotAllowed` only the primary key of the identity being recovered can cancel the recovery.
+        /// - `NoPendingIdentityRecovery` the caller's identity has no pending recovery.
+        #[weight = <T as Config>::WeightInfo::cancel_identity_recovery()]
+        pub fn cancel_identity_recovery(origin) {
+            Self::base_cancel_identity_recovery(origin)?;
+        }
+
+        /// Completes the recovery of `old_did` once its delay has passed.
+        ///
+        /// The caller's identity becomes the custodian of all portfolios owned or held by `old_did`,
+        /// receives a copy of its asset scoped claims and takes over its pending authorizations.
+        /// No tokens are transferred, and `old_did` keeps owning its portfolios and its claims.
+        ///
+        /// # Arguments
+        /// - `old_did` the identity being recovered.
+        /// - `max_portfolios` the maximum number of portfolios to migrate.
+        /// - `max_claims` the maximum number of asset scoped claims to copy.
+        /// - `max_auths` the maximum number of pending authorizations to migrate.
+        ///
+        /// # Errors
+        /// - `KeyNotAllowed` only the primary key of the successor can complete the recovery.
+        /// - `NoPendingIdentityRecovery` `old_did` has no pending recovery.
+        /// - `NotRecoverySuccessor` the caller's identity is not the attested successor.
+        /// - `IdentityRecoveryDelayNotElapsed` the recovery delay has not passed yet.
+        /// - `TooManyRecoveryItems` more portfolios, claims or authorizations than allowed would be migrated.
+        #[weight = <T as Config>::WeightInfo::complete_identity_recovery(*max_portfolios, *max_claims, *max_auths)
+            .saturating_add(T::ClaimHook::on_claim_change_weight().saturating_mul(*max_claims as u64))]
+        pub fn complete_identity_recovery(
+            origin,
+            old_did: IdentityId,
+            max_portfolios: u32,
+            max_claims: u32,
+            max_auths: u32,
+        ) {
+            Self::base_complete_identity_recovery(origin, old_did, max_portfolios, max_claims, max_auths)?;
+        }
     }
 }
 
@@ -669,6 +759,18 @@ decl_error! {
         ExceptNotAllowedForExtrinsics,
         /// Maximum number of given authorizations was exceeded.
         ExceededNumberOfGivenAuths,
+        /// An identity cannot be recovered by itself.
+        CannotRecoverToSameIdentity,
+        /// The identity already has a pending recovery.
+        IdentityRecoveryAlreadyPending,
+        /// The identity has no pending recovery.
+        NoPendingIdentityRecovery,
+        /// The caller is not the successor of the pending recovery.
+        NotRecoverySuccessor,
+        /// The recovery delay has not passed yet.
+        IdentityRecoveryDelayNotElapsed,
+        /// More portfolios, claims and authorizations need to be migrated than allowed.
+        TooManyRecoveryItems,
     }
 }
 
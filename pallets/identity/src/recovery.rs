@@ -0,0 +1,224 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::types::IdentityRecovery;
+use crate::{
+    Authorizations, AuthorizationsGiven, Claim1stKey, Claim2ndKey, Claims, Config, Error, Module,
+    OutdatedAuthorizations, PendingRecoveries, RawEvent,
+};
+use frame_support::dispatch::DispatchResult;
+use frame_support::traits::Get as _;
+use frame_support::{ensure, StorageDoubleMap, StorageMap};
+use polymesh_common_utilities::group::GroupTrait;
+use polymesh_common_utilities::identity::ClaimChangeHook;
+use polymesh_common_utilities::portfolio::PortfolioSubTrait;
+use polymesh_primitives::{
+    extract_auth, Authorization, ClaimType, IdentityClaim, IdentityId, Scope, Signatory,
+};
+use sp_runtime::traits::{SaturatedConversion, Saturating};
+use sp_std::vec::Vec;
+
+/// Claim types whose asset scoped claims are copied to the successor of a recovered identity.
+///
+/// CDD claims are issued to the successor by its own CDD provider,
+/// and custom claim types cannot be enumerated, so they must be issued again.
+const RECOVERED_CLAIM_TYPES: [ClaimType; 8] = [
+    ClaimType::Accredited,
+    ClaimType::Affiliate,
+    ClaimType::BuyLockup,
+    ClaimType::SellLockup,
+    ClaimType::KnowYourCustomer,
+    ClaimType::Jurisdiction,
+    ClaimType::Exempted,
+    ClaimType::Blocked,
+];
+
+impl<T: Config> Module<T> {
+    /// Starts the recovery of the identity attested by the CDD provider authorization `cdd_auth_id`,
+    /// using the caller's identity as the successor.
+    pub(crate) fn base_initiate_identity_recovery(
+        origin: T::RuntimeOrigin,
+        cdd_auth_id: u64,
+    ) -> DispatchResult {
+        let (sender, successor) = Self::ensure_primary_key(origin)?;
+        ensure!(Self::has_valid_cdd(successor), Error::<T>::TargetHasNoCdd);
+
+        Self::accept_auth_with(
+            &Signatory::Account(sender),
+            cdd_auth_id,
+            |data, attested_by| {
+                let old_did = extract_auth!(data, AttestPrimaryKeyRotation(a));
+                // Attestor must be a CDD service provider.
+                ensure!(
+                    T::CddServiceProviders::is_member(&attested_by),
+                    Error::<T>::NotCddProviderAttestation
+                );
+                Self::ensure_id_record_exists(old_did)?;
+                ensure!(
+                    old_did != successor,
+                    Error::<T>::CannotRecoverToSameIdentity
+                );
+                ensure!(
+                    !PendingRecoveries::<T>::contains_key(old_did),
+                    Error::<T>::IdentityRecoveryAlreadyPending
+                );
+
+                let executable_at = <pallet_timestamp::Pallet<T>>::get()
+                    .saturating_add(T::IdentityRecoveryDelay::get());
+                let recovery = IdentityRecovery {
+                    successor,
+                    attested_by,
+                    executable_at,
+                };
+                PendingRecoveries::<T>::insert(old_did, recovery);
+                Self::deposit_event(RawEvent::IdentityRecoveryInitiated(
+                    old_did,
+                    successor,
+                    attested_by,
+                    executable_at,
+                ));
+                Ok(())
+            },
+        )
+    }
+
+    /// Cancels the pending recovery of the caller's identity.
+    pub(crate) fn base_cancel_identity_recovery(origin: T::RuntimeOrigin) -> DispatchResult {
+        let (_, old_did) = Self::ensure_primary_key(origin)?;
+        let recovery =
+            PendingRecoveries::<T>::take(old_did).ok_or(Error::<T>::NoPendingIdentityRecovery)?;
+        Self::deposit_event(RawEvent::IdentityRecoveryCancelled(
+            old_did,
+            recovery.successor,
+        ));
+        Ok(())
+    }
+
+    /// Completes the pending recovery of `old_did`, migrating the custody of its portfolios and its pending
+    /// authorizations to the caller's identity, and copying its asset scoped claims.
+    ///
+    /// Nothing is migrated unless the items fit in `max_portfolios`, `max_claims` and `max_auths`.
+    pub(crate) fn base_complete_identity_recovery(
+        origin: T::RuntimeOrigin,
+        old_did: IdentityId,
+        max_portfolios: u32,
+        max_claims: u32,
+        max_auths: u32,
+    ) -> DispatchResult {
+        let (_, successor) = Self::ensure_primary_key(origin)?;
+        let recovery =
+            PendingRecoveries::<T>::get(old_did).ok_or(Error::<T>::NoPendingIdentityRecovery)?;
+        ensure!(
+            recovery.successor == successor,
+            Error::<T>::NotRecoverySuccessor
+        );
+        ensure!(
+            <pallet_timestamp::Pallet<T>>::get() >= recovery.executable_at,
+            Error::<T>::IdentityRecoveryDelayNotElapsed
+        );
+
+        // Collect all items before migrating any of them.
+        let portfolios = T::Portfolio::portfolios_in_custody(old_did);
+        let claims = Self::asset_claims(old_did);
+        let auths = Self::pending_authorizations(old_did);
+        ensure!(
+            portfolios.len() <= max_portfolios as usize
+                && claims.len() <= max_claims as usize
+                && auths.len() <= max_auths as usize,
+            Error::<T>::TooManyRecoveryItems
+        );
+
+        T::Portfolio::transfer_custody(old_did, successor, &portfolios);
+        Self::copy_asset_claims(successor, claims);
+        Self::migrate_pending_authorizations(old_did, successor, auths);
+
+        PendingRecoveries::<T>::remove(old_did);
+        Self::deposit_event(RawEvent::IdentityRecovered(old_did, successor));
+        Ok(())
+    }
+
+    /// Returns all non-custom claims of `did` which are scoped to an asset.
+    fn asset_claims(did: IdentityId) -> Vec<(ClaimType, Claim2ndKey, IdentityClaim)> {
+        RECOVERED_CLAIM_TYPES
+            .into_iter()
+            .flat_map(|claim_type| {
+                let pk = Claim1stKey {
+                    target: did,
+                    claim_type,
+                };
+                Claims::iter_prefix(&pk)
+                    .filter(|(sk, _)| matches!(sk.scope, Some(Scope::Asset(_))))
+                    .map(move |(sk, id_claim)| (claim_type, sk, id_claim))
+            })
+            .collect()
+    }
+
+    /// Copies the asset scoped `claims` of a recovered identity to `to`.
+    ///
+    /// The recovered identity keeps its claims, since it still owns its portfolios and their balances.
+    /// Claims that `to` already holds from the same issuer and scope are kept as they are.
+    fn copy_asset_claims(to: IdentityId, claims: Vec<(ClaimType, Claim2ndKey, IdentityClaim)>) {
+        let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        for (claim_type, sk, id_claim) in claims {
+            let new_pk = Claim1stKey {
+                target: to,
+                claim_type,
+            };
+            let not_expired = id_claim.expiry.map_or(true, |expiry| expiry > now);
+            let claim = Some(id_claim.claim.clone()).filter(|_| not_expired);
+
+            if !Claims::contains_key(&new_pk, &sk) {
+                Claims::insert(&new_pk, &sk, id_claim.clone());
+                T::ClaimHook::on_claim_change(
+                    to,
+                    sk.issuer,
+                    claim_type,
+                    sk.scope.as_ref(),
+                    None,
+                    claim,
+                );
+                Self::deposit_event(RawEvent::ClaimAdded(to, id_claim));
+            }
+        }
+    }
+
+    /// Returns the pending authorizations of `did`.
+    ///
+    /// Authorizations that are already outdated for `did` are left out.
+    fn pending_authorizations(
+        did: IdentityId,
+    ) -> Vec<(u64, Authorization<T::AccountId, T::Moment>)> {
+        let target = Signatory::Identity(did);
+        let outdated = OutdatedAuthorizations::<T>::get(&target);
+        Authorizations::<T>::iter_prefix(&target)
+            .filter(|(auth_id, _)| outdated.map_or(true, |outdated| *auth_id > outdated))
+            .collect()
+    }
+
+    /// Re-targets the pending authorizations `auths` of `from` to `to`.
+    fn migrate_pending_authorizations(
+        from: IdentityId,
+        to: IdentityId,
+        auths: Vec<(u64, Authorization<T::AccountId, T::Moment>)>,
+    ) {
+        let old_target = Signatory::Identity(from);
+        let new_target = Signatory::Identity(to);
+        for (auth_id, auth) in auths {
+            Authorizations::<T>::remove(&old_target, auth_id);
+            AuthorizationsGiven::<T>::insert(auth.authorized_by, auth_id, new_target.clone());
+            Authorizations::<T>::insert(&new_target, auth_id, auth);
+        }
+    }
+}
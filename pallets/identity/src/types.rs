@@ -74,3 +74,14 @@ pub struct Claim2ndKey {
     pub issuer: IdentityId,
     pub scope: Option<Scope>,
 }
+
+/// A pending recovery of an identity by a successor identity, attested by a CDD provider.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct IdentityRecovery<Moment> {
+    /// The identity that takes over the portfolios, asset claims and authorizations.
+    pub successor: IdentityId,
+    /// The CDD provider that attested the recovery.
+    pub attested_by: IdentityId,
+    /// The moment from which the recovery can be completed.
+    pub executable_at: Moment,
+}
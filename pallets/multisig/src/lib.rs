@@ -897,7 +897,8 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn ensure_proposal_is_active(multisig: &T::AccountId, proposal_id: u64) -> DispatchResult {
+    /// Ensures that the proposal exists, is still valid and has neither expired nor been executed or rejected.
+    pub fn ensure_proposal_is_active(multisig: &T::AccountId, proposal_id: u64) -> DispatchResult {
         match ProposalStates::<T>::get(multisig, proposal_id) {
             None => Err(Error::<T>::ProposalMissing.into()),
             Some(ProposalState::Rejected) => Err(Error::<T>::ProposalAlreadyRejected.into()),
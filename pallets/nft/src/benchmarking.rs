@@ -260,4 +260,67 @@ benchmarks! {
         assert_eq!(NFTsInCollection::get(nfts.asset_id()), n as u64);
     }
 
+    update_nft_metadata {
+        let user = user::<T>("target", 0);
+        let asset_id = create_collection_issue_nfts::<T>(&user, MAX_COLLECTION_KEYS, 1, PortfolioKind::Default);
+        let key = AssetMetadataKey::Global(AssetMetadataGlobalKey(MAX_COLLECTION_KEYS.into()));
+        MetadataValueDetails::<T>::insert((NFTCollectionId(1), NFTId(1)), key, AssetMetadataValueDetail::default());
+        let value = AssetMetadataValue(vec![b'v'; T::AssetMetadataValueMaxLength::get() as usize]);
+        let metadata_attribute = NFTMetadataAttribute { key, value: value.clone() };
+    }: _(user.origin, asset_id, NFTId(1), metadata_attribute, Some(AssetMetadataValueDetail::default()))
+    verify {
+        assert_eq!(MetadataValue::get((NFTCollectionId(1), NFTId(1)), key), value);
+    }
+
+    fractionalize_nft {
+        let user = user::<T>("target", 0);
+        let asset_id = create_collection_issue_nfts::<T>(&user, 0, 1, PortfolioKind::Default);
+        let fraction_asset_id = Asset::<T>::generate_asset_id(user.account(), false);
+    }: _(
+        user.origin,
+        asset_id,
+        NFTId(1),
+        PortfolioKind::Default,
+        AssetName(b"Fraction".to_vec()),
+        1_000_000,
+        PortfolioName(b"Custody".to_vec())
+    )
+    verify {
+        assert!(FractionalizedNFTs::contains_key(fraction_asset_id));
+    }
+
+    redeem_nft_fraction {
+        // The worst case moves the NFT to another identity, running the compliance checks of the collection.
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let (asset_id, alice_portfolio, bob_portfolio, _) =
+            setup_nft_transfer::<T>(&alice, &bob, 1, None, None, true, 0);
+        let fraction_asset_id = Asset::<T>::generate_asset_id(alice.account(), false);
+        Module::<T>::fractionalize_nft(
+            alice.origin().into(),
+            asset_id,
+            NFTId(1),
+            alice_portfolio.kind,
+            AssetName(b"Fraction".to_vec()),
+            1_000_000,
+            PortfolioName(b"Custody".to_vec()),
+        )
+        .unwrap();
+        with_transaction(|| {
+            Asset::<T>::unverified_transfer_asset(
+                alice_portfolio,
+                bob_portfolio,
+                fraction_asset_id,
+                1_000_000,
+                None,
+                None,
+                alice.did(),
+                &mut WeightMeter::max_limit_no_minimum(),
+            )
+        })
+        .unwrap();
+    }: _(bob.origin, fraction_asset_id, bob_portfolio.kind)
+    verify {
+        assert_eq!(NFTOwner::get(asset_id, NFTId(1)), Some(bob_portfolio));
+    }
 }
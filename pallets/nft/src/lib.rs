@@ -16,16 +16,20 @@ use pallet_asset::Frozen;
 use pallet_base::try_next_pre;
 use pallet_portfolio::{PortfolioLockedNFT, PortfolioNFT};
 use polymesh_common_utilities::compliance_manager::ComplianceFnConfig;
+use polymesh_common_utilities::portfolio::PortfolioSubTrait;
 pub use polymesh_common_utilities::traits::nft::{Config, Event, NFTTrait, WeightInfo};
 use polymesh_primitives::asset::{AssetId, AssetName, AssetType, NonFungibleType};
-use polymesh_primitives::asset_metadata::{AssetMetadataKey, AssetMetadataValue};
+use polymesh_primitives::asset_metadata::{
+    AssetMetadataKey, AssetMetadataValue, AssetMetadataValueDetail,
+};
 use polymesh_primitives::nft::{
-    NFTCollection, NFTCollectionId, NFTCollectionKeys, NFTCount, NFTId, NFTMetadataAttribute, NFTs,
+    FractionalizedNFT, NFTCollection, NFTCollectionId, NFTCollectionKeys, NFTCount, NFTId,
+    NFTMetadataAttribute, NFTs,
 };
 use polymesh_primitives::settlement::InstructionId;
 use polymesh_primitives::{
-    storage_migrate_on, storage_migration_ver, IdentityId, Memo, PortfolioId, PortfolioKind,
-    PortfolioUpdateReason, WeightMeter,
+    storage_migrate_on, storage_migration_ver, Balance, IdentityId, Memo, PortfolioId,
+    PortfolioKind, PortfolioName, PortfolioUpdateReason, WeightMeter,
 };
 
 type Asset<T> = pallet_asset::Module<T>;
@@ -57,6 +61,11 @@ decl_storage!(
         pub MetadataValue get(fn metadata_value):
             double_map hasher(blake2_128_concat) (NFTCollectionId, NFTId), hasher(blake2_128_concat) AssetMetadataKey => AssetMetadataValue;
 
+        /// Details (expire, lock status) of the metadata value of an nft given its collection id, token id and metadata key.
+        pub MetadataValueDetails get(fn metadata_value_details):
+            double_map hasher(blake2_128_concat) (NFTCollectionId, NFTId), hasher(blake2_128_concat) AssetMetadataKey =>
+                Option<AssetMetadataValueDetail<T::Moment>>;
+
         /// The total number of NFTs in a collection
         pub NFTsInCollection get(fn nfts_in_collection): map hasher(blake2_128_concat) AssetId => NFTCount;
 
@@ -69,6 +78,13 @@ decl_storage!(
         /// The last `NFTCollectionId` used for a collection.
         pub CurrentCollectionId get(fn current_collection_id): Option<NFTCollectionId>;
 
+        /// The locked NFT represented by each fraction asset.
+        pub FractionalizedNFTs get(fn fractionalized_nft): map hasher(blake2_128_concat) AssetId => Option<FractionalizedNFT>;
+
+        /// The fraction asset of a locked NFT given its collection asset and id.
+        pub NFTFractionAsset get(fn nft_fraction_asset):
+            double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) NFTId => Option<AssetId>;
+
         /// Storage version.
         StorageVersion get(fn storage_version) build(|_| Version::new(4)): Version;
     }
@@ -192,6 +208,103 @@ decl_module! {
         ) -> DispatchResult {
             Self::base_controller_transfer(origin, nfts, source_portfolio, callers_portfolio_kind)
         }
+
+        /// Updates the value of one of the metadata keys of an NFT.
+        ///
+        /// # Arguments
+        /// * `origin` - is a signer that has permissions to act as an agent of `asset_id`.
+        /// * `asset_id` - the [`AssetId`] of the NFT collection.
+        /// * `nft_id` - the [`NFTId`] of the NFT to be updated.
+        /// * `metadata_attribute` - the collection key and its new value.
+        /// * `detail` - optional [`AssetMetadataValueDetail`] (expire, lock status) for the value.
+        ///
+        /// ## Errors
+        /// - `CollectionNotFound` - if the collection associated to the given asset_id has not been created.
+        /// - `NFTNotFound` - if the given NFT does not exist.
+        /// - `InvalidMetadataAttribute` - if the key is not one of the collection keys.
+        /// - `MetadataValueMaxLengthExceeded` - if the value is longer than the maximum allowed.
+        /// - `MetadataValueIsLocked` - if the current value of the key is locked.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::update_nft_metadata()]
+        pub fn update_nft_metadata(
+            origin,
+            asset_id: AssetId,
+            nft_id: NFTId,
+            metadata_attribute: NFTMetadataAttribute,
+            detail: Option<AssetMetadataValueDetail<T::Moment>>
+        ) -> DispatchResult {
+            Self::base_update_nft_metadata(origin, asset_id, nft_id, metadata_attribute, detail)
+        }
+
+        /// Locks an NFT of the caller in a new custody portfolio and issues a fungible asset representing its fractions.
+        ///
+        /// The whole `fraction_supply` is issued to the portfolio that held the NFT, and can't be changed afterwards.
+        /// Whoever holds all fractions can exchange them for the NFT by calling [`Module::redeem_nft_fraction`].
+        ///
+        /// # Arguments
+        /// * `origin` - is a signer that has custody of the portfolio holding the NFT.
+        /// * `asset_id` - the [`AssetId`] of the NFT collection.
+        /// * `nft_id` - the [`NFTId`] of the NFT to be fractionalized.
+        /// * `portfolio_kind` - the portfolio that contains the NFT and that will receive the fractions.
+        /// * `fraction_name` - the [`AssetName`] of the new fraction asset.
+        /// * `fraction_supply` - the total supply of the new fraction asset.
+        /// * `custody_portfolio_name` - the name of the new portfolio that will hold the locked NFT.
+        ///
+        /// ## Errors
+        /// - `CollectionNotFound` - if the collection associated to the given asset_id has not been created.
+        /// - `NFTNotFound` - if the given NFT does not exist in the portfolio.
+        /// - `NFTIsLocked` - if the given NFT is locked.
+        /// - `ZeroNFTFractionSupply` - if `fraction_supply` is zero.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::fractionalize_nft()]
+        pub fn fractionalize_nft(
+            origin,
+            asset_id: AssetId,
+            nft_id: NFTId,
+            portfolio_kind: PortfolioKind,
+            fraction_name: AssetName,
+            fraction_supply: Balance,
+            custody_portfolio_name: PortfolioName
+        ) -> DispatchResult {
+            Self::base_fractionalize_nft(
+                origin,
+                asset_id,
+                nft_id,
+                portfolio_kind,
+                fraction_name,
+                fraction_supply,
+                custody_portfolio_name
+            )
+        }
+
+        /// Redeems the whole supply of a fraction asset in exchange for the NFT it represents.
+        ///
+        /// The NFT is unlocked and moved from the custody portfolio to the caller's portfolio,
+        /// following the compliance rules of the collection.
+        ///
+        /// # Arguments
+        /// * `origin` - is a signer that has custody of the portfolio holding the fractions.
+        /// * `fraction_asset_id` - the [`AssetId`] of the fraction asset.
+        /// * `portfolio_kind` - the portfolio that contains all fractions and that will receive the NFT.
+        ///
+        /// ## Errors
+        /// - `NFTFractionNotFound` - if the asset doesn't represent the fractions of an NFT.
+        /// - `InsufficientNFTFractions` - if the portfolio doesn't hold the whole supply of the fraction asset.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::redeem_nft_fraction()]
+        pub fn redeem_nft_fraction(
+            origin,
+            fraction_asset_id: AssetId,
+            portfolio_kind: PortfolioKind
+        ) -> DispatchResult {
+            Self::base_redeem_nft_fraction(origin, fraction_asset_id, portfolio_kind)
+        }
     }
 }
 
@@ -255,6 +368,18 @@ decl_error! {
         NFTIsLocked,
         /// The number of keys in the collection is greater than the input.
         NumberOfKeysIsLessThanExpected,
+        /// The maximum length of a metadata value has been exceeded.
+        MetadataValueMaxLengthExceeded,
+        /// The metadata value of the NFT is locked.
+        MetadataValueIsLocked,
+        /// The supply of an NFT fraction asset must be greater than zero.
+        ZeroNFTFractionSupply,
+        /// The asset doesn't represent the fractions of an NFT.
+        NFTFractionNotFound,
+        /// The portfolio doesn't hold the whole supply of the NFT fraction asset.
+        InsufficientNFTFractions,
+        /// The NFT is backing a fraction asset and can only be released by redeeming its fractions.
+        NFTIsFractionalized,
     }
 }
 
@@ -458,6 +583,7 @@ impl<T: Config> Module<T> {
         PortfolioNFT::remove(&caller_portfolio, (&asset_id, &nft_id));
         NFTOwner::remove(asset_id, nft_id);
         let removed_keys = MetadataValue::drain_prefix((&collection_id, &nft_id)).count();
+        MetadataValueDetails::<T>::drain_prefix((&collection_id, &nft_id)).for_each(drop);
         if let Some(number_of_keys) = number_of_keys {
             ensure!(
                 usize::from(number_of_keys) >= removed_keys,
@@ -477,6 +603,239 @@ impl<T: Config> Module<T> {
         )))
     }
 
+    fn base_update_nft_metadata(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        nft_id: NFTId,
+        metadata_attribute: NFTMetadataAttribute,
+        detail: Option<AssetMetadataValueDetail<T::Moment>>,
+    ) -> DispatchResult {
+        // Verifies if the caller has the right permissions for the asset
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+
+        // Verifies if the collection and the NFT exist
+        let collection_id =
+            CollectionAsset::try_get(&asset_id).map_err(|_| Error::<T>::CollectionNotFound)?;
+        ensure!(
+            NFTOwner::contains_key(&asset_id, &nft_id),
+            Error::<T>::NFTNotFound
+        );
+
+        // Verifies that the key belongs to the collection and that the value is within the limit
+        let NFTMetadataAttribute { key, value } = metadata_attribute;
+        ensure!(
+            Self::collection_keys(&collection_id).contains(&key),
+            Error::<T>::InvalidMetadataAttribute
+        );
+        ensure!(
+            value.len() <= T::AssetMetadataValueMaxLength::get() as usize,
+            Error::<T>::MetadataValueMaxLengthExceeded
+        );
+
+        // Verifies that the current value is not locked
+        let nft_key = (collection_id, nft_id);
+        if let Some(current_detail) = MetadataValueDetails::<T>::get(&nft_key, &key) {
+            ensure!(
+                !current_detail.is_locked(<pallet_timestamp::Pallet<T>>::get()),
+                Error::<T>::MetadataValueIsLocked
+            );
+        }
+
+        let old_value = MetadataValue::get(&nft_key, &key);
+        MetadataValue::insert(&nft_key, &key, &value);
+        if let Some(detail) = detail {
+            MetadataValueDetails::<T>::insert(&nft_key, &key, detail);
+        }
+
+        Self::deposit_event(Event::NFTMetadataUpdated(
+            caller_did, asset_id, nft_id, key, old_value, value,
+        ));
+        Ok(())
+    }
+
+    fn base_fractionalize_nft(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        nft_id: NFTId,
+        portfolio_kind: PortfolioKind,
+        fraction_name: AssetName,
+        fraction_supply: Balance,
+        custody_portfolio_name: PortfolioName,
+    ) -> DispatchResult {
+        // Verifies if the caller has custody and permissions for the portfolio holding the NFT
+        let caller_data = Identity::<T>::ensure_origin_call_permissions(origin.clone())?;
+        let caller_portfolio = PortfolioId::new(caller_data.primary_did, portfolio_kind);
+        Portfolio::<T>::ensure_portfolio_validity(&caller_portfolio)?;
+        Portfolio::<T>::ensure_portfolio_custody_and_permission(
+            caller_portfolio,
+            caller_data.primary_did,
+            caller_data.secondary_key.as_ref(),
+        )?;
+
+        // Verifies if the collection exists and the NFT is free in the caller's portfolio
+        ensure!(
+            CollectionAsset::contains_key(&asset_id),
+            Error::<T>::CollectionNotFound
+        );
+        ensure!(
+            PortfolioNFT::contains_key(&caller_portfolio, (&asset_id, &nft_id)),
+            Error::<T>::NFTNotFound
+        );
+        ensure!(
+            !PortfolioLockedNFT::contains_key(&caller_portfolio, (&asset_id, &nft_id)),
+            Error::<T>::NFTIsLocked
+        );
+        ensure!(fraction_supply > 0, Error::<T>::ZeroNFTFractionSupply);
+
+        // Creates the fraction asset and issues its whole supply to the caller's portfolio
+        let fraction_asset_id = Asset::<T>::generate_asset_id(caller_data.sender, false);
+        Asset::<T>::create_asset(
+            origin.clone(),
+            fraction_name,
+            true,
+            AssetType::default(),
+            Vec::new(),
+            None,
+        )?;
+        Asset::<T>::issue(origin, fraction_asset_id, fraction_supply, portfolio_kind)?;
+
+        // Moves the NFT to a new custody portfolio and locks it there
+        let custody_portfolio = Portfolio::<T>::unverified_create_portfolio(
+            caller_data.primary_did,
+            custody_portfolio_name,
+        )?;
+        Self::move_nft_between_own_portfolios(
+            &caller_portfolio,
+            &custody_portfolio,
+            &asset_id,
+            &nft_id,
+        );
+        Portfolio::<T>::lock_nft(&custody_portfolio, &asset_id, &nft_id)?;
+
+        FractionalizedNFTs::insert(
+            &fraction_asset_id,
+            FractionalizedNFT {
+                collection_asset_id: asset_id,
+                nft_id,
+                custody_portfolio,
+            },
+        );
+        NFTFractionAsset::insert(&asset_id, &nft_id, &fraction_asset_id);
+
+        Self::deposit_event(Event::NFTPortfolioUpdated(
+            caller_data.primary_did,
+            NFTs::new_unverified(asset_id, vec![nft_id]),
+            Some(caller_portfolio),
+            Some(custody_portfolio),
+            PortfolioUpdateReason::Transferred {
+                instruction_id: None,
+                instruction_memo: None,
+            },
+        ));
+        Self::deposit_event(Event::NFTFractionalized(
+            caller_data.primary_did,
+            asset_id,
+            nft_id,
+            fraction_asset_id,
+            custody_portfolio,
+        ));
+        Ok(())
+    }
+
+    fn base_redeem_nft_fraction(
+        origin: T::RuntimeOrigin,
+        fraction_asset_id: AssetId,
+        portfolio_kind: PortfolioKind,
+    ) -> DispatchResult {
+        // Verifies if the caller has custody and permissions for the portfolio holding the fractions
+        let caller_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        let caller_portfolio = PortfolioId::new(caller_data.primary_did, portfolio_kind);
+        Portfolio::<T>::ensure_portfolio_validity(&caller_portfolio)?;
+        Portfolio::<T>::ensure_portfolio_custody_and_permission(
+            caller_portfolio,
+            caller_data.primary_did,
+            caller_data.secondary_key.as_ref(),
+        )?;
+
+        // Verifies that the caller's portfolio holds the whole supply of fractions
+        let fractionalized_nft =
+            FractionalizedNFTs::get(&fraction_asset_id).ok_or(Error::<T>::NFTFractionNotFound)?;
+        let total_supply = Asset::<T>::try_get_asset_details(&fraction_asset_id)?.total_supply;
+        ensure!(
+            Portfolio::<T>::portfolio_asset_balances(&caller_portfolio, &fraction_asset_id)
+                == total_supply,
+            Error::<T>::InsufficientNFTFractions
+        );
+
+        // Burns all fractions and releases the NFT to the caller's portfolio
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+        Asset::<T>::unverified_redeem(
+            fraction_asset_id,
+            total_supply,
+            caller_portfolio,
+            &mut weight_meter,
+        )?;
+
+        let FractionalizedNFT {
+            collection_asset_id,
+            nft_id,
+            custody_portfolio,
+        } = fractionalized_nft;
+        Portfolio::<T>::unlock_nft(&custody_portfolio, &collection_asset_id, &nft_id)?;
+        let nfts = NFTs::new_unverified(collection_asset_id, vec![nft_id]);
+        if custody_portfolio.did == caller_portfolio.did {
+            Self::move_nft_between_own_portfolios(
+                &custody_portfolio,
+                &caller_portfolio,
+                &collection_asset_id,
+                &nft_id,
+            );
+        } else {
+            Self::validate_nft_transfer(
+                &custody_portfolio,
+                &caller_portfolio,
+                &nfts,
+                false,
+                Some(&mut weight_meter),
+            )?;
+            Self::unverified_nfts_transfer(&custody_portfolio, &caller_portfolio, &nfts);
+        }
+
+        FractionalizedNFTs::remove(&fraction_asset_id);
+        NFTFractionAsset::remove(&collection_asset_id, &nft_id);
+
+        Self::deposit_event(Event::NFTPortfolioUpdated(
+            caller_portfolio.did,
+            nfts,
+            Some(custody_portfolio),
+            Some(caller_portfolio),
+            PortfolioUpdateReason::Transferred {
+                instruction_id: None,
+                instruction_memo: None,
+            },
+        ));
+        Self::deposit_event(Event::NFTFractionRedeemed(
+            caller_portfolio.did,
+            fraction_asset_id,
+            collection_asset_id,
+            nft_id,
+            caller_portfolio,
+        ));
+        Ok(())
+    }
+
+    /// Moves the NFT between two portfolios of the same identity, leaving its balance untouched.
+    fn move_nft_between_own_portfolios(
+        from: &PortfolioId,
+        to: &PortfolioId,
+        asset_id: &AssetId,
+        nft_id: &NFTId,
+    ) {
+        PortfolioNFT::remove(from, (asset_id, nft_id));
+        PortfolioNFT::insert(to, (asset_id, nft_id), true);
+        NFTOwner::insert(asset_id, nft_id, to);
+    }
+
     /// Tranfer ownership of all NFTs.
     #[require_transactional]
     pub fn base_nft_transfer(
@@ -657,6 +1016,13 @@ impl<T: Config> Module<T> {
             true,
         )?;
 
+        // The lock of fractionalized NFTs is skipped by controller transfers, so they are rejected here
+        for nft_id in nfts.ids() {
+            ensure!(
+                !NFTFractionAsset::contains_key(nfts.asset_id(), nft_id),
+                Error::<T>::NFTIsFractionalized
+            );
+        }
         // Verifies if all rules for transfering the NFTs are being respected
         Self::validate_nft_transfer(&source_portfolio, &caller_portfolio, &nfts, true, None)?;
         // Transfer ownership of the NFTs
@@ -805,6 +1171,10 @@ impl<T: Config> NFTTrait<T::RuntimeOrigin> for Module<T> {
         NFTOwner::insert(asset_id, nft_id, new_owner_portfolio);
     }
 
+    fn is_nft_fraction(asset_id: &AssetId) -> bool {
+        FractionalizedNFTs::contains_key(asset_id)
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     fn create_nft_collection(
         origin: T::RuntimeOrigin,
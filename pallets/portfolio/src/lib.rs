@@ -34,6 +34,7 @@
 //!
 //! - `default_portfolio_balance`: Returns the asset balance of the identity's default portfolio.
 //! - `user_portfolio_balance`: Returns the asset balance of an identity's user portfolio.
+//! - `portfolio_holdings`: Returns a page of the holdings of all portfolios owned by an identity.
 //! - `set_portfolio_balance`: Sets the asset balance of a portfolio.
 //! - `unchecked_transfer_portfolio_balance`: Transfers funds from one portfolio to another.
 //! - `ensure_portfolio_custody`: Makes sure that the given identity has custodian access over the portfolio.
@@ -63,12 +64,18 @@ use polymesh_common_utilities::traits::portfolio::PortfolioSubTrait;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::{
     extract_auth, identity_id::PortfolioValidityResult, storage_migrate_on, storage_migration_ver,
-    Balance, Fund, FundDescription, IdentityId, NFTId, PortfolioId, PortfolioKind, PortfolioName,
+    Balance, Fund, FundDescription, IdentityId, NFTId, PortfolioAssetBalance, PortfolioHoldings,
+    PortfolioHoldingsPage, PortfolioId, PortfolioKind, PortfolioNFTHolding, PortfolioName,
     PortfolioNumber, SecondaryKey,
 };
 
 type Identity<T> = pallet_identity::Module<T>;
 
+/// Maximum number of portfolios returned in a page of [`PortfolioHoldings`].
+pub const MAX_HOLDINGS_PORTFOLIOS: u32 = 10;
+/// Maximum number of assets, NFTs and pre-approved assets returned in the [`PortfolioHoldings`] of a portfolio.
+pub const MAX_HOLDINGS_PER_PORTFOLIO: usize = 100;
+
 decl_storage! {
     trait Store for Module<T: Config> as Portfolio {
         /// The next portfolio sequence number of an identity.
@@ -442,6 +449,20 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Creates a portfolio named `portfolio_name` owned by `portfolio_owner_id`,
+    /// without checking the caller's permissions, and returns its id.
+    pub fn unverified_create_portfolio(
+        portfolio_owner_id: IdentityId,
+        portfolio_name: PortfolioName,
+    ) -> Result<PortfolioId, DispatchError> {
+        let portfolio_number = NextPortfolioNumber::get(&portfolio_owner_id);
+        Self::base_create_portfolio(portfolio_owner_id, portfolio_name)?;
+        Ok(PortfolioId::user_portfolio(
+            portfolio_owner_id,
+            portfolio_number,
+        ))
+    }
+
     /// Returns the custodian of `pid`.
     fn custodian(pid: &PortfolioId) -> IdentityId {
         PortfolioCustodian::get(&pid).unwrap_or(pid.did)
@@ -461,6 +482,94 @@ impl<T: Config> Module<T> {
         Self::portfolio_asset_balances(PortfolioId::user_portfolio(did, num), asset_id)
     }
 
+    /// Returns the holdings of up to `limit` portfolios owned by `did`, starting from the portfolio of kind `start`.
+    /// At most [`MAX_HOLDINGS_PORTFOLIOS`] portfolios are returned, whatever the `limit`.
+    ///
+    /// The default portfolio comes first, followed by the user portfolios in ascending order of their numbers.
+    pub fn portfolio_holdings(
+        did: IdentityId,
+        start: Option<PortfolioKind>,
+        limit: u32,
+    ) -> PortfolioHoldingsPage {
+        let mut kinds: Vec<PortfolioKind> = Portfolios::iter_key_prefix(&did)
+            .map(PortfolioKind::User)
+            .chain(iter::once(PortfolioKind::Default))
+            .collect();
+        kinds.sort_unstable();
+
+        let mut kinds = kinds
+            .into_iter()
+            .skip_while(|kind| start.map_or(false, |start| *kind < start));
+        let holdings = kinds
+            .by_ref()
+            .take(limit.min(MAX_HOLDINGS_PORTFOLIOS) as usize)
+            .map(|kind| Self::holdings_of(PortfolioId::new(did, kind)))
+            .collect();
+        PortfolioHoldingsPage {
+            holdings,
+            next: kinds.next(),
+        }
+    }
+
+    /// Returns the [`PortfolioHoldings`] of `pid`, with at most [`MAX_HOLDINGS_PER_PORTFOLIO`] items in each list.
+    fn holdings_of(pid: PortfolioId) -> PortfolioHoldings {
+        let name = match pid.kind {
+            PortfolioKind::Default => None,
+            PortfolioKind::User(num) => Portfolios::get(&pid.did, num),
+        };
+        let mut truncated = false;
+
+        let mut assets: Vec<PortfolioAssetBalance> =
+            Self::take_capped(PortfolioAssetBalances::iter_prefix(&pid), &mut truncated)
+                .into_iter()
+                .map(|(asset_id, total)| {
+                    let locked = PortfolioLockedAssets::get(&pid, &asset_id);
+                    PortfolioAssetBalance {
+                        asset_id,
+                        free: total.saturating_sub(locked),
+                        locked,
+                    }
+                })
+                .filter(|balance| !balance.free.is_zero() || !balance.locked.is_zero())
+                .collect();
+        assets.sort_unstable_by_key(|balance| balance.asset_id);
+
+        let mut nfts: Vec<PortfolioNFTHolding> =
+            Self::take_capped(PortfolioNFT::iter_key_prefix(&pid), &mut truncated)
+                .into_iter()
+                .map(|(asset_id, nft_id)| PortfolioNFTHolding {
+                    asset_id,
+                    nft_id,
+                    locked: PortfolioLockedNFT::get(&pid, (asset_id, nft_id)),
+                })
+                .collect();
+        nfts.sort_unstable_by_key(|nft| (nft.asset_id, nft.nft_id));
+
+        let mut pre_approved_assets: Vec<AssetId> =
+            Self::take_capped(PreApprovedPortfolios::iter_key_prefix(&pid), &mut truncated);
+        pre_approved_assets.sort_unstable();
+
+        PortfolioHoldings {
+            portfolio_id: pid,
+            name,
+            custodian: Self::custodian(&pid),
+            assets,
+            nfts,
+            pre_approved_assets,
+            truncated,
+        }
+    }
+
+    /// Collects up to [`MAX_HOLDINGS_PER_PORTFOLIO`] items of `iter`, setting `truncated` if there are more.
+    fn take_capped<I: Iterator>(iter: I, truncated: &mut bool) -> Vec<I::Item> {
+        let mut items: Vec<I::Item> = iter.take(MAX_HOLDINGS_PER_PORTFOLIO + 1).collect();
+        if items.len() > MAX_HOLDINGS_PER_PORTFOLIO {
+            items.truncate(MAX_HOLDINGS_PER_PORTFOLIO);
+            *truncated = true;
+        }
+        items
+    }
+
     /// Sets the asset balance of the a portfolio to `new`.
     pub fn set_portfolio_balance(pid: PortfolioId, asset_id: &AssetId, new: Balance) {
         PortfolioAssetBalances::mutate(&pid, asset_id, |old| {
@@ -739,6 +848,11 @@ impl<T: Config> Module<T> {
                         Error::<T>::NoDuplicateAssetsAllowed
                     );
                     Self::ensure_sufficient_balance(sender_portfolio, &asset_id, *amount)?;
+                    T::Asset::ensure_portfolio_balance_unfrozen(
+                        &asset_id,
+                        sender_portfolio,
+                        *amount,
+                    )?;
                 }
                 FundDescription::NonFungible(nfts) => {
                     ensure!(nfts.len() > 0, Error::<T>::EmptyTransfer);
@@ -1019,4 +1133,32 @@ impl<T: Config> PortfolioSubTrait<T::AccountId> for Module<T> {
         }
         PreApprovedPortfolios::get(portfolio_id, asset_id)
     }
+
+    /// Returns the portfolios owned and held by `did`.
+    ///
+    /// Portfolios owned by `did` whose custody was given to a third party are left out.
+    fn portfolios_in_custody(did: IdentityId) -> Vec<PortfolioId> {
+        let owned = Portfolios::iter_key_prefix(&did)
+            .map(|num| PortfolioId::user_portfolio(did, num))
+            .chain(iter::once(PortfolioId::default_portfolio(did)))
+            .filter(|pid| Self::custodian(pid) == did);
+        let held = PortfoliosInCustody::iter_key_prefix(&did);
+        owned
+            .chain(held)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    fn transfer_custody(from: IdentityId, to: IdentityId, portfolios: &[PortfolioId]) {
+        for pid in portfolios {
+            PortfoliosInCustody::remove(&from, pid);
+            if pid.did == to {
+                PortfolioCustodian::remove(pid);
+            } else {
+                Self::unverified_take_portfolio_custody(pid, &to);
+            }
+            Self::deposit_event(Event::PortfolioCustodianChanged(from, *pid, to));
+        }
+    }
 }
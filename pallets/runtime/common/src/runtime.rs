@@ -288,7 +288,12 @@ macro_rules! misc_pallet_impls {
             type MaxSigners = MaxMultiSigSigners;
         }
 
-        impl pallet_bridge::Config for Runtime {}
+        impl pallet_bridge::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type Proposal = RuntimeCall;
+            type Scheduler = Scheduler;
+            type WeightInfo = polymesh_weights::pallet_bridge::SubstrateWeight;
+        }
 
         impl pallet_portfolio::Config for Runtime {
             type RuntimeEvent = RuntimeEvent;
@@ -441,6 +446,7 @@ macro_rules! misc_pallet_impls {
             type Asset = Asset;
             type MaxStatsPerAsset = MaxStatsPerAsset;
             type MaxTransferConditionsPerAsset = MaxTransferConditionsPerAsset;
+            type MaxAssetsPerCustomClaimIssuer = MaxAssetsPerCustomClaimIssuer;
             type WeightInfo = polymesh_weights::pallet_statistics::SubstrateWeight;
         }
 
@@ -542,6 +548,8 @@ macro_rules! misc_pallet_impls {
             type MaxNumberOfPortfolios = MaxNumberOfPortfolios;
             type MaxNumberOfVenueSigners = MaxNumberOfVenueSigners;
             type MaxInstructionMediators = MaxInstructionMediators;
+            type MaxAutoAffirmRules = MaxAutoAffirmRules;
+            type MaxAutoAffirmCounterparties = MaxAutoAffirmCounterparties;
         }
 
         impl pallet_sto::Config for Runtime {
@@ -622,6 +630,11 @@ macro_rules! misc_pallet_impls {
             type MaxNumberOfNFTsCount = MaxNumberOfNFTsPerLeg;
         }
 
+        impl pallet_vesting::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type WeightInfo = polymesh_weights::pallet_vesting::SubstrateWeight;
+        }
+
         impl pallet_election_provider_multi_phase::Config for Runtime {
             type RuntimeEvent = RuntimeEvent;
             // Currency type
@@ -725,11 +738,18 @@ macro_rules! runtime_apis {
         use pallet_pips::{Vote, VoteCount};
         use pallet_protocol_fee_rpc_runtime_api::CappedFee;
         use polymesh_primitives::asset::AssetId;
-        use polymesh_primitives::settlement::{InstructionId, ExecuteInstructionInfo, AffirmationCount};
+        use polymesh_primitives::asset_metadata::{AssetMetadataDecodedValue, AssetMetadataKey};
+        use polymesh_primitives::settlement::{
+            AffirmationCount, AutoAffirmRuleMatch, ExecuteInstructionInfo, InstructionId, LegId, VenueId,
+        };
+        use pallet_corporate_actions::CAId;
+        use pallet_corporate_actions::distribution::DistributionEntitlement;
+        use polymesh_primitives::statistics::{AssetStatValue, StatType};
         use polymesh_primitives::transfer_compliance::TransferCondition;
         use polymesh_primitives::compliance_manager::{AssetComplianceResult, ComplianceReport};
         use polymesh_primitives::{
-            asset::CheckpointId, IdentityId, Index, NFTs,PortfolioId, Signatory, Ticker,
+            asset::CheckpointId, DryRunEffects, DryRunError, DryRunOrigin, EventFilter, EventTypes, IdentityId, Index, NFTs,PortfolioId, PortfolioHoldingsPage,
+            PortfolioKind, Signatory, Ticker,
             WeightMeter, IdentityClaim
         };
 
@@ -1108,6 +1128,19 @@ macro_rules! runtime_apis {
                         &mut weight_meter
                     )
                 }
+
+                #[inline]
+                fn metadata_value(
+                    asset_id: AssetId,
+                    key: AssetMetadataKey,
+                ) -> Result<Option<AssetMetadataDecodedValue>, DispatchError> {
+                    Asset::decoded_asset_metadata_value(asset_id, key)
+                }
+
+                #[inline]
+                fn allowance(portfolio: PortfolioId, asset_id: AssetId, spender: IdentityId) -> Balance {
+                    Asset::allowance((portfolio, asset_id), spender)
+                }
             }
 
             impl pallet_group_rpc_runtime_api::GroupApi<Block> for Runtime {
@@ -1171,6 +1204,20 @@ macro_rules! runtime_apis {
                     Settlement::execute_instruction_report(&instruction_id, &mut weight_meter)
                 }
 
+                #[inline]
+                fn get_auto_affirm_rule_matches(
+                    portfolio: PortfolioId,
+                    venue_id: VenueId,
+                    legs: Vec<Leg>,
+                ) -> Vec<AutoAffirmRuleMatch> {
+                    let instruction_legs: Vec<(LegId, Leg)> = legs
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, leg)| (LegId(index as u64), leg))
+                        .collect();
+                    Settlement::auto_affirm_rule_matches(&portfolio, &venue_id, &instruction_legs)
+                }
+
             }
 
             impl node_rpc_runtime_api::compliance::ComplianceApi<Block> for Runtime {
@@ -1207,6 +1254,106 @@ macro_rules! runtime_apis {
                         &mut weight_meter
                     )
                 }
+
+                #[inline]
+                fn active_asset_stats(asset_id: AssetId) -> Vec<StatType> {
+                    Statistics::active_asset_stats(asset_id).into_iter().collect()
+                }
+
+                #[inline]
+                fn asset_stats(asset_id: AssetId) -> FrameResult<Vec<AssetStatValue>, DispatchError> {
+                    Statistics::asset_stat_values(asset_id)
+                }
+            }
+
+            impl node_rpc_runtime_api::events::EventsApi<Block> for Runtime {
+                #[inline]
+                fn event_types() -> EventTypes {
+                    EventTypes::new::<RuntimeEvent>()
+                }
+
+                #[inline]
+                fn encoded_events() -> Vec<Vec<u8>> {
+                    use codec::Encode;
+
+                    System::events().into_iter().map(|record| record.event.encode()).collect()
+                }
+            }
+
+            impl node_rpc_runtime_api::portfolio::PortfolioApi<Block> for Runtime {
+                #[inline]
+                fn portfolio_holdings(
+                    did: IdentityId,
+                    start: Option<PortfolioKind>,
+                    limit: u32,
+                ) -> PortfolioHoldingsPage {
+                    Portfolio::portfolio_holdings(did, start, limit)
+                }
+            }
+
+            impl node_rpc_runtime_api::corporate_actions::CorporateActionsApi<Block> for Runtime {
+                #[inline]
+                fn distribution_entitlement(
+                    holder: IdentityId,
+                    ca_id: CAId,
+                ) -> FrameResult<DistributionEntitlement, DispatchError> {
+                    CapitalDistribution::entitlement(holder, ca_id)
+                }
+
+                #[inline]
+                fn unclaimed_distributions(
+                    holder: IdentityId,
+                    asset_id: AssetId,
+                ) -> Vec<(CAId, DistributionEntitlement)> {
+                    CapitalDistribution::unclaimed_distributions(holder, asset_id)
+                }
+            }
+
+            impl node_rpc_runtime_api::dry_run::DryRunApi<Block> for Runtime {
+                fn dry_run_call(
+                    origin: DryRunOrigin,
+                    call: Vec<u8>,
+                ) -> FrameResult<DryRunEffects, DryRunError> {
+                    use codec::DecodeLimit;
+                    use frame_support::dispatch::{GetCallMetadata, GetDispatchInfo};
+                    use polymesh_common_utilities::Context;
+                    use sp_runtime::traits::Dispatchable;
+
+                    let len = call.len() as u32;
+                    let call = RuntimeCall::decode_all_with_depth_limit(
+                        sp_api::MAX_EXTRINSIC_DEPTH,
+                        &mut &call[..],
+                    )
+                    .map_err(|_| DryRunError::InvalidCall)?;
+                    let caller = match origin {
+                        DryRunOrigin::Account(account) => account,
+                        DryRunOrigin::Identity(did) => Identity::get_primary_key(did)
+                            .ok_or(DryRunError::MissingPrimaryKey)?,
+                    };
+
+                    // Only the events emitted by the call are returned.
+                    System::reset_events();
+                    // Protocol fees are charged to the caller, as `ChargeTransactionPayment` does for signed extrinsics.
+                    Context::set_current_payer::<Identity>(Some(caller.clone()));
+
+                    let info = call.get_dispatch_info();
+                    let metadata = call.get_call_metadata();
+                    let (result, post_info) = match pallet_permissions::with_call_metadata(metadata, || {
+                        call.dispatch(RuntimeOrigin::signed(caller))
+                    }) {
+                        Ok(post_info) => (Ok(()), post_info),
+                        Err(e) => (Err(e.error), e.post_info),
+                    };
+                    Context::set_current_payer::<Identity>(None);
+
+                    Ok(DryRunEffects {
+                        result,
+                        actual_weight: post_info.calc_actual_weight(&info),
+                        partial_fee: TransactionPayment::compute_actual_fee(len, &info, &post_info, 0),
+                        events: EventFilter::default()
+                            .filter_events(System::events().into_iter().map(|record| record.event)),
+                    })
+                }
             }
 
             $($extra)*
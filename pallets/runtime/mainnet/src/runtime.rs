@@ -92,6 +92,8 @@ parameter_types! {
     pub const MaxNumberOfPortfolios: u32 = (10 + 100) * 2;
     pub const MaxNumberOfVenueSigners: u32 = 50;
     pub const MaxInstructionMediators: u32 = 4;
+    pub const MaxAutoAffirmRules: u32 = 16;
+    pub const MaxAutoAffirmCounterparties: u32 = 32;
 
     // Multisig
     pub const MaxMultiSigSigners: u32 = 50;
@@ -123,6 +125,7 @@ parameter_types! {
     // Statistics:
     pub const MaxStatsPerAsset: u32 = 10 + BENCHMARK_MAX_INCREASE;
     pub const MaxTransferConditionsPerAsset: u32 = 4 + BENCHMARK_MAX_INCREASE;
+    pub const MaxAssetsPerCustomClaimIssuer: u32 = 16;
 
     // Scheduler:
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * MaximumBlockWeight::get();
@@ -182,6 +185,7 @@ parameter_types! {
         BondingDuration::get() as u64 * SessionsPerEra::get() as u64 * EpochDuration::get();
 
     pub const MaxGivenAuths: u32 = 1024;
+    pub const IdentityRecoveryDelay: Moment = 7 * 24 * 60 * 60 * 1_000;
 
     // State trie Migration
     pub const MigrationSignedDepositPerItem: Balance = 0;
@@ -214,6 +218,8 @@ impl polymesh_common_utilities::traits::identity::Config for Runtime {
     type SchedulerOrigin = OriginCaller;
     type InitialPOLYX = InitialPOLYX;
     type MaxGivenAuths = MaxGivenAuths;
+    type IdentityRecoveryDelay = IdentityRecoveryDelay;
+    type ClaimHook = Statistics;
 }
 
 impl pallet_committee::Config<GovernanceCommittee> for Runtime {
@@ -337,7 +343,7 @@ construct_runtime!(
 
         MultiSig: pallet_multisig::{Pallet, Call, Config, Storage, Event<T>} = 15,
 
-        Bridge: pallet_bridge::{Pallet, Storage} = 16,
+        Bridge: pallet_bridge::{Pallet, Call, Storage, Event<T>} = 16,
 
         // Staking: Genesis config deps: Bridge, Balances, Indices, Identity, Babe, Timestamp, Committees
         Staking: pallet_staking::{Pallet, Call, Config<T>, Storage, Event<T>} = 17,
@@ -387,6 +393,8 @@ construct_runtime!(
         Nft: pallet_nft::{Pallet, Call, Storage, Event} = 49,
 
         ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 50,
+
+        Vesting: pallet_vesting::{Pallet, Call, Storage, Event} = 51,
     }
 );
 
@@ -95,6 +95,8 @@ parameter_types! {
     pub const MaxNumberOfPortfolios: u32 = (10 + 100) * 2;
     pub const MaxNumberOfVenueSigners: u32 = 50;
     pub const MaxInstructionMediators: u32 = 4;
+    pub const MaxAutoAffirmRules: u32 = 16;
+    pub const MaxAutoAffirmCounterparties: u32 = 32;
 
     // Multisig
     pub const MaxMultiSigSigners: u32 = 50;
@@ -126,6 +128,7 @@ parameter_types! {
     // Statistics:
     pub const MaxStatsPerAsset: u32 = 10 + BENCHMARK_MAX_INCREASE;
     pub const MaxTransferConditionsPerAsset: u32 = 4 + BENCHMARK_MAX_INCREASE;
+    pub const MaxAssetsPerCustomClaimIssuer: u32 = 16;
 
     // Scheduler:
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * MaximumBlockWeight::get();
@@ -190,6 +193,7 @@ parameter_types! {
         BondingDuration::get() as u64 * SessionsPerEra::get() as u64 * EpochDuration::get();
 
     pub MaxGivenAuths: u32 = 1024;
+    pub const IdentityRecoveryDelay: Moment = 24 * 60 * 60 * 1_000;
 }
 
 polymesh_runtime_common::misc_pallet_impls!();
@@ -224,6 +228,8 @@ impl polymesh_common_utilities::traits::identity::Config for Runtime {
     type SchedulerOrigin = OriginCaller;
     type InitialPOLYX = InitialPOLYX;
     type MaxGivenAuths = MaxGivenAuths;
+    type IdentityRecoveryDelay = IdentityRecoveryDelay;
+    type ClaimHook = Statistics;
 }
 
 impl pallet_committee::Config<GovernanceCommittee> for Runtime {
@@ -355,7 +361,7 @@ construct_runtime!(
 
         MultiSig: pallet_multisig::{Pallet, Call, Config, Storage, Event<T>} = 15,
 
-        Bridge: pallet_bridge::{Pallet, Storage} = 16,
+        Bridge: pallet_bridge::{Pallet, Call, Storage, Event<T>} = 16,
 
         // Staking: Genesis config deps: Bridge, Balances, Indices, Identity, Babe, Timestamp, Committees
         Staking: pallet_staking::{Pallet, Call, Config<T>, Storage, Event<T>} = 17,
@@ -406,6 +412,8 @@ construct_runtime!(
 
         ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 50,
 
+        Vesting: pallet_vesting::{Pallet, Call, Storage, Event} = 51,
+
         TestUtils: pallet_test_utils::{Pallet, Call, Storage, Event<T> } = 200,
     }
 );
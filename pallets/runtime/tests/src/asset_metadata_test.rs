@@ -5,11 +5,12 @@ use super::{
     storage::{TestStorage, User},
     ExtBuilder,
 };
+use codec::Encode;
 use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::asset_metadata::{
-    AssetMetadataKey, AssetMetadataLockStatus, AssetMetadataName, AssetMetadataSpec,
-    AssetMetadataValue, AssetMetadataValueDetail,
+    AssetMetadataDecodedValue, AssetMetadataKey, AssetMetadataLockStatus, AssetMetadataName,
+    AssetMetadataSpec, AssetMetadataTypeDef, AssetMetadataValue, AssetMetadataValueDetail,
 };
 use sp_keyring::AccountKeyring;
 
@@ -607,3 +608,88 @@ fn check_locked_until() {
         ));
     });
 }
+
+#[test]
+fn set_typed_asset_metadata() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = User::new(AccountKeyring::Dave);
+        let asset_id = create_and_issue_sample_asset(&owner);
+
+        // Register a local metadata type for a `Moment`.
+        let (name, mut spec) = make_metadata_type("MATURITY");
+        spec.set_type_def(AssetMetadataTypeDef::new_from_type::<Moment>());
+        exec_ok!(Asset::register_asset_metadata_local_type(
+            owner.origin(),
+            asset_id,
+            name.clone(),
+            spec,
+        ));
+        let key = Asset::asset_metadata_local_name_to_key(asset_id, name)
+            .map(AssetMetadataKey::from)
+            .unwrap();
+        assert_eq!(Asset::decoded_asset_metadata_value(asset_id, key), Ok(None));
+
+        // Values are not validated until the key opts in.
+        exec_ok!(Asset::set_asset_metadata(
+            owner.origin(),
+            asset_id,
+            key,
+            AssetMetadataValue(b"junk bytes".to_vec()),
+            None,
+        ));
+        assert_eq!(
+            Asset::decoded_asset_metadata_value(asset_id, key),
+            Err(AssetError::InvalidAssetMetadataValue.into())
+        );
+        exec_ok!(Asset::set_asset_metadata_validation(
+            owner.origin(),
+            asset_id,
+            key,
+            true
+        ));
+        assert!(Asset::validated_asset_metadata_keys(asset_id, key));
+
+        // Values that don't match the type definition are rejected.
+        exec_noop!(
+            Asset::set_asset_metadata(
+                owner.origin(),
+                asset_id,
+                key,
+                AssetMetadataValue(b"junk bytes".to_vec()),
+                None,
+            ),
+            AssetError::InvalidAssetMetadataValue
+        );
+
+        let maturity: Moment = 1_700_000_000_000;
+        exec_ok!(Asset::set_asset_metadata(
+            owner.origin(),
+            asset_id,
+            key,
+            AssetMetadataValue(maturity.encode()),
+            None,
+        ));
+        assert_eq!(
+            Asset::decoded_asset_metadata_value(asset_id, key),
+            Ok(Some(AssetMetadataDecodedValue::Unsigned(maturity.into())))
+        );
+
+        // Values of keys without a type definition are kept opaque.
+        let untyped_key = register_metadata_type(owner, None, "UNTYPED");
+        exec_noop!(
+            Asset::set_asset_metadata_validation(owner.origin(), asset_id, untyped_key, true),
+            AssetError::AssetMetadataTypeDefIsMissing
+        );
+        exec_ok!(Asset::set_asset_metadata(
+            owner.origin(),
+            asset_id,
+            untyped_key,
+            AssetMetadataValue(b"junk bytes".to_vec()),
+            None,
+        ));
+        assert_eq!(
+            Asset::decoded_asset_metadata_value(asset_id, untyped_key),
+            Err(AssetError::AssetMetadataTypeDefIsMissing.into())
+        );
+    });
+}
@@ -12,7 +12,7 @@ use sp_std::convert::{From, TryFrom, TryInto};
 use sp_std::iter;
 
 use pallet_asset::{
-    AssetDetails, AssetDocuments, AssetIdentifiers, AssetMetadataLocalKeyToName,
+    Allowances, AssetDetails, AssetDocuments, AssetIdentifiers, AssetMetadataLocalKeyToName,
     AssetMetadataLocalNameToKey, AssetMetadataLocalSpecs, AssetMetadataValues, Assets,
     AssetsExemptFromAffirmation, BalanceOf, Config as AssetConfig, CustomTypeIdSequence,
     CustomTypes, CustomTypesInverse, MandatoryMediators, PreApprovedAsset,
@@ -30,7 +30,7 @@ use polymesh_common_utilities::traits::checkpoint::{
 use polymesh_primitives::agent::AgentGroup;
 use polymesh_primitives::asset::{
     AssetId, AssetName, AssetType, CheckpointId, CustomAssetTypeId, FundingRoundName,
-    NonFungibleType,
+    IssuanceScheduleId, NonFungibleType,
 };
 use polymesh_primitives::asset_metadata::{
     AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataLockStatus, AssetMetadataName,
@@ -76,6 +76,7 @@ type AssetGenesis = pallet_asset::GenesisConfig<TestStorage>;
 type System = frame_system::Pallet<TestStorage>;
 type ExternalAgents = pallet_external_agents::Module<TestStorage>;
 type EAError = pallet_external_agents::Error<TestStorage>;
+type CheckpointError = pallet_asset::checkpoint::Error<TestStorage>;
 type FeeError = pallet_protocol_fee::Error<TestStorage>;
 type PortfolioError = pallet_portfolio::Error<TestStorage>;
 type StoreCallMetadata = pallet_permissions::StoreCallMetadata<TestStorage>;
@@ -658,6 +659,253 @@ fn freeze_unfreeze_asset() {
     });
 }
 
+#[test]
+fn freeze_identity_and_portfolio_balances() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did);
+        let alice_user_portfolio = PortfolioId::user_portfolio(alice.did, PortfolioNumber(1));
+        let bob_default_portfolio = PortfolioId::default_portfolio(bob.did);
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(Portfolio::create_portfolio(
+            alice.origin(),
+            PortfolioName(b"Treasury".to_vec())
+        ));
+
+        assert_noop!(
+            Asset::freeze_identity(bob.origin(), asset_id, alice.did, None),
+            EAError::UnauthorizedAgent
+        );
+        assert_noop!(
+            Asset::freeze_identity(alice.origin(), asset_id, bob.did, Some(0)),
+            AssetError::InvalidFrozenAmount
+        );
+        assert_noop!(
+            Asset::unfreeze_identity(alice.origin(), asset_id, bob.did),
+            AssetError::NotFrozen
+        );
+
+        // Only the holdings above the frozen amount can leave bob's identity.
+        assert_ok!(transfer(asset_id, alice, bob, 1_000));
+        assert_ok!(Asset::freeze_identity(
+            alice.origin(),
+            asset_id,
+            bob.did,
+            Some(600)
+        ));
+        assert_noop!(
+            transfer(asset_id, bob, alice, 500),
+            AssetError::IdentityBalanceFrozen
+        );
+        assert_ok!(transfer(asset_id, bob, alice, 400));
+        assert_ok!(transfer(asset_id, alice, bob, 100));
+
+        assert_ok!(Asset::freeze_identity(
+            alice.origin(),
+            asset_id,
+            bob.did,
+            None
+        ));
+        assert_noop!(
+            transfer(asset_id, bob, alice, 1),
+            AssetError::IdentityBalanceFrozen
+        );
+        assert_ok!(Asset::unfreeze_identity(alice.origin(), asset_id, bob.did));
+        assert_ok!(transfer(asset_id, bob, alice, 700));
+
+        // Freezing a portfolio blocks its holdings only, including moves to other portfolios of the same identity.
+        assert_ok!(Portfolio::move_portfolio_funds(
+            alice.origin(),
+            alice_default_portfolio,
+            alice_user_portfolio,
+            vec![Fund {
+                description: FundDescription::Fungible {
+                    asset_id,
+                    amount: 1_000,
+                },
+                memo: None,
+            }],
+        ));
+        assert_ok!(Asset::freeze_portfolio(
+            alice.origin(),
+            asset_id,
+            alice_default_portfolio,
+            None
+        ));
+        assert_noop!(
+            transfer(asset_id, alice, bob, 1),
+            AssetError::PortfolioBalanceFrozen
+        );
+        assert_noop!(
+            Portfolio::move_portfolio_funds(
+                alice.origin(),
+                alice_default_portfolio,
+                alice_user_portfolio,
+                vec![Fund {
+                    description: FundDescription::Fungible {
+                        asset_id,
+                        amount: 1,
+                    },
+                    memo: None,
+                }],
+            ),
+            AssetError::PortfolioBalanceFrozen
+        );
+        assert_ok!(Asset::base_transfer(
+            alice_user_portfolio,
+            bob_default_portfolio,
+            asset_id,
+            1_000,
+            None,
+            None,
+            IdentityId::default(),
+            &mut weight_meter,
+        ));
+
+        // Frozen holdings are reported for settlement legs.
+        let leg = Leg::Fungible {
+            sender: alice_default_portfolio,
+            receiver: bob_default_portfolio,
+            asset_id,
+            amount: 1,
+        };
+        let errors = Settlement::transfer_report(leg.clone(), false, &mut weight_meter);
+        assert!(errors.contains(&AssetError::PortfolioBalanceFrozen.into()));
+
+        assert_ok!(Asset::unfreeze_portfolio(
+            alice.origin(),
+            asset_id,
+            alice_default_portfolio
+        ));
+        assert!(Settlement::transfer_report(leg, false, &mut weight_meter).is_empty());
+        assert_ok!(transfer(asset_id, alice, bob, 1));
+    });
+}
+
+#[test]
+fn approve_and_transfer_from() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did);
+        let bob_default_portfolio = PortfolioId::default_portfolio(bob.did);
+        let charlie_default_portfolio = PortfolioId::default_portfolio(charlie.did);
+
+        let asset_id = create_and_issue_sample_asset(&alice);
+
+        assert_noop!(
+            Asset::approve(
+                bob.origin(),
+                alice_default_portfolio,
+                asset_id,
+                charlie.did,
+                1_000
+            ),
+            PortfolioError::UnauthorizedCustodian
+        );
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            alice_default_portfolio,
+            asset_id,
+            charlie.did,
+            1_000
+        ));
+        assert_eq!(
+            Asset::allowance((alice_default_portfolio, asset_id), charlie.did),
+            1_000
+        );
+
+        // The receiver must be in custody of the spender or have pre-approved the asset.
+        assert_noop!(
+            Asset::transfer_from(
+                charlie.origin(),
+                alice_default_portfolio,
+                bob_default_portfolio,
+                asset_id,
+                100
+            ),
+            AssetError::TransferReceiverNotPreApproved
+        );
+        assert_noop!(
+            Asset::transfer_from(
+                charlie.origin(),
+                alice_default_portfolio,
+                charlie_default_portfolio,
+                asset_id,
+                1_001
+            ),
+            AssetError::InsufficientAllowance
+        );
+        assert_ok!(Asset::transfer_from(
+            charlie.origin(),
+            alice_default_portfolio,
+            charlie_default_portfolio,
+            asset_id,
+            600
+        ));
+        assert_eq!(Asset::balance_of(asset_id, charlie.did), 600);
+        assert_eq!(
+            Asset::allowance((alice_default_portfolio, asset_id), charlie.did),
+            400
+        );
+
+        assert_ok!(Asset::pre_approve_asset(bob.origin(), asset_id));
+        assert_ok!(Asset::transfer_from(
+            charlie.origin(),
+            alice_default_portfolio,
+            bob_default_portfolio,
+            asset_id,
+            400
+        ));
+        assert_eq!(Asset::balance_of(asset_id, bob.did), 400);
+        assert!(!Allowances::contains_key(
+            (alice_default_portfolio, asset_id),
+            charlie.did
+        ));
+
+        // Assets with mandatory mediators can only be spent through settlement.
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            alice_default_portfolio,
+            asset_id,
+            charlie.did,
+            1_000
+        ));
+        assert_ok!(Asset::add_mandatory_mediators(
+            alice.origin(),
+            asset_id,
+            BTreeSet::from([bob.did]).try_into().unwrap()
+        ));
+        assert_noop!(
+            Asset::transfer_from(
+                charlie.origin(),
+                alice_default_portfolio,
+                charlie_default_portfolio,
+                asset_id,
+                100
+            ),
+            AssetError::AssetRequiresMediation
+        );
+
+        // An allowance of zero removes the entry.
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            alice_default_portfolio,
+            asset_id,
+            charlie.did,
+            0
+        ));
+        assert!(!Allowances::contains_key(
+            (alice_default_portfolio, asset_id),
+            charlie.did
+        ));
+    });
+}
+
 #[test]
 fn frozen_secondary_keys_create_asset_we() {
     ExtBuilder::default().build().execute_with(|| {
@@ -801,6 +1049,119 @@ fn non_recurring_schedule_works_we() {
     });
 }
 
+#[test]
+fn issuance_schedules() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1_000);
+
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        let schedule_id = IssuanceScheduleId(1);
+
+        // For simplicity, we use 1s = 1_000ms periods.
+        let period = CalendarPeriod {
+            unit: CalendarUnit::Second,
+            amount: 1,
+        };
+        let create = |user: User, amount, cap, start| {
+            Asset::create_issuance_schedule(
+                user.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                amount,
+                cap,
+                start,
+                period,
+            )
+        };
+
+        assert_noop!(
+            create(alice, 100, 250, 2_000),
+            CheckpointError::SchedulesOverMaxComplexity
+        );
+        assert_ok!(Checkpoint::set_schedules_max_complexity(
+            root(),
+            period.complexity()
+        ));
+        assert_noop!(create(bob, 100, 250, 2_000), EAError::UnauthorizedAgent);
+        assert_noop!(
+            create(alice, 0, 250, 2_000),
+            AssetError::InvalidIssuanceSchedule
+        );
+        assert_noop!(
+            create(alice, 100, 50, 2_000),
+            AssetError::InvalidIssuanceSchedule
+        );
+        assert_noop!(
+            create(alice, 100, 250, 500),
+            AssetError::InvalidIssuanceSchedule
+        );
+        assert_ok!(create(alice, 100, 250, 2_000));
+        assert_eq!(
+            Asset::issuance_schedules_complexity(asset_id),
+            period.complexity()
+        );
+        assert_noop!(
+            create(alice, 100, 250, 2_000),
+            CheckpointError::SchedulesOverMaxComplexity
+        );
+        assert_noop!(
+            Asset::execute_issuance_schedule(bob.origin(), asset_id, schedule_id),
+            AssetError::IssuanceNotDue
+        );
+
+        // All due periods are issued at once.
+        set_timestamp(3_500);
+        assert_ok!(Asset::execute_issuance_schedule(
+            bob.origin(),
+            asset_id,
+            schedule_id
+        ));
+        assert_eq!(Asset::balance_of(asset_id, alice.did), ISSUE_AMOUNT + 200);
+        assert_eq!(
+            Asset::issuance_schedules(asset_id, schedule_id)
+                .unwrap()
+                .next_at,
+            Some(4_000)
+        );
+
+        // The last issuance is limited by the cap and finishes the schedule.
+        set_timestamp(10_000);
+        assert_ok!(Asset::execute_issuance_schedule(
+            bob.origin(),
+            asset_id,
+            schedule_id
+        ));
+        assert_eq!(Asset::balance_of(asset_id, alice.did), ISSUE_AMOUNT + 250);
+        assert_eq!(
+            Asset::assets_details(asset_id).unwrap().total_supply,
+            ISSUE_AMOUNT + 250
+        );
+        assert_eq!(Asset::issuance_schedules(asset_id, schedule_id), None);
+        assert_eq!(Asset::issuance_schedules_complexity(asset_id), 0);
+        assert_noop!(
+            Asset::execute_issuance_schedule(bob.origin(), asset_id, schedule_id),
+            AssetError::NoSuchIssuanceSchedule
+        );
+
+        // Agents can remove a schedule before it finishes.
+        assert_ok!(create(alice, 100, 250, 20_000));
+        let schedule_id = IssuanceScheduleId(2);
+        assert_noop!(
+            Asset::remove_issuance_schedule(bob.origin(), asset_id, schedule_id),
+            EAError::UnauthorizedAgent
+        );
+        assert_ok!(Asset::remove_issuance_schedule(
+            alice.origin(),
+            asset_id,
+            schedule_id
+        ));
+        assert_eq!(Asset::issuance_schedules(asset_id, schedule_id), None);
+        assert_eq!(Asset::issuance_schedules_complexity(asset_id), 0);
+    });
+}
+
 fn checkpoint_ats(asset_id: AssetId) -> Vec<u64> {
     let cached = Checkpoint::cached_next_checkpoints(asset_id).unwrap_or_default();
     cached.schedules.values().copied().collect()
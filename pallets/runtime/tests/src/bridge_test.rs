@@ -0,0 +1,332 @@
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_core::{H160, H256};
+use sp_keyring::AccountKeyring;
+
+use pallet_bridge::{BridgeTx, BridgeTxStatus, RawEvent};
+use polymesh_primitives::{AccountId, Balance, Signatory};
+
+use super::multisig::create_signers;
+use super::next_block;
+use super::storage::{get_last_auth_id, EventTest, TestStorage, User};
+use super::ExtBuilder;
+
+type Balances = pallet_balances::Module<TestStorage>;
+type Bridge = pallet_bridge::Module<TestStorage>;
+type Error = pallet_bridge::Error<TestStorage>;
+type MultiSig = pallet_multisig::Pallet<TestStorage>;
+type Origin = <TestStorage as frame_system::Config>::RuntimeOrigin;
+type System = frame_system::Pallet<TestStorage>;
+
+const AMOUNT: Balance = 1_000;
+
+/// A local relayer network made of the signers of the bridge controller multisig.
+struct MockRelayer {
+    controller: AccountId,
+    signers: Vec<AccountKeyring>,
+}
+
+impl MockRelayer {
+    /// Creates a controller multisig owned by `owner` and sets it as the bridge controller.
+    fn new(owner: &User, signers: Vec<AccountKeyring>, sigs_required: u64) -> Self {
+        let controller = MultiSig::get_next_multisig_address(owner.acc()).expect("Next MS");
+        let accounts = signers.iter().map(|ring| ring.to_account_id()).collect();
+        assert_ok!(MultiSig::create_multisig(
+            owner.origin(),
+            create_signers(accounts),
+            sigs_required,
+            None
+        ));
+        for ring in &signers {
+            let auth_id = get_last_auth_id(&Signatory::Account(ring.to_account_id()));
+            assert_ok!(MultiSig::accept_multisig_signer(
+                Origin::signed(ring.to_account_id()),
+                auth_id
+            ));
+        }
+        assert_ok!(Bridge::change_controller(
+            RawOrigin::Root.into(),
+            controller.clone()
+        ));
+        Self {
+            controller,
+            signers,
+        }
+    }
+
+    /// Proposes or approves `bridge_tx` as the signer at index `signer`.
+    fn propose(&self, signer: usize, bridge_tx: &BridgeTx<AccountId>) {
+        assert_ok!(Bridge::propose_bridge_tx(
+            Origin::signed(self.signers[signer].to_account_id()),
+            bridge_tx.clone()
+        ));
+    }
+
+    /// Relays `bridge_tx` with the first `n` signers.
+    fn relay(&self, bridge_tx: &BridgeTx<AccountId>, n: usize) {
+        for signer in 0..n {
+            self.propose(signer, bridge_tx);
+        }
+    }
+}
+
+fn bridge_tx(recipient: &User, nonce: u32, amount: Balance) -> BridgeTx<AccountId> {
+    BridgeTx {
+        nonce,
+        recipient: recipient.acc(),
+        amount,
+        tx_hash: H256::zero(),
+    }
+}
+
+fn tx_status(bridge_tx: &BridgeTx<AccountId>) -> BridgeTxStatus {
+    Bridge::bridge_tx_details(&bridge_tx.recipient, bridge_tx.nonce).status
+}
+
+fn setup_admin(admin: &User) {
+    assert_ok!(Bridge::change_admin(RawOrigin::Root.into(), admin.acc()));
+}
+
+fn mock_relayer(owner: &User) -> MockRelayer {
+    MockRelayer::new(
+        owner,
+        vec![
+            AccountKeyring::Bob,
+            AccountKeyring::Charlie,
+            AccountKeyring::Dave,
+        ],
+        2,
+    )
+}
+
+#[test]
+fn bridge_tx_is_credited_at_quorum() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let eve = User::new(AccountKeyring::Eve);
+        let relayer = mock_relayer(&alice);
+        let tx = bridge_tx(&eve, 1, AMOUNT);
+        let balance = Balances::free_balance(eve.acc());
+
+        relayer.propose(0, &tx);
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Absent);
+        assert_eq!(Balances::free_balance(eve.acc()), balance);
+
+        relayer.propose(1, &tx);
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Handled);
+        assert_eq!(Balances::free_balance(eve.acc()), balance + AMOUNT);
+        assert!(System::events()
+            .iter()
+            .any(|e| e.event == EventTest::Bridge(RawEvent::Bridged(eve.did, tx.clone()))));
+
+        // Only the controller can accept new bridge transactions.
+        assert_noop!(
+            Bridge::handle_bridge_tx(eve.origin(), bridge_tx(&eve, 2, AMOUNT)),
+            Error::BadCaller
+        );
+        assert_noop!(
+            Bridge::handle_bridge_tx(Origin::signed(relayer.controller.clone()), tx),
+            Error::ProposalAlreadyHandled
+        );
+    });
+}
+
+#[test]
+fn timelocked_bridge_tx_can_be_frozen() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let eve = User::new(AccountKeyring::Eve);
+        setup_admin(&alice);
+        let relayer = mock_relayer(&alice);
+        assert_ok!(Bridge::change_timelock(alice.origin(), 3));
+        let tx = bridge_tx(&eve, 1, AMOUNT);
+        let balance = Balances::free_balance(eve.acc());
+
+        relayer.relay(&tx, 2);
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Timelocked);
+        assert_ok!(Bridge::freeze_txs(alice.origin(), vec![tx.clone()]));
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Frozen);
+
+        // The cancelled execution does not credit the frozen transaction.
+        for _ in 0..4 {
+            next_block();
+        }
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Frozen);
+        assert_eq!(Balances::free_balance(eve.acc()), balance);
+
+        // The timelock has expired, so the transaction is credited when unfrozen.
+        assert_ok!(Bridge::unfreeze_txs(alice.origin(), vec![tx.clone()]));
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Handled);
+        assert_eq!(Balances::free_balance(eve.acc()), balance + AMOUNT);
+    });
+}
+
+#[test]
+fn timelocked_bridge_tx_is_credited_by_scheduler() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let eve = User::new(AccountKeyring::Eve);
+        setup_admin(&alice);
+        let relayer = mock_relayer(&alice);
+        assert_ok!(Bridge::change_timelock(alice.origin(), 3));
+        let tx = bridge_tx(&eve, 1, AMOUNT);
+        let balance = Balances::free_balance(eve.acc());
+
+        relayer.relay(&tx, 2);
+        next_block();
+        next_block();
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Timelocked);
+        next_block();
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Handled);
+        assert_eq!(Balances::free_balance(eve.acc()), balance + AMOUNT);
+    });
+}
+
+#[test]
+fn bridge_limit_leaves_tx_pending() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let eve = User::new(AccountKeyring::Eve);
+        setup_admin(&alice);
+        let relayer = mock_relayer(&alice);
+        assert_ok!(Bridge::change_bridge_limit(alice.origin(), AMOUNT, 100));
+        let first_tx = bridge_tx(&eve, 1, AMOUNT);
+        let second_tx = bridge_tx(&eve, 2, AMOUNT);
+        let balance = Balances::free_balance(eve.acc());
+
+        relayer.relay(&first_tx, 2);
+        relayer.relay(&second_tx, 2);
+        assert_eq!(tx_status(&first_tx), BridgeTxStatus::Handled);
+        assert_eq!(tx_status(&second_tx), BridgeTxStatus::Pending(1));
+        assert_eq!(Balances::free_balance(eve.acc()), balance + AMOUNT);
+        assert!(System::events().iter().any(|e| e.event
+            == EventTest::Bridge(RawEvent::BridgeTxFailed(
+                second_tx.clone(),
+                Error::BridgeLimitReached.into()
+            ))));
+
+        // Anyone can retry a pending transaction, but not alter its amount.
+        assert_ok!(Bridge::handle_bridge_tx(eve.origin(), second_tx.clone()));
+        assert_eq!(tx_status(&second_tx), BridgeTxStatus::Pending(2));
+
+        assert_ok!(Bridge::change_bridge_exempted(
+            alice.origin(),
+            vec![(eve.did, true)]
+        ));
+        let altered_tx = bridge_tx(&eve, 2, 10 * AMOUNT);
+        assert_ok!(Bridge::handle_bridge_tx(eve.origin(), altered_tx));
+        assert_eq!(tx_status(&second_tx), BridgeTxStatus::Handled);
+        assert_eq!(Balances::free_balance(eve.acc()), balance + 2 * AMOUNT);
+    });
+}
+
+#[test]
+fn frozen_bridge_leaves_tx_pending() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let ferdie = User::new(AccountKeyring::Ferdie);
+        let eve = User::new(AccountKeyring::Eve);
+        setup_admin(&alice);
+        let relayer = mock_relayer(&alice);
+
+        // Freeze admins can freeze the bridge, but only the admin can unfreeze it.
+        assert_noop!(Bridge::freeze(ferdie.origin()), Error::BadAdmin);
+        assert_ok!(Bridge::add_freeze_admin(alice.origin(), ferdie.acc()));
+        assert_ok!(Bridge::freeze(ferdie.origin()));
+        assert_noop!(Bridge::unfreeze(ferdie.origin()), Error::BadAdmin);
+
+        let tx = bridge_tx(&eve, 1, AMOUNT);
+        relayer.relay(&tx, 2);
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Pending(1));
+
+        assert_ok!(Bridge::unfreeze(alice.origin()));
+        assert_ok!(Bridge::handle_bridge_tx(ferdie.origin(), tx.clone()));
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Handled);
+    });
+}
+
+#[test]
+fn burn_for_bridge() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice).balance(10 * AMOUNT);
+        let recipient = H160::repeat_byte(1);
+        let total_issuance = Balances::total_issuance();
+
+        assert_ok!(Bridge::burn_for_bridge(alice.origin(), AMOUNT, recipient));
+        assert_eq!(Balances::free_balance(alice.acc()), 9 * AMOUNT);
+        assert_eq!(Balances::total_issuance(), total_issuance - AMOUNT);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            EventTest::Bridge(RawEvent::BridgeBurned(
+                alice.did,
+                alice.acc(),
+                AMOUNT,
+                recipient
+            ))
+        );
+
+        setup_admin(&alice);
+        assert_ok!(Bridge::freeze(alice.origin()));
+        assert_noop!(
+            Bridge::burn_for_bridge(alice.origin(), AMOUNT, recipient),
+            Error::Frozen
+        );
+    });
+}
+
+#[test]
+fn bogus_bridge_tx_proposal_is_not_approved() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let eve = User::new(AccountKeyring::Eve);
+        let relayer = mock_relayer(&alice);
+        let tx = bridge_tx(&eve, 1, AMOUNT);
+        let bogus_tx = bridge_tx(&eve, 1, AMOUNT * 100);
+        let balance = Balances::free_balance(eve.acc());
+
+        // A relayer front-runs the transaction with a bogus amount.
+        relayer.propose(0, &bogus_tx);
+        relayer.propose(1, &tx);
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Absent);
+
+        relayer.propose(2, &tx);
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Handled);
+        assert_eq!(Balances::free_balance(eve.acc()), balance + AMOUNT);
+
+        // The bogus proposal can no longer reach the quorum.
+        assert_noop!(
+            Bridge::propose_bridge_tx(Origin::signed(relayer.signers[1].to_account_id()), bogus_tx),
+            Error::ProposalAlreadyHandled
+        );
+    });
+}
+
+#[test]
+fn bridge_tx_proposal_of_previous_controller_is_replaced() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let eve = User::new(AccountKeyring::Eve);
+        let relayer = mock_relayer(&alice);
+        let tx = bridge_tx(&eve, 1, AMOUNT);
+        let balance = Balances::free_balance(eve.acc());
+
+        relayer.propose(0, &tx);
+        let new_relayer =
+            MockRelayer::new(&alice, vec![AccountKeyring::Ferdie, AccountKeyring::One], 2);
+        assert_eq!(
+            Bridge::bridge_tx_proposals(&tx.recipient, tx.proposal_hash()).map(|(c, _)| c),
+            Some(relayer.controller)
+        );
+
+        new_relayer.relay(&tx, 2);
+        assert_eq!(tx_status(&tx), BridgeTxStatus::Handled);
+        assert_eq!(Balances::free_balance(eve.acc()), balance + AMOUNT);
+    });
+}
@@ -8,11 +8,12 @@ use polymesh_common_utilities::compliance_manager::ComplianceFnConfig;
 use polymesh_primitives::agent::AgentGroup;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::compliance_manager::{
-    ComplianceReport, ComplianceRequirement, ComplianceRequirementResult,
+    ComplianceReport, ComplianceRequirement, ComplianceRequirementResult, ExpressionReport,
 };
 use polymesh_primitives::{
-    AuthorizationData, Claim, ClaimType, Condition, ConditionType, CountryCode, IdentityId,
-    PortfolioId, Scope, Signatory, TargetIdentity, TrustedFor, WeightMeter,
+    AuthorizationData, Claim, ClaimDatePredicate, ClaimType, Condition, ConditionExpression,
+    ConditionType, CountryCode, IdentityId, PortfolioId, Scope, Signatory, TargetIdentity,
+    TrustedFor, WeightMeter,
 };
 use sp_keyring::AccountKeyring;
 
@@ -950,6 +951,217 @@ fn jurisdiction_asset_compliance_we() {
     assert_invalid_transfer!(asset_id, owner.did, user.did, 10);
 }
 
+#[test]
+fn expression_asset_compliance() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Eve.to_account_id()])
+        .build()
+        .execute_with(expression_asset_compliance_we);
+}
+fn expression_asset_compliance_we() {
+    // 0. Create accounts
+    let owner = User::new(AccountKeyring::Alice);
+    let issuer = User::new(AccountKeyring::Bob);
+    let charlie = User::new(AccountKeyring::Charlie);
+    let dave = User::new(AccountKeyring::Dave);
+
+    // 1. Create & mint token
+    let asset_id = create_and_issue_sample_asset(&owner);
+
+    // 2. Set up "(Accredited AND Jurisdiction=US) OR (Jurisdiction!=US AND KYC)" for receivers.
+    let scope = Scope::from(IdentityId::from(0));
+    let accredited = Claim::Accredited(scope.clone());
+    let us = Claim::Jurisdiction(CountryCode::US, scope.clone());
+    let kyc = Claim::KnowYourCustomer(scope.clone());
+    let present = |claim: &Claim| ConditionExpression::IsPresent(claim.clone());
+    let expression = ConditionExpression::Or(vec![
+        ConditionExpression::And(vec![present(&accredited), present(&us)]),
+        ConditionExpression::And(vec![
+            ConditionExpression::Not(Box::new(present(&us))),
+            present(&kyc),
+        ]),
+    ]);
+    let condition =
+        |expression| Condition::from_dids(ConditionType::Expression(expression), &[issuer.did]);
+
+    // Expression trees are limited in depth.
+    let too_deep = (0..5).fold(present(&kyc), |expr, _| {
+        ConditionExpression::Not(Box::new(expr))
+    });
+    assert_noop!(
+        ComplianceManager::add_compliance_requirement(
+            owner.origin(),
+            asset_id,
+            vec![],
+            vec![condition(too_deep)]
+        ),
+        CMError::<TestStorage>::ConditionExpressionTooDeep
+    );
+    assert_ok!(ComplianceManager::add_compliance_requirement(
+        owner.origin(),
+        asset_id,
+        vec![],
+        vec![condition(expression)]
+    ));
+
+    // 3. Validate behaviour.
+    let add_claim = |user: User, claim: &Claim| {
+        assert_add_claim!(issuer.origin(), user.did, claim.clone(), None);
+    };
+    let receiver_report = |user: User| {
+        let report = ComplianceManager::compliance_report(
+            &asset_id,
+            &owner.did,
+            &user.did,
+            &mut WeightMeter::max_limit_no_minimum(),
+        )
+        .unwrap();
+        let condition = report
+            .get_requirement(0)
+            .unwrap()
+            .get_receiver_condition(0)
+            .unwrap();
+        (
+            condition.is_condition_satisfied(),
+            condition.expression.clone().unwrap(),
+        )
+    };
+    let node = |satisfied, children| ExpressionReport::new(satisfied, children);
+    let leaf = |satisfied| ExpressionReport::new(satisfied, vec![]);
+
+    // 3.1. A KYCed investor outside the US is allowed.
+    assert_invalid_transfer!(asset_id, owner.did, charlie.did, 10);
+    add_claim(charlie, &kyc);
+    assert_valid_transfer!(asset_id, owner.did, charlie.did, 10);
+
+    // 3.2. A KYCed investor in the US must also be accredited.
+    add_claim(dave, &kyc);
+    add_claim(dave, &us);
+    assert_invalid_transfer!(asset_id, owner.did, dave.did, 10);
+    let expected = node(
+        false,
+        vec![
+            node(false, vec![leaf(false), leaf(true)]),
+            node(false, vec![node(false, vec![leaf(true)]), leaf(true)]),
+        ],
+    );
+    assert_eq!(receiver_report(dave), (false, expected));
+
+    add_claim(dave, &accredited);
+    assert_valid_transfer!(asset_id, owner.did, dave.did, 10);
+    let expected = node(
+        true,
+        vec![
+            node(true, vec![leaf(true), leaf(true)]),
+            node(false, vec![node(false, vec![leaf(true)]), leaf(true)]),
+        ],
+    );
+    assert_eq!(receiver_report(dave), (true, expected));
+}
+
+#[test]
+fn claim_predicate_asset_compliance() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Eve.to_account_id()])
+        .build()
+        .execute_with(claim_predicate_asset_compliance_we);
+}
+fn claim_predicate_asset_compliance_we() {
+    // 0. Create accounts
+    let owner = User::new(AccountKeyring::Alice);
+    let issuer = User::new(AccountKeyring::Bob);
+    let charlie = User::new(AccountKeyring::Charlie);
+
+    // 1. Create & mint token
+    let asset_id = create_and_issue_sample_asset(&owner);
+
+    // 2. Receivers need a recent KYC, an accreditation valid for a while and a US or CA jurisdiction.
+    let scope = Scope::from(IdentityId::from(0));
+    let kyc = Claim::KnowYourCustomer(scope.clone());
+    let accredited = Claim::Accredited(scope.clone());
+    let condition = |condition_type| Condition::from_dids(condition_type, &[issuer.did]);
+    assert_noop!(
+        ComplianceManager::add_compliance_requirement(
+            owner.origin(),
+            asset_id,
+            vec![],
+            vec![condition(ConditionType::IsJurisdictionIn(
+                vec![],
+                scope.clone()
+            ))]
+        ),
+        CMError::<TestStorage>::EmptyJurisdictionSet
+    );
+    assert_ok!(ComplianceManager::add_compliance_requirement(
+        owner.origin(),
+        asset_id,
+        vec![],
+        vec![
+            condition(ConditionType::IsPresentWith(
+                kyc.clone(),
+                ClaimDatePredicate::IssuedWithin(5_000)
+            )),
+            condition(ConditionType::IsPresentWith(
+                accredited.clone(),
+                ClaimDatePredicate::ValidFor(1_000)
+            )),
+            condition(ConditionType::IsJurisdictionIn(
+                vec![CountryCode::US, CountryCode::CA],
+                scope.clone()
+            )),
+        ]
+    ));
+
+    // 3. Validate behaviour.
+    set_timestamp(10_000);
+    assert_add_claim!(issuer.origin(), charlie.did, kyc.clone(), None);
+    assert_add_claim!(
+        issuer.origin(),
+        charlie.did,
+        accredited.clone(),
+        Some(12_000)
+    );
+    let jurisdiction = |country| Claim::Jurisdiction(country, scope.clone());
+    assert_add_claim!(
+        issuer.origin(),
+        charlie.did,
+        jurisdiction(CountryCode::GB),
+        None
+    );
+    assert_invalid_transfer!(asset_id, owner.did, charlie.did, 10);
+    assert_add_claim!(
+        issuer.origin(),
+        charlie.did,
+        jurisdiction(CountryCode::CA),
+        None
+    );
+    assert_valid_transfer!(asset_id, owner.did, charlie.did, 10);
+
+    // 3.1. The accreditation expires too soon.
+    set_timestamp(11_500);
+    assert_invalid_transfer!(asset_id, owner.did, charlie.did, 10);
+    assert_add_claim!(
+        issuer.origin(),
+        charlie.did,
+        accredited.clone(),
+        Some(20_000)
+    );
+    assert_valid_transfer!(asset_id, owner.did, charlie.did, 10);
+
+    // 3.2. The KYC is too old, and renewing it keeps its original issuance date.
+    set_timestamp(15_001);
+    assert_invalid_transfer!(asset_id, owner.did, charlie.did, 10);
+    assert_add_claim!(issuer.origin(), charlie.did, kyc.clone(), None);
+    assert_invalid_transfer!(asset_id, owner.did, charlie.did, 10);
+    assert_ok!(Identity::revoke_claim(
+        issuer.origin(),
+        charlie.did,
+        kyc.clone()
+    ));
+    assert_add_claim!(issuer.origin(), charlie.did, kyc, None);
+    assert_valid_transfer!(asset_id, owner.did, charlie.did, 10);
+}
+
 #[test]
 fn scope_asset_compliance() {
     ExtBuilder::default()
@@ -1565,6 +1777,47 @@ fn should_limit_compliance_requirements_complexity_we() {
     assert_eq!(asset_compliance.requirements.len(), 1);
 }
 
+#[test]
+fn should_limit_jurisdiction_set_complexity() {
+    ExtBuilder::default()
+        .build()
+        .execute_with(should_limit_jurisdiction_set_complexity_we);
+}
+
+fn should_limit_jurisdiction_set_complexity_we() {
+    let owner = User::new(AccountKeyring::Alice);
+
+    // 1. Create & mint token
+    let asset_id = create_and_issue_sample_asset(&owner);
+
+    let scope = Scope::Asset(asset_id);
+    let condition = |len| {
+        Condition::from_dids(
+            ConditionType::IsJurisdictionIn(vec![CountryCode::US; len], scope.clone()),
+            &[owner.did],
+        )
+    };
+
+    // Complexity = 51*1 = 51
+    assert_noop!(
+        ComplianceManager::add_compliance_requirement(
+            owner.origin(),
+            asset_id,
+            vec![],
+            vec![condition(51)]
+        ),
+        CMError::<TestStorage>::ComplianceRequirementTooComplex
+    );
+
+    // Complexity = 50*1 = 50
+    assert_ok!(ComplianceManager::add_compliance_requirement(
+        owner.origin(),
+        asset_id,
+        vec![],
+        vec![condition(50)]
+    ));
+}
+
 #[test]
 fn check_new_return_type_of_rpc() {
     ExtBuilder::default().build().execute_with(|| {
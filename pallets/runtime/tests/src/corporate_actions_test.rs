@@ -14,7 +14,7 @@ use frame_support::{
 use pallet_asset::Assets;
 use pallet_corporate_actions::{
     ballot::{BallotMeta, BallotTimeRange, BallotVote, Motion, Votes},
-    distribution::{self, Distribution, PER_SHARE_PRECISION},
+    distribution::{self, Distribution, DistributionEntitlement, PER_SHARE_PRECISION},
     CACheckpoint, CADetails, CAId, CAIdSequence, CAKind, CorporateAction, CorporateActions,
     Details, LocalCAId, RecordDate, RecordDateSpec, TargetIdentities, TargetTreatment,
     TargetTreatment::{Exclude, Include},
@@ -2151,6 +2151,90 @@ fn dist_claim_works() {
     });
 }
 
+#[test]
+fn dist_entitlement_works() {
+    currency_test(|asset_id, currency, [owner, foo, bar]| {
+        // Transfer 500 to `foo` and 1000 to `bar`.
+        transfer(&asset_id, owner, foo);
+        transfer_amount(&asset_id, owner, bar, AMOUNT * 2);
+
+        // Create the dist.
+        let id = dist_ca(owner, asset_id, Some(1)).unwrap();
+        let per_share = 101 * PER_SHARE_PRECISION;
+        assert_ok!(Dist::distribute(
+            owner.origin(),
+            id,
+            None,
+            currency,
+            per_share,
+            200_000,
+            5,
+            None,
+        ));
+
+        // `foo` has the default 25% tax and `bar` a 1/3 tax.
+        let tax_bar = Tax::from_rational(1u32, 3u32);
+        CorporateActions::mutate(asset_id, id.local_id, |ca| {
+            let ca = ca.as_mut().unwrap();
+            ca.default_withholding_tax = P25;
+            ca.withholding_tax = vec![(bar.did, tax_bar)];
+        });
+
+        let entitlement = |balance: Balance, tax: Tax, paid| {
+            let gross = balance * per_share / PER_SHARE_PRECISION;
+            let withheld = tax * gross;
+            DistributionEntitlement {
+                balance,
+                gross,
+                tax,
+                withheld,
+                net: gross - withheld,
+                paid,
+            }
+        };
+        let foo_unpaid = entitlement(AMOUNT, P25, false);
+        let bar_unpaid = entitlement(AMOUNT * 2, tax_bar, false);
+
+        // Entitlements can be previewed before the payment date.
+        assert_eq!(Dist::entitlement(foo.did, id), Ok(foo_unpaid));
+        assert_eq!(Dist::entitlement(bar.did, id), Ok(bar_unpaid));
+        assert_eq!(
+            Dist::unclaimed_distributions(foo.did, asset_id),
+            vec![(id, foo_unpaid)]
+        );
+        assert_eq!(
+            Dist::unclaimed_distributions(bar.did, asset_id),
+            vec![(id, bar_unpaid)]
+        );
+        // Only the distributions of the given asset are returned.
+        assert_eq!(Dist::unclaimed_distributions(foo.did, currency), vec![]);
+
+        // `foo` claims, so it's no longer unclaimed for them.
+        set_timestamp(5);
+        assert_ok!(Dist::claim(foo.origin(), id));
+        assert_eq!(Asset::balance_of(&currency, foo.did), foo_unpaid.net);
+        assert_eq!(
+            Dist::entitlement(foo.did, id),
+            Ok(entitlement(AMOUNT, P25, true))
+        );
+        assert_eq!(Dist::unclaimed_distributions(foo.did, asset_id), vec![]);
+        assert_eq!(
+            Dist::unclaimed_distributions(bar.did, asset_id),
+            vec![(id, bar_unpaid)]
+        );
+
+        // Untargeted identities have no entitlement.
+        CorporateActions::mutate(asset_id, id.local_id, |ca| {
+            ca.as_mut().unwrap().targets = TargetIdentities {
+                identities: vec![bar.did],
+                treatment: Exclude,
+            };
+        });
+        assert_noop!(Dist::entitlement(bar.did, id), Error::NotTargetedByCA);
+        assert_eq!(Dist::unclaimed_distributions(bar.did, asset_id), vec![]);
+    });
+}
+
 #[test]
 fn dist_claim_rounding_indivisible() {
     currency_test(|asset_id, currency, [owner, foo, bar]| {
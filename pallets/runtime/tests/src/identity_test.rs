@@ -35,8 +35,8 @@ use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::{
     AccountId, AssetPermissions, AuthorizationData, AuthorizationType, Claim, ClaimType,
     CustomClaimTypeId, ExtrinsicName, ExtrinsicPermissions, IdentityClaim, IdentityId, KeyRecord,
-    PalletName, PalletPermissions, Permissions, PortfolioId, PortfolioNumber, Scope, SecondaryKey,
-    Signatory, SubsetRestriction, Ticker, TransactionError,
+    PalletName, PalletPermissions, Permissions, PortfolioId, PortfolioName, PortfolioNumber, Scope,
+    SecondaryKey, Signatory, SubsetRestriction, Ticker, TransactionError,
 };
 use polymesh_runtime_develop::runtime::{CddHandler, RuntimeCall};
 use sp_core::H512;
@@ -51,6 +51,7 @@ type Balances = balances::Module<TestStorage>;
 type BaseError = pallet_base::Error<TestStorage>;
 type Identity = pallet_identity::Module<TestStorage>;
 type ParentDid = pallet_identity::ParentDid;
+type Portfolio = pallet_portfolio::Module<TestStorage>;
 type MultiSig = pallet_multisig::Pallet<TestStorage>;
 type System = frame_system::Pallet<TestStorage>;
 type Timestamp = pallet_timestamp::Pallet<TestStorage>;
@@ -2240,3 +2241,153 @@ fn do_create_child_identities_with_auth_test() {
         expires_at
     ));
 }
+
+#[test]
+fn identity_recovery() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Eve.to_account_id()])
+        .build()
+        .execute_with(identity_recovery_we);
+}
+
+fn identity_recovery_we() {
+    System::set_block_number(1);
+    let alice = User::new(AccountKeyring::Alice);
+    let bob = User::new(AccountKeyring::Bob);
+    let charlie = User::new(AccountKeyring::Charlie);
+    let cdd_did = get_identity_id(AccountKeyring::Eve).unwrap();
+
+    // Alice holds an asset, a user portfolio, an asset scoped claim and a pending authorization.
+    let asset_id = create_and_issue_sample_asset(&alice);
+    let balance = Asset::balance_of(&asset_id, alice.did);
+    assert_ok!(Portfolio::create_portfolio(
+        alice.origin(),
+        PortfolioName(b"AlicePortfolio".to_vec())
+    ));
+    let alice_user_portfolio = PortfolioId::user_portfolio(alice.did, PortfolioNumber(1));
+    let asset_claim = Claim::Accredited(Scope::Asset(asset_id));
+    let identity_claim = Claim::Accredited(Scope::Identity(charlie.did));
+    for claim in [asset_claim.clone(), identity_claim.clone()] {
+        assert_ok!(Identity::add_claim(
+            charlie.origin(),
+            alice.did,
+            claim,
+            None
+        ));
+    }
+    let charlie_portfolio = PortfolioId::default_portfolio(charlie.did);
+    let custody_auth_id = Identity::add_auth(
+        charlie.did,
+        alice.signatory_did(),
+        AuthorizationData::PortfolioCustody(charlie_portfolio),
+        None,
+    )
+    .unwrap();
+
+    let attest = |attestor: IdentityId| {
+        Identity::add_auth(
+            attestor,
+            bob.signatory_acc(),
+            AuthorizationData::AttestPrimaryKeyRotation(alice.did),
+            None,
+        )
+        .unwrap()
+    };
+
+    // Only CDD providers can attest a recovery.
+    let charlie_auth_id = attest(charlie.did);
+    assert_noop!(
+        Identity::initiate_identity_recovery(bob.origin(), charlie_auth_id),
+        Error::NotCddProviderAttestation
+    );
+    assert_ok!(Identity::initiate_identity_recovery(
+        bob.origin(),
+        attest(cdd_did)
+    ));
+    assert_noop!(
+        Identity::complete_identity_recovery(bob.origin(), alice.did, 10, 10, 10),
+        Error::IdentityRecoveryDelayNotElapsed
+    );
+
+    // The old primary key can cancel the recovery.
+    assert_ok!(Identity::cancel_identity_recovery(alice.origin()));
+    assert_eq!(Identity::pending_recovery(alice.did), None);
+    assert_noop!(
+        Identity::cancel_identity_recovery(alice.origin()),
+        Error::NoPendingIdentityRecovery
+    );
+
+    assert_ok!(Identity::initiate_identity_recovery(
+        bob.origin(),
+        attest(cdd_did)
+    ));
+    let recovery = Identity::pending_recovery(alice.did).unwrap();
+    assert_eq!(recovery.successor, bob.did);
+    assert_eq!(recovery.attested_by, cdd_did);
+    set_timestamp(recovery.executable_at);
+
+    assert_noop!(
+        Identity::complete_identity_recovery(charlie.origin(), alice.did, 10, 10, 10),
+        Error::NotRecoverySuccessor
+    );
+    // Two portfolios, one asset scoped claim and one authorization are migrated,
+    // and nothing is migrated if any of them doesn't fit.
+    for (max_portfolios, max_claims, max_auths) in [(1, 1, 1), (2, 0, 1), (2, 1, 0)] {
+        assert_noop!(
+            Identity::complete_identity_recovery(
+                bob.origin(),
+                alice.did,
+                max_portfolios,
+                max_claims,
+                max_auths
+            ),
+            Error::TooManyRecoveryItems
+        );
+    }
+    assert_ok!(Identity::complete_identity_recovery(
+        bob.origin(),
+        alice.did,
+        2,
+        1,
+        1
+    ));
+    assert_eq!(Identity::pending_recovery(alice.did), None);
+
+    // Bob is the custodian of Alice's portfolios, and no token moved.
+    for pid in [
+        PortfolioId::default_portfolio(alice.did),
+        alice_user_portfolio,
+    ] {
+        assert_eq!(Portfolio::portfolio_custodian(pid), Some(bob.did));
+        assert!(Portfolio::portfolios_in_custody(bob.did, pid));
+        assert!(System::events().iter().any(|e| e.event
+            == super::storage::EventTest::Portfolio(
+                pallet_portfolio::Event::PortfolioCustodianChanged(alice.did, pid, bob.did)
+            )));
+    }
+    assert_eq!(Asset::balance_of(&asset_id, alice.did), balance);
+
+    // Only the asset scoped claim was copied to Bob, and Alice keeps it for her own holdings.
+    let fetch = |target: IdentityId, claim: &Claim| {
+        Identity::fetch_claim(
+            target,
+            claim.claim_type(),
+            charlie.did,
+            claim.as_scope().cloned(),
+        )
+    };
+    assert!(fetch(alice.did, &asset_claim).is_some());
+    assert!(fetch(bob.did, &asset_claim).is_some());
+    assert!(fetch(alice.did, &identity_claim).is_some());
+    assert!(fetch(bob.did, &identity_claim).is_none());
+
+    // Bob can accept the authorization given to Alice.
+    assert_ok!(Portfolio::accept_portfolio_custody(
+        bob.origin(),
+        custody_auth_id
+    ));
+    assert_eq!(
+        Portfolio::portfolio_custodian(charlie_portfolio),
+        Some(bob.did)
+    );
+}
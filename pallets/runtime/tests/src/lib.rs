@@ -16,6 +16,7 @@ mod asset_pallet;
 mod asset_test;
 mod asset_metadata_test;
 mod balances_test;
+mod bridge_test;
 mod committee_test;
 mod compliance_manager_test;
 mod contracts_test;
@@ -41,3 +42,4 @@ mod transaction_payment_test;
 mod transfer_compliance_test;
 mod treasury_test;
 mod utility_test;
+mod vesting_test;
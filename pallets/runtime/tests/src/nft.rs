@@ -6,29 +6,30 @@ use pallet_nft::{
     Collection, CollectionKeys, CurrentCollectionId, CurrentNFTId, MetadataValue, NFTOwner,
     NFTsInCollection, NumberOfNFTs,
 };
-use pallet_portfolio::PortfolioNFT;
+use pallet_portfolio::{PortfolioLockedNFT, PortfolioNFT};
 use polymesh_common_utilities::traits::nft::Event;
 use polymesh_common_utilities::with_transaction;
 use polymesh_primitives::asset::{AssetId, AssetName, AssetType, NonFungibleType};
 use polymesh_primitives::asset_metadata::{
-    AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataName, AssetMetadataSpec,
-    AssetMetadataValue,
+    AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataLockStatus, AssetMetadataName,
+    AssetMetadataSpec, AssetMetadataValue, AssetMetadataValueDetail,
 };
 use polymesh_primitives::settlement::{InstructionId, Leg, SettlementType};
 use polymesh_primitives::{
-    AuthorizationData, Claim, ClaimType, Condition, ConditionType, CountryCode, IdentityId,
-    NFTCollectionId, NFTCollectionKeys, NFTId, NFTMetadataAttribute, NFTs, PortfolioId,
+    AuthorizationData, Claim, ClaimType, Condition, ConditionType, CountryCode, FractionalizedNFT,
+    IdentityId, NFTCollectionId, NFTCollectionKeys, NFTId, NFTMetadataAttribute, NFTs, PortfolioId,
     PortfolioKind, PortfolioNumber, PortfolioUpdateReason, Scope, Signatory, TrustedFor,
     TrustedIssuer, WeightMeter,
 };
 use sp_keyring::AccountKeyring;
 
-use super::asset_test::{get_asset_details, set_timestamp};
+use super::asset_test::{get_asset_details, set_timestamp, transfer};
 use crate::asset_pallet::setup::{create_and_issue_sample_asset, create_and_issue_sample_nft};
 use crate::ext_builder::ExtBuilder;
 use crate::storage::{default_portfolio_btreeset, TestStorage, User};
 
 type Asset = pallet_asset::Module<TestStorage>;
+type AssetError = pallet_asset::Error<TestStorage>;
 type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
 type EAError = pallet_external_agents::Error<TestStorage>;
 type Identity = pallet_identity::Module<TestStorage>;
@@ -1073,6 +1074,36 @@ fn controller_transfer_unauthorized_agent() {
     });
 }
 
+#[test]
+fn controller_transfer_fractionalized_nft() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice: User = User::new(AccountKeyring::Alice);
+        let custody_portfolio = PortfolioId::user_portfolio(alice.did, PortfolioNumber(1));
+
+        let asset_id = create_and_issue_sample_nft(&alice);
+        assert_ok!(NFT::fractionalize_nft(
+            alice.origin(),
+            asset_id,
+            NFTId(1),
+            PortfolioKind::Default,
+            b"Fraction".into(),
+            1_000,
+            b"Custody".into()
+        ));
+
+        // The NFT backing the fractions can't be taken by a controller transfer.
+        assert_noop!(
+            NFT::controller_transfer(
+                alice.origin(),
+                NFTs::new(asset_id, vec![NFTId(1)]).unwrap(),
+                custody_portfolio,
+                PortfolioKind::Default
+            ),
+            NFTError::NFTIsFractionalized
+        );
+    });
+}
+
 #[test]
 fn controller_transfer_nft_not_owned() {
     ExtBuilder::default().build().execute_with(|| {
@@ -1241,3 +1272,254 @@ fn reject_instruction_with_locked_asset() {
         );
     });
 }
+
+/// The metadata of an NFT can be updated by an agent until its value is locked.
+#[test]
+fn update_nft_metadata() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1_000);
+
+        let alice: User = User::new(AccountKeyring::Alice);
+        let bob: User = User::new(AccountKeyring::Bob);
+        let key = AssetMetadataKey::Local(AssetMetadataLocalKey(1));
+        let attribute = |value: &[u8]| NFTMetadataAttribute {
+            key,
+            value: AssetMetadataValue(value.to_vec()),
+        };
+
+        let asset_id = create_nft_collection(
+            alice.clone(),
+            AssetType::NonFungible(NonFungibleType::Derivative),
+            vec![key].into(),
+        );
+        mint_nft(
+            alice.clone(),
+            asset_id,
+            vec![attribute(b"100")],
+            PortfolioKind::Default,
+        );
+
+        assert_noop!(
+            NFT::update_nft_metadata(bob.origin(), asset_id, NFTId(1), attribute(b"200"), None),
+            EAError::UnauthorizedAgent
+        );
+        assert_noop!(
+            NFT::update_nft_metadata(alice.origin(), asset_id, NFTId(2), attribute(b"200"), None),
+            NFTError::NFTNotFound
+        );
+        assert_noop!(
+            NFT::update_nft_metadata(
+                alice.origin(),
+                asset_id,
+                NFTId(1),
+                NFTMetadataAttribute {
+                    key: AssetMetadataKey::Local(AssetMetadataLocalKey(2)),
+                    value: AssetMetadataValue(b"200".to_vec()),
+                },
+                None
+            ),
+            NFTError::InvalidMetadataAttribute
+        );
+
+        // Updates the value and locks it until the timestamp 2_000.
+        let detail = AssetMetadataValueDetail {
+            expire: None,
+            lock_status: AssetMetadataLockStatus::LockedUntil(2_000),
+        };
+        assert_ok!(NFT::update_nft_metadata(
+            alice.origin(),
+            asset_id,
+            NFTId(1),
+            attribute(b"200"),
+            Some(detail)
+        ));
+        assert_eq!(
+            MetadataValue::get((NFTCollectionId(1), NFTId(1)), key),
+            AssetMetadataValue(b"200".to_vec())
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            super::storage::EventTest::Nft(Event::NFTMetadataUpdated(
+                alice.did,
+                asset_id,
+                NFTId(1),
+                key,
+                AssetMetadataValue(b"100".to_vec()),
+                AssetMetadataValue(b"200".to_vec()),
+            ))
+        );
+        assert_noop!(
+            NFT::update_nft_metadata(alice.origin(), asset_id, NFTId(1), attribute(b"300"), None),
+            NFTError::MetadataValueIsLocked
+        );
+
+        // Once the lock expires the value can be updated again.
+        set_timestamp(2_000);
+        assert_ok!(NFT::update_nft_metadata(
+            alice.origin(),
+            asset_id,
+            NFTId(1),
+            attribute(b"300"),
+            None
+        ));
+        assert_eq!(
+            MetadataValue::get((NFTCollectionId(1), NFTId(1)), key),
+            AssetMetadataValue(b"300".to_vec())
+        );
+    });
+}
+
+#[test]
+fn fractionalize_nft() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(Utc::now().timestamp() as _);
+        System::set_block_number(1);
+
+        let alice: User = User::new(AccountKeyring::Alice);
+        let bob: User = User::new(AccountKeyring::Bob);
+        let alice_portfolio = PortfolioId::default_portfolio(alice.did);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let custody_portfolio = PortfolioId::user_portfolio(alice.did, PortfolioNumber(1));
+
+        let asset_id = create_and_issue_sample_nft(&alice);
+        assert_noop!(
+            NFT::fractionalize_nft(
+                bob.origin(),
+                asset_id,
+                NFTId(1),
+                PortfolioKind::Default,
+                b"Fraction".into(),
+                1_000,
+                b"Custody".into()
+            ),
+            NFTError::NFTNotFound
+        );
+        assert_noop!(
+            NFT::fractionalize_nft(
+                alice.origin(),
+                asset_id,
+                NFTId(1),
+                PortfolioKind::Default,
+                b"Fraction".into(),
+                0,
+                b"Custody".into()
+            ),
+            NFTError::ZeroNFTFractionSupply
+        );
+
+        // Locks the NFT in a new custody portfolio and issues all fractions to alice.
+        let fraction_id = Asset::generate_asset_id(alice.acc(), false);
+        assert_ok!(NFT::fractionalize_nft(
+            alice.origin(),
+            asset_id,
+            NFTId(1),
+            PortfolioKind::Default,
+            b"Fraction".into(),
+            1_000,
+            b"Custody".into()
+        ));
+        assert_eq!(
+            NFT::fractionalized_nft(fraction_id),
+            Some(FractionalizedNFT {
+                collection_asset_id: asset_id,
+                nft_id: NFTId(1),
+                custody_portfolio,
+            })
+        );
+        assert_eq!(
+            NFT::nft_fraction_asset(asset_id, NFTId(1)),
+            Some(fraction_id)
+        );
+        assert_eq!(NFTOwner::get(asset_id, NFTId(1)), Some(custody_portfolio));
+        assert!(PortfolioLockedNFT::contains_key(
+            custody_portfolio,
+            (asset_id, NFTId(1))
+        ));
+        assert_eq!(
+            Portfolio::portfolio_asset_balances(alice_portfolio, fraction_id),
+            1_000
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            super::storage::EventTest::Nft(Event::NFTFractionalized(
+                alice.did,
+                asset_id,
+                NFTId(1),
+                fraction_id,
+                custody_portfolio,
+            ))
+        );
+
+        // The supply of the fractions is fixed and the locked NFT can't be moved.
+        assert_noop!(
+            Asset::issue(alice.origin(), fraction_id, 1, PortfolioKind::Default),
+            AssetError::AssetSupplyIsFixed
+        );
+        assert_noop!(
+            Asset::redeem(alice.origin(), fraction_id, 1, PortfolioKind::Default),
+            AssetError::AssetSupplyIsFixed
+        );
+        assert_noop!(
+            NFT::redeem_nft(
+                alice.origin(),
+                asset_id,
+                NFTId(1),
+                custody_portfolio.kind,
+                None
+            ),
+            NFTError::NFTIsLocked
+        );
+
+        // Only the holder of all fractions can redeem the NFT.
+        ComplianceManager::pause_asset_compliance(alice.origin(), asset_id).unwrap();
+        ComplianceManager::pause_asset_compliance(alice.origin(), fraction_id).unwrap();
+        assert_ok!(transfer(fraction_id, alice, bob, 400));
+        assert_noop!(
+            NFT::redeem_nft_fraction(alice.origin(), fraction_id, PortfolioKind::Default),
+            NFTError::InsufficientNFTFractions
+        );
+        assert_noop!(
+            NFT::redeem_nft_fraction(bob.origin(), fraction_id, PortfolioKind::Default),
+            NFTError::InsufficientNFTFractions
+        );
+        assert_noop!(
+            NFT::redeem_nft_fraction(bob.origin(), asset_id, PortfolioKind::Default),
+            NFTError::NFTFractionNotFound
+        );
+
+        assert_ok!(transfer(fraction_id, alice, bob, 600));
+        assert_ok!(NFT::redeem_nft_fraction(
+            bob.origin(),
+            fraction_id,
+            PortfolioKind::Default
+        ));
+        assert_eq!(get_asset_details(&fraction_id).total_supply, 0);
+        assert_eq!(
+            Portfolio::portfolio_asset_balances(bob_portfolio, fraction_id),
+            0
+        );
+        assert_eq!(NFTOwner::get(asset_id, NFTId(1)), Some(bob_portfolio));
+        assert!(PortfolioNFT::contains_key(
+            bob_portfolio,
+            (asset_id, NFTId(1))
+        ));
+        assert!(!PortfolioLockedNFT::contains_key(
+            custody_portfolio,
+            (asset_id, NFTId(1))
+        ));
+        assert_eq!(NumberOfNFTs::get(&asset_id, alice.did), 0);
+        assert_eq!(NumberOfNFTs::get(&asset_id, bob.did), 1);
+        assert_eq!(NFT::fractionalized_nft(fraction_id), None);
+        assert_eq!(NFT::nft_fraction_asset(asset_id, NFTId(1)), None);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            super::storage::EventTest::Nft(Event::NFTFractionRedeemed(
+                bob.did,
+                fraction_id,
+                asset_id,
+                NFTId(1),
+                bob_portfolio,
+            ))
+        );
+    });
+}
@@ -4,7 +4,7 @@ use frame_support::{assert_noop, assert_ok, StorageMap};
 use pallet_nft::NFTOwner;
 use pallet_portfolio::{
     AllowedCustodians, Event, NameToNumber, PortfolioAssetBalances, PortfolioCustodian,
-    PortfolioNFT, Portfolios, PreApprovedPortfolios,
+    PortfolioNFT, Portfolios, PreApprovedPortfolios, MAX_HOLDINGS_PORTFOLIOS,
 };
 use polymesh_common_utilities::portfolio::PortfolioSubTrait;
 use polymesh_primitives::asset::{AssetId, AssetType, NonFungibleType};
@@ -14,8 +14,8 @@ use polymesh_primitives::asset_metadata::{
 use polymesh_primitives::settlement::{Leg, SettlementType};
 use polymesh_primitives::{
     AuthorizationData, AuthorizationError, Fund, FundDescription, Memo, NFTCollectionKeys, NFTId,
-    NFTMetadataAttribute, NFTs, PortfolioId, PortfolioKind, PortfolioName, PortfolioNumber,
-    Signatory,
+    NFTMetadataAttribute, NFTs, PortfolioAssetBalance, PortfolioHoldings, PortfolioId,
+    PortfolioKind, PortfolioName, PortfolioNumber, Signatory,
 };
 use sp_keyring::AccountKeyring;
 
@@ -1130,3 +1130,83 @@ fn allow_identity_to_create_portfolios_not_allowed() {
         );
     });
 }
+
+#[test]
+fn portfolio_holdings() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did);
+        let alice_user_portfolio = PortfolioId::user_portfolio(alice.did, PortfolioNumber(2));
+        Portfolio::create_portfolio(alice.origin(), b"AlicePortfolio1".into()).unwrap();
+        Portfolio::create_portfolio(alice.origin(), b"AlicePortfolio2".into()).unwrap();
+        set_custodian_ok(alice, bob, alice_user_portfolio);
+
+        // Issue to the default portfolio, lock some of it and pre-approve the asset.
+        let asset_id = create_and_issue_sample_asset(&alice);
+        Portfolio::unchecked_lock_tokens(&alice_default_portfolio, &asset_id, 100);
+        assert_ok!(Portfolio::pre_approve_portfolio(
+            alice.origin(),
+            asset_id,
+            alice_default_portfolio
+        ));
+
+        let empty_holdings = |num: u64, name: &[u8], custodian| PortfolioHoldings {
+            portfolio_id: PortfolioId::user_portfolio(alice.did, PortfolioNumber(num)),
+            name: Some(name.into()),
+            custodian,
+            assets: Vec::new(),
+            nfts: Vec::new(),
+            pre_approved_assets: Vec::new(),
+            truncated: false,
+        };
+
+        // The first page starts with the default portfolio.
+        let page = Portfolio::portfolio_holdings(alice.did, None, 2);
+        assert_eq!(
+            page.holdings,
+            vec![
+                PortfolioHoldings {
+                    portfolio_id: alice_default_portfolio,
+                    name: None,
+                    custodian: alice.did,
+                    assets: vec![PortfolioAssetBalance {
+                        asset_id,
+                        free: ISSUE_AMOUNT - 100,
+                        locked: 100,
+                    }],
+                    nfts: Vec::new(),
+                    pre_approved_assets: vec![asset_id],
+                    truncated: false,
+                },
+                empty_holdings(1, b"AlicePortfolio1", alice.did),
+            ]
+        );
+        assert_eq!(page.next, Some(PortfolioKind::User(PortfolioNumber(2))));
+
+        // The last page starts from where the first one ended.
+        let page = Portfolio::portfolio_holdings(alice.did, page.next, 2);
+        assert_eq!(
+            page.holdings,
+            vec![empty_holdings(2, b"AlicePortfolio2", bob.did)]
+        );
+        assert_eq!(page.next, None);
+
+        // Pages are capped, whatever the requested limit.
+        for num in 3..=MAX_HOLDINGS_PORTFOLIOS {
+            let name = format!("AlicePortfolio{}", num);
+            assert_ok!(Portfolio::create_portfolio(
+                alice.origin(),
+                PortfolioName::from(name)
+            ));
+        }
+        let page = Portfolio::portfolio_holdings(alice.did, None, u32::MAX);
+        assert_eq!(page.holdings.len(), MAX_HOLDINGS_PORTFOLIOS as usize);
+        assert_eq!(
+            page.next,
+            Some(PortfolioKind::User(PortfolioNumber(
+                MAX_HOLDINGS_PORTFOLIOS as u64
+            )))
+        );
+    });
+}
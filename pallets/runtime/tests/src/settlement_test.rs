@@ -9,6 +9,7 @@ use frame_support::{
     BoundedBTreeSet, IterableStorageDoubleMap, StorageDoubleMap, StorageMap,
 };
 use rand::{prelude::*, thread_rng};
+use sp_io::hashing::sha2_256;
 use sp_runtime::{AccountId32, AnySignature};
 use sp_std::collections::btree_set::BTreeSet;
 
@@ -28,9 +29,10 @@ use polymesh_primitives::asset_metadata::{
 };
 use polymesh_primitives::checked_inc::CheckedInc;
 use polymesh_primitives::settlement::{
-    AffirmationCount, AffirmationStatus, AssetCount, Instruction, InstructionId, InstructionStatus,
-    Leg, LegId, LegStatus, MediatorAffirmationStatus, Receipt, ReceiptDetails, SettlementType,
-    VenueDetails, VenueId, VenueType,
+    AffirmationCount, AffirmationSide, AffirmationStatus, AssetCount, AutoAffirmRule,
+    AutoAffirmRuleId, Instruction, InstructionId, InstructionStatus, Leg, LegId, LegStatus,
+    MediatorAffirmationStatus, Receipt, ReceiptDetails, SettlementType, VenueDetails, VenueId,
+    VenueType,
 };
 use polymesh_primitives::{
     AccountId, AuthorizationData, Balance, Claim, ClaimType, Condition, ConditionType, CountryCode,
@@ -41,7 +43,7 @@ use polymesh_primitives::{
 use sp_keyring::AccountKeyring;
 
 use super::asset_pallet::setup::{create_and_issue_sample_asset, ISSUE_AMOUNT};
-use super::asset_test::max_len_bytes;
+use super::asset_test::{max_len_bytes, set_timestamp};
 use super::nft::{create_nft_collection, mint_nft};
 use super::settlement_pallet::setup::create_and_issue_sample_asset_with_venue;
 use super::storage::{
@@ -4154,6 +4156,993 @@ fn missing_venue_for_offchain_asset() {
     });
 }
 
+#[test]
+fn add_instruction_with_netting() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_default_portfolio = PortfolioId::default_portfolio(bob.did);
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let asset_id2 = create_and_issue_sample_asset(&bob);
+        let alice = UserWithBalance::new(alice, &[asset_id, asset_id2]);
+        let bob = UserWithBalance::new(bob, &[asset_id, asset_id2]);
+        assert_ok!(Settlement::set_venue_netting(
+            alice.origin(),
+            venue_id.unwrap(),
+            true
+        ));
+
+        let legs: Vec<Leg> = vec![
+            Leg::Fungible {
+                sender: alice_default_portfolio,
+                receiver: bob_default_portfolio,
+                asset_id,
+                amount: 1_000,
+            },
+            Leg::Fungible {
+                sender: bob_default_portfolio,
+                receiver: alice_default_portfolio,
+                asset_id: asset_id2,
+                amount: 300,
+            },
+            Leg::Fungible {
+                sender: bob_default_portfolio,
+                receiver: alice_default_portfolio,
+                asset_id,
+                amount: 400,
+            },
+            Leg::Fungible {
+                sender: alice_default_portfolio,
+                receiver: bob_default_portfolio,
+                asset_id,
+                amount: 100,
+            },
+        ];
+        let netted_legs: Vec<Leg> = vec![
+            Leg::Fungible {
+                sender: alice_default_portfolio,
+                receiver: bob_default_portfolio,
+                asset_id,
+                amount: 700,
+            },
+            Leg::Fungible {
+                sender: bob_default_portfolio,
+                receiver: alice_default_portfolio,
+                asset_id: asset_id2,
+                amount: 300,
+            },
+        ];
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+        ));
+        let events = System::events();
+        assert_eq!(
+            events[events.len() - 2].event,
+            crate::storage::EventTest::Settlement(RawEvent::InstructionCreated(
+                alice.did,
+                venue_id,
+                InstructionId(0),
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                netted_legs.clone(),
+                None,
+            ))
+        );
+        assert_eq!(
+            events.last().unwrap().event,
+            crate::storage::EventTest::Settlement(RawEvent::InstructionLegsNetted(
+                alice.did,
+                InstructionId(0),
+                netted_legs.clone()
+            ))
+        );
+        assert_add_instruction_storage(
+            &InstructionId(0),
+            &BTreeSet::from([alice_default_portfolio, bob_default_portfolio]),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            None,
+            &netted_legs,
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+        );
+        assert_eq!(InstructionLegs::get(InstructionId(0), LegId(2)), None);
+
+        assert_affirm_instruction!(alice.origin(), InstructionId(0), alice.did);
+        assert_affirm_instruction!(bob.origin(), InstructionId(0), bob.did);
+        assert_locked_assets(&asset_id, &alice, 700);
+        assert_locked_assets(&asset_id2, &bob, 300);
+        next_block();
+
+        assert_instruction_status(InstructionId(0), InstructionStatus::Success(2));
+        alice.assert_balance_decreased(&asset_id, 700);
+        bob.assert_balance_increased(&asset_id, 700);
+        alice.assert_balance_increased(&asset_id2, 300);
+        bob.assert_balance_decreased(&asset_id2, 300);
+    });
+}
+
+#[test]
+fn add_instruction_netting_to_zero() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        assert_ok!(Settlement::set_venue_netting(
+            alice.origin(),
+            venue_id.unwrap(),
+            true
+        ));
+
+        let legs: Vec<Leg> = vec![
+            Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 1_000,
+            },
+            Leg::Fungible {
+                sender: PortfolioId::default_portfolio(bob.did),
+                receiver: PortfolioId::default_portfolio(alice.did),
+                asset_id,
+                amount: 1_000,
+            },
+        ];
+        assert_noop!(
+            Settlement::add_instruction(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                legs,
+                None,
+            ),
+            Error::InstructionNetsToZero
+        );
+    });
+}
+
+#[test]
+fn set_venue_netting_unauthorized() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (_, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        assert_noop!(
+            Settlement::set_venue_netting(bob.origin(), venue_id.unwrap(), true),
+            Error::Unauthorized
+        );
+        assert_ok!(Settlement::set_venue_netting(
+            alice.origin(),
+            venue_id.unwrap(),
+            true
+        ));
+        assert!(Settlement::venue_netting(venue_id.unwrap()));
+        assert_ok!(Settlement::set_venue_netting(
+            alice.origin(),
+            venue_id.unwrap(),
+            false
+        ));
+        assert!(!Settlement::venue_netting(venue_id.unwrap()));
+    });
+}
+
+#[test]
+fn instruction_rejected_at_affirmation_deadline() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let alice = UserWithBalance::new(alice, &[asset_id]);
+        let bob = UserWithBalance::new(bob, &[asset_id]);
+
+        let instruction_id = Settlement::instruction_counter();
+        let legs: Vec<Leg> = vec![Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(bob.did),
+            asset_id,
+            amount: 1_000,
+        }];
+        assert_noop!(
+            Settlement::add_instruction_with_deadline(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                legs.clone(),
+                None,
+                1,
+            ),
+            Error::AffirmationDeadlineInThePast
+        );
+        assert_ok!(Settlement::add_and_affirm_with_deadline(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            default_portfolio_btreeset(alice.did),
+            None,
+            3,
+        ));
+        assert_eq!(
+            Settlement::instruction_affirmation_deadline(instruction_id),
+            Some(3)
+        );
+        assert_eq!(1, scheduler::Agenda::<TestStorage>::get(3).len());
+        assert_locked_assets(&asset_id, &alice, 1_000);
+
+        // Bob doesn't affirm the instruction before the deadline
+        next_block();
+        assert_instruction_status(instruction_id, InstructionStatus::Pending);
+        next_block();
+        assert_instruction_status(instruction_id, InstructionStatus::Rejected(3));
+        assert_eq!(
+            Settlement::instruction_affirmation_deadline(instruction_id),
+            None
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == crate::storage::EventTest::Settlement(
+                RawEvent::InstructionAffirmationDeadlineReached(instruction_id)
+            )));
+        assert_locked_assets(&asset_id, &alice, 0);
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Unknown);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Unknown);
+        alice.assert_all_balances_unchanged();
+        bob.assert_all_balances_unchanged();
+    });
+}
+
+#[test]
+fn instruction_executed_before_affirmation_deadline() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let alice = UserWithBalance::new(alice, &[asset_id]);
+        let bob = UserWithBalance::new(bob, &[asset_id]);
+
+        let instruction_id = Settlement::instruction_counter();
+        let legs: Vec<Leg> = vec![Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(bob.did),
+            asset_id,
+            amount: 1_000,
+        }];
+        assert_ok!(Settlement::add_and_affirm_with_mediators_and_deadline(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            default_portfolio_btreeset(alice.did),
+            None,
+            BTreeSet::from([charlie.did]).try_into().unwrap(),
+            10,
+        ));
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        assert_ok!(Settlement::affirm_instruction_as_mediator(
+            charlie.origin(),
+            instruction_id,
+            None
+        ));
+        next_block();
+
+        assert_instruction_status(instruction_id, InstructionStatus::Success(2));
+        assert_eq!(
+            Settlement::instruction_affirmation_deadline(instruction_id),
+            None
+        );
+        assert!(scheduler::Agenda::<TestStorage>::get(10)
+            .iter()
+            .all(|task| task.is_none()));
+        alice.assert_balance_decreased(&asset_id, 1_000);
+        bob.assert_balance_increased(&asset_id, 1_000);
+    });
+}
+
+#[test]
+fn affirm_instruction_as_spender() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did);
+        let bob_default_portfolio = PortfolioId::default_portfolio(bob.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        let alice = UserWithBalance::new(alice, &[asset_id]);
+        let bob = UserWithBalance::new(bob, &[asset_id]);
+
+        // Charlie creates an instruction spending from alice's portfolio
+        let instruction_id = Settlement::instruction_counter();
+        let legs: Vec<Leg> = vec![Leg::Fungible {
+            sender: alice_default_portfolio,
+            receiver: bob_default_portfolio,
+            asset_id,
+            amount: 600,
+        }];
+        assert_ok!(Settlement::add_instruction(
+            charlie.origin(),
+            None,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+        ));
+
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            alice_default_portfolio,
+            asset_id,
+            charlie.did,
+            500
+        ));
+        assert_noop!(
+            Settlement::affirm_instruction_as_spender(
+                charlie.origin(),
+                instruction_id,
+                alice_default_portfolio
+            ),
+            AssetError::InsufficientAllowance
+        );
+        // The spender can't affirm on behalf of a receiving portfolio
+        assert_noop!(
+            Settlement::affirm_instruction_as_spender(
+                charlie.origin(),
+                instruction_id,
+                bob_default_portfolio
+            ),
+            Error::InvalidSpenderAffirmation
+        );
+
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            alice_default_portfolio,
+            asset_id,
+            charlie.did,
+            1_000
+        ));
+        assert_ok!(Settlement::affirm_instruction_as_spender(
+            charlie.origin(),
+            instruction_id,
+            alice_default_portfolio
+        ));
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Affirmed);
+        assert_locked_assets(&asset_id, &alice, 600);
+        assert_eq!(
+            Settlement::spender_affirmations(instruction_id, alice_default_portfolio),
+            Some(charlie.did)
+        );
+
+        // The allowance is consumed when the instruction is executed
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        next_block();
+
+        assert_instruction_status(instruction_id, InstructionStatus::Success(2));
+        alice.assert_balance_decreased(&asset_id, 600);
+        bob.assert_balance_increased(&asset_id, 600);
+        assert_eq!(
+            Asset::allowance((alice_default_portfolio, asset_id), charlie.did),
+            400
+        );
+        assert_eq!(
+            Settlement::spender_affirmations(instruction_id, alice_default_portfolio),
+            None
+        );
+    });
+}
+
+#[test]
+fn affirm_with_preimage() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        set_timestamp(1_000);
+
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let dave = User::new(AccountKeyring::Dave);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        let alice = UserWithBalance::new(alice, &[asset_id]);
+        let bob = UserWithBalance::new(bob, &[asset_id]);
+
+        // Alice sends on-chain tokens in exchange for bob's tokens on an external ledger
+        let secret = b"HashLockSecret".to_vec();
+        let hash_locked_leg = Leg::HashLocked {
+            sender_identity: bob.did,
+            receiver_identity: alice.did,
+            ticker: Ticker::from_slice_truncated(b"EXTERNAL".as_ref()),
+            amount: 50,
+            hash_lock: sha2_256(&secret),
+            expiry: 5_000,
+        };
+        let legs: Vec<Leg> = vec![
+            Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 100,
+            },
+            hash_locked_leg,
+        ];
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            None,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+        ));
+        assert_eq!(
+            OffChainAffirmations::get(instruction_id, LegId(1)),
+            AffirmationStatus::Pending
+        );
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        assert_eq!(InstructionAffirmsPending::get(instruction_id), 1);
+
+        // Hash-locked legs can't be affirmed with receipts
+        let receipt_details = ReceiptDetails::new(
+            0,
+            instruction_id,
+            LegId(1),
+            AccountKeyring::Alice.to_account_id(),
+            AccountKeyring::Alice.sign(b"").into(),
+            None,
+        );
+        assert_noop!(
+            Settlement::affirm_with_receipts(
+                bob.origin(),
+                instruction_id,
+                vec![receipt_details],
+                Default::default()
+            ),
+            Error::ReceiptForInvalidLegType
+        );
+        assert_noop!(
+            Settlement::affirm_with_preimage(
+                dave.origin(),
+                instruction_id,
+                LegId(0),
+                secret.clone()
+            ),
+            Error::PreimageForInvalidLegType
+        );
+        assert_noop!(
+            Settlement::affirm_with_preimage(
+                dave.origin(),
+                instruction_id,
+                LegId(1),
+                b"WrongSecret".to_vec()
+            ),
+            Error::InvalidHashLockPreimage
+        );
+
+        // Anyone who knows the secret can affirm the leg
+        assert_ok!(Settlement::affirm_with_preimage(
+            dave.origin(),
+            instruction_id,
+            LegId(1),
+            secret.clone()
+        ));
+        assert_eq!(
+            OffChainAffirmations::get(instruction_id, LegId(1)),
+            AffirmationStatus::Affirmed
+        );
+        assert_eq!(InstructionAffirmsPending::get(instruction_id), 0);
+        assert_noop!(
+            Settlement::affirm_with_preimage(dave.origin(), instruction_id, LegId(1), secret),
+            Error::UnexpectedAffirmationStatus
+        );
+        next_block();
+
+        assert_instruction_status(instruction_id, InstructionStatus::Success(2));
+        alice.assert_balance_decreased(&asset_id, 100);
+        bob.assert_balance_increased(&asset_id, 100);
+    });
+}
+
+#[test]
+fn hash_locked_leg_expiry() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        set_timestamp(1_000);
+
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let secret = b"HashLockSecret".to_vec();
+        let hash_locked_leg = |expiry| Leg::HashLocked {
+            sender_identity: bob.did,
+            receiver_identity: alice.did,
+            ticker: Ticker::from_slice_truncated(b"EXTERNAL".as_ref()),
+            amount: 50,
+            hash_lock: sha2_256(&secret),
+            expiry,
+        };
+
+        assert_noop!(
+            Settlement::add_instruction(
+                alice.origin(),
+                None,
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                vec![hash_locked_leg(1_000)],
+                None,
+            ),
+            Error::HashLockExpiryInThePast
+        );
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            None,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            vec![hash_locked_leg(5_000)],
+            None,
+        ));
+
+        // The secret can't be revealed once the leg has expired
+        set_timestamp(5_001);
+        assert_noop!(
+            Settlement::affirm_with_preimage(
+                bob.origin(),
+                instruction_id,
+                LegId(0),
+                secret.clone()
+            ),
+            Error::HashLockExpired
+        );
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+        let report = Settlement::execute_instruction_report(&instruction_id, &mut weight_meter);
+        assert!(report.contains(&Error::HashLockExpired.into()));
+        assert!(report.contains(&Error::NotAllAffirmationsHaveBeenReceived.into()));
+    });
+}
+
+#[test]
+fn amend_instruction() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let alice = UserWithBalance::new(alice, &[asset_id]);
+        let bob = UserWithBalance::new(bob, &[asset_id]);
+        let charlie = UserWithBalance::new(charlie, &[asset_id]);
+
+        let leg = |receiver: &UserWithBalance, amount| Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(receiver.did),
+            asset_id,
+            amount,
+        };
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            vec![leg(&bob, 100), leg(&charlie, 200)],
+            None,
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        assert_affirm_instruction!(charlie.origin(), instruction_id, charlie.did);
+        assert_locked_assets(&asset_id, &alice, 300);
+
+        // Only the venue creator can amend the instruction
+        assert_noop!(
+            Settlement::amend_instruction(
+                bob.origin(),
+                instruction_id,
+                None,
+                None,
+                vec![leg(&bob, 100), leg(&charlie, 250)],
+                None,
+                None,
+            ),
+            Error::Unauthorized
+        );
+
+        // The given asset count must not underestimate the legs being amended
+        assert_noop!(
+            Settlement::amend_instruction(
+                alice.origin(),
+                instruction_id,
+                None,
+                None,
+                vec![leg(&bob, 100), leg(&charlie, 250)],
+                None,
+                Some(AssetCount::new(1, 0, 0)),
+            ),
+            Error::NumberOfFungibleTransfersUnderestimated
+        );
+
+        // Only the portfolios in the changed leg must affirm again
+        assert_ok!(Settlement::amend_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            None,
+            vec![leg(&bob, 100), leg(&charlie, 250)],
+            Some(Memo::default()),
+            Some(AssetCount::new(2, 0, 0)),
+        ));
+        assert_eq!(Settlement::instruction_version(instruction_id), 1);
+        assert_eq!(Settlement::memo(instruction_id), Some(Memo::default()));
+        // The unchanged leg keeps its id and the changed one is numbered after the last leg
+        assert_eq!(
+            InstructionLegs::get(instruction_id, LegId(0)),
+            Some(leg(&bob, 100))
+        );
+        assert_eq!(InstructionLegs::get(instruction_id, LegId(1)), None);
+        assert_eq!(
+            InstructionLegs::get(instruction_id, LegId(2)),
+            Some(leg(&charlie, 250))
+        );
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Pending);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Affirmed);
+        assert_user_affirms(instruction_id, &charlie, AffirmationStatus::Pending);
+        assert_locked_assets(&asset_id, &alice, 0);
+        assert_eq!(InstructionAffirmsPending::get(instruction_id), 2);
+
+        // The execution scheduled after the last affirmation has been cancelled
+        next_block();
+        assert_instruction_status(instruction_id, InstructionStatus::Pending);
+
+        // Amending only the dates keeps all affirmations
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_ok!(Settlement::amend_instruction(
+            alice.origin(),
+            instruction_id,
+            Some(1),
+            Some(2),
+            vec![leg(&bob, 100), leg(&charlie, 250)],
+            Some(Memo::default()),
+            None,
+        ));
+        assert_eq!(Settlement::instruction_version(instruction_id), 2);
+        assert_eq!(
+            Settlement::instruction_details(instruction_id).value_date,
+            Some(2)
+        );
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Affirmed);
+        assert_locked_assets(&asset_id, &alice, 350);
+        assert_eq!(InstructionAffirmsPending::get(instruction_id), 1);
+
+        assert_affirm_instruction!(charlie.origin(), instruction_id, charlie.did);
+        next_block();
+        assert_instruction_status(instruction_id, InstructionStatus::Success(3));
+        alice.assert_balance_decreased(&asset_id, 350);
+        bob.assert_balance_increased(&asset_id, 100);
+        charlie.assert_balance_increased(&asset_id, 250);
+    });
+}
+
+#[test]
+fn amend_instruction_removes_legs() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+
+        let leg = |receiver: &User, amount| Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(receiver.did),
+            asset_id,
+            amount,
+        };
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleManual(System::block_number() + 1),
+            None,
+            None,
+            vec![leg(&bob, 100), leg(&charlie, 200)],
+            None,
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(charlie.origin(), instruction_id, charlie.did);
+
+        // Charlie is no longer a party in the instruction
+        assert_ok!(Settlement::amend_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            None,
+            vec![leg(&bob, 100)],
+            None,
+            None,
+        ));
+        assert_eq!(InstructionLegs::get(instruction_id, LegId(1)), None);
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Pending);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Pending);
+        assert_user_affirms(instruction_id, &charlie, AffirmationStatus::Unknown);
+        assert_locked_assets(&asset_id, &alice, 0);
+        assert_eq!(InstructionAffirmsPending::get(instruction_id), 2);
+    });
+}
+
+#[test]
+fn auto_affirm_rule_as_receiver() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let bob_default_portfolio = PortfolioId::default_portfolio(bob.did);
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+
+        let rule = AutoAffirmRule {
+            side: AffirmationSide::Receiver,
+            venue_id: venue_id.unwrap(),
+            asset_id: Some(asset_id),
+            counterparties: BTreeSet::new(),
+            daily_limit: Some(150),
+        };
+        assert_noop!(
+            Settlement::add_auto_affirm_rule(alice.origin(), bob_default_portfolio, rule.clone()),
+            PortfolioError::UnauthorizedCustodian
+        );
+        assert_noop!(
+            Settlement::add_auto_affirm_rule(
+                bob.origin(),
+                bob_default_portfolio,
+                AutoAffirmRule {
+                    venue_id: VenueId(u64::MAX),
+                    ..rule.clone()
+                }
+            ),
+            Error::InvalidVenue
+        );
+        assert_ok!(Settlement::add_auto_affirm_rule(
+            bob.origin(),
+            bob_default_portfolio,
+            rule.clone()
+        ));
+        assert_eq!(
+            Settlement::auto_affirm_rules(bob_default_portfolio, AutoAffirmRuleId(0)),
+            Some(rule)
+        );
+
+        let leg = |amount| Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: bob_default_portfolio,
+            asset_id,
+            amount,
+        };
+        let add_instruction = |amount| {
+            let instruction_id = Settlement::instruction_counter();
+            assert_ok!(Settlement::add_instruction(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                vec![leg(amount)],
+                None,
+            ));
+            instruction_id
+        };
+
+        // Bob's affirmation is automatically given
+        let auto_affirmed_id = add_instruction(100);
+        assert_user_affirms(auto_affirmed_id, &bob, AffirmationStatus::Affirmed);
+        assert_user_affirms(auto_affirmed_id, &alice, AffirmationStatus::Pending);
+        assert_eq!(InstructionAffirmsPending::get(auto_affirmed_id), 1);
+        assert_eq!(
+            Settlement::auto_affirm_rules_usage(bob_default_portfolio, AutoAffirmRuleId(0))
+                .instructions,
+            1
+        );
+
+        // The daily limit has been reached
+        let instruction_id = add_instruction(100);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Pending);
+        assert_eq!(
+            Settlement::auto_affirm_rule_matches(
+                &bob_default_portfolio,
+                &venue_id.unwrap(),
+                &[(LegId(0), leg(100))]
+            )[0]
+            .rule_id,
+            None
+        );
+
+        // Rejecting the auto-affirmed instruction refunds the usage of the rule
+        assert_ok!(Settlement::reject_instruction(
+            alice.origin(),
+            auto_affirmed_id,
+            PortfolioId::default_portfolio(alice.did)
+        ));
+        let usage = Settlement::auto_affirm_rules_usage(bob_default_portfolio, AutoAffirmRuleId(0));
+        assert_eq!((usage.daily_amount, usage.instructions), (0, 0));
+        let instruction_id = add_instruction(100);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Affirmed);
+
+        // The limit is reset on the next day
+        set_timestamp(Timestamp::get() + 86_400_000);
+        let instruction_id = add_instruction(100);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Affirmed);
+
+        // Bob's affirmation is automatically given again when the instruction is amended
+        assert_ok!(Settlement::amend_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            None,
+            vec![leg(50)],
+            None,
+            None,
+        ));
+        assert_eq!(
+            InstructionLegs::get(instruction_id, LegId(1)),
+            Some(leg(50))
+        );
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Affirmed);
+        assert_eq!(InstructionAffirmsPending::get(instruction_id), 1);
+    });
+}
+
+#[test]
+fn auto_affirm_rule_as_sender() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let dave = User::new(AccountKeyring::Dave);
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        let venue_id = Settlement::venue_counter();
+        assert_ok!(Settlement::create_venue(
+            bob.origin(),
+            VenueDetails::default(),
+            vec![bob.acc()],
+            VenueType::Other
+        ));
+        let alice = UserWithBalance::new(alice, &[asset_id]);
+        let charlie = UserWithBalance::new(charlie, &[asset_id]);
+
+        assert_ok!(Settlement::add_auto_affirm_rule(
+            alice.origin(),
+            alice_default_portfolio,
+            AutoAffirmRule {
+                side: AffirmationSide::Sender,
+                venue_id,
+                asset_id: None,
+                counterparties: BTreeSet::from([charlie.did]),
+                daily_limit: None,
+            }
+        ));
+
+        let add_instruction = |receiver: &User| {
+            let instruction_id = Settlement::instruction_counter();
+            assert_ok!(Settlement::add_instruction(
+                bob.origin(),
+                Some(venue_id),
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                vec![Leg::Fungible {
+                    sender: alice_default_portfolio,
+                    receiver: PortfolioId::default_portfolio(receiver.did),
+                    asset_id,
+                    amount: 100,
+                }],
+                None,
+            ));
+            instruction_id
+        };
+
+        // Dave is not in the rule's allowlist
+        let instruction_id = add_instruction(&dave);
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Pending);
+        assert_locked_assets(&asset_id, &alice, 0);
+
+        // Alice's assets are locked and the instruction executes once charlie affirms
+        let instruction_id = add_instruction(&charlie);
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Affirmed);
+        assert_locked_assets(&asset_id, &alice, 100);
+        assert_affirm_instruction!(charlie.origin(), instruction_id, charlie.did);
+        next_block();
+
+        assert_instruction_status(instruction_id, InstructionStatus::Success(2));
+        alice.assert_balance_decreased(&asset_id, 100);
+        charlie.assert_balance_increased(&asset_id, 100);
+    });
+}
+
+#[test]
+fn remove_auto_affirm_rule() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let bob_default_portfolio = PortfolioId::default_portfolio(bob.did);
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+
+        assert_ok!(Settlement::add_auto_affirm_rule(
+            bob.origin(),
+            bob_default_portfolio,
+            AutoAffirmRule {
+                side: AffirmationSide::Receiver,
+                venue_id: venue_id.unwrap(),
+                asset_id: None,
+                counterparties: BTreeSet::new(),
+                daily_limit: None,
+            }
+        ));
+        assert_ok!(Settlement::remove_auto_affirm_rule(
+            bob.origin(),
+            bob_default_portfolio,
+            AutoAffirmRuleId(0)
+        ));
+        assert_eq!(Settlement::auto_affirm_rule_count(bob_default_portfolio), 0);
+        assert_noop!(
+            Settlement::remove_auto_affirm_rule(
+                bob.origin(),
+                bob_default_portfolio,
+                AutoAffirmRuleId(0)
+            ),
+            Error::AutoAffirmRuleNotFound
+        );
+
+        // Bob must affirm the instruction again
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            vec![Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: bob_default_portfolio,
+                asset_id,
+                amount: 100,
+            }],
+            None,
+        ));
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Pending);
+    });
+}
+
 /// Asserts the storage has been updated after adding an instruction.
 /// While each portfolio in `portfolios_pending_approval` must have a pending `AffirmationStatus`, each portfolio in `portfolios_pre_approved`
 /// must have an affirmed status. The number of pending affirmations must be equal to the number of portfolios in `portfolios_pending_approval` + the number of offchain legs,
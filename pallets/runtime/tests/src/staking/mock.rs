@@ -311,6 +311,8 @@ impl polymesh_common_utilities::traits::identity::Config for Test {
     type SchedulerOrigin = OriginCaller;
     type InitialPOLYX = InitialPOLYX;
     type MaxGivenAuths = MaxGivenAuths;
+    type IdentityRecoveryDelay = IdentityRecoveryDelay;
+    type ClaimHook = ();
 }
 
 parameter_types! {
@@ -318,6 +320,7 @@ parameter_types! {
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * MaximumBlockWeight::get();
     pub const MaxScheduledPerBlock: u32 = 50;
     pub const MaxGivenAuths: u32 = 1024;
+    pub const IdentityRecoveryDelay: u64 = 60_000;
 }
 
 impl pallet_scheduler::Config for Test {
@@ -481,6 +484,14 @@ impl PortfolioSubTrait<AccountId> for Test {
     fn skip_portfolio_affirmation(_: &PortfolioId, _: &AssetId) -> bool {
         unimplemented!()
     }
+
+    fn portfolios_in_custody(_: IdentityId) -> Vec<PortfolioId> {
+        unimplemented!()
+    }
+
+    fn transfer_custody(_: IdentityId, _: IdentityId, _: &[PortfolioId]) {
+        unimplemented!()
+    }
 }
 
 impl CheckCdd<AccountId> for Test {
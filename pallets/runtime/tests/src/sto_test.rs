@@ -2,13 +2,16 @@ use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
 
 use pallet_sto::{
-    Fundraiser, FundraiserId, FundraiserName, FundraiserStatus, FundraiserTier, PriceTier,
-    MAX_TIERS,
+    EarlyAccess, EscrowStatus, Fundraiser, FundraiserEscrow, FundraiserId, FundraiserName,
+    FundraiserStatus, FundraiserTier, Investment, InvestorRestrictions, PriceTier, MAX_TIERS,
 };
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::checked_inc::CheckedInc;
 use polymesh_primitives::settlement::{InstructionStatus, VenueDetails, VenueId, VenueType};
-use polymesh_primitives::{IdentityId, PortfolioId, WeightMeter};
+use polymesh_primitives::{
+    Claim, Fund, FundDescription, IdentityId, PortfolioId, PortfolioName, PortfolioNumber, Scope,
+    WeightMeter,
+};
 use sp_keyring::AccountKeyring;
 
 use crate::asset_pallet::setup::create_and_issue_sample_asset;
@@ -22,7 +25,9 @@ type Asset = pallet_asset::Module<TestStorage>;
 type Sto = pallet_sto::Module<TestStorage>;
 type Error = pallet_sto::Error<TestStorage>;
 type EAError = pallet_external_agents::Error<TestStorage>;
+type Portfolio = pallet_portfolio::Module<TestStorage>;
 type PortfolioError = pallet_portfolio::Error<TestStorage>;
+type Identity = pallet_identity::Module<TestStorage>;
 type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
 type Settlement = pallet_settlement::Module<TestStorage>;
 type Timestamp = pallet_timestamp::Pallet<TestStorage>;
@@ -70,6 +75,21 @@ fn stop_fundraiser_ext() {
     test(stop_fundraiser);
 }
 
+#[test]
+fn investor_restrictions_ext() {
+    test(investor_restrictions);
+}
+
+#[test]
+fn escrow_released_ext() {
+    test(escrow_released);
+}
+
+#[test]
+fn escrow_refunded_ext() {
+    test(escrow_refunded);
+}
+
 struct RaiseContext {
     alice: User,
     alice_portfolio: PortfolioId,
@@ -497,7 +517,7 @@ fn basic_fundraiser() -> (FundraiserId, RaiseContext) {
         0,
         FundraiserName::default(),
     ));
-    (fundraiser_id, context)
+    (fundraiser_id, escrow_portfolio, context)
 }
 
 fn fundraiser_expired() {
@@ -676,3 +696,430 @@ fn stop_fundraiser() {
         Error::FundraiserClosed
     );
 }
+
+fn investor_restrictions() {
+    let RaiseContext {
+        alice,
+        alice_portfolio,
+        bob,
+        bob_portfolio,
+        offering_asset,
+        raise_asset,
+    } = init_raise_context();
+    let raise_asset = raise_asset.unwrap();
+
+    let mut weight_meter = WeightMeter::max_limit_no_minimum();
+    assert_ok!(Asset::unverified_transfer_asset(
+        alice_portfolio,
+        bob_portfolio,
+        raise_asset,
+        1_000_000,
+        None,
+        None,
+        IdentityId::default(),
+        &mut weight_meter
+    ));
+
+    let venue_counter = Settlement::venue_counter();
+    assert_ok!(Settlement::create_venue(
+        alice.origin(),
+        VenueDetails::default(),
+        vec![AccountKeyring::Alice.to_account_id()],
+        VenueType::Sto
+    ));
+
+    // The first tier is cheaper and reserved to the allocation list.
+    let now = Timestamp::get();
+    let start = now + 10;
+    let fundraiser_id = Sto::fundraiser_count(offering_asset);
+    assert_ok!(Sto::create_fundraiser(
+        alice.origin(),
+        alice_portfolio,
+        offering_asset,
+        alice_portfolio,
+        raise_asset,
+        vec![
+            PriceTier {
+                total: 100,
+                price: 1_000_000
+            },
+            PriceTier {
+                total: 1_000,
+                price: 2_000_000
+            },
+        ],
+        venue_counter,
+        Some(start),
+        None,
+        0,
+        FundraiserName::default(),
+    ));
+
+    let invest = |purchase_amount| {
+        Sto::invest(
+            bob.origin(),
+            bob_portfolio,
+            bob_portfolio,
+            offering_asset,
+            fundraiser_id,
+            purchase_amount,
+            None,
+            None,
+        )
+    };
+    let set_restrictions = |restrictions| {
+        Sto::set_investor_restrictions(alice.origin(), offering_asset, fundraiser_id, restrictions)
+    };
+
+    // The fundraiser has not started and has no early-access round.
+    assert_noop!(invest(10), Error::FundraiserExpired);
+
+    let claim = Claim::Accredited(Scope::Asset(offering_asset));
+    let early_access = EarlyAccess {
+        start: now,
+        claim: claim.clone(),
+        issuers: vec![alice.did],
+    };
+    let restrictions = InvestorRestrictions {
+        max_investment: Some(150),
+        allocated_tiers: [0].into_iter().collect(),
+        early_access: Some(early_access.clone()),
+    };
+
+    // Invalid restrictions.
+    assert_noop!(
+        set_restrictions(InvestorRestrictions {
+            allocated_tiers: [2].into_iter().collect(),
+            ..restrictions.clone()
+        }),
+        Error::InvalidPriceTiers
+    );
+    assert_noop!(
+        set_restrictions(InvestorRestrictions {
+            early_access: Some(EarlyAccess {
+                start,
+                ..early_access.clone()
+            }),
+            ..restrictions.clone()
+        }),
+        Error::InvalidEarlyAccess
+    );
+    assert_noop!(
+        set_restrictions(InvestorRestrictions {
+            early_access: Some(EarlyAccess {
+                issuers: vec![],
+                ..early_access
+            }),
+            ..restrictions.clone()
+        }),
+        Error::InvalidEarlyAccess
+    );
+    assert_noop!(
+        Sto::set_tier_allocations(
+            alice.origin(),
+            offering_asset,
+            fundraiser_id,
+            2,
+            vec![(bob.did, 50)]
+        ),
+        Error::InvalidPriceTiers
+    );
+    assert_noop!(
+        Sto::set_investor_restrictions(
+            bob.origin(),
+            offering_asset,
+            fundraiser_id,
+            restrictions.clone()
+        ),
+        EAError::UnauthorizedAgent
+    );
+
+    assert_ok!(set_restrictions(restrictions.clone()));
+    assert_eq!(
+        Sto::fundraiser_restrictions(offering_asset, fundraiser_id),
+        Some(restrictions)
+    );
+
+    // Only holders of the claim can invest during the early-access round.
+    assert_noop!(invest(10), Error::EarlyAccessDenied);
+    assert_ok!(Identity::add_claim(alice.origin(), bob.did, claim, None));
+
+    // Without an allocation, Bob can only buy from the second tier.
+    assert_ok!(invest(10));
+    assert_eq!(
+        Sto::investments((offering_asset, fundraiser_id), bob.did),
+        Investment {
+            offering_amount: 10,
+            raising_amount: 20
+        }
+    );
+
+    // The allocation is bought first and used up.
+    assert_ok!(Sto::set_tier_allocations(
+        alice.origin(),
+        offering_asset,
+        fundraiser_id,
+        0,
+        vec![(bob.did, 50)]
+    ));
+    assert_ok!(invest(60));
+    assert_eq!(
+        Sto::tier_allocation((offering_asset, fundraiser_id), (0, bob.did)),
+        0
+    );
+    assert_eq!(
+        Sto::investments((offering_asset, fundraiser_id), bob.did),
+        Investment {
+            offering_amount: 70,
+            raising_amount: 90
+        }
+    );
+    let fundraiser = Sto::fundraisers(offering_asset, fundraiser_id).unwrap();
+    assert_eq!(fundraiser.tiers[0].remaining, 50);
+    assert_eq!(fundraiser.tiers[1].remaining, 980);
+
+    // Bob can not spend more than the maximum investment, even after the start.
+    set_timestamp(start);
+    assert_noop!(invest(40), Error::MaxInvestmentExceeded);
+    assert_ok!(invest(30));
+    assert_eq!(
+        Sto::investments((offering_asset, fundraiser_id), bob.did),
+        Investment {
+            offering_amount: 100,
+            raising_amount: 150
+        }
+    );
+}
+
+/// Creates an escrowed fundraiser ending at `end`, in which Bob invests 100 units.
+/// Returns the ID of the fundraiser and its dedicated escrow portfolio.
+fn escrowed_fundraiser(soft_cap: u128, end: u64) -> (FundraiserId, PortfolioId, RaiseContext) {
+    let context = init_raise_context();
+    let raise_asset = context.raise_asset.unwrap();
+
+    let mut weight_meter = WeightMeter::max_limit_no_minimum();
+    assert_ok!(Asset::unverified_transfer_asset(
+        context.alice_portfolio,
+        context.bob_portfolio,
+        raise_asset,
+        1_000,
+        None,
+        None,
+        IdentityId::default(),
+        &mut weight_meter
+    ));
+
+    let venue_counter = Settlement::venue_counter();
+    assert_ok!(Settlement::create_venue(
+        context.alice.origin(),
+        VenueDetails::default(),
+        vec![AccountKeyring::Alice.to_account_id()],
+        VenueType::Sto
+    ));
+
+    assert_ok!(Portfolio::create_portfolio(
+        context.alice.origin(),
+        PortfolioName::from(b"Escrow")
+    ));
+    let escrow_portfolio = PortfolioId::user_portfolio(context.alice.did, PortfolioNumber(1));
+
+    let create_fundraiser = |raising_portfolio, soft_cap| {
+        Sto::create_escrowed_fundraiser(
+            context.alice.origin(),
+            context.alice_portfolio,
+            context.offering_asset,
+            raising_portfolio,
+            raise_asset,
+            vec![PriceTier {
+                total: 1_000,
+                price: 1_000_000,
+            }],
+            venue_counter,
+            None,
+            end,
+            0,
+            FundraiserName::default(),
+            soft_cap,
+        )
+    };
+    // Soft caps must be reachable.
+    assert_noop!(
+        create_fundraiser(escrow_portfolio, 0),
+        Error::InvalidSoftCap
+    );
+    assert_noop!(
+        create_fundraiser(escrow_portfolio, 1_001),
+        Error::InvalidSoftCap
+    );
+    // The escrowed funds must be kept apart from other funds.
+    assert_noop!(
+        create_fundraiser(context.alice_portfolio, soft_cap),
+        Error::EscrowPortfolioNotDedicated
+    );
+
+    let fundraiser_id = Sto::fundraiser_count(context.offering_asset);
+    assert_ok!(create_fundraiser(escrow_portfolio, soft_cap));
+    assert_eq!(
+        Sto::escrow_portfolio(escrow_portfolio),
+        Some((context.offering_asset, fundraiser_id))
+    );
+    // The escrow portfolio can not be used by another fundraiser.
+    assert_noop!(
+        create_fundraiser(escrow_portfolio, soft_cap),
+        Error::EscrowPortfolioNotDedicated
+    );
+    assert_noop!(
+        Sto::create_fundraiser(
+            context.alice.origin(),
+            context.alice_portfolio,
+            context.offering_asset,
+            escrow_portfolio,
+            raise_asset,
+            vec![PriceTier {
+                total: 1_000,
+                price: 1_000_000,
+            }],
+            venue_counter,
+            None,
+            None,
+            0,
+            FundraiserName::default(),
+        ),
+        Error::EscrowPortfolioNotDedicated
+    );
+
+    assert_ok!(Sto::invest(
+        context.bob.origin(),
+        context.bob_portfolio,
+        context.bob_portfolio,
+        context.offering_asset,
+        fundraiser_id,
+        100,
+        None,
+        None,
+    ));
+    assert_eq!(
+        Sto::fundraiser_escrow(context.offering_asset, fundraiser_id),
+        Some(FundraiserEscrow {
+            soft_cap,
+            raised: 100,
+            status: EscrowStatus::Open
+        })
+    );
+    (fundraiser_id, context)
+}
+
+fn escrow_released() {
+    let end = Timestamp::get() + 10;
+    let (
+        fundraiser_id,
+        escrow_portfolio,
+        RaiseContext {
+            alice,
+            alice_portfolio,
+            bob,
+            bob_portfolio,
+            offering_asset,
+            raise_asset,
+        },
+    ) = escrowed_fundraiser(100, end);
+    let raise_asset = raise_asset.unwrap();
+
+    // The raised funds are escrowed and no offering token is delivered.
+    assert_eq!(Asset::balance_of(&offering_asset, bob.did), 0);
+    assert_eq!(Asset::balance_of(&raise_asset, bob.did), 900);
+    assert_eq!(Portfolio::locked_assets(escrow_portfolio, raise_asset), 100);
+
+    // The escrowed funds can not be moved before the fundraiser closes.
+    assert_noop!(
+        Portfolio::move_portfolio_funds(
+            alice.origin(),
+            escrow_portfolio,
+            alice_portfolio,
+            vec![Fund {
+                description: FundDescription::Fungible {
+                    asset_id: raise_asset,
+                    amount: 100
+                },
+                memo: None,
+            }],
+        ),
+        PortfolioError::InsufficientPortfolioBalance
+    );
+
+    let claim = || Sto::claim_offering(bob.origin(), bob_portfolio, offering_asset, fundraiser_id);
+    let refund = || Sto::refund(bob.origin(), bob_portfolio, offering_asset, fundraiser_id);
+    assert_noop!(claim(), Error::EscrowStillOpen);
+    assert_noop!(refund(), Error::EscrowStillOpen);
+
+    // Escrowed fundraisers must keep an end.
+    assert_noop!(
+        Sto::modify_fundraiser_window(
+            alice.origin(),
+            offering_asset,
+            fundraiser_id,
+            Timestamp::get(),
+            None
+        ),
+        Error::EscrowRequiresEnd
+    );
+
+    // The soft cap was reached, so the offering tokens are released once the fundraiser ends.
+    set_timestamp(end);
+    assert_noop!(refund(), Error::SoftCapReached);
+    assert_ok!(claim());
+    assert_eq!(Asset::balance_of(&offering_asset, bob.did), 100);
+    assert_eq!(Portfolio::locked_assets(escrow_portfolio, raise_asset), 0);
+    assert_eq!(Sto::escrow_portfolio(escrow_portfolio), None);
+    assert_eq!(
+        Sto::fundraiser_escrow(offering_asset, fundraiser_id).map(|escrow| escrow.status),
+        Some(EscrowStatus::Released)
+    );
+    assert_noop!(claim(), Error::NoEscrowedInvestment);
+
+    // Only the unsold tokens are unlocked when the fundraiser is stopped.
+    assert_ok!(Sto::stop(alice.origin(), offering_asset, fundraiser_id));
+    assert_eq!(Portfolio::locked_assets(alice_portfolio, offering_asset), 0);
+}
+
+fn escrow_refunded() {
+    let (
+        fundraiser_id,
+        escrow_portfolio,
+        RaiseContext {
+            alice,
+            alice_portfolio,
+            bob,
+            bob_portfolio,
+            offering_asset,
+            raise_asset,
+        },
+    ) = escrowed_fundraiser(500, Timestamp::get() + 10);
+    let raise_asset = raise_asset.unwrap();
+
+    // Stopping the fundraiser before the soft cap is reached opens the escrow for refunds.
+    assert_ok!(Sto::stop(alice.origin(), offering_asset, fundraiser_id));
+    assert_eq!(
+        Sto::fundraiser_escrow(offering_asset, fundraiser_id).map(|escrow| escrow.status),
+        Some(EscrowStatus::Refunding)
+    );
+    assert_eq!(Portfolio::locked_assets(alice_portfolio, offering_asset), 0);
+
+    assert_noop!(
+        Sto::claim_offering(bob.origin(), bob_portfolio, offering_asset, fundraiser_id),
+        Error::SoftCapNotReached
+    );
+    assert_ok!(Sto::refund(
+        bob.origin(),
+        bob_portfolio,
+        offering_asset,
+        fundraiser_id
+    ));
+    assert_eq!(Asset::balance_of(&raise_asset, bob.did), 1_000);
+    assert_eq!(Asset::balance_of(&offering_asset, bob.did), 0);
+    assert_eq!(Portfolio::locked_assets(escrow_portfolio, raise_asset), 0);
+    assert_noop!(
+        Sto::refund(bob.origin(), bob_portfolio, offering_asset, fundraiser_id),
+        Error::NoEscrowedInvestment
+    );
+}
@@ -239,8 +239,11 @@ parameter_types! {
     pub const MaxNumberOfPortfolios: u32 = (10 + 100) * 2;
     pub const MaxNumberOfVenueSigners: u32 = 50;
     pub const MaxInstructionMediators: u32 = 4;
+    pub const MaxAutoAffirmRules: u32 = 16;
+    pub const MaxAutoAffirmCounterparties: u32 = 32;
     pub const MaxAssetMediators: u32 = 4;
     pub const MaxGivenAuths: u32 = 1024;
+    pub const IdentityRecoveryDelay: Moment = 60_000;
     pub const MigrationSignedDepositPerItem: Balance = 0;
     pub const MigrationSignedDepositBase: Balance = 0;
     pub const MaxKeyLen: u32 = 2048;
@@ -288,6 +291,7 @@ frame_support::construct_runtime!(
         UpgradeCommitteeMembership: pallet_group::<Instance4>::{Pallet, Call, Storage, Event<T>, Config<T>} = 14,
 
         MultiSig: pallet_multisig::{Pallet, Call, Config, Storage, Event<T>} = 15,
+        Bridge: pallet_bridge::{Pallet, Call, Storage, Event<T>} = 16,
 
         // Staking: Genesis config deps: Balances, Indices, Identity, Babe, Timestamp, CddServiceProviders.
         Staking: pallet_staking::{Pallet, Call, Config<T>, Storage, Event<T>} = 17,
@@ -335,6 +339,8 @@ frame_support::construct_runtime!(
 
         Nft: pallet_nft::{Pallet, Call, Storage, Event} = 49,
 
+        Vesting: pallet_vesting::{Pallet, Call, Storage, Event} = 51,
+
         TestUtils: pallet_test_utils::{Pallet, Call, Storage, Event<T> } = 200,
 
         // Testing only.
@@ -470,6 +476,7 @@ parameter_types! {
 
     pub const MaxStatsPerAsset: u32 = 10 + BENCHMARK_MAX_INCREASE;
     pub const MaxTransferConditionsPerAsset: u32 = 4 + BENCHMARK_MAX_INCREASE;
+    pub const MaxAssetsPerCustomClaimIssuer: u32 = 16;
 
     pub const MaxConditionComplexity: u32 = 50;
     pub const MaxDefaultTrustedClaimIssuers: usize = 10;
@@ -638,6 +645,8 @@ impl polymesh_common_utilities::traits::identity::Config for TestStorage {
     type SchedulerOrigin = OriginCaller;
     type InitialPOLYX = InitialPOLYX;
     type MaxGivenAuths = MaxGivenAuths;
+    type IdentityRecoveryDelay = IdentityRecoveryDelay;
+    type ClaimHook = Statistics;
 }
 
 impl example::Config for TestStorage {}
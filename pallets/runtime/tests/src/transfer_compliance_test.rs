@@ -1,4 +1,5 @@
 use super::{
+    asset_test::set_timestamp,
     storage::{account_from, make_account, TestStorage, User},
     ExtBuilder,
 };
@@ -8,6 +9,7 @@ use frame_support::{
 };
 use pallet_external_agents::Event;
 use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::calendar::{CalendarPeriod, CalendarUnit};
 use polymesh_primitives::{
     asset::AssetType, jurisdiction::CountryCode, statistics::*, transfer_compliance::*, AccountId,
     Balance, Claim, ClaimType, IdentityId, PortfolioId, PortfolioKind, Scope, WeightMeter,
@@ -68,6 +70,15 @@ impl InvestorState {
         self.claims.insert((claim_type, *did), claim.clone());
     }
 
+    pub fn revoke_issuer_claim(&mut self, did: &IdentityId, acc: &AccountId, claim: &Claim) {
+        assert_ok!(Identity::revoke_claim(
+            Origin::signed(acc.clone()),
+            self.did,
+            claim.clone(),
+        ));
+        self.claims.remove(&(claim.claim_type(), *did));
+    }
+
     pub fn fetch_stat_claim(&self, claim_issuer: &(ClaimType, IdentityId)) -> Stat2ndKey {
         let claim = self.claims.get(claim_issuer);
         Stat2ndKey::new_from(&claim_issuer.0, claim.cloned())
@@ -249,6 +260,7 @@ impl AssetTracker {
             }
             ClaimType::Exempted => Claim::Exempted(scope),
             ClaimType::Blocked => Claim::Blocked(scope),
+            ClaimType::Custom(id) => Claim::Custom(id, Some(scope)),
             _ => {
                 panic!("Asset issuers can't create {:?} claims", claim_type);
             }
@@ -315,6 +327,22 @@ impl AssetTracker {
         self.investor_mut(id).add_issuer_claim(did, acc, claim)
     }
 
+    pub fn revoke_claim_from_investors(&mut self, ids: &[u64], claim_type: ClaimType) {
+        let claim = self.make_claim(claim_type, None);
+        let issuers = self
+            .issuers
+            .values()
+            .filter(|issuer| issuer.is_trusted_for(&claim_type))
+            .map(|i| (i.issuer.did, i.issuer.acc()))
+            .collect::<Vec<_>>();
+
+        for id in ids.into_iter() {
+            for (did, acc) in &issuers {
+                self.investor_mut(*id).revoke_issuer_claim(did, acc, &claim);
+            }
+        }
+    }
+
     pub fn investor_start_id(&self) -> u64 {
         self.investor_start_id
     }
@@ -521,6 +549,7 @@ impl AssetTracker {
                 let cal_value = match operation_type {
                     StatOpType::Count => self.calculate_stat_count(claim_issuer, &key2) as u128,
                     StatOpType::Balance => self.calculate_stat_balance(claim_issuer, &key2),
+                    StatOpType::Volume(_) => unreachable!("Volume stats are not per-claim"),
                 };
                 // Get stat from pallet.
                 let value = Statistics::asset_stats(key1, key2.clone());
@@ -584,6 +613,9 @@ impl AssetTracker {
                     println!("Balance[{:?}]: cal={:?}, stat={:?}", key2, cal_value, value);
                     assert_eq!(value, cal_value as u128);
                 }
+                (StatOpType::Volume(_), _) => {
+                    // Volumes are not stored in the stats.
+                }
             }
         }
     }
@@ -1129,3 +1161,262 @@ fn ensure_invalid_transfer_conditions_ext() {
         Error::TransferConditionLimitReached
     );
 }
+
+#[test]
+fn max_volume_rules() {
+    ExtBuilder::default()
+        .cdd_providers(vec![CDD_PROVIDER.to_account_id()])
+        .build()
+        .execute_with(max_volume_rules_ext);
+}
+
+fn max_volume_rules_ext() {
+    let day = CalendarPeriod {
+        unit: CalendarUnit::Day,
+        amount: 1,
+    };
+    set_timestamp(1);
+    // Create an asset.
+    let mut tracker = AssetTracker::new();
+
+    // Volume stats need a period of fixed length.
+    let month = CalendarPeriod {
+        unit: CalendarUnit::Month,
+        amount: 1,
+    };
+    assert_noop!(
+        Statistics::set_active_asset_stats(
+            tracker.owner_origin(),
+            tracker.asset_id,
+            [StatType {
+                operation_type: StatOpType::Volume(month),
+                claim_issuer: None,
+            }]
+            .into(),
+        ),
+        Error::InvalidVolumeStatType
+    );
+
+    tracker.set_active_stats(vec![StatType {
+        operation_type: StatOpType::Volume(day),
+        claim_issuer: None,
+    }]);
+    tracker.set_transfer_conditions(vec![
+        TransferCondition::MaxInvestorVolume(day, 10_000),
+        TransferCondition::MaxAssetVolume(day, 25_000),
+    ]);
+
+    // Mint is not restricted by transfer rules.
+    tracker.mint(100_000);
+
+    // The owner can only send 10_000 per day.
+    let a = tracker.new_investor();
+    let b = tracker.new_investor();
+    tracker.do_valid_transfer(tracker.owner_id, a, 10_000);
+    tracker.ensure_invalid_transfer(tracker.owner_id, b, 1);
+
+    // Other investors have their own volume, but share the asset volume.
+    tracker.do_valid_transfer(a, b, 10_000);
+    tracker.do_valid_transfer(b, a, 5_000);
+    tracker.ensure_invalid_transfer(b, a, 1);
+    tracker.ensure_asset_stats();
+
+    // The volume is rolled over after a day.
+    set_timestamp(1 + 24 * 60 * 60 * 1000);
+    tracker.do_valid_transfer(tracker.owner_id, b, 10_000);
+}
+
+#[test]
+fn custom_and_kyc_claim_rules() {
+    ExtBuilder::default()
+        .cdd_providers(vec![CDD_PROVIDER.to_account_id()])
+        .build()
+        .execute_with(custom_and_kyc_claim_rules_ext);
+}
+
+fn custom_and_kyc_claim_rules_ext() {
+    // Create an asset.
+    let mut tracker = AssetTracker::new();
+
+    let issuer = User::new(AccountKeyring::Dave);
+    assert_ok!(Identity::register_custom_claim_type(
+        issuer.origin(),
+        b"qualified purchaser".to_vec()
+    ));
+    let qualified_id = Identity::custom_claim_id_seq();
+    let qualified = ClaimType::Custom(qualified_id);
+    let kyc = ClaimType::KnowYourCustomer;
+    // Add issuer.
+    tracker.add_issuer(&issuer, &[kyc, qualified]);
+
+    // Make the owner a KYCed qualified purchaser.
+    tracker.add_claim_to_investors(&[tracker.owner_id], kyc, None);
+    tracker.add_claim_to_investors(&[tracker.owner_id], qualified, None);
+
+    // Active stats.
+    let stats = [kyc, qualified]
+        .into_iter()
+        .flat_map(|claim_type| {
+            [StatOpType::Count, StatOpType::Balance]
+                .into_iter()
+                .map(move |operation_type| StatType {
+                    operation_type,
+                    claim_issuer: Some((claim_type, issuer.did)),
+                })
+        })
+        .collect();
+    tracker.set_active_stats(stats);
+
+    // Stat updates must be keyed by the claim type of the stat.
+    assert_noop!(
+        Statistics::batch_update_asset_stats(
+            tracker.owner_origin(),
+            tracker.asset_id,
+            StatType {
+                operation_type: StatOpType::Count,
+                claim_issuer: Some((qualified, issuer.did)),
+            },
+            [StatUpdate {
+                key2: Stat2ndKey::Claim(StatClaim::KnowYourCustomer(true)),
+                value: Some(1),
+            }]
+            .into(),
+        ),
+        Error::StatUpdateClaimTypeMismatch
+    );
+
+    // At most 10 non-KYCed investors and 10% of the supply held by non-qualified purchasers.
+    let no_kyc = tracker.make_stat_claim(kyc, false, None);
+    let not_qualified = tracker.make_stat_claim(qualified, false, None);
+    let p0 = Permill::zero();
+    let p10 = Permill::from_rational(10u32, 100u32);
+    tracker.set_transfer_conditions(vec![
+        TransferCondition::ClaimCount(no_kyc, issuer.did, 0, Some(10)),
+        TransferCondition::ClaimOwnership(not_qualified, issuer.did, p0, p10),
+    ]);
+
+    // Mint
+    tracker.mint(100_000);
+
+    // Create some investor batches.  10 - non-KYCed and 10 KYCed qualified purchasers.
+    let batches = tracker
+        .make_batches(vec![
+            (10, 1_000u128, vec![]),
+            (10, 2_000u128, vec![(kyc, None), (qualified, None)]),
+        ])
+        .expect("Failed to create batches");
+
+    tracker.ensure_asset_stats();
+    tracker.ensure_claim_stats(StatOpType::Count, kyc, false, None);
+    tracker.ensure_claim_stats(StatOpType::Balance, qualified, true, None);
+
+    // No more non-KYCed investors or non-qualified holdings.
+    let id = tracker.new_investor();
+    tracker.ensure_invalid_transfer(tracker.owner_id, id, 1_000);
+    tracker.ensure_invalid_transfer(batches[1].ids[0], batches[0].ids[0], 1);
+
+    // KYC a non-KYCed investor and revoke a qualified purchaser.
+    tracker.add_claim_to_investors(&batches[0].ids[..1], kyc, None);
+    tracker.revoke_claim_from_investors(&batches[1].ids[..1], qualified);
+
+    // The stats are updated when the claims are added or revoked.
+    tracker.ensure_asset_stats();
+
+    // There is room for one more non-KYCed investor, but none for non-qualified holdings.
+    tracker.ensure_invalid_transfer(tracker.owner_id, id, 1_000);
+    tracker.add_claim_to_investors(&[id], qualified, None);
+    tracker.do_valid_transfer(tracker.owner_id, id, 1_000);
+
+    tracker.ensure_asset_stats();
+
+    // Unscoped custom claims are counted for the investors without a scoped claim.
+    let key1 = Stat1stKey {
+        asset_id: tracker.asset_id,
+        stat_type: StatType {
+            operation_type: StatOpType::Count,
+            claim_issuer: Some((qualified, issuer.did)),
+        },
+    };
+    let qualified_key2 = Stat2ndKey::Claim(tracker.make_stat_claim(qualified, true, None));
+    let qualified_count = Statistics::asset_stats(key1, &qualified_key2);
+    let unscoped_claim = Claim::Custom(qualified_id, None);
+    let not_qualified_did = tracker.investor(batches[0].ids[0]).did();
+    let qualified_did = tracker.investor(batches[1].ids[1]).did();
+    for did in [not_qualified_did, qualified_did] {
+        assert_ok!(Identity::add_claim(
+            issuer.origin(),
+            did,
+            unscoped_claim.clone(),
+            None
+        ));
+    }
+    assert_eq!(
+        Statistics::asset_stats(key1, &qualified_key2),
+        qualified_count + 1
+    );
+
+    // Revoking the unscoped claim moves the investor back.
+    assert_ok!(Identity::revoke_claim(
+        issuer.origin(),
+        not_qualified_did,
+        unscoped_claim
+    ));
+    assert_eq!(
+        Statistics::asset_stats(key1, &qualified_key2),
+        qualified_count
+    );
+}
+
+#[test]
+fn asset_stat_values() {
+    ExtBuilder::default()
+        .cdd_providers(vec![CDD_PROVIDER.to_account_id()])
+        .build()
+        .execute_with(asset_stat_values_with_ext);
+}
+
+fn asset_stat_values_with_ext() {
+    // Create an asset.
+    let mut tracker = AssetTracker::new();
+
+    let count_stat = StatType {
+        operation_type: StatOpType::Count,
+        claim_issuer: None,
+    };
+    let volume_stat = StatType {
+        operation_type: StatOpType::Volume(CalendarPeriod {
+            unit: CalendarUnit::Day,
+            amount: 1,
+        }),
+        claim_issuer: None,
+    };
+    // Active stats.
+    tracker.set_active_stats(vec![count_stat, volume_stat]);
+
+    // No values before any investor holds the asset.
+    assert_eq!(
+        Statistics::asset_stat_values(tracker.asset_id).unwrap(),
+        vec![AssetStatValue {
+            stat_type: volume_stat,
+            key2: Stat2ndKey::NoClaimStat,
+            value: 0,
+            ownership: None,
+        }]
+    );
+
+    // Mint
+    tracker.mint(100_000_000);
+
+    // Create investor batches.
+    let _batches = create_batches(&mut tracker);
+
+    tracker.ensure_asset_stats();
+
+    let stat_values = Statistics::asset_stat_values(tracker.asset_id).unwrap();
+    let count_value = stat_values
+        .iter()
+        .find(|v| v.stat_type == count_stat && v.key2 == Stat2ndKey::NoClaimStat)
+        .expect("Missing investor count");
+    assert_eq!(count_value.value, tracker.active_investor_count() as u128);
+    assert_eq!(count_value.ownership, None);
+}
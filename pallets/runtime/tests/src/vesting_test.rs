@@ -0,0 +1,262 @@
+use frame_support::{assert_noop, assert_ok};
+use sp_keyring::AccountKeyring;
+
+use pallet_vesting::{
+    VestingGrantId, VestingGrantSpec, VestingGrants, VestingLocked, VestingRelease, VestingSchedule,
+};
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::{Balance, PortfolioId, PortfolioKind};
+
+use crate::asset_pallet::setup::{create_and_issue_sample_asset, ISSUE_AMOUNT};
+
+use super::asset_test::set_timestamp;
+use super::storage::{TestStorage, User};
+use super::ExtBuilder;
+
+type Asset = pallet_asset::Module<TestStorage>;
+type EAError = pallet_external_agents::Error<TestStorage>;
+type Error = pallet_vesting::Error<TestStorage>;
+type Portfolio = pallet_portfolio::Module<TestStorage>;
+type PortfolioError = pallet_portfolio::Error<TestStorage>;
+type Vesting = pallet_vesting::Module<TestStorage>;
+
+fn schedule(release: VestingRelease) -> VestingSchedule {
+    VestingSchedule {
+        start: 1_000,
+        cliff: 2_000,
+        end: 5_000,
+        release,
+    }
+}
+
+fn grant(beneficiary: PortfolioId, amount: Balance, release: VestingRelease) -> VestingGrantSpec {
+    VestingGrantSpec {
+        beneficiary,
+        amount,
+        schedule: schedule(release),
+    }
+}
+
+fn free_balance(portfolio: &PortfolioId, asset_id: &AssetId) -> Balance {
+    Portfolio::portfolio_asset_balances(portfolio, asset_id)
+        - Portfolio::locked_assets(portfolio, asset_id)
+}
+
+#[test]
+fn create_vesting_grants() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let charlie_portfolio = PortfolioId::default_portfolio(charlie.did);
+
+        let asset_id = create_and_issue_sample_asset(&alice);
+        let linear = grant(bob_portfolio, 4_000, VestingRelease::Linear);
+
+        assert_noop!(
+            Vesting::create_vesting_grants(
+                bob.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                vec![linear]
+            ),
+            EAError::UnauthorizedAgent
+        );
+        assert_noop!(
+            Vesting::create_vesting_grants(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                vec![]
+            ),
+            Error::InvalidNumberOfGrants
+        );
+        assert_noop!(
+            Vesting::create_vesting_grants(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                vec![linear]
+            ),
+            Error::BeneficiaryNotPreApproved
+        );
+        assert_ok!(Asset::pre_approve_asset(bob.origin(), asset_id));
+        assert_ok!(Asset::pre_approve_asset(charlie.origin(), asset_id));
+
+        let mut invalid = linear;
+        invalid.schedule.cliff = 6_000;
+        assert_noop!(
+            Vesting::create_vesting_grants(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                vec![invalid, linear]
+            ),
+            Error::InvalidVestingSchedule
+        );
+        assert_noop!(
+            Vesting::create_vesting_grants(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                vec![grant(bob_portfolio, 4_000, VestingRelease::Stepped(0))]
+            ),
+            Error::InvalidVestingSchedule
+        );
+        // A stepped schedule ending before its start is rejected rather than underflowing.
+        let mut inverted = grant(bob_portfolio, 4_000, VestingRelease::Stepped(1_000));
+        inverted.schedule.start = 6_000;
+        assert_noop!(
+            Vesting::create_vesting_grants(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                vec![inverted]
+            ),
+            Error::InvalidVestingSchedule
+        );
+        assert_noop!(
+            Vesting::create_vesting_grants(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                vec![grant(bob_portfolio, 0, VestingRelease::Linear)]
+            ),
+            Error::ZeroVestingAmount
+        );
+
+        assert_ok!(Vesting::create_vesting_grants(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            vec![
+                linear,
+                grant(charlie_portfolio, 3_000, VestingRelease::Stepped(1_000))
+            ]
+        ));
+        assert_eq!(Asset::balance_of(asset_id, alice.did), ISSUE_AMOUNT - 7_000);
+
+        // Locked tokens still count towards the beneficiary's balance, but can't be moved.
+        assert_eq!(Asset::balance_of(asset_id, bob.did), 4_000);
+        assert_eq!(VestingLocked::get(bob_portfolio, asset_id), 4_000);
+        assert_eq!(free_balance(&bob_portfolio, &asset_id), 0);
+        assert_noop!(
+            Portfolio::ensure_sufficient_balance(&bob_portfolio, &asset_id, 1),
+            PortfolioError::InsufficientPortfolioBalance
+        );
+        assert_eq!(
+            VestingGrants::get(charlie_portfolio, VestingGrantId(1))
+                .unwrap()
+                .total,
+            3_000
+        );
+    });
+}
+
+#[test]
+fn release_vested() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let charlie_portfolio = PortfolioId::default_portfolio(charlie.did);
+
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(Asset::pre_approve_asset(bob.origin(), asset_id));
+        assert_ok!(Asset::pre_approve_asset(charlie.origin(), asset_id));
+        assert_ok!(Vesting::create_vesting_grants(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            vec![
+                grant(bob_portfolio, 4_000, VestingRelease::Linear),
+                grant(charlie_portfolio, 4_000, VestingRelease::Stepped(1_000))
+            ]
+        ));
+
+        // Nothing vests before the cliff.
+        set_timestamp(1_500);
+        assert_noop!(
+            Vesting::release_vested(bob.origin(), bob_portfolio, VestingGrantId(0)),
+            Error::NothingToRelease
+        );
+
+        // Anyone can release vested tokens.
+        set_timestamp(2_500);
+        assert_ok!(Vesting::release_vested(
+            alice.origin(),
+            bob_portfolio,
+            VestingGrantId(0)
+        ));
+        assert_eq!(free_balance(&bob_portfolio, &asset_id), 1_500);
+        assert_ok!(Vesting::release_vested(
+            charlie.origin(),
+            charlie_portfolio,
+            VestingGrantId(1)
+        ));
+        assert_eq!(free_balance(&charlie_portfolio, &asset_id), 1_000);
+        assert_noop!(
+            Vesting::release_vested(charlie.origin(), charlie_portfolio, VestingGrantId(1)),
+            Error::NothingToRelease
+        );
+
+        // The grant is removed once everything has been released.
+        set_timestamp(5_000);
+        assert_ok!(Vesting::release_vested(
+            bob.origin(),
+            bob_portfolio,
+            VestingGrantId(0)
+        ));
+        assert_eq!(free_balance(&bob_portfolio, &asset_id), 4_000);
+        assert_eq!(VestingLocked::get(bob_portfolio, asset_id), 0);
+        assert!(VestingGrants::get(bob_portfolio, VestingGrantId(0)).is_none());
+        assert_noop!(
+            Vesting::release_vested(bob.origin(), bob_portfolio, VestingGrantId(0)),
+            Error::NoSuchVestingGrant
+        );
+    });
+}
+
+#[test]
+fn revoke_vesting_grant() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(Asset::pre_approve_asset(bob.origin(), asset_id));
+        assert_ok!(Vesting::create_vesting_grants(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            vec![grant(bob_portfolio, 4_000, VestingRelease::Linear)]
+        ));
+
+        set_timestamp(3_000);
+        assert_noop!(
+            Vesting::revoke_vesting_grant(
+                bob.origin(),
+                bob_portfolio,
+                VestingGrantId(0),
+                PortfolioKind::Default
+            ),
+            EAError::UnauthorizedAgent
+        );
+        assert_ok!(Vesting::revoke_vesting_grant(
+            alice.origin(),
+            bob_portfolio,
+            VestingGrantId(0),
+            PortfolioKind::Default
+        ));
+
+        // The vested half stays with bob, unlocked, and the unvested half goes back to alice.
+        assert_eq!(Asset::balance_of(asset_id, bob.did), 2_000);
+        assert_eq!(free_balance(&bob_portfolio, &asset_id), 2_000);
+        assert_eq!(VestingLocked::get(bob_portfolio, asset_id), 0);
+        assert_eq!(Asset::balance_of(asset_id, alice.did), ISSUE_AMOUNT - 2_000);
+        assert!(VestingGrants::get(bob_portfolio, VestingGrantId(0)).is_none());
+    });
+}
@@ -21,7 +21,7 @@ use sp_core::sr25519::Signature;
 use sp_runtime::MultiSignature;
 use sp_std::prelude::*;
 
-use pallet_asset::benchmarking::setup_asset_transfer;
+use pallet_asset::benchmarking::{create_portfolio, setup_asset_transfer};
 use pallet_nft::benchmarking::setup_nft_transfer;
 use polymesh_common_utilities::benchs::{
     create_and_issue_sample_asset, AccountIdOf, User, UserBuilder,
@@ -199,6 +199,7 @@ where
 /// Creates and affirms an instruction with `f` fungible legs, `n` non-fungible legs and `o` offchain legs.
 /// All legs have unique tickers, use custom portfolios, and have the maximum number of compliance requirements,
 /// active statistics and transfer restrictions,
+/// If `affirm_as_spender` is true, the sender portfolios of fungible legs are affirmed by `receiver` using an allowance.
 fn setup_execute_instruction<T>(
     sender: &User<T>,
     receiver: &User<T>,
@@ -210,6 +211,7 @@ fn setup_execute_instruction<T>(
     m: u32,
     pause_compliance: bool,
     pause_restrictions: bool,
+    affirm_as_spender: bool,
 ) -> Parameters<T>
 where
     T: Config + TestUtilsFn<AccountIdOf<T>>,
@@ -249,7 +251,38 @@ where
             )
         })
         .collect();
-    let sdr_portfolios = parameters.sdr_portfolios();
+    // Each fungible leg has its own sender portfolio, which grants an allowance to the receiver
+    let spender_portfolios: Vec<PortfolioId> = parameters
+        .legs
+        .iter()
+        .filter_map(|leg| match leg {
+            Leg::Fungible {
+                sender: portfolio,
+                asset_id,
+                amount,
+                ..
+            } if affirm_as_spender => {
+                Asset::<T>::approve(
+                    sender.origin.clone().into(),
+                    *portfolio,
+                    *asset_id,
+                    receiver.did(),
+                    *amount,
+                )
+                .unwrap();
+                Some(*portfolio)
+            }
+            _ => None,
+        })
+        .collect();
+    let sdr_portfolios: BoundedBTreeSet<PortfolioId, T::MaxNumberOfPortfolios> = parameters
+        .portfolios
+        .sdr_portfolios
+        .iter()
+        .filter(|portfolio| !spender_portfolios.contains(portfolio))
+        .cloned()
+        .try_collect()
+        .expect("shouldn't be too many");
     Module::<T>::affirm_with_receipts(
         sender.origin.clone().into(),
         InstructionId(1),
@@ -257,6 +290,14 @@ where
         sdr_portfolios,
     )
     .unwrap();
+    for portfolio in spender_portfolios {
+        Module::<T>::affirm_instruction_as_spender(
+            receiver.origin.clone().into(),
+            InstructionId(1),
+            portfolio,
+        )
+        .unwrap();
+    }
     let rcv_portfolios = parameters.rcv_portfolios();
     Module::<T>::affirm_with_receipts(
         receiver.origin.clone().into(),
@@ -327,6 +368,31 @@ where
     (m_user, m_identity)
 }
 
+/// Adds `r` auto-affirm rules to `portfolio`, where only the last rule matches legs created by `venue_id`.
+fn add_auto_affirm_rules<T: Config>(
+    custodian: &User<T>,
+    portfolio: PortfolioId,
+    side: AffirmationSide,
+    venue_id: VenueId,
+    filler_venue_id: VenueId,
+    r: u32,
+) {
+    for i in 0..r {
+        let rule = AutoAffirmRule {
+            side,
+            venue_id: if i + 1 == r {
+                venue_id
+            } else {
+                filler_venue_id
+            },
+            asset_id: None,
+            counterparties: BTreeSet::new(),
+            daily_limit: Some(ONE_UNIT * 1_000),
+        };
+        Module::<T>::add_auto_affirm_rule(custodian.origin().into(), portfolio, rule).unwrap();
+    }
+}
+
 benchmarks! {
     where_clause { where T: TestUtilsFn<AccountIdOf<T>>, T: pallet_scheduler::Config }
 
@@ -482,7 +548,7 @@ benchmarks! {
         let settlement_type = SettlementType::SettleManual(0u32.into());
         let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
 
-        setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false, true);
     }: _(alice.origin, InstructionId(1), None, f, n, o, Some(Weight::MAX))
 
     add_instruction{
@@ -552,7 +618,7 @@ benchmarks! {
         let settlement_type = SettlementType::SettleOnBlock(100u32.into());
         let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
 
-        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false, false);
         let portfolios = parameters.sdr_portfolios();
     }: _(alice.origin, InstructionId(1),  portfolios)
 
@@ -569,7 +635,7 @@ benchmarks! {
         let settlement_type = SettlementType::SettleOnBlock(100u32.into());
         let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
 
-        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false, false);
     }: _(alice.origin, InstructionId(1), parameters.portfolios.sdr_portfolios[0])
 
     execute_instruction_paused {
@@ -584,7 +650,7 @@ benchmarks! {
         let bob = UserBuilder::<T>::default().generate_did().build("Bob");
         let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
 
-        setup_execute_instruction::<T>(&alice, &bob, SettlementType::SettleOnAffirmation, venue_id, f, n, o, m, true, true);
+        setup_execute_instruction::<T>(&alice, &bob, SettlementType::SettleOnAffirmation, venue_id, f, n, o, m, true, true, true);
     }: execute_scheduled_instruction(RawOrigin::Root, InstructionId(1), Weight::MAX)
 
     execute_scheduled_instruction {
@@ -599,7 +665,7 @@ benchmarks! {
         let bob = UserBuilder::<T>::default().generate_did().build("Bob");
         let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
 
-        setup_execute_instruction::<T>(&alice, &bob, SettlementType::SettleOnAffirmation, venue_id, f, n, o, m, false, false);
+        setup_execute_instruction::<T>(&alice, &bob, SettlementType::SettleOnAffirmation, venue_id, f, n, o, m, false, false, true);
     }: _(RawOrigin::Root, InstructionId(1), Weight::MAX)
 
     ensure_root_origin {
@@ -680,7 +746,7 @@ benchmarks! {
         let settlement_type = SettlementType::SettleOnBlock(100u32.into());
         let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
 
-        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false, false);
         let portfolios = parameters.rcv_portfolios();
     }: withdraw_affirmation(bob.origin, InstructionId(1),  portfolios)
 
@@ -796,6 +862,199 @@ benchmarks! {
         let settlement_type = SettlementType::SettleOnBlock(100u32.into());
         let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
 
-        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false, false);
     }: _(parameters.asset_mediators[0].origin.clone(), InstructionId(1), None)
+
+    set_venue_netting {
+        let User { origin, did, .. } = creator::<T>();
+        let venue_id = create_venue_::<T>(did.unwrap(), vec![]);
+    }: _(origin, venue_id, true)
+    verify {
+        assert!(Module::<T>::venue_netting(venue_id), "Fail: set_venue_netting failed");
+    }
+
+    schedule_affirmation_deadline {
+        let deadline: T::BlockNumber = 100u32.into();
+    }: {
+        InstructionAffirmationDeadline::<T>::insert(InstructionId(1), deadline);
+        Module::<T>::schedule_affirmation_deadline(InstructionId(1), deadline, AssetCount::default());
+    }
+
+    affirm_instruction_as_spender {
+        // Number of fungible legs sent from the portfolio
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let charlie = UserBuilder::<T>::default().generate_did().build("Charlie");
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account()]);
+
+        // All legs are sent from the same portfolio, which granted an allowance to charlie
+        let parameters = setup_legs::<T>(&alice, &bob, f, 0, 0, false, false);
+        let alice_portfolio = create_portfolio::<T>(&alice, "SpenderPortfolio");
+        let legs: Vec<Leg> = parameters
+            .legs
+            .into_iter()
+            .map(|leg| match leg {
+                Leg::Fungible { receiver, asset_id, amount, .. } => {
+                    Asset::<T>::issue(alice.origin().into(), asset_id, amount, alice_portfolio.kind).unwrap();
+                    Asset::<T>::approve(alice.origin().into(), alice_portfolio, asset_id, charlie.did(), amount).unwrap();
+                    Leg::Fungible { sender: alice_portfolio, receiver, asset_id, amount }
+                }
+                leg => leg,
+            })
+            .collect();
+        Module::<T>::add_instruction(
+            alice.origin.clone().into(),
+            Some(venue_id),
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+        ).unwrap();
+    }: _(charlie.origin, InstructionId(1), alice_portfolio)
+    verify {
+        assert_eq!(Module::<T>::spender_affirmations(InstructionId(1), alice_portfolio), Some(charlie.did()));
+    }
+
+    affirm_with_preimage {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account()]);
+
+        // The scheduling weight is computed from all legs of the instruction
+        let f = T::MaxNumberOfFungibleAssets::get();
+        let mut legs = setup_legs::<T>(&alice, &bob, f, 0, 0, false, false).legs;
+        let preimage = b"HashLockSecret".to_vec();
+        legs.push(Leg::HashLocked {
+            sender_identity: alice.did(),
+            receiver_identity: bob.did(),
+            ticker: Ticker::from_slice_truncated(b"OFFTICKER"),
+            amount: ONE_UNIT,
+            hash_lock: sha2_256(&preimage),
+            expiry: u64::MAX,
+        });
+        Module::<T>::add_instruction(
+            alice.origin.clone().into(),
+            Some(venue_id),
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+        ).unwrap();
+    }: _(bob.origin, InstructionId(1), LegId(f as u64), preimage)
+    verify {
+        assert_eq!(Module::<T>::offchain_affirmations(InstructionId(1), LegId(f as u64)), AffirmationStatus::Affirmed);
+    }
+
+    amend_instruction {
+        // Number of fungible, non-fungible and offchain LEGS, both in the existing instruction and in the amendment
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+        let n in 0..T::MaxNumberOfNFTs::get();
+        let o in 0..T::MaxNumberOfOffChainAssets::get();
+
+        let m = T::MaxInstructionMediators::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let settlement_type = SettlementType::SettleOnBlock(100u32.into());
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
+
+        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false, false);
+        // Reverses all legs, so that every affirmation is withdrawn and every lock released
+        let legs: Vec<Leg> = parameters
+            .legs
+            .into_iter()
+            .map(|leg| match leg {
+                Leg::Fungible { sender, receiver, asset_id, amount } => {
+                    Leg::Fungible { sender: receiver, receiver: sender, asset_id, amount }
+                }
+                Leg::NonFungible { sender, receiver, nfts } => {
+                    Leg::NonFungible { sender: receiver, receiver: sender, nfts }
+                }
+                Leg::OffChain { sender_identity, receiver_identity, ticker, amount } => {
+                    Leg::OffChain { sender_identity: receiver_identity, receiver_identity: sender_identity, ticker, amount }
+                }
+                leg => leg,
+            })
+            .collect();
+    }: _(alice.origin, InstructionId(1), None, None, legs, Some(Memo::default()), Some(AssetCount::new(f, n, o)))
+    verify {
+        assert_eq!(Module::<T>::instruction_version(InstructionId(1)), 1);
+    }
+
+    add_auto_affirm_rule {
+        let c in 0..T::MaxAutoAffirmCounterparties::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let venue_id = create_venue_::<T>(alice.did(), vec![]);
+        let portfolio = PortfolioId::default_portfolio(alice.did());
+        let rule = AutoAffirmRule {
+            side: AffirmationSide::Receiver,
+            venue_id,
+            asset_id: None,
+            counterparties: (0..c).map(|i| IdentityId::from(i as u128)).collect(),
+            daily_limit: Some(ONE_UNIT),
+        };
+    }: _(alice.origin, portfolio, rule)
+    verify {
+        assert_eq!(Module::<T>::auto_affirm_rule_count(portfolio), 1);
+    }
+
+    remove_auto_affirm_rule {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let venue_id = create_venue_::<T>(alice.did(), vec![]);
+        let portfolio = PortfolioId::default_portfolio(alice.did());
+        add_auto_affirm_rules::<T>(&alice, portfolio, AffirmationSide::Receiver, venue_id, venue_id, T::MaxAutoAffirmRules::get());
+    }: _(alice.origin, portfolio, AutoAffirmRuleId(0))
+    verify {
+        assert!(Module::<T>::auto_affirm_rules(portfolio, AutoAffirmRuleId(0)).is_none());
+    }
+
+    auto_affirm_rules {
+        // Number of fungible legs in the instruction
+        let l in 1..T::MaxNumberOfFungibleAssets::get();
+        // Number of auto-affirm rules of each portfolio
+        let r in 1..T::MaxAutoAffirmRules::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let charlie = UserBuilder::<T>::default().generate_did().build("Charlie");
+        let venue_id = create_venue_::<T>(charlie.did(), vec![]);
+        let filler_venue_id = create_venue_::<T>(charlie.did(), vec![]);
+
+        let parameters = setup_legs::<T>(&alice, &bob, l, 0, 0, false, false);
+        Module::<T>::base_add_instruction(
+            charlie.did(),
+            Some(venue_id),
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            parameters.legs,
+            None,
+            None,
+            None,
+        ).unwrap();
+
+        // Every portfolio has `r` rules, and only its last rule matches
+        for portfolio in &parameters.portfolios.sdr_portfolios {
+            add_auto_affirm_rules::<T>(&alice, *portfolio, AffirmationSide::Sender, venue_id, filler_venue_id, r);
+        }
+        for portfolio in &parameters.portfolios.rcv_portfolios {
+            add_auto_affirm_rules::<T>(&bob, *portfolio, AffirmationSide::Receiver, venue_id, filler_venue_id, r);
+        }
+        let portfolios: BTreeSet<PortfolioId> = parameters
+            .portfolios
+            .sdr_portfolios
+            .into_iter()
+            .chain(parameters.portfolios.rcv_portfolios)
+            .collect();
+    }: {
+        Module::<T>::auto_affirm_portfolios(charlie.did(), InstructionId(1), &venue_id, &portfolios);
+    }
+    verify {
+        assert_eq!(Module::<T>::instruction_affirms_pending(InstructionId(1)), 0);
+    }
 }
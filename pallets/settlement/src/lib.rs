@@ -32,6 +32,12 @@
 //! Offchain settlements are represented via receipts. If a leg has a receipt attached to it, it will not be executed onchain.
 //! All other legs will be executed onchain during settlement.
 //!
+//! Hash-locked legs are offchain legs that don't need a receipt signer. They are affirmed by anyone revealing the
+//! preimage of the leg's hash lock before its expiry, which allows atomic swaps with external ledgers.
+//!
+//! Portfolios can register standing settlement instructions (auto-affirm rules). When a venue creates an instruction,
+//! a portfolio is automatically affirmed if every leg it is a party in matches one of its rules.
+//!
 //! ## Dispatchable Functions
 //!
 //! - `create_venue` - Registers a new venue.
@@ -42,6 +48,16 @@
 //! - `set_venue_filtering` - Enables or disabled venue filtering for a token.
 //! - `allow_venues` - Allows additional venues to create instructions involving an asset.
 //! - `disallow_venues` - Revokes permission given to venues for creating instructions involving a particular asset.
+//! - `set_venue_netting` - Enables or disables netting of fungible legs for all new instructions of a venue.
+//! - `add_instruction_with_deadline` - Adds a new instruction that is rejected if not affirmed before a deadline.
+//! - `add_and_affirm_with_deadline` - Adds and affirms a new instruction that is rejected if not affirmed before a deadline.
+//! - `add_instruction_with_mediators_and_deadline` - Adds a new instruction with mediators and an affirmation deadline.
+//! - `add_and_affirm_with_mediators_and_deadline` - Adds and affirms a new instruction with mediators and an affirmation deadline.
+//! - `affirm_instruction_as_spender` - Affirms an instruction on behalf of a portfolio, using a granted allowance.
+//! - `affirm_with_preimage` - Affirms a hash-locked leg by revealing the preimage of its hash lock.
+//! - `amend_instruction` - Amends the legs, dates and memo of a pending instruction, keeping untouched affirmations.
+//! - `add_auto_affirm_rule` - Adds a standing settlement instruction to a portfolio.
+//! - `remove_auto_affirm_rule` - Removes a standing settlement instruction from a portfolio.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
@@ -62,7 +78,9 @@ use frame_support::{
     decl_error, decl_module, decl_storage, ensure, BoundedBTreeSet, IterableStorageDoubleMap,
 };
 use frame_system::{ensure_root, RawOrigin};
+use sp_io::hashing::sha2_256;
 use sp_runtime::traits::{One, Verify};
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryFrom;
 use sp_std::prelude::*;
@@ -78,10 +96,11 @@ use polymesh_common_utilities::with_transaction;
 use polymesh_common_utilities::SystematicIssuers::Settlement as SettlementDID;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::settlement::{
-    AffirmationCount, AffirmationStatus, AssetCount, ExecuteInstructionInfo, FilteredLegs,
-    Instruction, InstructionId, InstructionInfo, InstructionStatus, Leg, LegId, LegStatus,
-    MediatorAffirmationStatus, Receipt, ReceiptDetails, SettlementType, Venue, VenueDetails,
-    VenueId, VenueType,
+    AffirmationCount, AffirmationSide, AffirmationStatus, AssetCount, AutoAffirmRule,
+    AutoAffirmRuleId, AutoAffirmRuleMatch, AutoAffirmRuleUsage, ExecuteInstructionInfo,
+    FilteredLegs, Instruction, InstructionId, InstructionInfo, InstructionStatus, Leg, LegId,
+    LegStatus, MediatorAffirmationStatus, Receipt, ReceiptDetails, SettlementType, Venue,
+    VenueDetails, VenueId, VenueType,
 };
 use polymesh_primitives::{
     storage_migrate_on, storage_migration_ver, Balance, IdentityId, Memo, NFTs, PortfolioId,
@@ -91,6 +110,7 @@ use polymesh_primitives::{
 type Identity<T> = pallet_identity::Module<T>;
 type System<T> = frame_system::Pallet<T>;
 type Asset<T> = pallet_asset::Module<T>;
+type Checkpoint<T> = pallet_asset::checkpoint::Module<T>;
 type ExternalAgents<T> = pallet_external_agents::Module<T>;
 type Nft<T> = pallet_nft::Module<T>;
 type EnsureValidInstructionResult<AccountId, Moment, BlockNumber> = Result<
@@ -143,6 +163,12 @@ pub trait Config:
 
     /// Maximum number mediators in the instruction level (this does not include asset mediators).
     type MaxInstructionMediators: Get<u32>;
+
+    /// Maximum number of auto-affirm rules per portfolio.
+    type MaxAutoAffirmRules: Get<u32>;
+
+    /// Maximum number of counterparties in an auto-affirm rule.
+    type MaxAutoAffirmCounterparties: Get<u32>;
 }
 
 decl_error! {
@@ -236,6 +262,30 @@ decl_error! {
         MediatorAffirmationExpired,
         /// Offchain assets must have a venue.
         OffChainAssetsMustHaveAVenue,
+        /// The total amount of the legs being netted overflowed.
+        NettingOverflow,
+        /// All legs of the instruction cancel each other out after netting.
+        InstructionNetsToZero,
+        /// The affirmation deadline must be in the future.
+        AffirmationDeadlineInThePast,
+        /// A spender can only affirm portfolios that are exclusively the sender of fungible legs.
+        InvalidSpenderAffirmation,
+        /// The expiry of a hash-locked leg must be in the future.
+        HashLockExpiryInThePast,
+        /// The hash-locked leg has expired.
+        HashLockExpired,
+        /// The preimage doesn't match the hash lock of the leg.
+        InvalidHashLockPreimage,
+        /// Preimages can only be revealed for [`Leg::HashLocked`] legs.
+        PreimageForInvalidLegType,
+        /// Only pending instructions can be amended.
+        InstructionNotPending,
+        /// The portfolio has no auto-affirm rule with the given id.
+        AutoAffirmRuleNotFound,
+        /// The maximum number of auto-affirm rules for the portfolio was exceeded.
+        MaxNumberOfAutoAffirmRulesExceeded,
+        /// The maximum number of counterparties in an auto-affirm rule was exceeded.
+        MaxNumberOfCounterpartiesExceeded,
     }
 }
 
@@ -308,6 +358,33 @@ decl_storage! {
         /// The status for the mediators affirmation.
         pub InstructionMediatorsAffirmations get(fn venue_mediators_affirmations):
             double_map hasher(twox_64_concat) InstructionId, hasher(identity) IdentityId => MediatorAffirmationStatus<T::Moment>;
+        /// Tracks if a venue nets the fungible legs of its instructions. venue_id -> netting_enabled
+        pub VenueNetting get(fn venue_netting): map hasher(twox_64_concat) VenueId => bool;
+        /// The block number after which a pending instruction is rejected. instruction_id -> deadline
+        pub InstructionAffirmationDeadline get(fn instruction_affirmation_deadline):
+            map hasher(twox_64_concat) InstructionId => Option<T::BlockNumber>;
+        /// The spender that affirmed an instruction on behalf of a portfolio. (instruction_id, portfolio) -> spender
+        pub SpenderAffirmations get(fn spender_affirmations):
+            double_map hasher(twox_64_concat) InstructionId, hasher(blake2_128_concat) PortfolioId => Option<IdentityId>;
+        /// The number of times an instruction has been amended. instruction_id -> version
+        pub InstructionVersions get(fn instruction_version):
+            map hasher(twox_64_concat) InstructionId => u32;
+        /// Standing settlement instructions of a portfolio. (portfolio, rule_id) -> AutoAffirmRule
+        pub AutoAffirmRules get(fn auto_affirm_rules):
+            double_map hasher(blake2_128_concat) PortfolioId, hasher(twox_64_concat) AutoAffirmRuleId => Option<AutoAffirmRule>;
+        /// Tracks how much each auto-affirm rule has affirmed. (portfolio, rule_id) -> AutoAffirmRuleUsage
+        pub AutoAffirmRulesUsage get(fn auto_affirm_rules_usage):
+            double_map hasher(blake2_128_concat) PortfolioId, hasher(twox_64_concat) AutoAffirmRuleId => AutoAffirmRuleUsage;
+        /// Usage recorded by the auto-affirm rules that affirmed an instruction for a portfolio, refunded if the
+        /// affirmation is withdrawn or the instruction is rejected. (instruction_id, portfolio) -> (day, rule_id -> amount)
+        pub AutoAffirmedInstructions get(fn auto_affirmed_instructions):
+            double_map hasher(twox_64_concat) InstructionId, hasher(blake2_128_concat) PortfolioId => Option<(u64, BTreeMap<AutoAffirmRuleId, Balance>)>;
+        /// Number of auto-affirm rules of a portfolio. portfolio -> rule_count
+        pub AutoAffirmRuleCount get(fn auto_affirm_rule_count):
+            map hasher(blake2_128_concat) PortfolioId => u32;
+        /// The id of the next auto-affirm rule of a portfolio. portfolio -> rule_id
+        NextAutoAffirmRuleId get(fn next_auto_affirm_rule_id):
+            map hasher(blake2_128_concat) PortfolioId => AutoAffirmRuleId;
         /// Storage version.
         StorageVersion get(fn storage_version) build(|_| Version::new(3)): Version;
     }
@@ -532,7 +609,7 @@ decl_module! {
         /// * `value_date`: Optional date after which the instruction should be settled (not enforced).
         /// * `legs`: A vector of all [`Leg`] included in this instruction.
         /// * `memo`: An optional [`Memo`] field for this instruction.
-        #[weight = <T as Config>::WeightInfo::add_instruction_legs(legs)]
+        #[weight = <T as Config>::WeightInfo::add_instruction_legs(legs, T::MaxAutoAffirmRules::get())]
         pub fn add_instruction(
             origin,
             venue_id: Option<VenueId>,
@@ -551,6 +628,7 @@ decl_module! {
                 value_date,
                 legs,
                 instruction_memo,
+                None,
                 None
             )?;
         }
@@ -568,7 +646,7 @@ decl_module! {
         ///
         /// # Permissions
         /// * Portfolio
-        #[weight = <T as Config>::WeightInfo::add_and_affirm_instruction_legs(legs, portfolios.len() as u32)]
+        #[weight = <T as Config>::WeightInfo::add_and_affirm_instruction_legs(legs, portfolios.len() as u32, T::MaxAutoAffirmRules::get())]
         pub fn add_and_affirm_instruction(
             origin,
             venue_id: Option<VenueId>,
@@ -588,6 +666,7 @@ decl_module! {
                 value_date,
                 legs,
                 instruction_memo,
+                None,
                 None
             )?;
             Self::affirm_and_maybe_schedule_instruction(
@@ -769,7 +848,7 @@ decl_module! {
         /// * `legs`: A vector of all [`Leg`] included in this instruction.
         /// * `instruction_memo`: An optional [`Memo`] field for this instruction.
         /// * `mediators`: A set of [`IdentityId`] of all the mandatory mediators for the instruction.
-        #[weight = <T as Config>::WeightInfo::add_instruction_with_mediators_legs(legs, mediators.len() as u32)]
+        #[weight = <T as Config>::WeightInfo::add_instruction_with_mediators_legs(legs, mediators.len() as u32, T::MaxAutoAffirmRules::get())]
         pub fn add_instruction_with_mediators(
             origin,
             venue_id: Option<VenueId>,
@@ -789,7 +868,8 @@ decl_module! {
                 value_date,
                 legs,
                 instruction_memo,
-                Some(mediators)
+                Some(mediators),
+                None
             )?;
         }
 
@@ -807,7 +887,7 @@ decl_module! {
         ///
         /// # Permissions
         /// * Portfolio
-        #[weight = <T as Config>::WeightInfo::add_and_affirm_with_mediators_legs(legs, portfolios.len() as u32, mediators.len() as u32)]
+        #[weight = <T as Config>::WeightInfo::add_and_affirm_with_mediators_legs(legs, portfolios.len() as u32, mediators.len() as u32, T::MaxAutoAffirmRules::get())]
         pub fn add_and_affirm_with_mediators(
             origin,
             venue_id: Option<VenueId>,
@@ -828,7 +908,8 @@ decl_module! {
                 value_date,
                 legs,
                 instruction_memo,
-                Some(mediators)
+                Some(mediators),
+                None
             )?;
             Self::affirm_and_maybe_schedule_instruction(
                 origin,
@@ -879,6 +960,307 @@ decl_module! {
         ) -> DispatchResultWithPostInfo {
             Self::base_reject_instruction(origin, instruction_id, None, number_of_assets)
         }
+
+        /// Enables or disables netting for all new instructions created under a venue.
+        ///
+        /// When enabled, opposing [`Leg::Fungible`] legs that move the same asset between the same pair of portfolios
+        /// are netted when the instruction is added, so that only the net position is locked and transferred.
+        ///
+        /// # Arguments
+        /// * `venue_id` - the [`VenueId`] of the venue.
+        /// * `enabled` - Boolean that decides if netting should be enabled.
+        ///
+        /// # Permissions
+        /// * The caller must be the venue creator.
+        #[weight = <T as Config>::WeightInfo::set_venue_netting()]
+        pub fn set_venue_netting(origin, venue_id: VenueId, enabled: bool) {
+            let did = Identity::<T>::ensure_perms(origin)?;
+            Self::venue_for_management(venue_id, did)?;
+            if enabled {
+                VenueNetting::insert(venue_id, enabled);
+            } else {
+                VenueNetting::remove(venue_id);
+            }
+            Self::deposit_event(RawEvent::VenueNetting(did, venue_id, enabled));
+        }
+
+        /// Adds a new instruction with an affirmation deadline.
+        /// If not all affirmations have been received by `affirmation_deadline`, the instruction is rejected
+        /// and all assets locked by its legs are released.
+        ///
+        /// # Arguments
+        /// * `venue_id`: The [`VenueId`] of the venue this instruction belongs to.
+        /// * `settlement_type`: The [`SettlementType`] specifying when the instruction should be settled.
+        /// * `trade_date`: Optional date from which people can interact with this instruction.
+        /// * `value_date`: Optional date after which the instruction should be settled (not enforced).
+        /// * `legs`: A vector of all [`Leg`] included in this instruction.
+        /// * `instruction_memo`: An optional [`Memo`] field for this instruction.
+        /// * `affirmation_deadline`: The block number at which the instruction is rejected if it has pending affirmations.
+        #[weight = <T as Config>::WeightInfo::add_instruction_with_deadline_legs(legs, T::MaxAutoAffirmRules::get())]
+        pub fn add_instruction_with_deadline(
+            origin,
+            venue_id: Option<VenueId>,
+            settlement_type: SettlementType<T::BlockNumber>,
+            trade_date: Option<T::Moment>,
+            value_date: Option<T::Moment>,
+            legs: Vec<Leg>,
+            instruction_memo: Option<Memo>,
+            affirmation_deadline: T::BlockNumber,
+        ) {
+            let did = Identity::<T>::ensure_perms(origin)?;
+            Self::base_add_instruction(
+                did,
+                venue_id,
+                settlement_type,
+                trade_date,
+                value_date,
+                legs,
+                instruction_memo,
+                None,
+                Some(affirmation_deadline)
+            )?;
+        }
+
+        /// Adds and affirms a new instruction with an affirmation deadline.
+        /// If not all affirmations have been received by `affirmation_deadline`, the instruction is rejected
+        /// and all assets locked by its legs are released.
+        ///
+        /// # Arguments
+        /// * `venue_id`: The [`VenueId`] of the venue this instruction belongs to.
+        /// * `settlement_type`: The [`SettlementType`] specifying when the instruction should be settled.
+        /// * `trade_date`: Optional date from which people can interact with this instruction.
+        /// * `value_date`: Optional date after which the instruction should be settled (not enforced).
+        /// * `legs`: A vector of all [`Leg`] included in this instruction.
+        /// * `portfolios`: A vector of [`PortfolioId`] under the caller's control and intended for affirmation.
+        /// * `instruction_memo`: An optional [`Memo`] field for this instruction.
+        /// * `affirmation_deadline`: The block number at which the instruction is rejected if it has pending affirmations.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::add_and_affirm_with_deadline_legs(legs, portfolios.len() as u32, T::MaxAutoAffirmRules::get())]
+        pub fn add_and_affirm_with_deadline(
+            origin,
+            venue_id: Option<VenueId>,
+            settlement_type: SettlementType<T::BlockNumber>,
+            trade_date: Option<T::Moment>,
+            value_date: Option<T::Moment>,
+            legs: Vec<Leg>,
+            portfolios: BoundedBTreeSet<PortfolioId, T::MaxNumberOfPortfolios>,
+            instruction_memo: Option<Memo>,
+            affirmation_deadline: T::BlockNumber,
+        ) {
+            let did = Identity::<T>::ensure_perms(origin.clone())?;
+            let instruction_id = Self::base_add_instruction(
+                did,
+                venue_id,
+                settlement_type,
+                trade_date,
+                value_date,
+                legs,
+                instruction_memo,
+                None,
+                Some(affirmation_deadline)
+            )?;
+            Self::affirm_and_maybe_schedule_instruction(
+                origin,
+                instruction_id,
+                portfolios.into_inner(),
+                None
+            )
+            .map_err(|e| e.error)?;
+        }
+
+        /// Adds a new instruction with mediators and an affirmation deadline.
+        /// If not all affirmations have been received by `affirmation_deadline`, the instruction is rejected
+        /// and all assets locked by its legs are released.
+        ///
+        /// # Arguments
+        /// * `venue_id`: The [`VenueId`] of the venue this instruction belongs to.
+        /// * `settlement_type`: The [`SettlementType`] specifying when the instruction should be settled.
+        /// * `trade_date`: Optional date from which people can interact with this instruction.
+        /// * `value_date`: Optional date after which the instruction should be settled (not enforced).
+        /// * `legs`: A vector of all [`Leg`] included in this instruction.
+        /// * `instruction_memo`: An optional [`Memo`] field for this instruction.
+        /// * `mediators`: A set of [`IdentityId`] of all the mandatory mediators for the instruction.
+        /// * `affirmation_deadline`: The block number at which the instruction is rejected if it has pending affirmations.
+        #[weight = <T as Config>::WeightInfo::add_instruction_with_mediators_and_deadline_legs(legs, mediators.len() as u32, T::MaxAutoAffirmRules::get())]
+        pub fn add_instruction_with_mediators_and_deadline(
+            origin,
+            venue_id: Option<VenueId>,
+            settlement_type: SettlementType<T::BlockNumber>,
+            trade_date: Option<T::Moment>,
+            value_date: Option<T::Moment>,
+            legs: Vec<Leg>,
+            instruction_memo: Option<Memo>,
+            mediators: BoundedBTreeSet<IdentityId, T::MaxInstructionMediators>,
+            affirmation_deadline: T::BlockNumber,
+        ) {
+            let did = Identity::<T>::ensure_perms(origin)?;
+            Self::base_add_instruction(
+                did,
+                venue_id,
+                settlement_type,
+                trade_date,
+                value_date,
+                legs,
+                instruction_memo,
+                Some(mediators),
+                Some(affirmation_deadline)
+            )?;
+        }
+
+        /// Adds and affirms a new instruction with mediators and an affirmation deadline.
+        /// If not all affirmations have been received by `affirmation_deadline`, the instruction is rejected
+        /// and all assets locked by its legs are released.
+        ///
+        /// # Arguments
+        /// * `venue_id`: The [`VenueId`] of the venue this instruction belongs to.
+        /// * `settlement_type`: The [`SettlementType`] specifying when the instruction should be settled.
+        /// * `trade_date`: Optional date from which people can interact with this instruction.
+        /// * `value_date`: Optional date after which the instruction should be settled (not enforced).
+        /// * `legs`: A vector of all [`Leg`] included in this instruction.
+        /// * `portfolios`: A vector of [`PortfolioId`] under the caller's control and intended for affirmation.
+        /// * `instruction_memo`: An optional [`Memo`] field for this instruction.
+        /// * `mediators`: A set of [`IdentityId`] of all the mandatory mediators for the instruction.
+        /// * `affirmation_deadline`: The block number at which the instruction is rejected if it has pending affirmations.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::add_and_affirm_with_mediators_and_deadline_legs(legs, portfolios.len() as u32, mediators.len() as u32, T::MaxAutoAffirmRules::get())]
+        pub fn add_and_affirm_with_mediators_and_deadline(
+            origin,
+            venue_id: Option<VenueId>,
+            settlement_type: SettlementType<T::BlockNumber>,
+            trade_date: Option<T::Moment>,
+            value_date: Option<T::Moment>,
+            legs: Vec<Leg>,
+            portfolios: BoundedBTreeSet<PortfolioId, T::MaxNumberOfPortfolios>,
+            instruction_memo: Option<Memo>,
+            mediators: BoundedBTreeSet<IdentityId, T::MaxInstructionMediators>,
+            affirmation_deadline: T::BlockNumber,
+        ) {
+            let did = Identity::<T>::ensure_perms(origin.clone())?;
+            let instruction_id = Self::base_add_instruction(
+                did,
+                venue_id,
+                settlement_type,
+                trade_date,
+                value_date,
+                legs,
+                instruction_memo,
+                Some(mediators),
+                Some(affirmation_deadline)
+            )?;
+            Self::affirm_and_maybe_schedule_instruction(
+                origin,
+                instruction_id,
+                portfolios.into_inner(),
+                None
+            )
+            .map_err(|e| e.error)?;
+        }
+
+        /// Root callable extrinsic, used as an internal call to reject an instruction that has reached its affirmation deadline.
+        #[weight = <T as Config>::WeightInfo::reject_instruction_input(Some(*asset_count), false)]
+        fn reject_expired_instruction(origin, id: InstructionId, asset_count: AssetCount) {
+            ensure_root(origin)?;
+            Self::base_reject_expired_instruction(id)?;
+        }
+
+        /// Affirms an instruction on behalf of `portfolio`, using the allowance granted to the caller.
+        /// The allowance is consumed when the instruction is executed.
+        ///
+        /// # Arguments
+        /// * `id` - the [`InstructionId`] of the instruction being affirmed.
+        /// * `portfolio` - the [`PortfolioId`] that granted the caller an allowance. It must only be the sender of fungible legs.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::affirm_instruction_as_spender(T::MaxNumberOfFungibleAssets::get())]
+        pub fn affirm_instruction_as_spender(origin, id: InstructionId, portfolio: PortfolioId) -> DispatchResultWithPostInfo {
+            Self::base_affirm_instruction_as_spender(origin, id, portfolio)
+        }
+
+        /// Affirms a [`Leg::HashLocked`] leg by revealing the preimage of its hash lock.
+        /// Once all other affirmations have been received the instruction is scheduled as usual.
+        ///
+        /// # Arguments
+        /// * `id` - the [`InstructionId`] of the instruction containing the leg.
+        /// * `leg_id` - the [`LegId`] of the hash-locked leg.
+        /// * `preimage` - the secret whose sha2-256 hash matches the leg's hash lock.
+        ///
+        /// # Permissions
+        /// * Any identity can reveal the preimage, as long as the leg hasn't expired.
+        #[weight = <T as Config>::WeightInfo::affirm_with_preimage()]
+        pub fn affirm_with_preimage(origin, id: InstructionId, leg_id: LegId, preimage: Vec<u8>) {
+            Self::base_affirm_with_preimage(origin, id, leg_id, preimage)?;
+        }
+
+        /// Amends the legs, dates and memo of a pending instruction, keeping its [`InstructionId`].
+        /// Portfolios that are a party in a changed leg have their affirmations withdrawn and their assets unlocked,
+        /// while all other affirmations are kept. If any leg changes, all mediators must affirm the instruction again.
+        /// Unchanged legs keep their [`LegId`], and the auto-affirm rules of the withdrawn portfolios are applied again.
+        ///
+        /// # Arguments
+        /// * `id` - the [`InstructionId`] of the instruction being amended.
+        /// * `trade_date`: Optional date from which people can interact with this instruction.
+        /// * `value_date`: Optional date after which the instruction should be settled (not enforced).
+        /// * `legs`: A vector of all [`Leg`] included in the amended instruction.
+        /// * `instruction_memo`: An optional [`Memo`] field for this instruction.
+        /// * `number_of_assets` - an optional [`AssetCount`] of the legs being amended, that will be used for a precise fee estimation before executing the extrinsic.
+        ///
+        /// Note: calling the rpc method `get_execute_instruction_info` returns an instance of [`ExecuteInstructionInfo`], which contain the asset count.
+        ///
+        /// # Permissions
+        /// * The caller must be the creator of the instruction's venue.
+        #[weight = <T as Config>::WeightInfo::amend_instruction_input(legs, *number_of_assets, T::MaxAutoAffirmRules::get())]
+        pub fn amend_instruction(
+            origin,
+            id: InstructionId,
+            trade_date: Option<T::Moment>,
+            value_date: Option<T::Moment>,
+            legs: Vec<Leg>,
+            instruction_memo: Option<Memo>,
+            number_of_assets: Option<AssetCount>
+        ) {
+            let did = Identity::<T>::ensure_perms(origin)?;
+            Self::base_amend_instruction(
+                did,
+                id,
+                trade_date,
+                value_date,
+                legs,
+                instruction_memo,
+                number_of_assets,
+            )?;
+        }
+
+        /// Adds a standing settlement instruction to `portfolio`. Instructions created by the rule's venue
+        /// are automatically affirmed on behalf of `portfolio` when every leg it is a party in matches one of its rules.
+        ///
+        /// # Arguments
+        /// * `portfolio` - the [`PortfolioId`] the rule affirms instructions for.
+        /// * `rule` - the [`AutoAffirmRule`] being added.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::add_auto_affirm_rule(rule.counterparties.len() as u32)]
+        pub fn add_auto_affirm_rule(origin, portfolio: PortfolioId, rule: AutoAffirmRule) {
+            Self::base_add_auto_affirm_rule(origin, portfolio, rule)?;
+        }
+
+        /// Removes an auto-affirm rule from `portfolio`. Instructions that were already affirmed are not affected.
+        ///
+        /// # Arguments
+        /// * `portfolio` - the [`PortfolioId`] the rule belongs to.
+        /// * `rule_id` - the [`AutoAffirmRuleId`] of the rule being removed.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::remove_auto_affirm_rule()]
+        pub fn remove_auto_affirm_rule(origin, portfolio: PortfolioId, rule_id: AutoAffirmRuleId) {
+            Self::base_remove_auto_affirm_rule(origin, portfolio, rule_id)?;
+        }
     }
 }
 
@@ -897,7 +1279,9 @@ impl<T: Config> Module<T> {
                 }
                 Ok(())
             }),
-            Leg::OffChain { .. } => Err(Error::<T>::OffChainAssetCantBeLocked.into()),
+            Leg::OffChain { .. } | Leg::HashLocked { .. } => {
+                Err(Error::<T>::OffChainAssetCantBeLocked.into())
+            }
         }
     }
 
@@ -915,7 +1299,9 @@ impl<T: Config> Module<T> {
                 }
                 Ok(())
             }),
-            Leg::OffChain { .. } => Err(Error::<T>::OffChainAssetCantBeLocked.into()),
+            Leg::OffChain { .. } | Leg::HashLocked { .. } => {
+                Err(Error::<T>::OffChainAssetCantBeLocked.into())
+            }
         }
     }
 
@@ -950,6 +1336,7 @@ impl<T: Config> Module<T> {
         legs: Vec<Leg>,
         memo: Option<Memo>,
         mediators: Option<BoundedBTreeSet<IdentityId, T::MaxInstructionMediators>>,
+        affirmation_deadline: Option<T::BlockNumber>,
     ) -> Result<InstructionId, DispatchError> {
         // Verifies if the block number is in the future so that `T::Scheduler::schedule_named` doesn't fail.
         if let SettlementType::SettleOnBlock(block_number) = &settlement_type {
@@ -958,6 +1345,12 @@ impl<T: Config> Module<T> {
                 Error::<T>::SettleOnPastBlock
             );
         }
+        if let Some(affirmation_deadline) = affirmation_deadline {
+            ensure!(
+                affirmation_deadline > System::<T>::block_number(),
+                Error::<T>::AffirmationDeadlineInThePast
+            );
+        }
 
         // Ensure that instruction dates are valid.
         if let (Some(trade_date), Some(value_date)) = (trade_date, value_date) {
@@ -972,8 +1365,10 @@ impl<T: Config> Module<T> {
             Self::venue_for_management(venue_id, did)?;
         }
 
-        // Verifies if all legs are valid.
-        let mut instruction_info = Self::ensure_valid_legs(&legs, &venue_id)?;
+        // Verifies if all legs are valid and nets them if the venue has netting enabled.
+        let (mut instruction_info, netted_legs) =
+            Self::ensure_valid_and_netted_legs(&legs, &venue_id)?;
+        let instruction_legs = netted_legs.as_ref().unwrap_or(&legs);
 
         // Adds the instruction mediators
         if let Some(mediators) = mediators {
@@ -1002,7 +1397,7 @@ impl<T: Config> Module<T> {
             instruction_info.number_of_pending_affirmations(),
         );
 
-        legs.iter().enumerate().for_each(|(index, leg)| {
+        instruction_legs.iter().enumerate().for_each(|(index, leg)| {
             let leg_id = LegId(index as u64);
             InstructionLegs::insert(instruction_id, leg_id, leg.clone());
             if leg.is_off_chain() {
@@ -1035,10 +1430,18 @@ impl<T: Config> Module<T> {
             settlement_type,
             trade_date,
             value_date,
-            legs,
+            instruction_legs.clone(),
             memo,
         ));
 
+        if let Some(netted_legs) = netted_legs {
+            Self::deposit_event(RawEvent::InstructionLegsNetted(
+                did,
+                instruction_id,
+                netted_legs,
+            ));
+        }
+
         for portfolio_id in instruction_info.portfolios_pre_approved_difference() {
             UserAffirmations::insert(portfolio_id, instruction_id, AffirmationStatus::Affirmed);
             AffirmsReceived::insert(instruction_id, portfolio_id, AffirmationStatus::Affirmed);
@@ -1049,6 +1452,24 @@ impl<T: Config> Module<T> {
             ));
         }
 
+        // Applies the standing settlement instructions of the remaining portfolios
+        if let Some(venue_id) = venue_id {
+            let affirms_pending = Self::auto_affirm_portfolios(
+                did,
+                instruction_id,
+                &venue_id,
+                instruction_info.portfolios_pending_approval(),
+            );
+            if affirms_pending < instruction_info.number_of_pending_affirmations() {
+                let weight_limit = Self::execute_scheduled_instruction_weight_limit(
+                    instruction_info.fungible_transfers(),
+                    instruction_info.nfts_transferred(),
+                    instruction_info.off_chain(),
+                );
+                Self::maybe_schedule_instruction(affirms_pending, instruction_id, weight_limit);
+            }
+        }
+
         if !instruction_info.mediators().is_empty() {
             Self::deposit_event(RawEvent::InstructionMediators(
                 instruction_id,
@@ -1065,9 +1486,42 @@ impl<T: Config> Module<T> {
             Self::schedule_instruction(instruction_id, block_number, weight_limit);
         }
 
+        if let Some(affirmation_deadline) = affirmation_deadline {
+            InstructionAffirmationDeadline::<T>::insert(instruction_id, affirmation_deadline);
+            Self::deposit_event(RawEvent::InstructionAffirmationDeadline(
+                instruction_id,
+                affirmation_deadline,
+            ));
+            Self::schedule_affirmation_deadline(
+                instruction_id,
+                affirmation_deadline,
+                instruction_info.asset_count(),
+            );
+        }
+
         Ok(instruction_id)
     }
 
+    /// Returns [`InstructionInfo`] if all legs are valid, otherwise returns an error.
+    /// If the venue has netting enabled, the fungible legs are netted and also returned.
+    fn ensure_valid_and_netted_legs(
+        legs: &[Leg],
+        venue_id: &Option<VenueId>,
+    ) -> Result<(InstructionInfo, Option<Vec<Leg>>), DispatchError> {
+        let instruction_info = Self::ensure_valid_legs(legs, venue_id)?;
+        match venue_id {
+            Some(venue_id) if Self::venue_netting(venue_id) => {
+                let netted_legs =
+                    Leg::net_fungible_legs(legs).ok_or(Error::<T>::NettingOverflow)?;
+                ensure!(!netted_legs.is_empty(), Error::<T>::InstructionNetsToZero);
+                // Affirmations are only required from the portfolios that are still in the netted legs
+                let instruction_info = Self::ensure_valid_legs(&netted_legs, &Some(*venue_id))?;
+                Ok((instruction_info, Some(netted_legs)))
+            }
+            _ => Ok((instruction_info, None)),
+        }
+    }
+
     /// Returns [`InstructionInfo`] if all legs are valid, otherwise returns an error.
     /// See also: [`Module::ensure_valid_fungible_leg`], [`Module::ensure_valid_nft_leg`] and [`Module::ensure_valid_off_chain_leg`].
     fn ensure_valid_legs(
@@ -1102,7 +1556,7 @@ impl<T: Config> Module<T> {
                         receiver,
                         nfts,
                     } => (nfts.asset_id(), sender, receiver),
-                    Leg::OffChain { .. } => continue,
+                    Leg::OffChain { .. } | Leg::HashLocked { .. } => continue,
                 }
             };
             Identity::<T>::ensure_id_record_exists(sender.did)?;
@@ -1171,6 +1625,8 @@ impl<T: Config> Module<T> {
         for portfolio in &portfolios {
             UserAffirmations::insert(portfolio, id, AffirmationStatus::Pending);
             AffirmsReceived::remove(id, portfolio);
+            SpenderAffirmations::remove(id, portfolio);
+            Self::refund_auto_affirm_rules_usage(id, portfolio);
             Self::deposit_event(RawEvent::AffirmationWithdrawn(did, *portfolio, id));
         }
 
@@ -1295,11 +1751,14 @@ impl<T: Config> Module<T> {
                     if let Some(venue_id) = instruction_details.venue_id {
                         VenueInstructions::remove(venue_id, instruction_id);
                     }
+                    Self::remove_affirmation_deadline(instruction_id);
                     let _ = InstructionLegStatus::<T>::clear_prefix(
                         instruction_id,
                         instruction_legs.len() as u32,
                         None,
                     );
+                    let _ = SpenderAffirmations::drain_prefix(instruction_id).count();
+                    let _ = AutoAffirmedInstructions::drain_prefix(instruction_id).count();
                     // Change instruction status
                     InstructionStatuses::<T>::insert(
                         instruction_id,
@@ -1394,7 +1853,7 @@ impl<T: Config> Module<T> {
                         );
                     }
                 }
-                Leg::OffChain { .. } => {
+                Leg::OffChain { .. } | Leg::HashLocked { .. } => {
                     ensure!(
                         OffChainAffirmations::take(instruction_id, leg_id)
                             == AffirmationStatus::Affirmed,
@@ -1423,6 +1882,13 @@ impl<T: Config> Module<T> {
                         asset_id,
                         amount,
                     } => {
+                        if let Some(spender) = SpenderAffirmations::get(instruction_id, sender) {
+                            if <Asset<T>>::consume_allowance(*sender, *asset_id, spender, *amount)
+                                .is_err()
+                            {
+                                return Err(*leg_id);
+                            }
+                        }
                         if <Asset<T>>::base_transfer(
                             *sender,
                             *receiver,
@@ -1457,7 +1923,7 @@ impl<T: Config> Module<T> {
                             return Err(*leg_id);
                         }
                     }
-                    Leg::OffChain { .. } => {}
+                    Leg::OffChain { .. } | Leg::HashLocked { .. } => {}
                 }
             }
         }
@@ -1472,6 +1938,14 @@ impl<T: Config> Module<T> {
             VenueInstructions::remove(venue_id, instruction_id);
         }
         InstructionAffirmsPending::remove(instruction_id);
+        Self::remove_affirmation_deadline(instruction_id);
+        let _ = SpenderAffirmations::drain_prefix(instruction_id).count();
+        // The rules that affirmed a rejected instruction, including one that failed to execute, are refunded
+        let auto_affirmed_portfolios: Vec<PortfolioId> =
+            AutoAffirmedInstructions::iter_key_prefix(instruction_id).collect();
+        for portfolio in &auto_affirmed_portfolios {
+            Self::refund_auto_affirm_rules_usage(instruction_id, portfolio);
+        }
         let _ = InstructionMediatorsAffirmations::<T>::clear_prefix(
             instruction_id,
             T::MaxInstructionMediators::get(),
@@ -1498,7 +1972,7 @@ impl<T: Config> Module<T> {
                     AffirmsReceived::remove(instruction_id, sender);
                     AffirmsReceived::remove(instruction_id, receiver);
                 }
-                Leg::OffChain { .. } => {
+                Leg::OffChain { .. } | Leg::HashLocked { .. } => {
                     OffChainAffirmations::remove(instruction_id, leg_id);
                 }
             }
@@ -1531,15 +2005,27 @@ impl<T: Config> Module<T> {
         if let Some(affirmation_count) = affirmation_count {
             Self::ensure_valid_affirmation_count(&filtered_legs, &affirmation_count)?
         }
-        for (leg_id, leg) in filtered_legs.sender_subset() {
-            Self::lock_via_leg(&leg)?;
-            <InstructionLegStatus<T>>::insert(id, leg_id, LegStatus::ExecutionPending);
-        }
+        Self::unsafe_lock_and_affirm(did, id, &portfolios, &filtered_legs)?;
+        Ok(filtered_legs)
+    }
+
+    /// Locks the assets of all legs in `filtered_legs` where `portfolios` are the sender and
+    /// marks `portfolios` as affirmed.
+    fn unsafe_lock_and_affirm(
+        did: IdentityId,
+        id: InstructionId,
+        portfolios: &BTreeSet<PortfolioId>,
+        filtered_legs: &FilteredLegs,
+    ) -> DispatchResult {
+        for (leg_id, leg) in filtered_legs.sender_subset() {
+            Self::lock_via_leg(&leg)?;
+            <InstructionLegStatus<T>>::insert(id, leg_id, LegStatus::ExecutionPending);
+        }
 
         let affirms_pending = Self::instruction_affirms_pending(id);
 
         // Updates storage
-        for portfolio in &portfolios {
+        for portfolio in portfolios {
             UserAffirmations::insert(portfolio, id, AffirmationStatus::Affirmed);
             AffirmsReceived::insert(id, portfolio, AffirmationStatus::Affirmed);
             Self::deposit_event(RawEvent::InstructionAffirmed(did, *portfolio, id));
@@ -1548,7 +2034,62 @@ impl<T: Config> Module<T> {
             id,
             affirms_pending.saturating_sub(u64::try_from(portfolios.len()).unwrap_or_default()),
         );
-        Ok(filtered_legs)
+        Ok(())
+    }
+
+    /// Affirms the instruction of the given `id` on behalf of `portfolio`, using the allowance granted to the caller.
+    fn base_affirm_instruction_as_spender(
+        origin: <T as frame_system::Config>::RuntimeOrigin,
+        id: InstructionId,
+        portfolio: PortfolioId,
+    ) -> DispatchResultWithPostInfo {
+        let (spender, _, _) = Self::ensure_origin_perm_and_instruction_validity(origin, id, false)?;
+        ensure!(
+            Self::user_affirmations(portfolio, id) == AffirmationStatus::Pending,
+            Error::<T>::UnexpectedAffirmationStatus
+        );
+
+        // The spender can only affirm the portfolio as the sender of fungible assets
+        let portfolios = BTreeSet::from([portfolio]);
+        let filtered_legs = Self::filtered_legs(id, &portfolios);
+        ensure!(
+            filtered_legs.receiver_asset_count() == &AssetCount::default(),
+            Error::<T>::InvalidSpenderAffirmation
+        );
+        let mut amount_per_asset: BTreeMap<AssetId, Balance> = BTreeMap::new();
+        for (_, leg) in filtered_legs.sender_subset() {
+            match leg {
+                Leg::Fungible {
+                    asset_id, amount, ..
+                } => {
+                    let total = amount_per_asset.entry(*asset_id).or_default();
+                    *total = total.saturating_add(*amount);
+                }
+                Leg::NonFungible { .. } | Leg::OffChain { .. } | Leg::HashLocked { .. } => {
+                    return Err(Error::<T>::InvalidSpenderAffirmation.into())
+                }
+            }
+        }
+        for (asset_id, amount) in amount_per_asset {
+            <Asset<T>>::ensure_sufficient_allowance(portfolio, asset_id, spender, amount)?;
+        }
+
+        Self::unsafe_lock_and_affirm(spender, id, &portfolios, &filtered_legs)?;
+        SpenderAffirmations::insert(id, portfolio, spender);
+
+        let instruction_asset_count = filtered_legs.unfiltered_asset_count();
+        let weight_limit = Self::execute_scheduled_instruction_weight_limit(
+            instruction_asset_count.fungible(),
+            instruction_asset_count.non_fungible(),
+            instruction_asset_count.off_chain(),
+        );
+        // Schedule the instruction if conditions are met
+        Self::maybe_schedule_instruction(Self::instruction_affirms_pending(id), id, weight_limit);
+        Ok(PostDispatchInfo::from(Some(
+            <T as Config>::WeightInfo::affirm_instruction_as_spender(
+                filtered_legs.sender_asset_count().fungible(),
+            ),
+        )))
     }
 
     fn release_locks(id: InstructionId, instruction_legs: &[(LegId, Leg)]) -> DispatchResult {
@@ -1598,6 +2139,60 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Schedules the rejection of the given instruction at its affirmation deadline.
+    fn schedule_affirmation_deadline(
+        id: InstructionId,
+        deadline: T::BlockNumber,
+        asset_count: AssetCount,
+    ) {
+        let call = Call::<T>::reject_expired_instruction { id, asset_count }.into();
+        if let Err(_) = T::Scheduler::schedule_named(
+            id.deadline_name(),
+            DispatchTime::At(deadline),
+            None,
+            SETTLEMENT_INSTRUCTION_EXECUTION_PRIORITY,
+            RawOrigin::Root.into(),
+            call,
+        ) {
+            Self::deposit_event(RawEvent::SchedulingFailed(
+                id,
+                Error::<T>::FailedToSchedule.into(),
+            ));
+        }
+    }
+
+    /// Removes the affirmation deadline of the given instruction and cancels its scheduled rejection.
+    fn remove_affirmation_deadline(id: InstructionId) {
+        if InstructionAffirmationDeadline::<T>::take(id).is_some() {
+            let _ = T::Scheduler::cancel_named(id.deadline_name());
+        }
+    }
+
+    /// Rejects the instruction of the given `id` if its affirmation deadline has passed and it still has pending
+    /// affirmations. Instructions that have received all affirmations are kept until they are executed or rejected.
+    fn base_reject_expired_instruction(id: InstructionId) -> DispatchResult {
+        match Self::instruction_affirmation_deadline(id) {
+            Some(deadline) if deadline <= System::<T>::block_number() => {}
+            _ => return Ok(()),
+        }
+        // The scheduled task is being executed, so there is nothing to cancel
+        InstructionAffirmationDeadline::<T>::remove(id);
+        match Self::instruction_status(id) {
+            InstructionStatus::Pending | InstructionStatus::Failed => {}
+            InstructionStatus::Unknown
+            | InstructionStatus::Success(_)
+            | InstructionStatus::Rejected(_) => return Ok(()),
+        }
+        if Self::instruction_affirms_pending(id) == 0 {
+            return Ok(());
+        }
+
+        let legs: Vec<(LegId, Leg)> = InstructionLegs::iter_prefix(&id).collect();
+        Self::unsafe_reject_instruction(SettlementDID.as_id(), id, &legs)?;
+        Self::deposit_event(RawEvent::InstructionAffirmationDeadlineReached(id));
+        Ok(())
+    }
+
     /// Affirms all legs from the instruction of the given `instruction_id`, where `portfolios` are a counter party.
     /// If the portfolio is the sender, the asset is also locked.
     pub fn base_affirm_with_receipts(
@@ -1732,6 +2327,451 @@ impl<T: Config> Module<T> {
         )))
     }
 
+    /// Replaces the legs, dates and memo of the pending instruction of the given `id`.
+    /// Only the affirmations of portfolios that are a party in a changed leg are withdrawn.
+    fn base_amend_instruction(
+        did: IdentityId,
+        id: InstructionId,
+        trade_date: Option<T::Moment>,
+        value_date: Option<T::Moment>,
+        legs: Vec<Leg>,
+        memo: Option<Memo>,
+        instruction_count: Option<AssetCount>,
+    ) -> DispatchResult {
+        let instruction_details = Self::ensure_instruction_validity(id, false)?;
+        ensure!(
+            Self::instruction_status(id) == InstructionStatus::Pending,
+            Error::<T>::InstructionNotPending
+        );
+        // Only the venue creator can amend an instruction
+        let venue_id = instruction_details
+            .venue_id
+            .ok_or(Error::<T>::Unauthorized)?;
+        Self::venue_for_management(venue_id, did)?;
+
+        if let (Some(trade_date), Some(value_date)) = (trade_date, value_date) {
+            ensure!(
+                value_date >= trade_date,
+                Error::<T>::InstructionDatesInvalid
+            );
+        }
+
+        let (instruction_info, netted_legs) =
+            Self::ensure_valid_and_netted_legs(&legs, &Some(venue_id))?;
+        let old_legs: Vec<(LegId, Leg)> = InstructionLegs::iter_prefix(&id).collect();
+        // If the fee was estimated in advance, the input values must be at least equal to the actual values
+        if let Some(instruction_count) = instruction_count {
+            Self::ensure_valid_cost(&AssetCount::from_legs(&old_legs), &instruction_count)?;
+        }
+        let old_legs: BTreeMap<LegId, Leg> = old_legs.into_iter().collect();
+
+        // Unchanged legs keep their id, and new legs are numbered after the current last leg
+        let mut unmatched_legs = old_legs.clone();
+        let mut next_leg_id = old_legs.keys().last().map_or(0, |leg_id| leg_id.0 + 1);
+        let new_legs: BTreeMap<LegId, Leg> = netted_legs
+            .as_ref()
+            .unwrap_or(&legs)
+            .iter()
+            .map(|leg| {
+                let unchanged_leg_id = unmatched_legs
+                    .iter()
+                    .find(|(_, old_leg)| *old_leg == leg)
+                    .map(|(leg_id, _)| *leg_id);
+                let leg_id = match unchanged_leg_id {
+                    Some(leg_id) => {
+                        unmatched_legs.remove(&leg_id);
+                        leg_id
+                    }
+                    None => {
+                        let leg_id = LegId(next_leg_id);
+                        next_leg_id += 1;
+                        leg_id
+                    }
+                };
+                (leg_id, leg.clone())
+            })
+            .collect();
+        let changed_legs: BTreeSet<LegId> = old_legs
+            .keys()
+            .chain(new_legs.keys())
+            .filter(|leg_id| old_legs.get(leg_id) != new_legs.get(leg_id))
+            .copied()
+            .collect();
+
+        // All portfolios that are a party in a changed leg, either before or after the amendment, must affirm again
+        let affected_portfolios: BTreeSet<PortfolioId> = changed_legs
+            .iter()
+            .flat_map(|leg_id| [old_legs.get(leg_id), new_legs.get(leg_id)])
+            .flatten()
+            .filter_map(|leg| leg.portfolios())
+            .flat_map(|(sender, receiver)| [*sender, *receiver])
+            .collect();
+
+        // Unlocks all assets locked by the affected portfolios, using the legs they have affirmed
+        for (leg_id, leg) in &old_legs {
+            let is_affected_sender = leg
+                .portfolios()
+                .map_or(false, |(sender, _)| affected_portfolios.contains(sender));
+            if is_affected_sender
+                && Self::instruction_leg_status(id, leg_id) == LegStatus::ExecutionPending
+            {
+                Self::unlock_via_leg(leg)?;
+                <InstructionLegStatus<T>>::insert(id, leg_id, LegStatus::PendingTokenLock);
+            }
+        }
+
+        for leg_id in &changed_legs {
+            <InstructionLegStatus<T>>::remove(id, leg_id);
+            match new_legs.get(leg_id) {
+                Some(leg) => {
+                    InstructionLegs::insert(id, leg_id, leg);
+                    if leg.is_off_chain() {
+                        OffChainAffirmations::insert(id, leg_id, AffirmationStatus::Pending);
+                    } else {
+                        OffChainAffirmations::remove(id, leg_id);
+                    }
+                }
+                None => {
+                    InstructionLegs::remove(id, leg_id);
+                    OffChainAffirmations::remove(id, leg_id);
+                }
+            }
+        }
+
+        let instruction_portfolios: BTreeSet<&PortfolioId> = instruction_info
+            .portfolios_pending_approval()
+            .iter()
+            .chain(instruction_info.portfolios_pre_approved_difference())
+            .collect();
+        for portfolio in &affected_portfolios {
+            if Self::user_affirmations(portfolio, id) == AffirmationStatus::Affirmed {
+                Self::deposit_event(RawEvent::AffirmationWithdrawn(did, *portfolio, id));
+            }
+            AffirmsReceived::remove(id, portfolio);
+            SpenderAffirmations::remove(id, portfolio);
+            Self::refund_auto_affirm_rules_usage(id, portfolio);
+            if instruction_portfolios.contains(&portfolio) {
+                UserAffirmations::insert(portfolio, id, AffirmationStatus::Pending);
+            } else {
+                UserAffirmations::remove(portfolio, id);
+            }
+        }
+        for portfolio in instruction_info.portfolios_pre_approved_difference() {
+            if affected_portfolios.contains(portfolio) {
+                UserAffirmations::insert(portfolio, id, AffirmationStatus::Affirmed);
+                AffirmsReceived::insert(id, portfolio, AffirmationStatus::Affirmed);
+                Self::deposit_event(RawEvent::InstructionAutomaticallyAffirmed(
+                    did, *portfolio, id,
+                ));
+            }
+        }
+
+        // Updates the instruction details
+        InstructionDetails::<T>::mutate(id, |details| {
+            details.trade_date = trade_date;
+            details.value_date = value_date;
+        });
+        match memo {
+            Some(ref memo) => InstructionMemos::insert(id, memo),
+            None => InstructionMemos::remove(id),
+        }
+        let version = InstructionVersions::mutate(id, |version| {
+            *version = version.saturating_add(1);
+            *version
+        });
+        Self::deposit_event(RawEvent::InstructionAmended(
+            did, id, version, trade_date, value_date, legs, memo,
+        ));
+        if let Some(netted_legs) = netted_legs {
+            Self::deposit_event(RawEvent::InstructionLegsNetted(did, id, netted_legs));
+        }
+
+        if changed_legs.is_empty() {
+            return Ok(());
+        }
+
+        // Mediators must affirm the amended instruction again, including the mediators of new assets
+        let current_mediators: BTreeSet<IdentityId> =
+            InstructionMediatorsAffirmations::<T>::iter_key_prefix(id).collect();
+        let mediators: BTreeSet<IdentityId> = current_mediators
+            .union(instruction_info.mediators())
+            .copied()
+            .collect();
+        for mediator in &mediators {
+            InstructionMediatorsAffirmations::<T>::insert(
+                id,
+                mediator,
+                MediatorAffirmationStatus::Pending,
+            );
+        }
+        if mediators.len() > current_mediators.len() {
+            Self::deposit_event(RawEvent::InstructionMediators(id, mediators.clone()));
+        }
+
+        let pending_portfolios = instruction_portfolios
+            .iter()
+            .filter(|portfolio| {
+                Self::user_affirmations(**portfolio, id) == AffirmationStatus::Pending
+            })
+            .count();
+        let pending_off_chain_legs = new_legs
+            .iter()
+            .filter(|(leg_id, leg)| {
+                leg.is_off_chain()
+                    && Self::offchain_affirmations(id, *leg_id) == AffirmationStatus::Pending
+            })
+            .count();
+        let affirms_pending =
+            (pending_portfolios + pending_off_chain_legs + mediators.len()) as u64;
+        InstructionAffirmsPending::insert(id, affirms_pending);
+
+        // Applies the standing settlement instructions of the portfolios that must affirm again
+        let reaffirming_portfolios: BTreeSet<PortfolioId> = affected_portfolios
+            .into_iter()
+            .filter(|portfolio| {
+                Self::user_affirmations(portfolio, id) == AffirmationStatus::Pending
+            })
+            .collect();
+        let affirms_pending =
+            Self::auto_affirm_portfolios(did, id, &venue_id, &reaffirming_portfolios);
+
+        // The scheduled calls depend on the number of assets in the instruction
+        let asset_count = instruction_info.asset_count();
+        let weight_limit = Self::execute_scheduled_instruction_weight_limit(
+            asset_count.fungible(),
+            asset_count.non_fungible(),
+            asset_count.off_chain(),
+        );
+        let _ = T::Scheduler::cancel_named(id.execution_name());
+        match instruction_details.settlement_type {
+            SettlementType::SettleOnAffirmation => {
+                Self::maybe_schedule_instruction(affirms_pending, id, weight_limit);
+            }
+            SettlementType::SettleOnBlock(block_number) => {
+                Self::schedule_instruction(id, block_number, weight_limit);
+            }
+            SettlementType::SettleManual(_) => {}
+        }
+        if let Some(deadline) = Self::instruction_affirmation_deadline(id) {
+            let _ = T::Scheduler::cancel_named(id.deadline_name());
+            Self::schedule_affirmation_deadline(id, deadline, asset_count);
+        }
+        Ok(())
+    }
+
+    /// Adds `rule` to the auto-affirm rules of `portfolio`, which must be in the custody of the caller.
+    fn base_add_auto_affirm_rule(
+        origin: <T as frame_system::Config>::RuntimeOrigin,
+        portfolio: PortfolioId,
+        rule: AutoAffirmRule,
+    ) -> DispatchResult {
+        let origin_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        let did = origin_data.primary_did;
+        T::Portfolio::ensure_portfolio_custody_and_permission(
+            portfolio,
+            did,
+            origin_data.secondary_key.as_ref(),
+        )?;
+        ensure!(
+            VenueInfo::contains_key(rule.venue_id),
+            Error::<T>::InvalidVenue
+        );
+        ensure!(
+            rule.counterparties.len() <= T::MaxAutoAffirmCounterparties::get() as usize,
+            Error::<T>::MaxNumberOfCounterpartiesExceeded
+        );
+        let rule_count = Self::auto_affirm_rule_count(portfolio);
+        ensure!(
+            rule_count < T::MaxAutoAffirmRules::get(),
+            Error::<T>::MaxNumberOfAutoAffirmRulesExceeded
+        );
+
+        // Update storage
+        let rule_id = NextAutoAffirmRuleId::try_mutate(portfolio, try_next_post::<T, _>)?;
+        AutoAffirmRules::insert(portfolio, rule_id, rule.clone());
+        AutoAffirmRuleCount::insert(portfolio, rule_count.saturating_add(1));
+        Self::deposit_event(RawEvent::AutoAffirmRuleAdded(did, portfolio, rule_id, rule));
+        Ok(())
+    }
+
+    fn base_remove_auto_affirm_rule(
+        origin: <T as frame_system::Config>::RuntimeOrigin,
+        portfolio: PortfolioId,
+        rule_id: AutoAffirmRuleId,
+    ) -> DispatchResult {
+        let origin_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        let did = origin_data.primary_did;
+        T::Portfolio::ensure_portfolio_custody_and_permission(
+            portfolio,
+            did,
+            origin_data.secondary_key.as_ref(),
+        )?;
+        ensure!(
+            AutoAffirmRules::contains_key(portfolio, rule_id),
+            Error::<T>::AutoAffirmRuleNotFound
+        );
+
+        // Update storage
+        AutoAffirmRules::remove(portfolio, rule_id);
+        AutoAffirmRulesUsage::remove(portfolio, rule_id);
+        AutoAffirmRuleCount::mutate(portfolio, |rule_count| {
+            *rule_count = rule_count.saturating_sub(1)
+        });
+        Self::deposit_event(RawEvent::AutoAffirmRuleRemoved(did, portfolio, rule_id));
+        Ok(())
+    }
+
+    /// Affirms `instruction_id` on behalf of every portfolio in `portfolios` whose auto-affirm rules match all legs
+    /// it is a party in. Portfolios in the custody of the caller are skipped, as well as portfolios whose assets can't be locked.
+    /// Returns the number of affirmations that are still pending.
+    fn auto_affirm_portfolios(
+        did: IdentityId,
+        instruction_id: InstructionId,
+        venue_id: &VenueId,
+        portfolios: &BTreeSet<PortfolioId>,
+    ) -> u64 {
+        let instruction_legs: Vec<(LegId, Leg)> =
+            InstructionLegs::iter_prefix(&instruction_id).collect();
+        let today = AutoAffirmRuleUsage::day_of(<Checkpoint<T>>::now_unix());
+        let mut affirms_pending = Self::instruction_affirms_pending(instruction_id);
+
+        for portfolio in portfolios {
+            if Self::auto_affirm_rule_count(portfolio) == 0
+                || T::Portfolio::ensure_portfolio_custody(*portfolio, did).is_ok()
+            {
+                continue;
+            }
+
+            let rule_matches =
+                Self::auto_affirm_rule_matches(portfolio, venue_id, &instruction_legs);
+            if rule_matches.is_empty() || rule_matches.iter().any(|m| m.rule_id.is_none()) {
+                continue;
+            }
+
+            // Locks the assets of all legs where the portfolio is the sender
+            let filtered_legs = FilteredLegs::filter_sender(
+                instruction_legs.clone(),
+                &BTreeSet::from([*portfolio]),
+            );
+            let lock_result: DispatchResult = with_transaction(|| {
+                for (leg_id, leg) in filtered_legs.sender_subset() {
+                    Self::lock_via_leg(&leg)?;
+                    <InstructionLegStatus<T>>::insert(
+                        instruction_id,
+                        leg_id,
+                        LegStatus::ExecutionPending,
+                    );
+                }
+                Ok(())
+            });
+            if lock_result.is_err() {
+                continue;
+            }
+
+            // Updates storage
+            let mut rule_amounts: BTreeMap<AutoAffirmRuleId, Balance> = BTreeMap::new();
+            for rule_match in rule_matches {
+                if let Some(rule_id) = rule_match.rule_id {
+                    let amount = rule_amounts.entry(rule_id).or_default();
+                    *amount = amount.saturating_add(rule_match.amount);
+                }
+            }
+            for (rule_id, amount) in &rule_amounts {
+                AutoAffirmRulesUsage::mutate(portfolio, rule_id, |usage| {
+                    usage.record(today, *amount);
+                    usage.instructions = usage.instructions.saturating_add(1)
+                });
+            }
+            let rule_ids: BTreeSet<AutoAffirmRuleId> = rule_amounts.keys().copied().collect();
+            AutoAffirmedInstructions::insert(instruction_id, portfolio, (today, rule_amounts));
+            UserAffirmations::insert(portfolio, instruction_id, AffirmationStatus::Affirmed);
+            AffirmsReceived::insert(instruction_id, portfolio, AffirmationStatus::Affirmed);
+            affirms_pending = affirms_pending.saturating_sub(1);
+            Self::deposit_event(RawEvent::InstructionAutomaticallyAffirmed(
+                did,
+                *portfolio,
+                instruction_id,
+            ));
+            Self::deposit_event(RawEvent::AutoAffirmRulesApplied(
+                *portfolio,
+                instruction_id,
+                rule_ids,
+            ));
+        }
+
+        InstructionAffirmsPending::insert(instruction_id, affirms_pending);
+        affirms_pending
+    }
+
+    /// Refunds the usage recorded by the auto-affirm rules that affirmed `instruction_id` for `portfolio`.
+    fn refund_auto_affirm_rules_usage(instruction_id: InstructionId, portfolio: &PortfolioId) {
+        let (day, rule_amounts) = match AutoAffirmedInstructions::take(instruction_id, portfolio) {
+            Some(auto_affirmed) => auto_affirmed,
+            None => return,
+        };
+        for (rule_id, amount) in rule_amounts {
+            // The usage of removed rules has already been cleared
+            if AutoAffirmRules::contains_key(portfolio, rule_id) {
+                AutoAffirmRulesUsage::mutate(portfolio, rule_id, |usage| usage.refund(day, amount));
+            }
+        }
+    }
+
+    /// Affirms the [`Leg::HashLocked`] leg of the given `leg_id` if `preimage` matches its hash lock and the leg
+    /// hasn't expired. If all affirmation have been received and the settlement type is
+    /// [`SettlementType::SettleOnAffirmation`] the instruction will be scheduled for the next block.
+    fn base_affirm_with_preimage(
+        origin: <T as frame_system::Config>::RuntimeOrigin,
+        id: InstructionId,
+        leg_id: LegId,
+        preimage: Vec<u8>,
+    ) -> DispatchResult {
+        let did = Identity::<T>::ensure_perms(origin)?;
+        ensure_string_limited::<T>(&preimage)?;
+        Self::ensure_instruction_validity(id, false)?;
+
+        let leg = InstructionLegs::get(&id, &leg_id).ok_or(Error::<T>::LegNotFound)?;
+        let (hash_lock, expiry) = match leg {
+            Leg::HashLocked {
+                hash_lock, expiry, ..
+            } => (hash_lock, expiry),
+            Leg::Fungible { .. } | Leg::NonFungible { .. } | Leg::OffChain { .. } => {
+                return Err(Error::<T>::PreimageForInvalidLegType.into())
+            }
+        };
+        ensure!(
+            Self::offchain_affirmations(id, leg_id) == AffirmationStatus::Pending,
+            Error::<T>::UnexpectedAffirmationStatus
+        );
+        ensure!(
+            <Checkpoint<T>>::now_unix() <= expiry,
+            Error::<T>::HashLockExpired
+        );
+        ensure!(
+            sha2_256(&preimage) == hash_lock,
+            Error::<T>::InvalidHashLockPreimage
+        );
+
+        // Update storage
+        OffChainAffirmations::insert(id, leg_id, AffirmationStatus::Affirmed);
+        let affirms_pending = Self::instruction_affirms_pending(id).saturating_sub(1);
+        InstructionAffirmsPending::insert(id, affirms_pending);
+        Self::deposit_event(RawEvent::HashLockPreimageRevealed(
+            did, id, leg_id, preimage,
+        ));
+
+        let instruction_legs: Vec<(LegId, Leg)> = InstructionLegs::iter_prefix(&id).collect();
+        let instruction_asset_count = AssetCount::from_legs(&instruction_legs);
+        let weight_limit = Self::execute_scheduled_instruction_weight_limit(
+            instruction_asset_count.fungible(),
+            instruction_asset_count.non_fungible(),
+            instruction_asset_count.off_chain(),
+        );
+        // Schedule instruction to be executed in the next block (expected) if conditions are met.
+        Self::maybe_schedule_instruction(affirms_pending, id, weight_limit);
+        Ok(())
+    }
+
     /// Affirms all legs from the instruction of the given `id`, where `portfolios` are a counter party.
     /// If the portfolio is the sender, the asset is also locked. If all affirmation have been received and
     /// the settlement type is [`SettlementType::SettleOnAffirmation`] the instruction will be scheduled for
@@ -1937,16 +2977,23 @@ impl<T: Config> Module<T> {
             }
         };
         // All checks have been made - write to storage
-        Self::release_locks(instruction_id, &legs)?;
+        Self::unsafe_reject_instruction(origin_data.primary_did, instruction_id, &legs)?;
+        // Return the actual weight for the call
+        Ok(PostDispatchInfo::from(Some(actual_weight)))
+    }
+
+    /// Releases all locked assets, cancels the scheduled execution and prunes the storage of the given instruction.
+    fn unsafe_reject_instruction(
+        caller_did: IdentityId,
+        instruction_id: InstructionId,
+        legs: &[(LegId, Leg)],
+    ) -> DispatchResult {
+        Self::release_locks(instruction_id, legs)?;
         let _ = T::Scheduler::cancel_named(instruction_id.execution_name());
         // Remove all data from storage
         Self::prune_rejected_instruction(instruction_id);
-        Self::deposit_event(RawEvent::InstructionRejected(
-            origin_data.primary_did,
-            instruction_id,
-        ));
-        // Return the actual weight for the call
-        Ok(PostDispatchInfo::from(Some(actual_weight)))
+        Self::deposit_event(RawEvent::InstructionRejected(caller_did, instruction_id));
+        Ok(())
     }
 
     /// Returns `Ok` if the number of fungible, nonfungible and offchain assets is under the input given by the user.
@@ -2063,6 +3110,23 @@ impl<T: Config> Module<T> {
                     .map_err(|_| Error::<T>::MaxNumberOfOffChainAssetsExceeded)?;
                 Ok(())
             }
+            Leg::HashLocked {
+                sender_identity,
+                receiver_identity,
+                amount,
+                expiry,
+                ..
+            } => {
+                Self::ensure_valid_off_chain_leg(sender_identity, receiver_identity, *amount)?;
+                ensure!(
+                    *expiry > <Checkpoint<T>>::now_unix(),
+                    Error::<T>::HashLockExpiryInThePast
+                );
+                instruction_asset_count
+                    .try_add_off_chain()
+                    .map_err(|_| Error::<T>::MaxNumberOfOffChainAssetsExceeded)?;
+                Ok(())
+            }
         }
     }
 
@@ -2216,24 +3280,32 @@ impl<T: Config> Module<T> {
                         return true;
                     }
                 }
-                Leg::OffChain { .. } => continue,
+                Leg::OffChain { .. } | Leg::HashLocked { .. } => continue,
             }
         }
         false
     }
 
-    /// Returns `true` if the given `caller_did` is a party in any [`Leg::OffChain`] in the `instruction_set`.
+    /// Returns `true` if the given `caller_did` is a party in any [`Leg::OffChain`] or [`Leg::HashLocked`]
+    /// in the `instruction_set`.
     fn is_offchain_party(instruction_set: &[(LegId, Leg)], caller_did: &IdentityId) -> bool {
         for (_, leg) in instruction_set {
-            if let Leg::OffChain {
-                sender_identity,
-                receiver_identity,
-                ..
-            } = leg
-            {
-                if sender_identity == caller_did || receiver_identity == caller_did {
-                    return true;
+            match leg {
+                Leg::OffChain {
+                    sender_identity,
+                    receiver_identity,
+                    ..
                 }
+                | Leg::HashLocked {
+                    sender_identity,
+                    receiver_identity,
+                    ..
+                } => {
+                    if sender_identity == caller_did || receiver_identity == caller_did {
+                        return true;
+                    }
+                }
+                Leg::Fungible { .. } | Leg::NonFungible { .. } => continue,
             }
         }
         false
@@ -2306,7 +3378,7 @@ impl<T: Config> Module<T> {
                         Error::<T>::InvalidSignature
                     );
                 }
-                Leg::Fungible { .. } | Leg::NonFungible { .. } => {
+                Leg::Fungible { .. } | Leg::NonFungible { .. } | Leg::HashLocked { .. } => {
                     return Err(Error::<T>::ReceiptForInvalidLegType.into())
                 }
             }
@@ -2645,7 +3717,7 @@ impl<T: Config> Module<T> {
                     weight_meter
                 )
             }
-            Leg::OffChain { .. } => {
+            Leg::OffChain { .. } | Leg::HashLocked { .. } => {
                 Vec::new()
             },
         }
@@ -2683,6 +3755,13 @@ impl<T: Config> Module<T> {
         }
 
         for (leg_id, leg) in instruction_legs {
+            if let Leg::HashLocked { expiry, .. } = leg {
+                if Self::offchain_affirmations(instruction_id, leg_id) == AffirmationStatus::Pending
+                    && <Checkpoint<T>>::now_unix() > expiry
+                {
+                    execution_errors.push(Error::<T>::HashLockExpired.into());
+                }
+            }
             let leg_status = Self::instruction_leg_status(instruction_id, leg_id);
             if leg_status == LegStatus::ExecutionPending {
                 let transfer_errors = Self::transfer_report(leg, true, weight_meter);
@@ -2692,4 +3771,71 @@ impl<T: Config> Module<T> {
 
         execution_errors
     }
+
+    /// Returns, for every leg `portfolio` is a party in, the first of its auto-affirm rules that matches the leg.
+    /// The daily limit of a rule is shared by all legs in `instruction_legs`.
+    pub fn auto_affirm_rule_matches(
+        portfolio: &PortfolioId,
+        venue_id: &VenueId,
+        instruction_legs: &[(LegId, Leg)],
+    ) -> Vec<AutoAffirmRuleMatch> {
+        let rules: BTreeMap<AutoAffirmRuleId, AutoAffirmRule> =
+            AutoAffirmRules::iter_prefix(portfolio).collect();
+        let today = AutoAffirmRuleUsage::day_of(<Checkpoint<T>>::now_unix());
+        // Tracks the amount each rule has affirmed today, including the legs matched so far
+        let mut used_today: BTreeMap<AutoAffirmRuleId, Balance> = BTreeMap::new();
+
+        let mut rule_matches = Vec::new();
+        for (leg_id, leg) in instruction_legs {
+            let (sender, receiver, asset_id, amount) = match leg {
+                Leg::Fungible {
+                    sender,
+                    receiver,
+                    asset_id,
+                    amount,
+                } => (sender, receiver, asset_id, *amount),
+                Leg::NonFungible {
+                    sender,
+                    receiver,
+                    nfts,
+                } => (sender, receiver, nfts.asset_id(), nfts.len() as Balance),
+                Leg::OffChain { .. } | Leg::HashLocked { .. } => continue,
+            };
+            let (side, counterparty) = if sender == portfolio {
+                (AffirmationSide::Sender, receiver.did)
+            } else if receiver == portfolio {
+                (AffirmationSide::Receiver, sender.did)
+            } else {
+                continue;
+            };
+
+            let rule_id = rules
+                .iter()
+                .find(|(rule_id, rule)| {
+                    if !rule.matches(side, venue_id, asset_id, &counterparty) {
+                        return false;
+                    }
+                    rule.daily_limit.map_or(true, |daily_limit| {
+                        let used = used_today.entry(**rule_id).or_insert_with(|| {
+                            Self::auto_affirm_rules_usage(portfolio, *rule_id).used_on(today)
+                        });
+                        used.saturating_add(amount) <= daily_limit
+                    })
+                })
+                .map(|(rule_id, _)| *rule_id);
+            if let Some(rule_id) = rule_id {
+                if let Some(used) = used_today.get_mut(&rule_id) {
+                    *used = used.saturating_add(amount);
+                }
+            }
+
+            rule_matches.push(AutoAffirmRuleMatch {
+                leg_id: *leg_id,
+                side,
+                amount,
+                rule_id,
+            });
+        }
+        rule_matches
+    }
 }
@@ -8,10 +8,16 @@ use polymesh_common_utilities::benchs::{
 };
 use polymesh_common_utilities::constants::currency::{ONE_UNIT, POLY};
 use polymesh_common_utilities::traits::{asset::Config as Asset, TestUtilsFn};
+use polymesh_primitives::calendar::{CalendarPeriod, CalendarUnit};
 use polymesh_primitives::{jurisdiction::*, statistics::*, Claim, ClaimType, Scope};
 
 use crate::*;
 
+const DAY: CalendarPeriod = CalendarPeriod {
+    unit: CalendarUnit::Day,
+    amount: 1,
+};
+
 const STAT_TYPES: &[(StatOpType, Option<ClaimType>)] = &[
     (StatOpType::Count, None),
     (StatOpType::Balance, None),
@@ -77,6 +83,9 @@ fn make_transfer_conditions(stats: &BTreeSet<StatType>, count: u32) -> BTreeSet<
                         claim_type_to_stat_claim(claim_type).expect("Unsupported ClaimType");
                     TransferCondition::ClaimOwnership(claim, issuer, p0, p40)
                 }
+                (StatOpType::Volume(period), _) => {
+                    TransferCondition::MaxInvestorVolume(period, ONE_UNIT * POLY)
+                }
             },
         )
         .collect()
@@ -456,6 +465,48 @@ benchmarks! {
         .unwrap();
     }
 
+    update_asset_volume_stats {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let stat_type = StatType {
+            operation_type: StatOpType::Volume(DAY),
+            claim_issuer: None,
+        };
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+
+        let asset_id = create_and_issue_sample_asset::<T>(&alice, true, None, b"MyAsset", true);
+        let key1 = Stat1stKey { asset_id, stat_type };
+    }: {
+        Module::<T>::update_asset_volume_stats(
+            key1,
+            &DAY,
+            &alice.did(),
+            ONE_UNIT,
+            &mut weight_meter
+        )
+        .unwrap();
+    }
+
+    volume_restriction {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+
+        let asset_id = create_and_issue_sample_asset::<T>(&alice, true, None, b"MyAsset", true);
+        let transfer_condition = TransferCondition::MaxInvestorVolume(DAY, ONE_UNIT * POLY);
+    }: {
+        assert!(Module::<T>::check_transfer_condition(
+            &transfer_condition,
+            asset_id,
+            &alice.did(),
+            &bob.did(),
+            0,
+            ONE_UNIT,
+            ONE_UNIT * POLY,
+            None,
+            &mut weight_meter
+        ).unwrap());
+    }
+
     verify_requirements {
         let i in 0..T::MaxTransferConditionsPerAsset::get();
 
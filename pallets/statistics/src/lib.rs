@@ -23,24 +23,32 @@ use codec::{Decode, Encode};
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
-use frame_support::{decl_error, decl_module, decl_storage, ensure, BoundedBTreeSet};
+use frame_support::{
+    decl_error, decl_module, decl_storage, ensure, BoundedBTreeSet, IterableStorageDoubleMap,
+};
+use sp_runtime::SaturatedConversion;
 use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
 
 use polymesh_common_utilities::asset::AssetFnTrait;
+use polymesh_common_utilities::traits::identity::ClaimChangeHook;
 pub use polymesh_common_utilities::traits::statistics::{Config, Event, WeightInfo};
 use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::calendar::CalendarPeriod;
 use polymesh_primitives::statistics::{
-    Percentage, Stat1stKey, Stat2ndKey, StatOpType, StatType, StatUpdate,
+    AssetStatValue, Percentage, RollingVolume, Stat1stKey, Stat2ndKey, StatOpType, StatType,
+    StatUpdate,
 };
 use polymesh_primitives::transfer_compliance::{
     AssetTransferCompliance, TransferCondition, TransferConditionExemptKey,
 };
 use polymesh_primitives::{
-    storage_migrate_on, storage_migration_ver, Balance, IdentityId, WeightMeter,
+    storage_migrate_on, storage_migration_ver, Balance, Claim, ClaimType, IdentityId, Scope,
+    WeightMeter,
 };
 
 type Identity<T> = pallet_identity::Module<T>;
 type ExternalAgents<T> = pallet_external_agents::Module<T>;
+type Timestamp<T> = pallet_timestamp::Pallet<T>;
 
 storage_migration_ver!(3);
 
@@ -62,6 +70,16 @@ decl_storage! {
         pub TransferConditionExemptEntities get(fn transfer_condition_exempt_entities):
             double_map hasher(blake2_128_concat) TransferConditionExemptKey, hasher(blake2_128_concat) IdentityId => bool;
 
+        /// Rolling volume of the [`StatOpType::Volume`] stats.
+        /// The volume is tracked per sender and for the whole asset (`None`).
+        pub AssetVolumes get(fn asset_volumes):
+            double_map hasher(blake2_128_concat) Stat1stKey, hasher(blake2_128_concat) Option<IdentityId> => RollingVolume;
+
+        /// Assets with stats on the custom claims of a claim issuer.
+        /// Used to update the stats of all those assets when an unscoped custom claim changes.
+        pub CustomClaimIssuerAssets get(fn custom_claim_issuer_assets):
+            map hasher(blake2_128_concat) (ClaimType, IdentityId) => BoundedBTreeSet<AssetId, T::MaxAssetsPerCustomClaimIssuer>;
+
         /// Storage migration version.
         StorageVersion get(fn storage_version) build(|_| Version::new(3)): Version;
     }
@@ -73,6 +91,7 @@ decl_module! {
 
         const MaxStatsPerAsset: u32 = T::MaxStatsPerAsset::get();
         const MaxTransferConditionsPerAsset: u32 = T::MaxTransferConditionsPerAsset::get();
+        const MaxAssetsPerCustomClaimIssuer: u32 = T::MaxAssetsPerCustomClaimIssuer::get();
 
         /// initialize the default event for this module
         fn deposit_event() = default;
@@ -94,12 +113,15 @@ decl_module! {
         /// # Errors
         /// - `StatTypeLimitReached` - too many stat types enabled for the `asset_id`.
         /// - `CannotRemoveStatTypeInUse` - can not remove a stat type that is in use by transfer conditions.
+        /// - `InvalidVolumeStatType` - a volume stat type has a claim issuer or a period without a fixed length.
+        /// - `CustomClaimIssuerAssetLimitReached` - too many assets have stats on the custom claims of the same issuer.
         /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
         ///
         /// # Permissions
         /// - Agent
         /// - Asset
-        #[weight = <T as Config>::WeightInfo::set_active_asset_stats(stat_types.len() as u32)]
+        #[weight = <T as Config>::WeightInfo::set_active_asset_stats(stat_types.len() as u32)
+            .saturating_add(Module::<T>::custom_claim_issuer_assets_weight())]
         pub fn set_active_asset_stats(origin, asset_id: AssetId, stat_types: BTreeSet<StatType>) {
             Self::base_set_active_asset_stats(origin, asset_id, stat_types)?;
         }
@@ -114,6 +136,8 @@ decl_module! {
         ///
         /// # Errors
         /// - `StatTypeMissing` - `stat_type` is not enabled for the `asset_id`.
+        /// - `CannotUpdateVolumeStats` - volume stats can not be updated manually.
+        /// - `StatUpdateClaimTypeMismatch` - an update is not keyed by the claim type of `stat_type`.
         /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
         ///
         /// # Permissions
@@ -189,6 +213,16 @@ impl<T: Config> Module<T> {
             .try_into()
             .map_err(|_| Error::<T>::StatTypeLimitReached)?;
 
+        // Volume stats are not per-claim and need a period of fixed length.
+        for stat_type in stat_types.iter() {
+            if let StatOpType::Volume(period) = stat_type.operation_type {
+                ensure!(
+                    stat_type.claim_issuer.is_none() && RollingVolume::bucket(&period, 0).is_some(),
+                    Error::<T>::InvalidVolumeStatType
+                );
+            }
+        }
+
         // Get list of StatTypes required by current TransferConditions.
         let required_types = AssetTransferCompliances::<T>::get(&asset_id)
             .requirements
@@ -212,17 +246,19 @@ impl<T: Config> Module<T> {
             })
             .collect::<Result<Vec<_>, Error<T>>>()?;
 
+        Self::update_custom_claim_issuer_assets(asset_id, &stat_types, &remove_types)?;
+
         // Cleanup storage for old types to be removed.
         for stat_type in &remove_types {
+            let key1 = Stat1stKey {
+                asset_id,
+                stat_type: *stat_type,
+            };
             // Cleanup storage for this stat type, since it is being removed.
             #[allow(deprecated)]
-            AssetStats::remove_prefix(
-                Stat1stKey {
-                    asset_id,
-                    stat_type: *stat_type,
-                },
-                None,
-            );
+            AssetStats::remove_prefix(key1, None);
+            #[allow(deprecated)]
+            AssetVolumes::remove_prefix(key1, None);
         }
 
         // Save new stat types.
@@ -238,6 +274,46 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Updates the assets of the custom claim issuers that are added to or removed from the stats of `asset_id`.
+    fn update_custom_claim_issuer_assets(
+        asset_id: AssetId,
+        stat_types: &BoundedBTreeSet<StatType, T::MaxStatsPerAsset>,
+        remove_types: &[StatType],
+    ) -> DispatchResult {
+        let custom_claim_issuer = |stat_type: &StatType| match stat_type.claim_issuer {
+            Some((claim_type @ ClaimType::Custom(_), issuer)) => Some((claim_type, issuer)),
+            _ => None,
+        };
+        let claim_issuers = stat_types
+            .iter()
+            .filter_map(custom_claim_issuer)
+            .collect::<BTreeSet<_>>();
+
+        for claim_issuer in &claim_issuers {
+            CustomClaimIssuerAssets::<T>::try_mutate(claim_issuer, |assets| {
+                assets
+                    .try_insert(asset_id)
+                    .map_err(|_| Error::<T>::CustomClaimIssuerAssetLimitReached)
+            })?;
+        }
+        // Only remove the asset once none of its stats use the claim issuer.
+        for claim_issuer in remove_types.iter().filter_map(custom_claim_issuer) {
+            if !claim_issuers.contains(&claim_issuer) {
+                CustomClaimIssuerAssets::<T>::mutate(claim_issuer, |assets| {
+                    assets.remove(&asset_id);
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum weight of `update_custom_claim_issuer_assets`.
+    fn custom_claim_issuer_assets_weight() -> Weight {
+        // Each of the old and new stat types can have a different custom claim issuer.
+        let max_stats = T::MaxStatsPerAsset::get() as u64;
+        T::DbWeight::get().reads_writes(2 * max_stats, 2 * max_stats)
+    }
+
     fn base_batch_update_asset_stats(
         origin: T::RuntimeOrigin,
         asset_id: AssetId,
@@ -251,6 +327,23 @@ impl<T: Config> Module<T> {
             Self::is_asset_stat_active(&asset_id, &stat_type),
             Error::<T>::StatTypeMissing
         );
+        // Volume stats are only maintained by transfers.
+        ensure!(
+            !matches!(stat_type.operation_type, StatOpType::Volume(_)),
+            Error::<T>::CannotUpdateVolumeStats
+        );
+        // Check that the updates are keyed by the claim type of `stat_type`.
+        let claim_type = stat_type.claim_issuer.map(|(claim_type, _)| claim_type);
+        for update in &values {
+            let key2_claim_type = match &update.key2 {
+                Stat2ndKey::NoClaimStat => None,
+                Stat2ndKey::Claim(claim) => Some(claim.claim_type()),
+            };
+            ensure!(
+                key2_claim_type == claim_type,
+                Error::<T>::StatUpdateClaimTypeMismatch
+            );
+        }
         let key1 = Stat1stKey {
             asset_id,
             stat_type,
@@ -444,6 +537,32 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Update the rolling volume of the sender and of the whole asset.
+    fn update_asset_volume_stats(
+        key1: Stat1stKey,
+        period: &CalendarPeriod,
+        from_did: &IdentityId,
+        amount: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        Self::consume_weight_meter(
+            weight_meter,
+            <T as Config>::WeightInfo::update_asset_volume_stats(),
+        )?;
+        if let Some(bucket) = Self::current_volume_bucket(period) {
+            AssetVolumes::mutate(key1, Some(from_did), |volume| volume.add(bucket, amount));
+            AssetVolumes::mutate(key1, None::<IdentityId>, |volume| {
+                volume.add(bucket, amount)
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the bucket of the rolling `period` containing the current time.
+    fn current_volume_bucket(period: &CalendarPeriod) -> Option<u64> {
+        RollingVolume::bucket(period, Timestamp::<T>::get().saturated_into())
+    }
+
     /// Fetch a claim for an identity as needed by the stat type.
     fn fetch_claim_as_key(did: Option<&IdentityId>, key1: &Stat1stKey) -> Stat2ndKey {
         key1.stat_type
@@ -453,6 +572,13 @@ impl<T: Config> Module<T> {
                 let did_claim = did.and_then(|did| {
                     let claim_scope = key1.claim_scope();
                     Identity::<T>::fetch_claim(*did, claim_type, issuer, Some(claim_scope))
+                        .or_else(|| match claim_type {
+                            // Custom claims can also be issued without a scope.
+                            ClaimType::Custom(_) => {
+                                Identity::<T>::fetch_claim(*did, claim_type, issuer, None)
+                            }
+                            _ => None,
+                        })
                         .map(|c| c.claim)
                 });
                 Stat2ndKey::new_from(&claim_type, did_claim)
@@ -528,6 +654,20 @@ impl<T: Config> Module<T> {
                         )?;
                     }
                 }
+                StatOpType::Volume(period) => {
+                    // Only transfers between investors count towards the volume.
+                    if let (Some(from_did), true, true) =
+                        (from_did, from_balance.is_some(), to_balance.is_some())
+                    {
+                        Self::update_asset_volume_stats(
+                            key1,
+                            &period,
+                            from_did,
+                            amount,
+                            weight_meter,
+                        )?;
+                    }
+                }
                 StatOpType::Balance => {
                     // TODO: no-claim case doesn't need to update balances here.
                     let from_key2 = Self::fetch_claim_as_key(from_did, &key1);
@@ -748,6 +888,27 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Verify rolling volume restrictions.
+    ///
+    /// The volume of `did` is checked if it is `Some`, otherwise the volume of the whole asset is checked.
+    fn verify_volume_restriction(
+        key1: Stat1stKey,
+        period: &CalendarPeriod,
+        did: Option<&IdentityId>,
+        value: Balance,
+        max_volume: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> Result<bool, DispatchError> {
+        Self::consume_weight_meter(
+            weight_meter,
+            <T as Config>::WeightInfo::volume_restriction(),
+        )?;
+        let volume = Self::current_volume_bucket(period)
+            .map(|bucket| AssetVolumes::get(key1, did).total(bucket))
+            .unwrap_or_default();
+        Ok(volume.saturating_add(value) <= max_volume)
+    }
+
     /// Check transfer condition.
     fn check_transfer_condition(
         condition: &TransferCondition,
@@ -807,6 +968,17 @@ impl<T: Config> Module<T> {
                     weight_meter,
                 )?
             }
+            TransferCondition::MaxInvestorVolume(period, max) => Self::verify_volume_restriction(
+                key1,
+                period,
+                Some(from_did),
+                amount,
+                *max,
+                weight_meter,
+            )?,
+            TransferCondition::MaxAssetVolume(period, max) => {
+                Self::verify_volume_restriction(key1, period, None, amount, *max, weight_meter)?
+            }
         };
         if passed {
             Ok(true)
@@ -816,9 +988,9 @@ impl<T: Config> Module<T> {
         }
     }
 
-    /// Returns `true` if the [`TransferCondition`] operation is of type [`StatOpType::Count`] and `sender_did`
-    /// is in the exemption list or if [`TransferCondition`] operation is of type [`StatOpType::Balance`] and
-    /// `receiver_did` is in the exemption list, otherwise returns `false`.
+    /// Returns `true` if the [`TransferCondition`] operation is of type [`StatOpType::Count`] or
+    /// [`StatOpType::Volume`] and `sender_did` is in the exemption list or if [`TransferCondition`] operation
+    /// is of type [`StatOpType::Balance`] and `receiver_did` is in the exemption list, otherwise returns `false`.
     fn is_exempt(
         asset_id: AssetId,
         transfer_condition: &TransferCondition,
@@ -827,8 +999,8 @@ impl<T: Config> Module<T> {
     ) -> bool {
         let transfer_condition_exempt_key = transfer_condition.get_exempt_key(asset_id);
         match transfer_condition_exempt_key.op {
-            // Count transfer conditions require the sender to be exempt.
-            StatOpType::Count => {
+            // Count and volume transfer conditions require the sender to be exempt.
+            StatOpType::Count | StatOpType::Volume(_) => {
                 Self::transfer_condition_exempt_entities(transfer_condition_exempt_key, sender_did)
             }
             // Percent ownersip transfer conditions require the receiver to be exempt.
@@ -953,6 +1125,51 @@ impl<T: Config> Module<T> {
         Ok(failed_conditions)
     }
 
+    /// Returns the values of all active statistics of `asset_id`, for every [`Stat2ndKey`] with a stored value.
+    /// [`StatOpType::Volume`] statistics return the volume of the current rolling period for the whole asset.
+    pub fn asset_stat_values(asset_id: AssetId) -> Result<Vec<AssetStatValue>, DispatchError> {
+        let asset_total_supply = T::Asset::asset_total_supply(&asset_id)?;
+        let ownership = |balance: Balance| {
+            if asset_total_supply == 0 {
+                return Percentage::from_parts(0);
+            }
+            Percentage::from_rational(balance, asset_total_supply)
+        };
+
+        let mut stat_values = Vec::new();
+        for stat_type in Self::active_asset_stats(asset_id) {
+            let key1 = Stat1stKey {
+                asset_id,
+                stat_type,
+            };
+            match stat_type.operation_type {
+                StatOpType::Count | StatOpType::Balance => {
+                    for (key2, value) in AssetStats::iter_prefix(key1) {
+                        stat_values.push(AssetStatValue {
+                            stat_type,
+                            key2,
+                            value,
+                            ownership: (stat_type.operation_type == StatOpType::Balance)
+                                .then(|| ownership(value)),
+                        });
+                    }
+                }
+                StatOpType::Volume(period) => {
+                    let volume = Self::current_volume_bucket(&period)
+                        .map(|bucket| AssetVolumes::get(key1, None::<IdentityId>).total(bucket))
+                        .unwrap_or_default();
+                    stat_values.push(AssetStatValue {
+                        stat_type,
+                        key2: Stat2ndKey::NoClaimStat,
+                        value: volume,
+                        ownership: None,
+                    });
+                }
+            }
+        }
+        Ok(stat_values)
+    }
+
     /// Consumes from `weight_meter` the given `weight`.
     /// If the new consumed weight is greater than the limit, consumed will be set to limit and an error will be returned.
     fn consume_weight_meter(weight_meter: &mut WeightMeter, weight: Weight) -> DispatchResult {
@@ -960,6 +1177,115 @@ impl<T: Config> Module<T> {
             .consume_weight_until_limit(weight)
             .map_err(|_| Error::<T>::WeightLimitExceeded.into())
     }
+
+    /// Moves `target` from the `old` to the `new` claim in the `claim_type` stats of `asset_id` from `issuer`.
+    fn move_claim_stats(
+        asset_id: AssetId,
+        target: IdentityId,
+        issuer: IdentityId,
+        claim_type: ClaimType,
+        old: Option<Claim>,
+        new: Option<Claim>,
+    ) {
+        let from_key2 = Stat2ndKey::new_from(&claim_type, old);
+        let to_key2 = Stat2ndKey::new_from(&claim_type, new);
+        if from_key2 == to_key2 {
+            return;
+        }
+        // Identities without a balance are not counted in any stat.
+        let balance = T::Asset::asset_balance(&asset_id, &target);
+        if balance == 0 {
+            return;
+        }
+
+        // Move the investor from the old claim to the new one.
+        for stat_type in Self::active_asset_stats(asset_id).into_iter() {
+            if stat_type.claim_issuer != Some((claim_type, issuer)) {
+                continue;
+            }
+            let key1 = Stat1stKey {
+                asset_id,
+                stat_type,
+            };
+            let amount = match stat_type.operation_type {
+                StatOpType::Count => 1,
+                StatOpType::Balance => balance,
+                StatOpType::Volume(_) => continue,
+            };
+            AssetStats::mutate(key1, &from_key2, |value| {
+                *value = value.saturating_sub(amount)
+            });
+            AssetStats::mutate(key1, &to_key2, |value| {
+                *value = value.saturating_add(amount)
+            });
+        }
+    }
+
+    /// Returns the maximum weight of `move_claim_stats`.
+    fn move_claim_stats_weight() -> Weight {
+        let max_stats = T::MaxStatsPerAsset::get();
+        let update_weight = <T as Config>::WeightInfo::update_asset_balance_stats(2)
+            .max(<T as Config>::WeightInfo::update_asset_count_stats(2));
+        <T as Config>::WeightInfo::active_asset_statistics_load(max_stats)
+            .saturating_add(update_weight.saturating_mul(max_stats.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+    }
+}
+
+impl<T: Config> ClaimChangeHook for Module<T> {
+    fn on_claim_change(
+        target: IdentityId,
+        issuer: IdentityId,
+        claim_type: ClaimType,
+        scope: Option<&Scope>,
+        old: Option<Claim>,
+        new: Option<Claim>,
+    ) {
+        let is_custom = matches!(claim_type, ClaimType::Custom(_));
+        match scope {
+            Some(Scope::Asset(asset_id)) => {
+                // Without a scoped custom claim, the stats use the unscoped one.
+                let unscoped_claim = || {
+                    if !is_custom {
+                        return None;
+                    }
+                    Identity::<T>::fetch_claim(target, claim_type, issuer, None).map(|c| c.claim)
+                };
+                let old = old.or_else(unscoped_claim);
+                let new = new.or_else(unscoped_claim);
+                Self::move_claim_stats(*asset_id, target, issuer, claim_type, old, new);
+            }
+            None if is_custom => {
+                for asset_id in Self::custom_claim_issuer_assets((claim_type, issuer)) {
+                    // A scoped custom claim takes precedence over the unscoped one.
+                    let scope = Some(Scope::Asset(asset_id));
+                    if Identity::<T>::fetch_claim(target, claim_type, issuer, scope).is_some() {
+                        continue;
+                    }
+                    Self::move_claim_stats(
+                        asset_id,
+                        target,
+                        issuer,
+                        claim_type,
+                        old.clone(),
+                        new.clone(),
+                    );
+                }
+            }
+            // Other claims are not used by the stats.
+            _ => {}
+        }
+    }
+
+    fn on_claim_change_weight() -> Weight {
+        let reads = T::DbWeight::get().reads(1);
+        let scoped_weight = Self::move_claim_stats_weight().saturating_add(reads);
+        let unscoped_weight = Self::move_claim_stats_weight()
+            .saturating_add(reads)
+            .saturating_mul(T::MaxAssetsPerCustomClaimIssuer::get().into())
+            .saturating_add(reads);
+        scoped_weight.max(unscoped_weight)
+    }
 }
 
 decl_error! {
@@ -979,5 +1305,13 @@ decl_error! {
         TransferConditionLimitReached,
         /// The maximum weight limit for executing the function was exceeded.
         WeightLimitExceeded,
+        /// Volume stats must not have a claim issuer and need a period of fixed length.
+        InvalidVolumeStatType,
+        /// Volume stats can not be updated manually.
+        CannotUpdateVolumeStats,
+        /// The stat update is not keyed by the claim type of the stat type.
+        StatUpdateClaimTypeMismatch,
+        /// Too many assets have stats on the custom claims of the same claim issuer.
+        CustomClaimIssuerAssetLimitReached,
     }
 }
@@ -6,7 +6,7 @@ use pallet_asset::benchmarking::setup_asset_transfer;
 use polymesh_common_utilities::benchs::{AccountIdOf, User, UserBuilder};
 use polymesh_common_utilities::TestUtilsFn;
 use polymesh_primitives::settlement::VenueDetails;
-use polymesh_primitives::TrustedIssuer;
+use polymesh_primitives::{Scope, TrustedIssuer};
 
 use crate::*;
 
@@ -17,6 +17,8 @@ pub type Timestamp<T> = pallet_timestamp::Pallet<T>;
 pub type Settlement<T> = pallet_settlement::Module<T>;
 pub type Sto<T> = crate::Module<T>;
 
+const MAX_ALLOCATIONS: u32 = 100;
+
 struct SetupPortfolios {
     pub fundraiser_offering_portfolio: PortfolioId,
     pub investor_offering_portfolio: PortfolioId,
@@ -124,6 +126,49 @@ where
     setup_portfolios
 }
 
+/// Creates an escrowed fundraiser in which `investor` invested, and which has ended.
+fn setup_escrowed_fundraiser<T>(
+    fundraiser: &User<T>,
+    investor: &User<T>,
+    soft_cap: Balance,
+) -> SetupPortfolios
+where
+    T: Config + TestUtilsFn<AccountIdOf<T>>,
+{
+    let setup_portfolios = create_assets_and_compliance::<T>(&fundraiser, &investor);
+    let venue_id = create_venue(&fundraiser).unwrap();
+
+    <Sto<T>>::create_escrowed_fundraiser(
+        fundraiser.origin().into(),
+        setup_portfolios.fundraiser_offering_portfolio,
+        setup_portfolios.offering_asset_id,
+        setup_portfolios.fundraiser_raising_portfolio,
+        setup_portfolios.raising_asset_id,
+        generate_tiers(1),
+        venue_id,
+        None,
+        101u32.into(),
+        2,
+        vec![].into(),
+        soft_cap,
+    )
+    .unwrap();
+    <Sto<T>>::invest(
+        investor.origin().into(),
+        setup_portfolios.investor_offering_portfolio,
+        setup_portfolios.investor_raising_portfolio,
+        setup_portfolios.offering_asset_id,
+        FundraiserId(0),
+        100,
+        None,
+        None,
+    )
+    .unwrap();
+    <Timestamp<T>>::set_timestamp(101u32.into());
+
+    setup_portfolios
+}
+
 benchmarks! {
     where_clause { where T: TestUtilsFn<AccountIdOf<T>> }
 
@@ -155,21 +200,43 @@ benchmarks! {
     }
 
     invest {
+        // Number of tiers
+        let t in 1 .. MAX_TIERS as u32;
+
+        let id = FundraiserId(0);
         let alice = <UserBuilder<T>>::default().generate_did().build("Alice");
         let bob = <UserBuilder<T>>::default().generate_did().build("Bob");
-        let setup_portfolios = setup_fundraiser::<T>(&alice, &bob, MAX_TIERS as u32);
+        let setup_portfolios = setup_fundraiser::<T>(&alice, &bob, t);
+        let offering_asset_id = setup_portfolios.offering_asset_id;
+
+        // Invest during the early-access round, holding the claim of the last issuer only.
+        <Sto<T>>::modify_fundraiser_window(alice.origin().into(), offering_asset_id, id, 100u32.into(), Some(101u32.into())).unwrap();
+        let claim = Claim::Accredited(Scope::Asset(offering_asset_id));
+        let issuers: Vec<_> = (0..MAX_EARLY_ACCESS_ISSUERS as u128).map(IdentityId::from).collect();
+        Identity::<T>::unverified_add_claim_with_scope(bob.did(), claim.clone(), claim.as_scope().cloned(), issuers[issuers.len() - 1], None);
+        let restrictions = InvestorRestrictions {
+            max_investment: None,
+            allocated_tiers: (0..t).collect(),
+            early_access: Some(EarlyAccess { start: 0u32.into(), claim, issuers }),
+        };
+        <Sto<T>>::set_investor_restrictions(alice.origin().into(), offering_asset_id, id, restrictions).unwrap();
+
+        // Buy from every tier, up to the allocation of the investor.
+        for tier in 0..t {
+            <Sto<T>>::set_tier_allocations(alice.origin().into(), offering_asset_id, id, tier, vec![(bob.did(), 100)]).unwrap();
+        }
     }: _(
             bob.origin(),
             setup_portfolios.investor_offering_portfolio,
             setup_portfolios.investor_raising_portfolio,
-            setup_portfolios.offering_asset_id,
-            FundraiserId(0),
-            100,
+            offering_asset_id,
+            id,
+            100 * t as u128,
             Some(1_000_000u128.into()),
             None
         )
     verify {
-        assert!(<Asset<T>>::balance_of(&setup_portfolios.offering_asset_id, bob.did()) > 0u32.into(), "invest");
+        assert!(<Asset<T>>::balance_of(&offering_asset_id, bob.did()) > 0u32.into(), "invest");
     }
 
     freeze_fundraiser {
@@ -212,4 +279,83 @@ benchmarks! {
     verify {
         assert!(<Fundraisers<T>>::get(setup_portfolios.offering_asset_id, id).unwrap().is_closed(), "stop");
     }
+
+    set_investor_restrictions {
+        let i in 0 .. MAX_EARLY_ACCESS_ISSUERS as u32;
+
+        let id = FundraiserId(0);
+        let alice = <UserBuilder<T>>::default().generate_did().build("Alice");
+        let bob = <UserBuilder<T>>::default().generate_did().build("Bob");
+        let setup_portfolios = setup_fundraiser::<T>(&alice, &bob, 1);
+        <Sto<T>>::modify_fundraiser_window(alice.origin().into(), setup_portfolios.offering_asset_id, id, 100u32.into(), Some(101u32.into())).unwrap();
+        let early_access = (i > 0).then(|| EarlyAccess {
+            start: 0u32.into(),
+            claim: Claim::Accredited(Scope::Asset(setup_portfolios.offering_asset_id)),
+            issuers: (0..i).map(|i| IdentityId::from(i as u128)).collect(),
+        });
+        let restrictions = InvestorRestrictions {
+            max_investment: Some(1_000_000),
+            allocated_tiers: BTreeSet::from([0]),
+            early_access,
+        };
+    }: _(alice.origin(), setup_portfolios.offering_asset_id, id, restrictions.clone())
+    verify {
+        assert_eq!(<FundraiserRestrictions<T>>::get(setup_portfolios.offering_asset_id, id), Some(restrictions), "set_investor_restrictions");
+    }
+
+    set_tier_allocations {
+        let a in 0 .. MAX_ALLOCATIONS;
+
+        let id = FundraiserId(0);
+        let alice = <UserBuilder<T>>::default().generate_did().build("Alice");
+        let bob = <UserBuilder<T>>::default().generate_did().build("Bob");
+        let setup_portfolios = setup_fundraiser::<T>(&alice, &bob, 1);
+        let allocations = (0..a).map(|i| (IdentityId::from(i as u128), 100)).collect();
+    }: _(alice.origin(), setup_portfolios.offering_asset_id, id, 0, allocations)
+
+    create_escrowed_fundraiser {
+        // Number of tiers
+        let i in 1 .. MAX_TIERS as u32;
+
+        let alice = <UserBuilder<T>>::default().generate_did().build("Alice");
+        let bob = <UserBuilder<T>>::default().generate_did().build("Bob");
+        let setup_portfolios = create_assets_and_compliance::<T>(&alice, &bob);
+
+        let venue_id = create_venue(&alice).unwrap();
+        let tiers = generate_tiers(i);
+    }: _(
+            alice.origin(),
+            setup_portfolios.fundraiser_offering_portfolio,
+            setup_portfolios.offering_asset_id,
+            setup_portfolios.fundraiser_raising_portfolio,
+            setup_portfolios.raising_asset_id,
+            tiers,
+            venue_id,
+            None,
+            101u32.into(),
+            0u32.into(),
+            vec![].into(),
+            1
+        )
+    verify {
+        assert!(FundraiserEscrows::contains_key(setup_portfolios.offering_asset_id, FundraiserId(0)), "create_escrowed_fundraiser");
+    }
+
+    claim_offering {
+        let alice = <UserBuilder<T>>::default().generate_did().build("Alice");
+        let bob = <UserBuilder<T>>::default().generate_did().build("Bob");
+        let setup_portfolios = setup_escrowed_fundraiser::<T>(&alice, &bob, 1);
+    }: _(bob.origin(), setup_portfolios.investor_offering_portfolio, setup_portfolios.offering_asset_id, FundraiserId(0))
+    verify {
+        assert!(<Asset<T>>::balance_of(&setup_portfolios.offering_asset_id, bob.did()) > 0u32.into(), "claim_offering");
+    }
+
+    refund {
+        let alice = <UserBuilder<T>>::default().generate_did().build("Alice");
+        let bob = <UserBuilder<T>>::default().generate_did().build("Bob");
+        let setup_portfolios = setup_escrowed_fundraiser::<T>(&alice, &bob, 10_000);
+    }: _(bob.origin(), setup_portfolios.investor_raising_portfolio, setup_portfolios.offering_asset_id, FundraiserId(0))
+    verify {
+        assert_eq!(<EscrowedInvestments>::get((setup_portfolios.offering_asset_id, FundraiserId(0)), bob.did()), Investment::default(), "refund");
+    }
 }
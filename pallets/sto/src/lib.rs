@@ -10,6 +10,10 @@
 //! Fundraisers are of fixed supply, with optional expiry and tiered pricing.
 //! Fundraisers allow a single payment asset, known as the raising asset.
 //! Investors can invest through on-chain balance or off-chain receipts.
+//! Fundraisers can cap the amount each identity invests, restrict tiers to a list of
+//! pre-registered allocations and open an early-access round to holders of a given claim.
+//! Escrowed fundraisers lock the raised funds until the fundraiser closes, investors then receive
+//! the offering tokens if the soft cap was reached or get refunded otherwise.
 //!
 //! ## Dispatchable Functions
 //!
@@ -19,6 +23,11 @@
 //! - `unfreeze_fundraiser` - Unfreeze a fundraiser.
 //! - `modify_fundraiser_window` - Modify the time window a fundraiser is active.
 //! - `stop` - stop a fundraiser.
+//! - `set_investor_restrictions` - Set the investor cap, allocated tiers and early-access round of a fundraiser.
+//! - `set_tier_allocations` - Set the allocations of a tier for a list of identities.
+//! - `create_escrowed_fundraiser` - Create a new fundraiser with a soft cap and escrowed funds.
+//! - `claim_offering` - Receive the offering tokens bought in an escrowed fundraiser that reached its soft cap.
+//! - `refund` - Get refunded from an escrowed fundraiser that did not reach its soft cap.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
@@ -46,12 +55,18 @@ use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::impl_checked_inc;
 use polymesh_primitives::settlement::{Leg, ReceiptDetails, SettlementType, VenueId, VenueType};
 use polymesh_primitives::{
-    storage_migrate_on, storage_migration_ver, Balance, EventDid, IdentityId, PortfolioId,
-    WeightMeter,
+    storage_migrate_on, storage_migration_ver, Balance, Claim, EventDid, IdentityId, PortfolioId,
+    PortfolioKind, WeightMeter,
 };
 use polymesh_primitives_derive::VecU8StrongTyped;
 
 pub const MAX_TIERS: usize = 10;
+pub const MAX_EARLY_ACCESS_ISSUERS: usize = 10;
+
+/// Price is entered as a multiple of 1_000_000
+/// i.e. a price of 1 unit is 1_000_000
+/// a price of 1.5 units is 1_500_000
+const PRICE_DIVISOR: Balance = 1_000_000;
 
 type ExternalAgents<T> = pallet_external_agents::Module<T>;
 type Identity<T> = pallet_identity::Module<T>;
@@ -155,6 +170,75 @@ impl Into<FundraiserTier> for PriceTier {
     }
 }
 
+/// Restrictions applied to the investors of a `Fundraiser`.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct InvestorRestrictions<Moment> {
+    /// Maximum amount of `raising_asset` a single identity can spend in the fundraiser.
+    pub max_investment: Option<Balance>,
+    /// Tiers that can only be bought up to the allocation registered for each identity.
+    pub allocated_tiers: BTreeSet<u32>,
+    /// Round opening the fundraiser to holders of a claim before its `start`.
+    pub early_access: Option<EarlyAccess<Moment>>,
+}
+
+/// Early-access round of a `Fundraiser`.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct EarlyAccess<Moment> {
+    /// Start time of the round, must come before the start of the fundraiser.
+    pub start: Moment,
+    /// Claim an investor must hold to invest during the round.
+    pub claim: Claim,
+    /// Identities trusted to issue `claim`.
+    pub issuers: Vec<IdentityId>,
+}
+
+/// Amounts bought by an investor in a `Fundraiser`.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Investment {
+    /// Total amount of `offering_asset` bought.
+    pub offering_amount: Balance,
+    /// Total amount of `raising_asset` spent.
+    pub raising_amount: Balance,
+}
+
+/// Escrow of a `Fundraiser` with a soft cap.
+///
+/// Investments in an escrowed fundraiser only transfer the raising asset, which stays locked
+/// in the dedicated raising portfolio until the fundraiser closes.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FundraiserEscrow {
+    /// Minimum amount of `raising_asset` that must be raised for the offering tokens to be released.
+    pub soft_cap: Balance,
+    /// Total amount of `raising_asset` raised.
+    pub raised: Balance,
+    /// Status of the escrow.
+    pub status: EscrowStatus,
+}
+
+/// Status of a `FundraiserEscrow`.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscrowStatus {
+    /// The fundraiser is still running, funds are escrowed.
+    Open,
+    /// The soft cap was reached, investors can claim their offering tokens.
+    Released,
+    /// The soft cap was not reached, investors can be refunded.
+    Refunding,
+}
+
+impl Default for EscrowStatus {
+    fn default() -> Self {
+        Self::Open
+    }
+}
+
 /// Wrapper type for Fundraiser name.
 #[derive(Encode, Decode, TypeInfo, VecU8StrongTyped)]
 #[derive(Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -162,11 +246,16 @@ pub struct FundraiserName(Vec<u8>);
 
 pub trait WeightInfo {
     fn create_fundraiser(i: u32) -> Weight;
-    fn invest() -> Weight;
+    fn invest(t: u32) -> Weight;
     fn freeze_fundraiser() -> Weight;
     fn unfreeze_fundraiser() -> Weight;
     fn modify_fundraiser_window() -> Weight;
     fn stop() -> Weight;
+    fn set_investor_restrictions(i: u32) -> Weight;
+    fn set_tier_allocations(a: u32) -> Weight;
+    fn create_escrowed_fundraiser(i: u32) -> Weight;
+    fn claim_offering() -> Weight;
+    fn refund() -> Weight;
 }
 
 pub trait Config:
@@ -212,6 +301,24 @@ decl_event!(
         /// A fundraiser has been stopped.
         /// (Agent DID, fundraiser id)
         FundraiserClosed(IdentityId, FundraiserId),
+        /// The investor restrictions of a fundraiser have been updated.
+        /// (Agent DID, fundraiser id, restrictions)
+        InvestorRestrictionsUpdated(IdentityId, FundraiserId, InvestorRestrictions<Moment>),
+        /// The allocations of a fundraiser tier have been updated.
+        /// (Agent DID, fundraiser id, tier, [(investor, allocation)])
+        TierAllocationsUpdated(IdentityId, FundraiserId, u32, Vec<(IdentityId, Balance)>),
+        /// A fundraiser has been created with an escrow.
+        /// (Agent DID, fundraiser id, soft cap)
+        FundraiserEscrowCreated(IdentityId, FundraiserId, Balance),
+        /// The escrow of a fundraiser has been released or opened for refunds.
+        /// (fundraiser id, escrow status, raised amount)
+        FundraiserEscrowFinalized(FundraiserId, EscrowStatus, Balance),
+        /// An investor received the offering tokens bought in an escrowed fundraiser.
+        /// (Investor, fundraiser id, offering_token_amount)
+        OfferingClaimed(IdentityId, FundraiserId, Balance),
+        /// An investor has been refunded from an escrowed fundraiser.
+        /// (Investor, fundraiser id, raise_token_amount)
+        InvestmentRefunded(IdentityId, FundraiserId, Balance),
     }
 );
 
@@ -241,7 +348,32 @@ decl_error! {
         /// Price of the investment exceeded the max price.
         MaxPriceExceeded,
         /// Investment amount is lower than minimum investment amount.
-        InvestmentAmountTooLow
+        InvestmentAmountTooLow,
+        /// Investment would take the investor over the maximum investment amount.
+        MaxInvestmentExceeded,
+        /// The investor does not hold the claim required during the early-access round.
+        EarlyAccessDenied,
+        /// The early-access round does not start before the fundraiser or has no valid issuers.
+        InvalidEarlyAccess,
+        /// The soft cap is zero or higher than the amount that can be raised.
+        InvalidSoftCap,
+        /// Escrowed fundraisers must have an end.
+        EscrowRequiresEnd,
+        /// Investments in escrowed fundraisers can not be paid with off-chain receipts.
+        EscrowReceiptNotAllowed,
+        /// The fundraiser does not have an escrow.
+        EscrowNotFound,
+        /// The escrow can not be settled before the fundraiser closes.
+        EscrowStillOpen,
+        /// The soft cap was reached, investments can not be refunded.
+        SoftCapReached,
+        /// The soft cap was not reached, the offering tokens can not be claimed.
+        SoftCapNotReached,
+        /// The investor has no escrowed investment in the fundraiser.
+        NoEscrowedInvestment,
+        /// The raising portfolio of an escrowed fundraiser must be a user portfolio, without any `raising_asset`,
+        /// that isn't used by another fundraiser.
+        EscrowPortfolioNotDedicated
     }
 }
 
@@ -270,6 +402,51 @@ decl_storage! {
                 hasher(twox_64_concat) FundraiserId
                 => Option<FundraiserName>;
 
+        /// Restrictions applied to the investors of a fundraiser.
+        /// (AssetId, fundraiser_id) -> InvestorRestrictions
+        FundraiserRestrictions get(fn fundraiser_restrictions):
+            double_map
+                hasher(blake2_128_concat) AssetId,
+                hasher(twox_64_concat) FundraiserId
+                => Option<InvestorRestrictions<T::Moment>>;
+
+        /// Amount of an allocated tier each investor can still buy.
+        /// ((AssetId, fundraiser_id), (tier, investor)) -> remaining allocation
+        TierAllocations get(fn tier_allocation):
+            double_map
+                hasher(blake2_128_concat) (AssetId, FundraiserId),
+                hasher(blake2_128_concat) (u32, IdentityId)
+                => Balance;
+
+        /// Amounts bought by each investor in a fundraiser.
+        /// ((AssetId, fundraiser_id), investor) -> Investment
+        Investments get(fn investments):
+            double_map
+                hasher(blake2_128_concat) (AssetId, FundraiserId),
+                hasher(blake2_128_concat) IdentityId
+                => Investment;
+
+        /// Escrow of the fundraisers created with a soft cap.
+        /// (AssetId, fundraiser_id) -> FundraiserEscrow
+        FundraiserEscrows get(fn fundraiser_escrow):
+            double_map
+                hasher(blake2_128_concat) AssetId,
+                hasher(twox_64_concat) FundraiserId
+                => Option<FundraiserEscrow>;
+
+        /// Investments held in escrow, until claimed or refunded.
+        /// ((AssetId, fundraiser_id), investor) -> Investment
+        EscrowedInvestments get(fn escrowed_investments):
+            double_map
+                hasher(blake2_128_concat) (AssetId, FundraiserId),
+                hasher(blake2_128_concat) IdentityId
+                => Investment;
+
+        /// Raising portfolios dedicated to the escrow of a fundraiser, until the escrow is finalized.
+        /// raising_portfolio -> (AssetId, fundraiser_id)
+        EscrowPortfolios get(fn escrow_portfolio):
+            map hasher(blake2_128_concat) PortfolioId => Option<(AssetId, FundraiserId)>;
+
         /// Storage migration version.
         StorageVersion get(fn storage_version) build(|_| Version::new(1)): Version;
     }
@@ -318,65 +495,19 @@ decl_module! {
             minimum_investment: Balance,
             fundraiser_name: FundraiserName
         ) {
-            pallet_base::ensure_string_limited::<T>(&fundraiser_name)?;
-
-            let PermissionedCallOriginData {
-                primary_did: did,
-                secondary_key,
-                ..
-            } = <ExternalAgents<T>>::ensure_agent_asset_perms(origin, offering_asset)?;
-
-            VenueInfo::get(venue_id)
-                .filter(|v| v.creator == did && v.venue_type == VenueType::Sto)
-                .ok_or(Error::<T>::InvalidVenue)?;
-
-            <Portfolio<T>>::ensure_portfolio_custody_and_permission(raising_portfolio, did, secondary_key.as_ref())?;
-            <Portfolio<T>>::ensure_portfolio_custody_and_permission(offering_portfolio, did, secondary_key.as_ref())?;
-
-            // Ensure there are [1, MAX_TIERS] tiers and that all of their totals are non-zero.
-            let mut totals = tiers.iter().map(|t| t.total);
-            ensure!(
-                (1..=MAX_TIERS).contains(&tiers.len()) && totals.clone().all(|t| t > 0),
-                Error::<T>::InvalidPriceTiers
-            );
-
-            // Sum all totals, or bail on overflow.
-            let offering_amount = totals
-                .try_fold(0, |total: Balance, x| total.checked_add(x))
-                .ok_or(Error::<T>::InvalidPriceTiers)?;
-
-            // Use current time if start isn't provided.
-            let start = start.unwrap_or_else(Timestamp::<T>::get);
-            // The start must come strictly before the end.
-            if let Some(end) = end {
-                ensure!(start < end, Error::<T>::InvalidOfferingWindow);
-            }
-
-            // Get the next fundraiser ID.
-            let mut seq = FundraiserCount::get(&offering_asset);
-            let id = try_next_post::<T, _>(&mut seq)?;
-
-            <Portfolio<T>>::lock_tokens(&offering_portfolio, &offering_asset, offering_amount)?;
-
-            let fundraiser = Fundraiser {
-                creator: did,
+            Self::base_create_fundraiser(
+                origin,
                 offering_portfolio,
                 offering_asset,
                 raising_portfolio,
                 raising_asset,
-                tiers: tiers.into_iter().map(Into::into).collect(),
+                tiers,
                 venue_id,
                 start,
                 end,
-                status: FundraiserStatus::Live,
-                minimum_investment
-            };
-
-            FundraiserCount::insert(offering_asset, seq);
-            Fundraisers::<T>::insert(offering_asset, id, fundraiser.clone());
-            FundraiserNames::insert(offering_asset, id, fundraiser_name.clone());
-
-            Self::deposit_event(RawEvent::FundraiserCreated(did, id, fundraiser_name, fundraiser));
+                minimum_investment,
+                fundraiser_name
+            )?;
         }
 
         /// Invest in a fundraiser.
@@ -391,7 +522,7 @@ decl_module! {
         ///
         /// # Permissions
         /// * Portfolio
-        #[weight = <T as Config>::WeightInfo::invest()]
+        #[weight = <T as Config>::WeightInfo::invest(MAX_TIERS as u32)]
         pub fn invest(
             origin,
             investment_portfolio: PortfolioId,
@@ -415,11 +546,21 @@ decl_module! {
 
             ensure!(fundraiser.status == FundraiserStatus::Live, Error::<T>::FundraiserNotLive);
 
+            let mut escrow = Self::fundraiser_escrow(offering_asset, id);
+            ensure!(escrow.is_none() || receipt.is_none(), Error::<T>::EscrowReceiptNotAllowed);
+
+            let restrictions = Self::fundraiser_restrictions(offering_asset, id).unwrap_or_default();
+
             let now = Timestamp::<T>::get();
-            ensure!(
-                fundraiser.start <= now && fundraiser.end.filter(|e| now >= *e).is_none(),
-                Error::<T>::FundraiserExpired
-            );
+            ensure!(fundraiser.end.filter(|e| now >= *e).is_none(), Error::<T>::FundraiserExpired);
+            // Before the start, only holders of the early-access claim can invest.
+            if now < fundraiser.start {
+                let early_access = restrictions.early_access
+                    .as_ref()
+                    .filter(|early_access| early_access.start <= now)
+                    .ok_or(Error::<T>::FundraiserExpired)?;
+                ensure!(Self::has_early_access(did, early_access), Error::<T>::EarlyAccessDenied);
+            }
 
             // Remaining tokens to fulfil the investment amount
             let mut remaining = purchase_amount;
@@ -428,35 +569,41 @@ decl_module! {
             // Blended price must be <= to max_price or the investment will fail.
             let mut cost = Balance::from(0u32);
 
-            // Price is entered as a multiple of 1_000_000
-            // i.e. a price of 1 unit is 1_000_000
-            // a price of 1.5 units is 1_500_00
-            let price_divisor = Balance::from(1_000_000u32);
             // Individual purchases from each tier that accumulate to fulfil the investment amount.
             // Tuple of (tier_id, amount to purchase from that tier).
             let mut purchases = Vec::new();
 
-            for (id, tier) in fundraiser.tiers.iter().enumerate().filter(|(_, tier)| tier.remaining > 0u32.into()) {
+            for (tier_id, tier) in fundraiser.tiers.iter().enumerate().filter(|(_, tier)| tier.remaining > 0u32.into()) {
                 // fulfilled the investment amount
                 if remaining == 0u32.into() {
                     break
                 }
 
+                // Allocated tiers can only be bought up to the investor's allocation.
+                let available = if restrictions.allocated_tiers.contains(&(tier_id as u32)) {
+                    tier.remaining.min(TierAllocations::get((offering_asset, id), (tier_id as u32, did)))
+                } else {
+                    tier.remaining
+                };
+                if available == 0u32.into() {
+                    continue
+                }
+
                 // Check if this tier can fulfil the remaining investment amount.
                 // If it can, purchase the remaining amount.
-                // If it can't, purchase what's remaining in the tier.
-                let purchase_amount = if tier.remaining >= remaining {
+                // If it can't, purchase what's available in the tier.
+                let purchase_amount = if available >= remaining {
                     remaining
                 } else {
-                    tier.remaining
+                    available
                 };
 
                 remaining -= purchase_amount;
-                purchases.push((id, purchase_amount));
+                purchases.push((tier_id, purchase_amount));
                 cost = purchase_amount
                     .checked_mul(tier.price)
                     .ok_or(Error::<T>::Overflow)?
-                    .checked_div(price_divisor)
+                    .checked_div(PRICE_DIVISOR)
                     .and_then(|pa| cost.checked_add(pa))
                     .ok_or(Error::<T>::Overflow)?;
             }
@@ -464,61 +611,79 @@ decl_module! {
             ensure!(remaining == 0u32.into(), Error::<T>::InsufficientTokensRemaining);
             ensure!(cost >= fundraiser.minimum_investment, Error::<T>::InvestmentAmountTooLow);
             ensure!(
-                max_price.map(|max_price| cost <= max_price.saturating_mul(purchase_amount) / price_divisor).unwrap_or(true),
+                max_price.map(|max_price| cost <= max_price.saturating_mul(purchase_amount) / PRICE_DIVISOR).unwrap_or(true),
                 Error::<T>::MaxPriceExceeded
             );
 
-            let legs = vec![
-                Leg::Fungible {
-                    sender: fundraiser.offering_portfolio,
-                    receiver: investment_portfolio,
-                    asset_id: fundraiser.offering_asset,
-                    amount: purchase_amount
-                },
-                Leg::Fungible {
-                    sender: funding_portfolio,
-                    receiver: fundraiser.raising_portfolio,
-                    asset_id: fundraiser.raising_asset,
-                    amount: cost
-                }
-            ];
+            let mut investment = Self::investments((offering_asset, id), did);
+            investment.offering_amount = investment.offering_amount
+                .checked_add(purchase_amount)
+                .ok_or(Error::<T>::Overflow)?;
+            investment.raising_amount = investment.raising_amount
+                .checked_add(cost)
+                .ok_or(Error::<T>::Overflow)?;
+            ensure!(
+                restrictions.max_investment.map_or(true, |max| investment.raising_amount <= max),
+                Error::<T>::MaxInvestmentExceeded
+            );
+
+            let raising_leg = Leg::Fungible {
+                sender: funding_portfolio,
+                receiver: fundraiser.raising_portfolio,
+                asset_id: fundraiser.raising_asset,
+                amount: cost
+            };
 
             with_transaction(|| {
-                <Portfolio<T>>::unlock_tokens(&fundraiser.offering_portfolio, &fundraiser.offering_asset, purchase_amount)?;
-
-                let instruction_id = Settlement::<T>::base_add_instruction(
-                    fundraiser.creator,
-                    Some(fundraiser.venue_id),
-                    SettlementType::SettleOnAffirmation,
-                    None,
-                    None,
-                    legs,
-                    None,
-                    None
-                )?;
-
-                let portfolios = [fundraiser.offering_portfolio, fundraiser.raising_portfolio].iter().copied().collect::<BTreeSet<_>>();
-                Settlement::<T>::unsafe_affirm_instruction(
-                    fundraiser.creator,
-                    instruction_id,
-                    portfolios,
-                    None,
-                    None
-                )?;
-
-                let portfolios = [investment_portfolio, funding_portfolio].iter().copied().collect::<BTreeSet<_>>();
-                Settlement::<T>::affirm_and_execute_instruction(
-                    origin,
-                    instruction_id,
-                    receipt,
-                    portfolios,
-                    did,
-                    &mut WeightMeter::max_limit_no_minimum()
-                )
+                match escrow.as_mut() {
+                    // The offering tokens stay locked and the raised funds are locked until the escrow is released.
+                    Some(escrow) => {
+                        escrow.raised = escrow.raised.checked_add(cost).ok_or(Error::<T>::Overflow)?;
+                        Self::settle_with_investor(
+                            origin,
+                            did,
+                            &fundraiser,
+                            vec![raising_leg],
+                            [fundraiser.raising_portfolio].into(),
+                            [funding_portfolio].into(),
+                            None
+                        )?;
+                        <Portfolio<T>>::lock_tokens(&fundraiser.raising_portfolio, &fundraiser.raising_asset, cost)
+                    }
+                    None => {
+                        <Portfolio<T>>::unlock_tokens(&fundraiser.offering_portfolio, &fundraiser.offering_asset, purchase_amount)?;
+                        let offering_leg = Leg::Fungible {
+                            sender: fundraiser.offering_portfolio,
+                            receiver: investment_portfolio,
+                            asset_id: fundraiser.offering_asset,
+                            amount: purchase_amount
+                        };
+                        Self::settle_with_investor(
+                            origin,
+                            did,
+                            &fundraiser,
+                            vec![offering_leg, raising_leg],
+                            [fundraiser.offering_portfolio, fundraiser.raising_portfolio].into(),
+                            [investment_portfolio, funding_portfolio].into(),
+                            receipt
+                        )
+                    }
+                }
             })?;
 
-            for (id, amount) in purchases {
-                fundraiser.tiers[id].remaining -= amount;
+            for (tier_id, amount) in purchases {
+                fundraiser.tiers[tier_id].remaining -= amount;
+                if restrictions.allocated_tiers.contains(&(tier_id as u32)) {
+                    TierAllocations::mutate((offering_asset, id), (tier_id as u32, did), |allocation| *allocation -= amount);
+                }
+            }
+            Investments::insert((offering_asset, id), did, investment);
+            if let Some(escrow) = escrow {
+                EscrowedInvestments::mutate((offering_asset, id), did, |escrowed| {
+                    escrowed.offering_amount = escrowed.offering_amount.saturating_add(purchase_amount);
+                    escrowed.raising_amount = escrowed.raising_amount.saturating_add(cost);
+                });
+                FundraiserEscrows::insert(offering_asset, id, escrow);
             }
 
             Self::deposit_event(RawEvent::Invested(did, id, offering_asset, fundraiser.raising_asset, purchase_amount, cost));
@@ -577,6 +742,11 @@ decl_module! {
                 if let Some(end) = end {
                     ensure!(start < end, Error::<T>::InvalidOfferingWindow);
                 }
+                // Escrowed funds can not be locked forever.
+                ensure!(
+                    end.is_some() || !FundraiserEscrows::contains_key(offering_asset, id),
+                    Error::<T>::EscrowRequiresEnd
+                );
                 Self::deposit_event(RawEvent::FundraiserWindowModified(did, id, fundraiser.start, fundraiser.end, start, end));
                 fundraiser.start = start;
                 fundraiser.end = end;
@@ -612,13 +782,442 @@ decl_module! {
                 Some(end) if end > Timestamp::<T>::get() => FundraiserStatus::ClosedEarly,
                 _ => FundraiserStatus::Closed,
             };
-            <Fundraisers<T>>::insert(offering_asset, id, fundraiser);
             Self::deposit_event(RawEvent::FundraiserClosed(did, id));
+            // Stopping an escrowed fundraiser settles its escrow.
+            if FundraiserEscrows::contains_key(offering_asset, id) {
+                Self::ensure_escrow_finalized(offering_asset, id, &fundraiser)?;
+            }
+            <Fundraisers<T>>::insert(offering_asset, id, fundraiser);
+        }
+
+        /// Set the restrictions applied to the investors of a fundraiser.
+        ///
+        /// * `offering_asset` - Asset of the fundraiser.
+        /// * `id` - ID of the fundraiser.
+        /// * `restrictions` - Maximum investment per identity, tiers restricted to their allocations
+        ///    and early-access round, replacing the current restrictions.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::set_investor_restrictions(
+            restrictions.early_access.as_ref().map_or(0, |early_access| early_access.issuers.len() as u32)
+        )]
+        pub fn set_investor_restrictions(
+            origin,
+            offering_asset: AssetId,
+            id: FundraiserId,
+            restrictions: InvestorRestrictions<T::Moment>,
+        ) {
+            let did = <ExternalAgents<T>>::ensure_perms(origin, offering_asset)?;
+            let fundraiser = Self::ensure_fundraiser(offering_asset, id)?;
+            ensure!(!fundraiser.is_closed(), Error::<T>::FundraiserClosed);
+
+            ensure!(
+                restrictions.allocated_tiers.iter().all(|tier| (*tier as usize) < fundraiser.tiers.len()),
+                Error::<T>::InvalidPriceTiers
+            );
+            if let Some(early_access) = &restrictions.early_access {
+                ensure!(
+                    early_access.start < fundraiser.start
+                        && (1..=MAX_EARLY_ACCESS_ISSUERS).contains(&early_access.issuers.len()),
+                    Error::<T>::InvalidEarlyAccess
+                );
+            }
+
+            FundraiserRestrictions::<T>::insert(offering_asset, id, restrictions.clone());
+            Self::deposit_event(RawEvent::InvestorRestrictionsUpdated(did, id, restrictions));
+        }
+
+        /// Set the allocations of a fundraiser tier.
+        /// The allocations only apply while the tier is listed in the `allocated_tiers` of the fundraiser.
+        ///
+        /// * `offering_asset` - Asset of the fundraiser.
+        /// * `id` - ID of the fundraiser.
+        /// * `tier` - Index of the tier.
+        /// * `allocations` - Amount of the tier each identity can buy, a zero amount removes the allocation.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::set_tier_allocations(allocations.len() as u32)]
+        pub fn set_tier_allocations(
+            origin,
+            offering_asset: AssetId,
+            id: FundraiserId,
+            tier: u32,
+            allocations: Vec<(IdentityId, Balance)>,
+        ) {
+            let did = <ExternalAgents<T>>::ensure_perms(origin, offering_asset)?;
+            let fundraiser = Self::ensure_fundraiser(offering_asset, id)?;
+            ensure!(!fundraiser.is_closed(), Error::<T>::FundraiserClosed);
+            ensure!((tier as usize) < fundraiser.tiers.len(), Error::<T>::InvalidPriceTiers);
+
+            for &(investor, allocation) in &allocations {
+                if allocation == 0u32.into() {
+                    TierAllocations::remove((offering_asset, id), (tier, investor));
+                } else {
+                    TierAllocations::insert((offering_asset, id), (tier, investor), allocation);
+                }
+            }
+            Self::deposit_event(RawEvent::TierAllocationsUpdated(did, id, tier, allocations));
+        }
+
+        /// Create a new fundraiser whose raised funds are escrowed until it closes.
+        ///
+        /// Investors only receive the offering tokens, through `claim_offering`, if at least `soft_cap`
+        /// was raised when the fundraiser closes. Otherwise, they can get their funds back through `refund`.
+        ///
+        /// * `offering_portfolio` - Portfolio containing the `offering_asset`.
+        /// * `offering_asset` - Asset being offered.
+        /// * `raising_portfolio` - Dedicated portfolio holding the escrowed `raising_asset`.
+        ///    It must be a user portfolio, without any `raising_asset`, that isn't used by another fundraiser.
+        /// * `raising_asset` - Asset being exchanged for `offering_asset` on investment.
+        /// * `tiers` - Price tiers to charge investors on investment.
+        /// * `venue_id` - Venue to handle settlement.
+        /// * `start` - Fundraiser start time, if `None` the fundraiser will start immediately.
+        /// * `end` - Fundraiser end time.
+        /// * `minimum_investment` - Minimum amount of `raising_asset` that an investor needs to spend to invest in this raise.
+        /// * `fundraiser_name` - Fundraiser name, only used in the UIs.
+        /// * `soft_cap` - Minimum amount of `raising_asset` to raise for the offering tokens to be released.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::create_escrowed_fundraiser(tiers.len() as u32)]
+        pub fn create_escrowed_fundraiser(
+            origin,
+            offering_portfolio: PortfolioId,
+            offering_asset: AssetId,
+            raising_portfolio: PortfolioId,
+            raising_asset: AssetId,
+            tiers: Vec<PriceTier>,
+            venue_id: VenueId,
+            start: Option<T::Moment>,
+            end: T::Moment,
+            minimum_investment: Balance,
+            fundraiser_name: FundraiserName,
+            soft_cap: Balance
+        ) {
+            // The soft cap must be reachable by selling every tier.
+            let hard_cap = tiers
+                .iter()
+                .try_fold(0, |total: Balance, tier| {
+                    tier.total
+                        .checked_mul(tier.price)
+                        .map(|cost| cost / PRICE_DIVISOR)
+                        .and_then(|cost| total.checked_add(cost))
+                })
+                .ok_or(Error::<T>::InvalidPriceTiers)?;
+            ensure!(soft_cap > 0 && soft_cap <= hard_cap, Error::<T>::InvalidSoftCap);
+
+            // The escrowed funds can't be mixed with other funds of the raising asset.
+            ensure!(
+                raising_portfolio.kind != PortfolioKind::Default
+                    && raising_portfolio != offering_portfolio
+                    && <Portfolio<T>>::portfolio_asset_balances(raising_portfolio, raising_asset) == 0,
+                Error::<T>::EscrowPortfolioNotDedicated
+            );
+
+            let (did, id) = Self::base_create_fundraiser(
+                origin,
+                offering_portfolio,
+                offering_asset,
+                raising_portfolio,
+                raising_asset,
+                tiers,
+                venue_id,
+                start,
+                Some(end),
+                minimum_investment,
+                fundraiser_name
+            )?;
+            FundraiserEscrows::insert(offering_asset, id, FundraiserEscrow {
+                soft_cap,
+                ..FundraiserEscrow::default()
+            });
+            EscrowPortfolios::insert(raising_portfolio, (offering_asset, id));
+            Self::deposit_event(RawEvent::FundraiserEscrowCreated(did, id, soft_cap));
+        }
+
+        /// Receive the offering tokens bought in an escrowed fundraiser that reached its soft cap.
+        ///
+        /// * `investment_portfolio` - Portfolio that `offering_asset` will be deposited in.
+        /// * `offering_asset` - Asset of the fundraiser.
+        /// * `id` - ID of the fundraiser.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::claim_offering()]
+        pub fn claim_offering(
+            origin,
+            investment_portfolio: PortfolioId,
+            offering_asset: AssetId,
+            id: FundraiserId
+        ) {
+            let PermissionedCallOriginData {
+                primary_did: did,
+                secondary_key,
+                ..
+            } = Identity::<T>::ensure_origin_call_permissions(origin.clone())?;
+            <Portfolio<T>>::ensure_portfolio_custody_and_permission(investment_portfolio, did, secondary_key.as_ref())?;
+
+            let fundraiser = Self::ensure_fundraiser(offering_asset, id)?;
+            let escrow = Self::ensure_escrow_finalized(offering_asset, id, &fundraiser)?;
+            ensure!(escrow.status == EscrowStatus::Released, Error::<T>::SoftCapNotReached);
+            let investment = EscrowedInvestments::take((offering_asset, id), did);
+            ensure!(investment.offering_amount > 0, Error::<T>::NoEscrowedInvestment);
+
+            <Portfolio<T>>::unlock_tokens(&fundraiser.offering_portfolio, &fundraiser.offering_asset, investment.offering_amount)?;
+            let leg = Leg::Fungible {
+                sender: fundraiser.offering_portfolio,
+                receiver: investment_portfolio,
+                asset_id: fundraiser.offering_asset,
+                amount: investment.offering_amount
+            };
+            Self::settle_with_investor(
+                origin,
+                did,
+                &fundraiser,
+                vec![leg],
+                [fundraiser.offering_portfolio].into(),
+                [investment_portfolio].into(),
+                None
+            )?;
+
+            Self::deposit_event(RawEvent::OfferingClaimed(did, id, investment.offering_amount));
+        }
+
+        /// Get refunded from an escrowed fundraiser that did not reach its soft cap.
+        ///
+        /// * `funding_portfolio` - Portfolio that the refunded `raising_asset` will be deposited in.
+        /// * `offering_asset` - Asset of the fundraiser.
+        /// * `id` - ID of the fundraiser.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::refund()]
+        pub fn refund(
+            origin,
+            funding_portfolio: PortfolioId,
+            offering_asset: AssetId,
+            id: FundraiserId
+        ) {
+            let PermissionedCallOriginData {
+                primary_did: did,
+                secondary_key,
+                ..
+            } = Identity::<T>::ensure_origin_call_permissions(origin.clone())?;
+            <Portfolio<T>>::ensure_portfolio_custody_and_permission(funding_portfolio, did, secondary_key.as_ref())?;
+
+            let fundraiser = Self::ensure_fundraiser(offering_asset, id)?;
+            let escrow = Self::ensure_escrow_finalized(offering_asset, id, &fundraiser)?;
+            ensure!(escrow.status == EscrowStatus::Refunding, Error::<T>::SoftCapReached);
+            let investment = EscrowedInvestments::take((offering_asset, id), did);
+            ensure!(investment.raising_amount > 0, Error::<T>::NoEscrowedInvestment);
+
+            <Portfolio<T>>::unlock_tokens(&fundraiser.raising_portfolio, &fundraiser.raising_asset, investment.raising_amount)?;
+            let leg = Leg::Fungible {
+                sender: fundraiser.raising_portfolio,
+                receiver: funding_portfolio,
+                asset_id: fundraiser.raising_asset,
+                amount: investment.raising_amount
+            };
+            Self::settle_with_investor(
+                origin,
+                did,
+                &fundraiser,
+                vec![leg],
+                [fundraiser.raising_portfolio].into(),
+                [funding_portfolio].into(),
+                None
+            )?;
+
+            Self::deposit_event(RawEvent::InvestmentRefunded(did, id, investment.raising_amount));
         }
     }
 }
 
 impl<T: Config> Module<T> {
+    fn base_create_fundraiser(
+        origin: T::RuntimeOrigin,
+        offering_portfolio: PortfolioId,
+        offering_asset: AssetId,
+        raising_portfolio: PortfolioId,
+        raising_asset: AssetId,
+        tiers: Vec<PriceTier>,
+        venue_id: VenueId,
+        start: Option<T::Moment>,
+        end: Option<T::Moment>,
+        minimum_investment: Balance,
+        fundraiser_name: FundraiserName,
+    ) -> Result<(IdentityId, FundraiserId), DispatchError> {
+        pallet_base::ensure_string_limited::<T>(&fundraiser_name)?;
+
+        let PermissionedCallOriginData {
+            primary_did: did,
+            secondary_key,
+            ..
+        } = <ExternalAgents<T>>::ensure_agent_asset_perms(origin, offering_asset)?;
+
+        VenueInfo::get(venue_id)
+            .filter(|v| v.creator == did && v.venue_type == VenueType::Sto)
+            .ok_or(Error::<T>::InvalidVenue)?;
+
+        <Portfolio<T>>::ensure_portfolio_custody_and_permission(
+            raising_portfolio,
+            did,
+            secondary_key.as_ref(),
+        )?;
+        <Portfolio<T>>::ensure_portfolio_custody_and_permission(
+            offering_portfolio,
+            did,
+            secondary_key.as_ref(),
+        )?;
+        ensure!(
+            !EscrowPortfolios::contains_key(raising_portfolio),
+            Error::<T>::EscrowPortfolioNotDedicated
+        );
+
+        // Ensure there are [1, MAX_TIERS] tiers and that all of their totals are non-zero.
+        let mut totals = tiers.iter().map(|t| t.total);
+        ensure!(
+            (1..=MAX_TIERS).contains(&tiers.len()) && totals.clone().all(|t| t > 0),
+            Error::<T>::InvalidPriceTiers
+        );
+
+        // Sum all totals, or bail on overflow.
+        let offering_amount = totals
+            .try_fold(0, |total: Balance, x| total.checked_add(x))
+            .ok_or(Error::<T>::InvalidPriceTiers)?;
+
+        // Use current time if start isn't provided.
+        let start = start.unwrap_or_else(Timestamp::<T>::get);
+        // The start must come strictly before the end.
+        if let Some(end) = end {
+            ensure!(start < end, Error::<T>::InvalidOfferingWindow);
+        }
+
+        // Get the next fundraiser ID.
+        let mut seq = FundraiserCount::get(&offering_asset);
+        let id = try_next_post::<T, _>(&mut seq)?;
+
+        <Portfolio<T>>::lock_tokens(&offering_portfolio, &offering_asset, offering_amount)?;
+
+        let fundraiser = Fundraiser {
+            creator: did,
+            offering_portfolio,
+            offering_asset,
+            raising_portfolio,
+            raising_asset,
+            tiers: tiers.into_iter().map(Into::into).collect(),
+            venue_id,
+            start,
+            end,
+            status: FundraiserStatus::Live,
+            minimum_investment,
+        };
+
+        FundraiserCount::insert(offering_asset, seq);
+        Fundraisers::<T>::insert(offering_asset, id, fundraiser.clone());
+        FundraiserNames::insert(offering_asset, id, fundraiser_name.clone());
+
+        Self::deposit_event(RawEvent::FundraiserCreated(
+            did,
+            id,
+            fundraiser_name,
+            fundraiser,
+        ));
+        Ok((did, id))
+    }
+
+    /// Adds an instruction with `legs` in the venue of `fundraiser`, affirms it for the
+    /// `creator_portfolios` and executes it with the affirmation of the investor.
+    fn settle_with_investor(
+        origin: T::RuntimeOrigin,
+        did: IdentityId,
+        fundraiser: &Fundraiser<T::Moment>,
+        legs: Vec<Leg>,
+        creator_portfolios: BTreeSet<PortfolioId>,
+        investor_portfolios: BTreeSet<PortfolioId>,
+        receipt: Option<ReceiptDetails<T::AccountId, T::OffChainSignature>>,
+    ) -> DispatchResult {
+        let instruction_id = Settlement::<T>::base_add_instruction(
+            fundraiser.creator,
+            Some(fundraiser.venue_id),
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+            None,
+            None,
+        )?;
+        Settlement::<T>::unsafe_affirm_instruction(
+            fundraiser.creator,
+            instruction_id,
+            creator_portfolios,
+            None,
+            None,
+        )?;
+        Settlement::<T>::affirm_and_execute_instruction(
+            origin,
+            instruction_id,
+            receipt,
+            investor_portfolios,
+            did,
+            &mut WeightMeter::max_limit_no_minimum(),
+        )
+    }
+
+    /// Returns the escrow of `fundraiser`, releasing it or opening it for refunds once the fundraiser has closed.
+    fn ensure_escrow_finalized(
+        offering_asset: AssetId,
+        id: FundraiserId,
+        fundraiser: &Fundraiser<T::Moment>,
+    ) -> Result<FundraiserEscrow, DispatchError> {
+        let mut escrow =
+            Self::fundraiser_escrow(offering_asset, id).ok_or(Error::<T>::EscrowNotFound)?;
+        if escrow.status != EscrowStatus::Open {
+            return Ok(escrow);
+        }
+
+        let expired = fundraiser
+            .end
+            .map_or(false, |end| Timestamp::<T>::get() >= end);
+        ensure!(
+            fundraiser.is_closed() || expired,
+            Error::<T>::EscrowStillOpen
+        );
+
+        if escrow.raised >= escrow.soft_cap {
+            // The raised funds are released, the offering tokens stay locked until claimed.
+            <Portfolio<T>>::unlock_tokens(
+                &fundraiser.raising_portfolio,
+                &fundraiser.raising_asset,
+                escrow.raised,
+            )?;
+            escrow.status = EscrowStatus::Released;
+        } else {
+            // The sold offering tokens are released, the raised funds stay locked until refunded.
+            let sold = fundraiser.tiers.iter().fold(0, |sold: Balance, tier| {
+                sold.saturating_add(tier.total.saturating_sub(tier.remaining))
+            });
+            <Portfolio<T>>::unlock_tokens(
+                &fundraiser.offering_portfolio,
+                &fundraiser.offering_asset,
+                sold,
+            )?;
+            escrow.status = EscrowStatus::Refunding;
+        }
+        FundraiserEscrows::insert(offering_asset, id, escrow);
+        EscrowPortfolios::remove(fundraiser.raising_portfolio);
+        Self::deposit_event(RawEvent::FundraiserEscrowFinalized(
+            id,
+            escrow.status,
+            escrow.raised,
+        ));
+        Ok(escrow)
+    }
+
     fn set_frozen(
         origin: T::RuntimeOrigin,
         offering_asset: AssetId,
@@ -639,6 +1238,17 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Returns `true` if `did` holds the claim required by `early_access` from one of its issuers.
+    fn has_early_access(did: IdentityId, early_access: &EarlyAccess<T::Moment>) -> bool {
+        let claim_type = early_access.claim.claim_type();
+        let scope = early_access.claim.as_scope().cloned();
+        early_access.issuers.iter().any(|issuer| {
+            Identity::<T>::fetch_claim(did, claim_type, *issuer, scope.clone())
+                .filter(|identity_claim| identity_claim.claim == early_access.claim)
+                .is_some()
+        })
+    }
+
     fn ensure_fundraiser(
         asset_id: AssetId,
         id: FundraiserId,
@@ -0,0 +1,104 @@
+use frame_benchmarking::benchmarks;
+use scale_info::prelude::format;
+use sp_std::vec;
+
+use pallet_asset::benchmarking::setup_asset_transfer;
+use polymesh_common_utilities::benchs::{AccountIdOf, User, UserBuilder};
+use polymesh_common_utilities::TestUtilsFn;
+
+use crate::*;
+
+pub type Timestamp<T> = pallet_timestamp::Pallet<T>;
+
+const GRANT_AMOUNT: Balance = 1_000;
+
+fn linear_schedule() -> VestingSchedule {
+    VestingSchedule {
+        start: 0,
+        cliff: 0,
+        end: 1_000,
+        release: VestingRelease::Linear,
+    }
+}
+
+/// Creates an asset and a pre-approved beneficiary portfolio, returning the agent, the agent's portfolio,
+/// the beneficiary portfolio and the asset.
+fn setup_vesting<T>() -> (User<T>, PortfolioId, PortfolioId, AssetId)
+where
+    T: Config + TestUtilsFn<AccountIdOf<T>>,
+{
+    let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+    let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+    let (alice_portfolio, bob_portfolio, _, asset_id) = setup_asset_transfer(
+        &alice,
+        &bob,
+        Some(&format!("SdrPortfolio{:?}", alice.did())),
+        Some(&format!("RcvPortfolio{:?}", bob.did())),
+        true,
+        true,
+        0,
+    );
+    Asset::<T>::pre_approve_asset(bob.origin().into(), asset_id).unwrap();
+    (alice, alice_portfolio, bob_portfolio, asset_id)
+}
+
+/// Creates a single linear grant of `GRANT_AMOUNT` to `beneficiary`, half vested.
+fn setup_half_vested_grant<T>() -> (User<T>, PortfolioId, PortfolioId)
+where
+    T: Config + TestUtilsFn<AccountIdOf<T>>,
+{
+    let (alice, alice_portfolio, bob_portfolio, asset_id) = setup_vesting::<T>();
+    Module::<T>::create_vesting_grants(
+        alice.origin().into(),
+        asset_id,
+        alice_portfolio.kind,
+        vec![VestingGrantSpec {
+            beneficiary: bob_portfolio,
+            amount: GRANT_AMOUNT,
+            schedule: linear_schedule(),
+        }],
+    )
+    .unwrap();
+    <Timestamp<T>>::set_timestamp(500u32.into());
+    (alice, alice_portfolio, bob_portfolio)
+}
+
+benchmarks! {
+    where_clause { where T: TestUtilsFn<AccountIdOf<T>> }
+
+    create_vesting_grants {
+        let g in 1..MAX_GRANTS_PER_BATCH as u32;
+
+        let (alice, alice_portfolio, bob_portfolio, asset_id) = setup_vesting::<T>();
+        let grants = (0..g)
+            .map(|_| VestingGrantSpec {
+                beneficiary: bob_portfolio,
+                amount: GRANT_AMOUNT,
+                schedule: linear_schedule(),
+            })
+            .collect::<Vec<_>>();
+    }: _(alice.origin(), asset_id, alice_portfolio.kind, grants)
+    verify {
+        assert_eq!(
+            VestingLocked::get(bob_portfolio, asset_id),
+            GRANT_AMOUNT * g as Balance
+        );
+    }
+
+    release_vested {
+        let (alice, _, bob_portfolio) = setup_half_vested_grant::<T>();
+    }: _(alice.origin(), bob_portfolio, VestingGrantId(0))
+    verify {
+        assert_eq!(
+            VestingGrants::get(bob_portfolio, VestingGrantId(0)).unwrap().released,
+            GRANT_AMOUNT / 2
+        );
+    }
+
+    revoke_vesting_grant {
+        let (alice, alice_portfolio, bob_portfolio) = setup_half_vested_grant::<T>();
+    }: _(alice.origin(), bob_portfolio, VestingGrantId(0), alice_portfolio.kind)
+    verify {
+        assert!(VestingGrants::get(bob_portfolio, VestingGrantId(0)).is_none());
+    }
+}
@@ -0,0 +1,470 @@
+// Copyright (c) 2020 Polymesh Association
+
+//! # Vesting Module
+//!
+//! The vesting module locks asset balances in portfolios and releases them over time.
+//!
+//! ## Overview
+//!
+//! Sufficiently permissioned external agents of an asset can grant vested tokens to investors.
+//! Granted tokens are transferred from one of the agent's portfolios to the beneficiary portfolio
+//! and locked there, following the usual compliance rules of the asset.
+//! Each grant follows a [`VestingSchedule`]: nothing vests before its cliff, everything is vested at its end
+//! and in between tokens are released either linearly or in fixed steps.
+//!
+//! Locked tokens still belong to the beneficiary, so they count for checkpoints, corporate actions and voting,
+//! but they can't be moved out of the portfolio until they are vested and released.
+//! Agents can revoke a grant, which releases the vested tokens and returns the unvested ones to the agent.
+//!
+//! ## Dispatchable Functions
+//!
+//! - `create_vesting_grants` - Create a batch of vesting grants of an asset.
+//! - `release_vested` - Unlock the vested tokens of a grant.
+//! - `revoke_vesting_grant` - Revoke a grant, returning its unvested tokens to the agent.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+use codec::{Decode, Encode};
+use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+use pallet_asset::MandatoryMediators;
+use pallet_base::try_next_post;
+use polymesh_common_utilities::traits::asset::AssetFnTrait;
+use polymesh_common_utilities::traits::portfolio::PortfolioSubTrait;
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::impl_checked_inc;
+use polymesh_primitives::{Balance, IdentityId, Moment, PortfolioId, PortfolioKind, WeightMeter};
+
+/// The maximum number of grants that can be created in a single call.
+pub const MAX_GRANTS_PER_BATCH: usize = 100;
+
+type Asset<T> = pallet_asset::Module<T>;
+type Checkpoint<T> = pallet_asset::checkpoint::Module<T>;
+type Identity<T> = pallet_identity::Module<T>;
+type Portfolio<T> = pallet_portfolio::Module<T>;
+
+/// The global ID of a vesting grant.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+pub struct VestingGrantId(pub u64);
+impl_checked_inc!(VestingGrantId);
+
+/// How the tokens of a grant are released between its cliff and its end.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VestingRelease {
+    /// Tokens vest continuously over time.
+    Linear,
+    /// Tokens vest in equal steps, each lasting the given number of milliseconds.
+    Stepped(Moment),
+}
+
+/// The schedule followed by a vesting grant.
+/// All moments are unix timestamps in milliseconds.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct VestingSchedule {
+    /// When the vesting starts accruing.
+    pub start: Moment,
+    /// No tokens are vested before the cliff.
+    /// Once the cliff is reached, everything accrued since `start` is vested.
+    pub cliff: Moment,
+    /// When all tokens are vested.
+    pub end: Moment,
+    /// How tokens are released between `start` and `end`.
+    pub release: VestingRelease,
+}
+
+impl VestingSchedule {
+    /// Returns `true` if `start <= cliff <= end`, `start < end` and steps are non-empty and fit the schedule.
+    pub fn is_valid(&self) -> bool {
+        if !(self.start <= self.cliff && self.cliff <= self.end && self.start < self.end) {
+            return false;
+        }
+        match self.release {
+            VestingRelease::Linear => true,
+            VestingRelease::Stepped(step) => step > 0 && step <= self.end - self.start,
+        }
+    }
+
+    /// Returns how much of `total` is vested at `now`.
+    pub fn vested(&self, total: Balance, now: Moment) -> Balance {
+        if now < self.cliff {
+            return 0;
+        }
+        if now >= self.end {
+            return total;
+        }
+        let mut elapsed = now.saturating_sub(self.start);
+        if let VestingRelease::Stepped(step) = self.release {
+            elapsed -= elapsed % step;
+        }
+        // Can't overflow for any realistic supply, since `elapsed` is bounded by the duration.
+        total.saturating_mul(elapsed.into()) / Balance::from(self.end - self.start)
+    }
+}
+
+/// The parameters of a grant to be created.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct VestingGrantSpec {
+    /// The portfolio that receives the tokens.
+    pub beneficiary: PortfolioId,
+    /// The total amount of tokens granted.
+    pub amount: Balance,
+    /// The vesting schedule of the grant.
+    pub schedule: VestingSchedule,
+}
+
+/// Details about a vesting grant.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct VestingGrant {
+    /// The asset being vested.
+    pub asset_id: AssetId,
+    /// The agent that created the grant.
+    pub grantor: IdentityId,
+    /// The total amount of tokens granted.
+    pub total: Balance,
+    /// The amount of tokens already released.
+    pub released: Balance,
+    /// The vesting schedule of the grant.
+    pub schedule: VestingSchedule,
+}
+
+impl VestingGrant {
+    /// Returns the amount of tokens that are vested at `now` but still locked.
+    pub fn releasable(&self, now: Moment) -> Balance {
+        self.schedule
+            .vested(self.total, now)
+            .saturating_sub(self.released)
+    }
+
+    /// Returns the amount of tokens still locked by the grant.
+    pub fn locked(&self) -> Balance {
+        self.total.saturating_sub(self.released)
+    }
+}
+
+pub trait WeightInfo {
+    fn create_vesting_grants(g: u32) -> Weight;
+    fn release_vested() -> Weight;
+    fn revoke_vesting_grant() -> Weight;
+}
+
+pub trait Config: frame_system::Config + pallet_asset::Config {
+    /// The overarching event type.
+    type RuntimeEvent: From<Event> + Into<<Self as frame_system::Config>::RuntimeEvent>;
+    /// Weight information for extrinsic of the vesting pallet.
+    type WeightInfo: WeightInfo;
+}
+
+decl_event!(
+    pub enum Event {
+        /// A vesting grant has been created.
+        /// (Agent DID, beneficiary portfolio, grant id, grant details)
+        VestingGrantCreated(IdentityId, PortfolioId, VestingGrantId, VestingGrant),
+        /// Vested tokens of a grant have been unlocked.
+        /// (Caller DID, beneficiary portfolio, grant id, released amount)
+        VestedTokensReleased(IdentityId, PortfolioId, VestingGrantId, Balance),
+        /// A vesting grant has been revoked and its unvested tokens returned to the agent.
+        /// (Agent DID, beneficiary portfolio, grant id, agent portfolio, revoked amount)
+        VestingGrantRevoked(
+            IdentityId,
+            PortfolioId,
+            VestingGrantId,
+            PortfolioId,
+            Balance,
+        ),
+    }
+);
+
+decl_error! {
+    /// Errors for the Vesting module.
+    pub enum Error for Module<T: Config> {
+        /// The vesting grant does not exist.
+        NoSuchVestingGrant,
+        /// The schedule is invalid, e.g. the cliff is after the end or a step is zero.
+        InvalidVestingSchedule,
+        /// A grant must have a non-zero amount.
+        ZeroVestingAmount,
+        /// The number of grants in the batch is zero or exceeds `MAX_GRANTS_PER_BATCH`.
+        InvalidNumberOfGrants,
+        /// The beneficiary must pre-approve the asset, or be in the custody of the agent.
+        BeneficiaryNotPreApproved,
+        /// Assets with mandatory mediators can't be granted outside of settlement.
+        AssetRequiresMediation,
+        /// No tokens of the grant can be released yet.
+        NothingToRelease
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Config> as Vesting {
+        /// All active vesting grants.
+        /// (beneficiary portfolio, grant id) -> VestingGrant
+        VestingGrants get(fn vesting_grants):
+            double_map
+                hasher(blake2_128_concat) PortfolioId,
+                hasher(twox_64_concat) VestingGrantId
+                => Option<VestingGrant>;
+
+        /// The amount of tokens locked by vesting grants.
+        /// (beneficiary portfolio, asset_id) -> locked balance
+        VestingLocked get(fn vesting_locked):
+            double_map
+                hasher(blake2_128_concat) PortfolioId,
+                hasher(blake2_128_concat) AssetId
+                => Balance;
+
+        /// The next id to use for a vesting grant.
+        NextVestingGrantId get(fn next_vesting_grant_id): VestingGrantId;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Config> for enum Call where origin: <T as frame_system::Config>::RuntimeOrigin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// Creates a batch of vesting grants of `asset_id`.
+        ///
+        /// The tokens of each grant are transferred from the caller's `portfolio_kind` to the beneficiary portfolio,
+        /// following the compliance rules of the asset, and are locked there until they are released.
+        ///
+        /// * `asset_id` - the [`AssetId`] of the granted tokens.
+        /// * `portfolio_kind` - the caller's portfolio funding the grants.
+        /// * `grants` - the beneficiary, amount and schedule of each grant.
+        ///
+        /// ## Errors
+        /// - `InvalidNumberOfGrants` - if `grants` is empty or has more than `MAX_GRANTS_PER_BATCH` entries.
+        /// - `InvalidVestingSchedule` - if the schedule of a grant is not valid.
+        /// - `ZeroVestingAmount` - if the amount of a grant is zero.
+        /// - `BeneficiaryNotPreApproved` - if a beneficiary has not pre-approved the asset.
+        /// - `AssetRequiresMediation` - if the asset has mandatory mediators.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::create_vesting_grants(grants.len() as u32)]
+        pub fn create_vesting_grants(
+            origin,
+            asset_id: AssetId,
+            portfolio_kind: PortfolioKind,
+            grants: Vec<VestingGrantSpec>
+        ) -> DispatchResult {
+            Self::base_create_vesting_grants(origin, asset_id, portfolio_kind, grants)
+        }
+
+        /// Unlocks the vested tokens of a grant that haven't been released yet.
+        /// The grant is removed once all of its tokens have been released.
+        ///
+        /// * `portfolio` - the beneficiary portfolio of the grant.
+        /// * `grant_id` - the [`VestingGrantId`] of the grant.
+        ///
+        /// ## Errors
+        /// - `NoSuchVestingGrant` - if the grant does not exist.
+        /// - `NothingToRelease` - if no tokens can be released yet.
+        #[weight = <T as Config>::WeightInfo::release_vested()]
+        pub fn release_vested(origin, portfolio: PortfolioId, grant_id: VestingGrantId) -> DispatchResult {
+            Self::base_release_vested(origin, portfolio, grant_id)
+        }
+
+        /// Revokes a grant of an asset the caller is an agent of.
+        ///
+        /// The tokens vested so far are released to the beneficiary, while the unvested tokens
+        /// are transferred back to the caller's `portfolio_kind`.
+        ///
+        /// * `portfolio` - the beneficiary portfolio of the grant.
+        /// * `grant_id` - the [`VestingGrantId`] of the grant.
+        /// * `portfolio_kind` - the caller's portfolio receiving the unvested tokens.
+        ///
+        /// ## Errors
+        /// - `NoSuchVestingGrant` - if the grant does not exist.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::revoke_vesting_grant()]
+        pub fn revoke_vesting_grant(
+            origin,
+            portfolio: PortfolioId,
+            grant_id: VestingGrantId,
+            portfolio_kind: PortfolioKind
+        ) -> DispatchResult {
+            Self::base_revoke_vesting_grant(origin, portfolio, grant_id, portfolio_kind)
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    fn base_create_vesting_grants(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_kind: PortfolioKind,
+        grants: Vec<VestingGrantSpec>,
+    ) -> DispatchResult {
+        let grantor_portfolio = Asset::<T>::ensure_origin_asset_and_portfolio_permissions(
+            origin,
+            asset_id,
+            portfolio_kind,
+            true,
+        )?;
+        let did = grantor_portfolio.did;
+
+        ensure!(
+            (1..=MAX_GRANTS_PER_BATCH).contains(&grants.len()),
+            Error::<T>::InvalidNumberOfGrants
+        );
+        // Mediated assets can only be moved through settlement
+        ensure!(
+            MandatoryMediators::<T>::get(asset_id).is_empty(),
+            Error::<T>::AssetRequiresMediation
+        );
+
+        let mut seq = NextVestingGrantId::get();
+        for spec in grants {
+            ensure!(spec.schedule.is_valid(), Error::<T>::InvalidVestingSchedule);
+            ensure!(spec.amount > 0, Error::<T>::ZeroVestingAmount);
+            // Tokens are pushed to the beneficiary, who must accept them without affirming.
+            ensure!(
+                Portfolio::<T>::ensure_portfolio_custody(spec.beneficiary, did).is_ok()
+                    || Asset::<T>::skip_asset_affirmation(&spec.beneficiary.did, &asset_id),
+                Error::<T>::BeneficiaryNotPreApproved
+            );
+
+            Asset::<T>::base_transfer(
+                grantor_portfolio,
+                spec.beneficiary,
+                asset_id,
+                spec.amount,
+                None,
+                None,
+                did,
+                &mut WeightMeter::max_limit_no_minimum(),
+            )?;
+            Portfolio::<T>::lock_tokens(&spec.beneficiary, &asset_id, spec.amount)?;
+            VestingLocked::mutate(spec.beneficiary, asset_id, |locked| {
+                *locked = locked.saturating_add(spec.amount)
+            });
+
+            let id = try_next_post::<T, _>(&mut seq)?;
+            let grant = VestingGrant {
+                asset_id,
+                grantor: did,
+                total: spec.amount,
+                released: 0,
+                schedule: spec.schedule,
+            };
+            VestingGrants::insert(spec.beneficiary, id, grant);
+            Self::deposit_event(RawEvent::VestingGrantCreated(
+                did,
+                spec.beneficiary,
+                id,
+                grant,
+            ));
+        }
+        NextVestingGrantId::put(seq);
+        Ok(())
+    }
+
+    fn base_release_vested(
+        origin: T::RuntimeOrigin,
+        portfolio: PortfolioId,
+        grant_id: VestingGrantId,
+    ) -> DispatchResult {
+        let caller_did = Identity::<T>::ensure_perms(origin)?;
+        let mut grant =
+            VestingGrants::get(portfolio, grant_id).ok_or(Error::<T>::NoSuchVestingGrant)?;
+
+        let amount = grant.releasable(Checkpoint::<T>::now_unix());
+        ensure!(amount > 0, Error::<T>::NothingToRelease);
+
+        Self::unlock(&portfolio, &grant.asset_id, amount)?;
+        grant.released = grant.released.saturating_add(amount);
+        if grant.locked() == 0 {
+            VestingGrants::remove(portfolio, grant_id);
+        } else {
+            VestingGrants::insert(portfolio, grant_id, grant);
+        }
+
+        Self::deposit_event(RawEvent::VestedTokensReleased(
+            caller_did, portfolio, grant_id, amount,
+        ));
+        Ok(())
+    }
+
+    fn base_revoke_vesting_grant(
+        origin: T::RuntimeOrigin,
+        portfolio: PortfolioId,
+        grant_id: VestingGrantId,
+        portfolio_kind: PortfolioKind,
+    ) -> DispatchResult {
+        let grant =
+            VestingGrants::get(portfolio, grant_id).ok_or(Error::<T>::NoSuchVestingGrant)?;
+        let agent_portfolio = Asset::<T>::ensure_origin_asset_and_portfolio_permissions(
+            origin,
+            grant.asset_id,
+            portfolio_kind,
+            true,
+        )?;
+
+        // Everything still locked by the grant is unlocked, vested tokens stay with the beneficiary.
+        let unvested = grant.total.saturating_sub(
+            grant
+                .schedule
+                .vested(grant.total, Checkpoint::<T>::now_unix()),
+        );
+        Self::unlock(&portfolio, &grant.asset_id, grant.locked())?;
+        VestingGrants::remove(portfolio, grant_id);
+
+        // Agents reclaim unvested tokens the same way a controller transfer does.
+        if unvested > 0 {
+            let weight_meter = &mut WeightMeter::max_limit_no_minimum();
+            Asset::<T>::validate_asset_transfer(
+                grant.asset_id,
+                &portfolio,
+                &agent_portfolio,
+                unvested,
+                true,
+                weight_meter,
+            )?;
+            Asset::<T>::unverified_transfer_asset(
+                portfolio,
+                agent_portfolio,
+                grant.asset_id,
+                unvested,
+                None,
+                None,
+                agent_portfolio.did,
+                weight_meter,
+            )?;
+        }
+
+        Self::deposit_event(RawEvent::VestingGrantRevoked(
+            agent_portfolio.did,
+            portfolio,
+            grant_id,
+            agent_portfolio,
+            unvested,
+        ));
+        Ok(())
+    }
+
+    /// Unlocks `amount` of `asset_id` held in `portfolio` by vesting grants.
+    fn unlock(portfolio: &PortfolioId, asset_id: &AssetId, amount: Balance) -> DispatchResult {
+        Portfolio::<T>::unlock_tokens(portfolio, asset_id, amount)?;
+        VestingLocked::mutate(portfolio, asset_id, |locked| {
+            *locked = locked.saturating_sub(amount)
+        });
+        Ok(())
+    }
+}
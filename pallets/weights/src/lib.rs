@@ -21,6 +21,7 @@ pub mod frame_system;
 pub mod pallet_asset;
 pub mod pallet_babe;
 pub mod pallet_balances;
+pub mod pallet_bridge;
 pub mod pallet_capital_distribution;
 pub mod pallet_checkpoint;
 pub mod pallet_committee;
@@ -51,4 +52,5 @@ pub mod pallet_test_utils;
 pub mod pallet_timestamp;
 pub mod pallet_treasury;
 pub mod pallet_utility;
+pub mod pallet_vesting;
 pub mod polymesh_contracts;
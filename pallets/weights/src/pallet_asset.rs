@@ -460,12 +460,16 @@ impl pallet_asset::WeightInfo for SubstrateWeight {
     // Proof Skipped: Asset AssetMetadataGlobalKeyToName (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset AssetMetadataValueDetails (r:1 w:1)
     // Proof Skipped: Asset AssetMetadataValueDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset ValidatedAssetMetadataKeys (r:1 w:0)
+    // Proof Skipped: Asset ValidatedAssetMetadataKeys (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset AssetMetadataGlobalSpecs (r:1 w:0)
+    // Proof Skipped: Asset AssetMetadataGlobalSpecs (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset AssetMetadataValues (r:0 w:1)
     // Proof Skipped: Asset AssetMetadataValues (max_values: None, max_size: None, mode: Measured)
     fn set_asset_metadata() -> Weight {
-        // Minimum execution time: 55_263 nanoseconds.
-        Weight::from_ref_time(57_176_000)
-            .saturating_add(DbWeight::get().reads(6))
+        // Minimum execution time: 96_412 nanoseconds.
+        Weight::from_ref_time(99_873_000)
+            .saturating_add(DbWeight::get().reads(8))
             .saturating_add(DbWeight::get().writes(2))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
@@ -764,4 +768,232 @@ impl pallet_asset::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(6))
             .saturating_add(DbWeight::get().writes(4))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity DidRecords (r:1 w:0)
+    // Proof Skipped: Identity DidRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FrozenIdentityBalances (r:0 w:1)
+    // Proof Skipped: Asset FrozenIdentityBalances (max_values: None, max_size: None, mode: Measured)
+    fn freeze_identity() -> Weight {
+        // Minimum execution time: 41_207 nanoseconds.
+        Weight::from_ref_time(42_881_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset FrozenIdentityBalances (r:1 w:1)
+    // Proof Skipped: Asset FrozenIdentityBalances (max_values: None, max_size: None, mode: Measured)
+    fn unfreeze_identity() -> Weight {
+        // Minimum execution time: 37_615 nanoseconds.
+        Weight::from_ref_time(38_902_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FrozenPortfolioBalances (r:0 w:1)
+    // Proof Skipped: Asset FrozenPortfolioBalances (max_values: None, max_size: None, mode: Measured)
+    fn freeze_portfolio() -> Weight {
+        // Minimum execution time: 41_936 nanoseconds.
+        Weight::from_ref_time(43_254_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset FrozenPortfolioBalances (r:1 w:1)
+    // Proof Skipped: Asset FrozenPortfolioBalances (max_values: None, max_size: None, mode: Measured)
+    fn unfreeze_portfolio() -> Weight {
+        // Minimum execution time: 37_988 nanoseconds.
+        Weight::from_ref_time(39_110_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Allowances (r:0 w:1)
+    // Proof Skipped: Asset Allowances (max_values: None, max_size: None, mode: Measured)
+    fn approve() -> Weight {
+        // Minimum execution time: 29_412 nanoseconds.
+        Weight::from_ref_time(30_575_000)
+            .saturating_add(DbWeight::get().reads(3))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset MandatoryMediators (r:1 w:0)
+    // Proof Skipped: Asset MandatoryMediators (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Allowances (r:1 w:1)
+    // Proof Skipped: Asset Allowances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Frozen (r:1 w:0)
+    // Proof Skipped: Asset Frozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FrozenIdentityBalances (r:1 w:0)
+    // Proof Skipped: Asset FrozenIdentityBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FrozenPortfolioBalances (r:1 w:0)
+    // Proof Skipped: Asset FrozenPortfolioBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:2 w:2)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetTransferCompliances (r:1 w:0)
+    // Proof Skipped: Statistics AssetTransferCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:1 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: ComplianceManager AssetCompliances (r:1 w:0)
+    // Proof Skipped: ComplianceManager AssetCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    fn transfer_from() -> Weight {
+        // Minimum execution time: 164_207 nanoseconds.
+        Weight::from_ref_time(168_931_000)
+            .saturating_add(DbWeight::get().reads(19))
+            .saturating_add(DbWeight::get().writes(6))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Nft FractionalizedNFTs (r:1 w:0)
+    // Proof Skipped: Nft FractionalizedNFTs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Asset IssuanceSchedulesComplexity (r:1 w:1)
+    // Proof Skipped: Asset IssuanceSchedulesComplexity (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint SchedulesMaxComplexity (r:1 w:0)
+    // Proof Skipped: Checkpoint SchedulesMaxComplexity (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset IssuanceScheduleIdSequence (r:1 w:1)
+    // Proof Skipped: Asset IssuanceScheduleIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IssuanceSchedules (r:0 w:1)
+    // Proof Skipped: Asset IssuanceSchedules (max_values: None, max_size: None, mode: Measured)
+    fn create_issuance_schedule() -> Weight {
+        // Minimum execution time: 48_713 nanoseconds.
+        Weight::from_ref_time(50_274_000)
+            .saturating_add(DbWeight::get().reads(12))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset IssuanceSchedules (r:1 w:1)
+    // Proof Skipped: Asset IssuanceSchedules (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IssuanceSchedulesComplexity (r:1 w:1)
+    // Proof Skipped: Asset IssuanceSchedulesComplexity (max_values: None, max_size: None, mode: Measured)
+    fn remove_issuance_schedule() -> Weight {
+        // Minimum execution time: 39_825 nanoseconds.
+        Weight::from_ref_time(41_187_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IssuanceSchedules (r:1 w:1)
+    // Proof Skipped: Asset IssuanceSchedules (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:1)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:1 w:1)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:1 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FundingRound (r:1 w:0)
+    // Proof Skipped: Asset FundingRound (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IssuedInFundingRound (r:1 w:1)
+    // Proof Skipped: Asset IssuedInFundingRound (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IssuanceSchedulesComplexity (r:1 w:1)
+    // Proof Skipped: Asset IssuanceSchedulesComplexity (max_values: None, max_size: None, mode: Measured)
+    fn execute_issuance_schedule() -> Weight {
+        // Minimum execution time: 92_418 nanoseconds.
+        Weight::from_ref_time(101_346_000)
+            .saturating_add(DbWeight::get().reads(14))
+            .saturating_add(DbWeight::get().writes(7))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset AssetMetadataGlobalKeyToName (r:1 w:0)
+    // Proof Skipped: Asset AssetMetadataGlobalKeyToName (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset AssetMetadataGlobalSpecs (r:1 w:0)
+    // Proof Skipped: Asset AssetMetadataGlobalSpecs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset ValidatedAssetMetadataKeys (r:0 w:1)
+    // Proof Skipped: Asset ValidatedAssetMetadataKeys (max_values: None, max_size: None, mode: Measured)
+    fn set_asset_metadata_validation() -> Weight {
+        // Minimum execution time: 41_738 nanoseconds.
+        Weight::from_ref_time(43_519_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(1))
+    }
 }
@@ -0,0 +1,305 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_bridge
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-11-03, STEPS: `100`, REPEAT: 5, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 512
+//! HOSTNAME: `ubuntu-8gb-nbg1-1-bench2`, CPU: `AMD EPYC-Milan Processor`
+
+// Executed Command:
+// ./polymesh
+// benchmark
+// pallet
+// -s
+// 100
+// -r
+// 5
+// -p=*
+// -e=*
+// --heap-pages
+// 4096
+// --db-cache
+// 512
+// --execution
+// wasm
+// --wasm-execution
+// compiled
+// --output
+// ./Polymesh/pallets/weights/src/
+// --template
+// ./Polymesh/.maintain/frame-weight-template.hbs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use polymesh_runtime_common::{RocksDbWeight as DbWeight, Weight};
+
+/// Weights for pallet_bridge using the Substrate node and recommended hardware.
+pub struct SubstrateWeight;
+impl pallet_bridge::WeightInfo for SubstrateWeight {
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge Controller (r:0 w:1)
+    // Proof Skipped: Bridge Controller (max_values: Some(1), max_size: None, mode: Measured)
+    fn change_controller() -> Weight {
+        // Minimum execution time: 24_133 nanoseconds.
+        Weight::from_ref_time(25_248_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:1)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    fn change_admin() -> Weight {
+        // Minimum execution time: 23_981 nanoseconds.
+        Weight::from_ref_time(24_860_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge Timelock (r:0 w:1)
+    // Proof Skipped: Bridge Timelock (max_values: Some(1), max_size: None, mode: Measured)
+    fn change_timelock() -> Weight {
+        // Minimum execution time: 23_562 nanoseconds.
+        Weight::from_ref_time(24_315_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge Frozen (r:1 w:1)
+    // Proof Skipped: Bridge Frozen (max_values: Some(1), max_size: None, mode: Measured)
+    fn freeze() -> Weight {
+        // Minimum execution time: 25_103 nanoseconds.
+        Weight::from_ref_time(26_012_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge Frozen (r:1 w:1)
+    // Proof Skipped: Bridge Frozen (max_values: Some(1), max_size: None, mode: Measured)
+    fn unfreeze() -> Weight {
+        // Minimum execution time: 24_877 nanoseconds.
+        Weight::from_ref_time(25_731_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeLimit (r:0 w:1)
+    // Proof Skipped: Bridge BridgeLimit (max_values: Some(1), max_size: None, mode: Measured)
+    fn change_bridge_limit() -> Weight {
+        // Minimum execution time: 24_002 nanoseconds.
+        Weight::from_ref_time(24_920_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeLimitExempted (r:0 w:100)
+    // Proof Skipped: Bridge BridgeLimitExempted (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[0, 100]`.
+    fn change_bridge_exempted(n: u32) -> Weight {
+        // Minimum execution time: 23_410 nanoseconds.
+        Weight::from_ref_time(24_604_000)
+            // Standard Error: 4_212
+            .saturating_add(Weight::from_ref_time(2_391_520).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    // Storage: Bridge Controller (r:1 w:0)
+    // Proof Skipped: Bridge Controller (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeTxProposals (r:1 w:1)
+    // Proof Skipped: Bridge BridgeTxProposals (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NextProposalId (r:1 w:1)
+    // Proof Skipped: MultiSig NextProposalId (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Proposals (r:0 w:1)
+    // Proof Skipped: MultiSig Proposals (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Votes (r:1 w:1)
+    // Proof Skipped: MultiSig Votes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge BridgeTxDetails (r:1 w:1)
+    // Proof Skipped: Bridge BridgeTxDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Scheduler Lookup (r:1 w:1)
+    // Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    // Storage: Scheduler Agenda (r:1 w:1)
+    // Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    fn propose_bridge_tx() -> Weight {
+        // Minimum execution time: 121_554 nanoseconds.
+        Weight::from_ref_time(125_873_000)
+            .saturating_add(DbWeight::get().reads(16))
+            .saturating_add(DbWeight::get().writes(10))
+    }
+    // Storage: Bridge Controller (r:1 w:0)
+    // Proof Skipped: Bridge Controller (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeTxDetails (r:1 w:1)
+    // Proof Skipped: Bridge BridgeTxDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Timelock (r:1 w:0)
+    // Proof Skipped: Bridge Timelock (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Scheduler Lookup (r:1 w:1)
+    // Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    // Storage: Scheduler Agenda (r:1 w:1)
+    // Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    fn handle_bridge_tx() -> Weight {
+        // Minimum execution time: 45_760 nanoseconds.
+        Weight::from_ref_time(47_215_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Bridge BridgeTxDetails (r:1 w:1)
+    // Proof Skipped: Bridge BridgeTxDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Frozen (r:1 w:0)
+    // Proof Skipped: Bridge Frozen (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Claims (r:2 w:0)
+    // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge BridgeLimit (r:1 w:0)
+    // Proof Skipped: Bridge BridgeLimit (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeLimitExempted (r:1 w:0)
+    // Proof Skipped: Bridge BridgeLimitExempted (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge PolyxBridged (r:1 w:1)
+    // Proof Skipped: Bridge PolyxBridged (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Account (r:1 w:1)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    // Storage: Balances TotalIssuance (r:1 w:1)
+    // Proof Skipped: Balances TotalIssuance (max_values: Some(1), max_size: None, mode: Measured)
+    fn handle_scheduled_bridge_tx() -> Weight {
+        // Minimum execution time: 68_392 nanoseconds.
+        Weight::from_ref_time(70_944_000)
+            .saturating_add(DbWeight::get().reads(12))
+            .saturating_add(DbWeight::get().writes(4))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeTxDetails (r:100 w:100)
+    // Proof Skipped: Bridge BridgeTxDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Scheduler Lookup (r:100 w:100)
+    // Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    // Storage: Scheduler Agenda (r:100 w:100)
+    // Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 100]`.
+    fn freeze_txs(n: u32) -> Weight {
+        // Minimum execution time: 51_884 nanoseconds.
+        Weight::from_ref_time(28_190_402)
+            // Standard Error: 21_337
+            .saturating_add(Weight::from_ref_time(24_615_380).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeTxDetails (r:100 w:100)
+    // Proof Skipped: Bridge BridgeTxDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Frozen (r:1 w:0)
+    // Proof Skipped: Bridge Frozen (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Identity Claims (r:2 w:0)
+    // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge PolyxBridged (r:1 w:1)
+    // Proof Skipped: Bridge PolyxBridged (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Account (r:1 w:1)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 100]`.
+    fn unfreeze_txs(n: u32) -> Weight {
+        // Minimum execution time: 77_215 nanoseconds.
+        Weight::from_ref_time(40_117_936)
+            // Standard Error: 35_902
+            .saturating_add(Weight::from_ref_time(39_702_118).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge BridgeTxDetails (r:100 w:100)
+    // Proof Skipped: Bridge BridgeTxDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge BridgeTxProposals (r:0 w:100)
+    // Proof Skipped: Bridge BridgeTxProposals (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[1, 100]`.
+    fn remove_txs(n: u32) -> Weight {
+        // Minimum execution time: 35_519 nanoseconds.
+        Weight::from_ref_time(24_871_263)
+            // Standard Error: 9_764
+            .saturating_add(Weight::from_ref_time(8_912_540).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge FreezeAdmins (r:0 w:1)
+    // Proof Skipped: Bridge FreezeAdmins (max_values: None, max_size: None, mode: Measured)
+    fn add_freeze_admin() -> Weight {
+        // Minimum execution time: 24_316 nanoseconds.
+        Weight::from_ref_time(25_102_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Admin (r:1 w:0)
+    // Proof Skipped: Bridge Admin (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Bridge FreezeAdmins (r:0 w:1)
+    // Proof Skipped: Bridge FreezeAdmins (max_values: None, max_size: None, mode: Measured)
+    fn remove_freeze_admin() -> Weight {
+        // Minimum execution time: 24_190 nanoseconds.
+        Weight::from_ref_time(24_988_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Bridge Frozen (r:1 w:0)
+    // Proof Skipped: Bridge Frozen (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: System Account (r:1 w:1)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    // Storage: Balances TotalIssuance (r:1 w:1)
+    // Proof Skipped: Balances TotalIssuance (max_values: Some(1), max_size: None, mode: Measured)
+    fn burn_for_bridge() -> Weight {
+        // Minimum execution time: 38_447 nanoseconds.
+        Weight::from_ref_time(39_885_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+}
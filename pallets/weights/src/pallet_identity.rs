@@ -489,4 +489,81 @@ impl pallet_identity::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(3))
             .saturating_add(DbWeight::get().writes(3))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Claims (r:2 w:0)
+    // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Instance2Group ActiveMembers (r:1 w:0)
+    // Proof Skipped: Instance2Group ActiveMembers (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Instance2Group InactiveMembers (r:1 w:0)
+    // Proof Skipped: Instance2Group InactiveMembers (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Identity Authorizations (r:1 w:1)
+    // Proof Skipped: Identity Authorizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity OutdatedAuthorizations (r:1 w:0)
+    // Proof Skipped: Identity OutdatedAuthorizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Identity DidRecords (r:1 w:0)
+    // Proof Skipped: Identity DidRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity PendingRecoveries (r:1 w:1)
+    // Proof Skipped: Identity PendingRecoveries (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity NumberOfGivenAuths (r:1 w:1)
+    // Proof Skipped: Identity NumberOfGivenAuths (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity AuthorizationsGiven (r:0 w:1)
+    // Proof Skipped: Identity AuthorizationsGiven (max_values: None, max_size: None, mode: Measured)
+    fn initiate_identity_recovery() -> Weight {
+        // Minimum execution time: 61_205 nanoseconds.
+        Weight::from_ref_time(63_112_000)
+            .saturating_add(DbWeight::get().reads(11))
+            .saturating_add(DbWeight::get().writes(4))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity PendingRecoveries (r:1 w:1)
+    // Proof Skipped: Identity PendingRecoveries (max_values: None, max_size: None, mode: Measured)
+    fn cancel_identity_recovery() -> Weight {
+        // Minimum execution time: 20_873 nanoseconds.
+        Weight::from_ref_time(21_634_000)
+            .saturating_add(DbWeight::get().reads(2))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity PendingRecoveries (r:1 w:1)
+    // Proof Skipped: Identity PendingRecoveries (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfoliosInCustody (r:1 w:1)
+    // Proof Skipped: Portfolio PortfoliosInCustody (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Claims (r:9 w:0)
+    // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity OutdatedAuthorizations (r:1 w:0)
+    // Proof Skipped: Identity OutdatedAuthorizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Authorizations (r:1 w:0)
+    // Proof Skipped: Identity Authorizations (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `p` is `[0, 100]`.
+    /// The range of component `c` is `[0, 100]`.
+    /// The range of component `a` is `[0, 100]`.
+    fn complete_identity_recovery(p: u32, c: u32, a: u32) -> Weight {
+        // Minimum execution time: 118_402 nanoseconds.
+        Weight::from_ref_time(124_876_455)
+            // Standard Error: 24_113
+            .saturating_add(Weight::from_ref_time(17_683_209).saturating_mul(p.into()))
+            // Standard Error: 24_113
+            .saturating_add(Weight::from_ref_time(15_306_482).saturating_mul(c.into()))
+            // Standard Error: 24_113
+            .saturating_add(Weight::from_ref_time(19_204_311).saturating_mul(a.into()))
+            .saturating_add(DbWeight::get().reads(17))
+            .saturating_add(DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+            .saturating_add(DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(DbWeight::get().writes(3))
+            .saturating_add(DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+            .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(a.into())))
+    }
 }
@@ -223,15 +223,157 @@ impl pallet_nft::WeightInfo for SubstrateWeight {
     // Proof Skipped: Portfolio PortfolioLockedNFT (max_values: None, max_size: None, mode: Measured)
     // Storage: NFT NFTOwner (r:0 w:10)
     // Proof Skipped: NFT NFTOwner (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT NFTFractionAsset (r:10 w:0)
+    // Proof Skipped: NFT NFTFractionAsset (max_values: None, max_size: None, mode: Measured)
     /// The range of component `n` is `[1, 10]`.
     fn controller_transfer(n: u32) -> Weight {
-        // Minimum execution time: 79_298 nanoseconds.
-        Weight::from_ref_time(70_256_608)
+        // Minimum execution time: 83_911 nanoseconds.
+        Weight::from_ref_time(72_508_314)
             // Standard Error: 122_051
-            .saturating_add(Weight::from_ref_time(14_678_121).saturating_mul(n.into()))
+            .saturating_add(Weight::from_ref_time(17_102_650).saturating_mul(n.into()))
             .saturating_add(DbWeight::get().reads(9))
-            .saturating_add(DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().writes(2))
             .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(n.into())))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: NFT CollectionAsset (r:1 w:0)
+    // Proof Skipped: NFT CollectionAsset (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT NFTOwner (r:1 w:0)
+    // Proof Skipped: NFT NFTOwner (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT CollectionKeys (r:1 w:0)
+    // Proof Skipped: NFT CollectionKeys (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT MetadataValueDetails (r:1 w:1)
+    // Proof Skipped: NFT MetadataValueDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: NFT MetadataValue (r:1 w:1)
+    // Proof Skipped: NFT MetadataValue (max_values: None, max_size: None, mode: Measured)
+    fn update_nft_metadata() -> Weight {
+        // Minimum execution time: 71_402 nanoseconds.
+        Weight::from_ref_time(73_118_000)
+            .saturating_add(DbWeight::get().reads(10))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:1)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT CollectionAsset (r:1 w:0)
+    // Proof Skipped: NFT CollectionAsset (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioNFT (r:1 w:2)
+    // Proof Skipped: Portfolio PortfolioNFT (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedNFT (r:2 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedNFT (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset AssetNonce (r:1 w:1)
+    // Proof Skipped: Asset AssetNonce (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:2 w:1)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset AssetNames (r:0 w:1)
+    // Proof Skipped: Asset AssetNames (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:1 w:1)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:1)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents NumFullAgents (r:1 w:1)
+    // Proof Skipped: ExternalAgents NumFullAgents (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents AgentOf (r:0 w:1)
+    // Proof Skipped: ExternalAgents AgentOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: ProtocolFee Coefficient (r:1 w:0)
+    // Proof Skipped: ProtocolFee Coefficient (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: ProtocolFee BaseFees (r:2 w:0)
+    // Proof Skipped: ProtocolFee BaseFees (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:1 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio NameToNumber (r:1 w:1)
+    // Proof Skipped: Portfolio NameToNumber (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio NextPortfolioNumber (r:1 w:1)
+    // Proof Skipped: Portfolio NextPortfolioNumber (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT NFTOwner (r:0 w:1)
+    // Proof Skipped: NFT NFTOwner (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT FractionalizedNFTs (r:0 w:1)
+    // Proof Skipped: NFT FractionalizedNFTs (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT NFTFractionAsset (r:0 w:1)
+    // Proof Skipped: NFT NFTFractionAsset (max_values: None, max_size: None, mode: Measured)
+    fn fractionalize_nft() -> Weight {
+        // Minimum execution time: 214_361 nanoseconds.
+        Weight::from_ref_time(221_905_000)
+            .saturating_add(DbWeight::get().reads(30))
+            .saturating_add(DbWeight::get().writes(22))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT FractionalizedNFTs (r:1 w:1)
+    // Proof Skipped: NFT FractionalizedNFTs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:1)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:1 w:1)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:1 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedNFT (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedNFT (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT CollectionAsset (r:1 w:0)
+    // Proof Skipped: NFT CollectionAsset (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT NumberOfNFTs (r:2 w:2)
+    // Proof Skipped: NFT NumberOfNFTs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioNFT (r:1 w:2)
+    // Proof Skipped: Portfolio PortfolioNFT (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Frozen (r:1 w:0)
+    // Proof Skipped: Asset Frozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity DidRecords (r:2 w:0)
+    // Proof Skipped: Identity DidRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ComplianceManager AssetCompliances (r:1 w:0)
+    // Proof Skipped: ComplianceManager AssetCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT NFTOwner (r:0 w:1)
+    // Proof Skipped: NFT NFTOwner (max_values: None, max_size: None, mode: Measured)
+    // Storage: NFT NFTFractionAsset (r:0 w:1)
+    // Proof Skipped: NFT NFTFractionAsset (max_values: None, max_size: None, mode: Measured)
+    fn redeem_nft_fraction() -> Weight {
+        // Minimum execution time: 163_540 nanoseconds.
+        Weight::from_ref_time(168_774_000)
+            .saturating_add(DbWeight::get().reads(25))
+            .saturating_add(DbWeight::get().writes(13))
+    }
 }
@@ -267,6 +267,10 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
     // Proof Skipped: Settlement InstructionMemos (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset Assets (r:10 w:0)
     // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement SpenderAffirmations (r:10 w:10)
+    // Proof Skipped: Settlement SpenderAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Allowances (r:10 w:10)
+    // Proof Skipped: Asset Allowances (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset BalanceOf (r:20 w:20)
     // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
     // Storage: Portfolio Portfolios (r:20 w:0)
@@ -310,15 +314,15 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
         // Minimum execution time: 4_326_122 nanoseconds.
         Weight::from_ref_time(4_334_153_000)
             // Standard Error: 5_116_456
-            .saturating_add(Weight::from_ref_time(248_402_942).saturating_mul(f.into()))
+            .saturating_add(Weight::from_ref_time(269_876_850).saturating_mul(f.into()))
             // Standard Error: 494_029
             .saturating_add(Weight::from_ref_time(179_827_924).saturating_mul(n.into()))
             .saturating_add(DbWeight::get().reads(19))
-            .saturating_add(DbWeight::get().reads((60_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().reads((62_u64).saturating_mul(f.into())))
             .saturating_add(DbWeight::get().reads((18_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(o.into())))
             .saturating_add(DbWeight::get().writes(8))
-            .saturating_add(DbWeight::get().writes((26_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().writes((28_u64).saturating_mul(f.into())))
             .saturating_add(DbWeight::get().writes((16_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(o.into())))
     }
@@ -638,6 +642,10 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
     // Proof Skipped: Settlement InstructionMemos (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset Assets (r:10 w:0)
     // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement SpenderAffirmations (r:10 w:10)
+    // Proof Skipped: Settlement SpenderAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Allowances (r:10 w:10)
+    // Proof Skipped: Asset Allowances (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset BalanceOf (r:20 w:20)
     // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
     // Storage: Portfolio Portfolios (r:20 w:0)
@@ -679,15 +687,15 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
         // Minimum execution time: 2_385_781 nanoseconds.
         Weight::from_ref_time(2_426_592_000)
             // Standard Error: 3_430_970
-            .saturating_add(Weight::from_ref_time(118_611_412).saturating_mul(f.into()))
+            .saturating_add(Weight::from_ref_time(140_085_320).saturating_mul(f.into()))
             // Standard Error: 331_283
             .saturating_add(Weight::from_ref_time(176_390_618).saturating_mul(n.into()))
             .saturating_add(DbWeight::get().reads(16))
-            .saturating_add(DbWeight::get().reads((26_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().reads((28_u64).saturating_mul(f.into())))
             .saturating_add(DbWeight::get().reads((18_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(o.into())))
             .saturating_add(DbWeight::get().writes(8))
-            .saturating_add(DbWeight::get().writes((16_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().writes((18_u64).saturating_mul(f.into())))
             .saturating_add(DbWeight::get().writes((16_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(o.into())))
     }
@@ -721,6 +729,10 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
     // Proof Skipped: Settlement InstructionMemos (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset Assets (r:10 w:0)
     // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement SpenderAffirmations (r:10 w:10)
+    // Proof Skipped: Settlement SpenderAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Allowances (r:10 w:10)
+    // Proof Skipped: Asset Allowances (max_values: None, max_size: None, mode: Measured)
     // Storage: Asset BalanceOf (r:20 w:20)
     // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
     // Storage: Portfolio Portfolios (r:20 w:0)
@@ -764,15 +776,15 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
         // Minimum execution time: 4_243_219 nanoseconds.
         Weight::from_ref_time(4_250_300_000)
             // Standard Error: 4_870_147
-            .saturating_add(Weight::from_ref_time(258_973_141).saturating_mul(f.into()))
+            .saturating_add(Weight::from_ref_time(280_447_049).saturating_mul(f.into()))
             // Standard Error: 470_246
             .saturating_add(Weight::from_ref_time(175_126_988).saturating_mul(n.into()))
             .saturating_add(DbWeight::get().reads(17))
-            .saturating_add(DbWeight::get().reads((60_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().reads((62_u64).saturating_mul(f.into())))
             .saturating_add(DbWeight::get().reads((18_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(o.into())))
             .saturating_add(DbWeight::get().writes(8))
-            .saturating_add(DbWeight::get().writes((26_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().writes((28_u64).saturating_mul(f.into())))
             .saturating_add(DbWeight::get().writes((16_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(o.into())))
     }
@@ -1129,4 +1141,234 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().writes((7_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(o.into())))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueInfo (r:1 w:0)
+    // Proof Skipped: Settlement VenueInfo (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueNetting (r:0 w:1)
+    // Proof Skipped: Settlement VenueNetting (max_values: None, max_size: None, mode: Measured)
+    fn set_venue_netting() -> Weight {
+        // Minimum execution time: 26_901 nanoseconds.
+        Weight::from_ref_time(28_120_000)
+            .saturating_add(DbWeight::get().reads(2))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Scheduler Lookup (r:1 w:1)
+    // Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    // Storage: Scheduler Agenda (r:1 w:1)
+    // Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    // Storage: Settlement InstructionAffirmationDeadline (r:0 w:1)
+    // Proof Skipped: Settlement InstructionAffirmationDeadline (max_values: None, max_size: None, mode: Measured)
+    fn schedule_affirmation_deadline() -> Weight {
+        // Minimum execution time: 21_463 nanoseconds.
+        Weight::from_ref_time(22_850_000)
+            .saturating_add(DbWeight::get().reads(2))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionDetails (r:1 w:0)
+    // Proof Skipped: Settlement InstructionDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionStatuses (r:1 w:0)
+    // Proof Skipped: Settlement InstructionStatuses (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement UserAffirmations (r:1 w:1)
+    // Proof Skipped: Settlement UserAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegs (r:11 w:0)
+    // Proof Skipped: Settlement InstructionLegs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Allowances (r:10 w:0)
+    // Proof Skipped: Asset Allowances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:10 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:10 w:10)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionAffirmsPending (r:1 w:1)
+    // Proof Skipped: Settlement InstructionAffirmsPending (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AffirmsReceived (r:0 w:1)
+    // Proof Skipped: Settlement AffirmsReceived (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegStatus (r:0 w:10)
+    // Proof Skipped: Settlement InstructionLegStatus (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement SpenderAffirmations (r:0 w:1)
+    // Proof Skipped: Settlement SpenderAffirmations (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `f` is `[1, 10]`.
+    fn affirm_instruction_as_spender(f: u32) -> Weight {
+        // Minimum execution time: 98_416 nanoseconds.
+        Weight::from_ref_time(71_302_837)
+            // Standard Error: 48_120
+            .saturating_add(Weight::from_ref_time(29_114_406).saturating_mul(f.into()))
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().reads((4_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().writes(4))
+            .saturating_add(DbWeight::get().writes((2_u64).saturating_mul(f.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionDetails (r:2 w:0)
+    // Proof Skipped: Settlement InstructionDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionStatuses (r:1 w:0)
+    // Proof Skipped: Settlement InstructionStatuses (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegs (r:12 w:0)
+    // Proof Skipped: Settlement InstructionLegs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement OffChainAffirmations (r:1 w:1)
+    // Proof Skipped: Settlement OffChainAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Settlement InstructionAffirmsPending (r:1 w:1)
+    // Proof Skipped: Settlement InstructionAffirmsPending (max_values: None, max_size: None, mode: Measured)
+    fn affirm_with_preimage() -> Weight {
+        // Minimum execution time: 64_871 nanoseconds.
+        Weight::from_ref_time(67_204_000)
+            .saturating_add(DbWeight::get().reads(19))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionDetails (r:2 w:1)
+    // Proof Skipped: Settlement InstructionDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionStatuses (r:1 w:0)
+    // Proof Skipped: Settlement InstructionStatuses (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueInfo (r:1 w:0)
+    // Proof Skipped: Settlement VenueInfo (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueNetting (r:1 w:0)
+    // Proof Skipped: Settlement VenueNetting (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:110 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueFiltering (r:110 w:0)
+    // Proof Skipped: Settlement VenueFiltering (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity DidRecords (r:2 w:0)
+    // Proof Skipped: Identity DidRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:220 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PreApprovedPortfolios (r:110 w:0)
+    // Proof Skipped: Portfolio PreApprovedPortfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset PreApprovedAsset (r:110 w:0)
+    // Proof Skipped: Asset PreApprovedAsset (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset MandatoryMediators (r:110 w:0)
+    // Proof Skipped: Asset MandatoryMediators (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegs (r:121 w:120)
+    // Proof Skipped: Settlement InstructionLegs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegStatus (r:110 w:110)
+    // Proof Skipped: Settlement InstructionLegStatus (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedNFT (r:100 w:100)
+    // Proof Skipped: Portfolio PortfolioLockedNFT (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:10 w:10)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement OffChainAffirmations (r:10 w:10)
+    // Proof Skipped: Settlement OffChainAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement UserAffirmations (r:220 w:220)
+    // Proof Skipped: Settlement UserAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionMediatorsAffirmations (r:5 w:5)
+    // Proof Skipped: Settlement InstructionMediatorsAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionVersions (r:1 w:1)
+    // Proof Skipped: Settlement InstructionVersions (max_values: None, max_size: None, mode: Measured)
+    // Storage: Scheduler Lookup (r:1 w:1)
+    // Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    // Storage: Scheduler Agenda (r:1 w:1)
+    // Proof: Scheduler Agenda (max_values: None, max_size: Some(10463), added: 12938, mode: MaxEncodedLen)
+    // Storage: Settlement InstructionAffirmationDeadline (r:1 w:0)
+    // Proof Skipped: Settlement InstructionAffirmationDeadline (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionAffirmsPending (r:0 w:1)
+    // Proof Skipped: Settlement InstructionAffirmsPending (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AffirmsReceived (r:0 w:220)
+    // Proof Skipped: Settlement AffirmsReceived (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement SpenderAffirmations (r:0 w:220)
+    // Proof Skipped: Settlement SpenderAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionMemos (r:0 w:1)
+    // Proof Skipped: Settlement InstructionMemos (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `f` is `[1, 10]`.
+    /// The range of component `n` is `[0, 100]`.
+    /// The range of component `o` is `[0, 10]`.
+    fn amend_instruction(f: u32, n: u32, o: u32) -> Weight {
+        // Minimum execution time: 1_023_457 nanoseconds.
+        Weight::from_ref_time(318_776_204)
+            // Standard Error: 1_104_385
+            .saturating_add(Weight::from_ref_time(81_392_118).saturating_mul(f.into()))
+            // Standard Error: 103_829
+            .saturating_add(Weight::from_ref_time(79_845_907).saturating_mul(n.into()))
+            // Standard Error: 1_104_385
+            .saturating_add(Weight::from_ref_time(9_864_210).saturating_mul(o.into()))
+            .saturating_add(DbWeight::get().reads(15))
+            .saturating_add(DbWeight::get().reads((17_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().reads((17_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().reads((2_u64).saturating_mul(o.into())))
+            .saturating_add(DbWeight::get().writes(11))
+            .saturating_add(DbWeight::get().writes((15_u64).saturating_mul(f.into())))
+            .saturating_add(DbWeight::get().writes((15_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes((2_u64).saturating_mul(o.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueInfo (r:1 w:0)
+    // Proof Skipped: Settlement VenueInfo (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRuleCount (r:1 w:1)
+    // Proof Skipped: Settlement AutoAffirmRuleCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement NextAutoAffirmRuleId (r:1 w:1)
+    // Proof Skipped: Settlement NextAutoAffirmRuleId (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRules (r:0 w:1)
+    // Proof Skipped: Settlement AutoAffirmRules (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `c` is `[0, 32]`.
+    fn add_auto_affirm_rule(c: u32) -> Weight {
+        // Minimum execution time: 38_245 nanoseconds.
+        Weight::from_ref_time(41_302_517)
+            // Standard Error: 3_418
+            .saturating_add(Weight::from_ref_time(402_118).saturating_mul(c.into()))
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRules (r:1 w:1)
+    // Proof Skipped: Settlement AutoAffirmRules (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRuleCount (r:1 w:1)
+    // Proof Skipped: Settlement AutoAffirmRuleCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRulesUsage (r:0 w:1)
+    // Proof Skipped: Settlement AutoAffirmRulesUsage (max_values: None, max_size: None, mode: Measured)
+    fn remove_auto_affirm_rule() -> Weight {
+        // Minimum execution time: 33_617 nanoseconds.
+        Weight::from_ref_time(35_084_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Settlement InstructionLegs (r:11 w:0)
+    // Proof Skipped: Settlement InstructionLegs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Settlement InstructionAffirmsPending (r:1 w:1)
+    // Proof Skipped: Settlement InstructionAffirmsPending (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRuleCount (r:20 w:0)
+    // Proof Skipped: Settlement AutoAffirmRuleCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:20 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRules (r:320 w:0)
+    // Proof Skipped: Settlement AutoAffirmRules (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AutoAffirmRulesUsage (r:20 w:20)
+    // Proof Skipped: Settlement AutoAffirmRulesUsage (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:10 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:10 w:10)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegStatus (r:0 w:10)
+    // Proof Skipped: Settlement InstructionLegStatus (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement UserAffirmations (r:0 w:20)
+    // Proof Skipped: Settlement UserAffirmations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement AffirmsReceived (r:0 w:20)
+    // Proof Skipped: Settlement AffirmsReceived (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `l` is `[1, 10]`.
+    /// The range of component `r` is `[1, 16]`.
+    fn auto_affirm_rules(l: u32, r: u32) -> Weight {
+        // Minimum execution time: 412_733 nanoseconds.
+        Weight::from_ref_time(98_417_352)
+            // Standard Error: 402_615
+            .saturating_add(Weight::from_ref_time(250_011_304).saturating_mul(l.into()))
+            // Standard Error: 251_634
+            .saturating_add(Weight::from_ref_time(43_057_764).saturating_mul(r.into()))
+            .saturating_add(DbWeight::get().reads(3))
+            .saturating_add(DbWeight::get().reads((9_u64).saturating_mul(l.into())))
+            .saturating_add(DbWeight::get().reads((20_u64).saturating_mul(r.into())))
+            .saturating_add(DbWeight::get().writes(1))
+            .saturating_add(DbWeight::get().writes((8_u64).saturating_mul(l.into())))
+    }
 }
@@ -241,4 +241,22 @@ impl pallet_statistics::WeightInfo for SubstrateWeight {
         // Minimum execution time: 8_683 nanoseconds.
         Weight::from_ref_time(8_954_000).saturating_add(DbWeight::get().reads(1))
     }
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Statistics AssetVolumes (r:2 w:2)
+    // Proof Skipped: Statistics AssetVolumes (max_values: None, max_size: None, mode: Measured)
+    fn update_asset_volume_stats() -> Weight {
+        // Minimum execution time: 19_726 nanoseconds.
+        Weight::from_ref_time(20_861_000)
+            .saturating_add(DbWeight::get().reads(3))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Statistics AssetVolumes (r:1 w:0)
+    // Proof Skipped: Statistics AssetVolumes (max_values: None, max_size: None, mode: Measured)
+    fn volume_restriction() -> Weight {
+        // Minimum execution time: 11_403 nanoseconds.
+        Weight::from_ref_time(12_137_000).saturating_add(DbWeight::get().reads(2))
+    }
 }
@@ -65,6 +65,8 @@ impl pallet_sto::WeightInfo for SubstrateWeight {
     // Proof Skipped: Settlement VenueInfo (max_values: None, max_size: None, mode: Measured)
     // Storage: Portfolio PortfolioCustodian (r:2 w:0)
     // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto EscrowPortfolios (r:1 w:0)
+    // Proof Skipped: Sto EscrowPortfolios (max_values: None, max_size: None, mode: Measured)
     // Storage: Timestamp Now (r:1 w:0)
     // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
     // Storage: Sto FundraiserCount (r:1 w:1)
@@ -85,7 +87,7 @@ impl pallet_sto::WeightInfo for SubstrateWeight {
         Weight::from_ref_time(96_229_792)
             // Standard Error: 44_701
             .saturating_add(Weight::from_ref_time(91_319).saturating_mul(i.into()))
-            .saturating_add(DbWeight::get().reads(12))
+            .saturating_add(DbWeight::get().reads(13))
             .saturating_add(DbWeight::get().writes(4))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
@@ -94,8 +96,14 @@ impl pallet_sto::WeightInfo for SubstrateWeight {
     // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
     // Storage: Sto Fundraisers (r:1 w:1)
     // Proof Skipped: Sto Fundraisers (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto FundraiserRestrictions (r:1 w:0)
+    // Proof Skipped: Sto FundraiserRestrictions (max_values: None, max_size: None, mode: Measured)
     // Storage: Timestamp Now (r:1 w:0)
     // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Sto Investments (r:1 w:1)
+    // Proof Skipped: Sto Investments (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto TierAllocations (r:10 w:10)
+    // Proof Skipped: Sto TierAllocations (max_values: None, max_size: None, mode: Measured)
     // Storage: Portfolio PortfolioLockedAssets (r:2 w:2)
     // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
     // Storage: Settlement VenueInfo (r:1 w:0)
@@ -132,7 +140,7 @@ impl pallet_sto::WeightInfo for SubstrateWeight {
     // Proof Skipped: Asset Frozen (max_values: None, max_size: None, mode: Measured)
     // Storage: Instance2Group ActiveMembers (r:1 w:0)
     // Proof Skipped: Instance2Group ActiveMembers (max_values: Some(1), max_size: None, mode: Measured)
-    // Storage: Identity Claims (r:46 w:0)
+    // Storage: Identity Claims (r:56 w:0)
     // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
     // Storage: Statistics AssetTransferCompliances (r:2 w:0)
     // Proof Skipped: Statistics AssetTransferCompliances (max_values: None, max_size: None, mode: Measured)
@@ -162,11 +170,16 @@ impl pallet_sto::WeightInfo for SubstrateWeight {
     // Proof Skipped: Settlement AffirmsReceived (max_values: None, max_size: None, mode: Measured)
     // Storage: Settlement InstructionLegStatus (r:0 w:2)
     // Proof Skipped: Settlement InstructionLegStatus (max_values: None, max_size: None, mode: Measured)
-    fn invest() -> Weight {
-        // Minimum execution time: 1_051_755 nanoseconds.
-        Weight::from_ref_time(1_059_396_000)
-            .saturating_add(DbWeight::get().reads(131))
-            .saturating_add(DbWeight::get().writes(50))
+    /// The range of component `t` is `[1, 10]`.
+    fn invest(t: u32) -> Weight {
+        // Minimum execution time: 1_103_516 nanoseconds.
+        Weight::from_ref_time(1_098_763_412)
+            // Standard Error: 41_905
+            .saturating_add(Weight::from_ref_time(4_127_306).saturating_mul(t.into()))
+            .saturating_add(DbWeight::get().reads(143))
+            .saturating_add(DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+            .saturating_add(DbWeight::get().writes(51))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul(t.into())))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
@@ -222,14 +235,195 @@ impl pallet_sto::WeightInfo for SubstrateWeight {
     // Proof Skipped: Sto Fundraisers (max_values: None, max_size: None, mode: Measured)
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
-    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Storage: Portfolio PortfolioLockedAssets (r:2 w:2)
     // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
     // Storage: Timestamp Now (r:1 w:0)
     // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Sto FundraiserEscrows (r:1 w:1)
+    // Proof Skipped: Sto FundraiserEscrows (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto EscrowPortfolios (r:0 w:1)
+    // Proof Skipped: Sto EscrowPortfolios (max_values: None, max_size: None, mode: Measured)
     fn stop() -> Weight {
-        // Minimum execution time: 48_333 nanoseconds.
-        Weight::from_ref_time(48_753_000)
-            .saturating_add(DbWeight::get().reads(4))
-            .saturating_add(DbWeight::get().writes(2))
+        // Minimum execution time: 61_972 nanoseconds.
+        Weight::from_ref_time(62_804_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(5))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Sto Fundraisers (r:1 w:0)
+    // Proof Skipped: Sto Fundraisers (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto FundraiserRestrictions (r:0 w:1)
+    // Proof Skipped: Sto FundraiserRestrictions (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `i` is `[0, 10]`.
+    fn set_investor_restrictions(i: u32) -> Weight {
+        // Minimum execution time: 44_913 nanoseconds.
+        Weight::from_ref_time(46_087_412)
+            // Standard Error: 11_284
+            .saturating_add(Weight::from_ref_time(97_503).saturating_mul(i.into()))
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Sto Fundraisers (r:1 w:0)
+    // Proof Skipped: Sto Fundraisers (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto TierAllocations (r:0 w:100)
+    // Proof Skipped: Sto TierAllocations (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `a` is `[0, 100]`.
+    fn set_tier_allocations(a: u32) -> Weight {
+        // Minimum execution time: 42_571 nanoseconds.
+        Weight::from_ref_time(44_961_237)
+            // Standard Error: 6_417
+            .saturating_add(Weight::from_ref_time(2_315_846).saturating_mul(a.into()))
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Settlement VenueInfo (r:1 w:0)
+    // Proof Skipped: Settlement VenueInfo (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:2 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto EscrowPortfolios (r:1 w:1)
+    // Proof Skipped: Sto EscrowPortfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Sto FundraiserCount (r:1 w:1)
+    // Proof Skipped: Sto FundraiserCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto FundraiserNames (r:0 w:1)
+    // Proof Skipped: Sto FundraiserNames (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto Fundraisers (r:0 w:1)
+    // Proof Skipped: Sto Fundraisers (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto FundraiserEscrows (r:0 w:1)
+    // Proof Skipped: Sto FundraiserEscrows (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `i` is `[1, 10]`.
+    fn create_escrowed_fundraiser(i: u32) -> Weight {
+        // Minimum execution time: 95_112 nanoseconds.
+        Weight::from_ref_time(98_804_611)
+            // Standard Error: 41_952
+            .saturating_add(Weight::from_ref_time(127_468).saturating_mul(i.into()))
+            .saturating_add(DbWeight::get().reads(14))
+            .saturating_add(DbWeight::get().writes(6))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:3 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto Fundraisers (r:1 w:0)
+    // Proof Skipped: Sto Fundraisers (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto FundraiserEscrows (r:1 w:1)
+    // Proof Skipped: Sto FundraiserEscrows (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio PortfolioLockedAssets (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto EscrowedInvestments (r:1 w:1)
+    // Proof Skipped: Sto EscrowedInvestments (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueInfo (r:1 w:0)
+    // Proof Skipped: Settlement VenueInfo (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueFiltering (r:1 w:0)
+    // Proof Skipped: Settlement VenueFiltering (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity DidRecords (r:2 w:0)
+    // Proof Skipped: Identity DidRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:2 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionCounter (r:1 w:1)
+    // Proof Skipped: Settlement InstructionCounter (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegs (r:2 w:1)
+    // Proof Skipped: Settlement InstructionLegs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:2 w:2)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Claims (r:23 w:0)
+    // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetStats (r:14 w:10)
+    // Proof Skipped: Statistics AssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionStatuses (r:0 w:1)
+    // Proof Skipped: Settlement InstructionStatuses (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionDetails (r:0 w:1)
+    // Proof Skipped: Settlement InstructionDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto EscrowPortfolios (r:0 w:1)
+    // Proof Skipped: Sto EscrowPortfolios (max_values: None, max_size: None, mode: Measured)
+    fn claim_offering() -> Weight {
+        // Minimum execution time: 612_409 nanoseconds.
+        Weight::from_ref_time(618_937_000)
+            .saturating_add(DbWeight::get().reads(72))
+            .saturating_add(DbWeight::get().writes(29))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:3 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto Fundraisers (r:1 w:0)
+    // Proof Skipped: Sto Fundraisers (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto FundraiserEscrows (r:1 w:1)
+    // Proof Skipped: Sto FundraiserEscrows (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio PortfolioLockedAssets (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto EscrowedInvestments (r:1 w:1)
+    // Proof Skipped: Sto EscrowedInvestments (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueInfo (r:1 w:0)
+    // Proof Skipped: Settlement VenueInfo (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement VenueFiltering (r:1 w:0)
+    // Proof Skipped: Settlement VenueFiltering (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity DidRecords (r:2 w:0)
+    // Proof Skipped: Identity DidRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:2 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionCounter (r:1 w:1)
+    // Proof Skipped: Settlement InstructionCounter (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Settlement InstructionLegs (r:2 w:1)
+    // Proof Skipped: Settlement InstructionLegs (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:2 w:2)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Claims (r:23 w:0)
+    // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetStats (r:14 w:10)
+    // Proof Skipped: Statistics AssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionStatuses (r:0 w:1)
+    // Proof Skipped: Settlement InstructionStatuses (max_values: None, max_size: None, mode: Measured)
+    // Storage: Settlement InstructionDetails (r:0 w:1)
+    // Proof Skipped: Settlement InstructionDetails (max_values: None, max_size: None, mode: Measured)
+    // Storage: Sto EscrowPortfolios (r:0 w:1)
+    // Proof Skipped: Sto EscrowPortfolios (max_values: None, max_size: None, mode: Measured)
+    fn refund() -> Weight {
+        // Minimum execution time: 608_236 nanoseconds.
+        Weight::from_ref_time(615_528_000)
+            .saturating_add(DbWeight::get().reads(72))
+            .saturating_add(DbWeight::get().writes(29))
     }
 }
@@ -0,0 +1,165 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_vesting
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-11-03, STEPS: `100`, REPEAT: 5, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 512
+//! HOSTNAME: `ubuntu-8gb-nbg1-1-bench2`, CPU: `AMD EPYC-Milan Processor`
+
+// Executed Command:
+// ./polymesh
+// benchmark
+// pallet
+// -s
+// 100
+// -r
+// 5
+// -p=*
+// -e=*
+// --heap-pages
+// 4096
+// --db-cache
+// 512
+// --execution
+// wasm
+// --wasm-execution
+// compiled
+// --output
+// ./Polymesh/pallets/weights/src/
+// --template
+// ./Polymesh/.maintain/frame-weight-template.hbs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use polymesh_runtime_common::{RocksDbWeight as DbWeight, Weight};
+
+/// Weights for pallet_vesting using the Substrate node and recommended hardware.
+pub struct SubstrateWeight;
+impl pallet_vesting::WeightInfo for SubstrateWeight {
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:2 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset MandatoryMediators (r:1 w:0)
+    // Proof Skipped: Asset MandatoryMediators (max_values: None, max_size: None, mode: Measured)
+    // Storage: Vesting NextVestingGrantId (r:1 w:1)
+    // Proof Skipped: Vesting NextVestingGrantId (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset PreApprovedAsset (r:1 w:0)
+    // Proof Skipped: Asset PreApprovedAsset (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Frozen (r:1 w:0)
+    // Proof Skipped: Asset Frozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FrozenIdentityBalances (r:1 w:0)
+    // Proof Skipped: Asset FrozenIdentityBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FrozenPortfolioBalances (r:1 w:0)
+    // Proof Skipped: Asset FrozenPortfolioBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:2 w:2)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:2 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetTransferCompliances (r:1 w:0)
+    // Proof Skipped: Statistics AssetTransferCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:1 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: ComplianceManager AssetCompliances (r:1 w:0)
+    // Proof Skipped: ComplianceManager AssetCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Vesting VestingLocked (r:1 w:1)
+    // Proof Skipped: Vesting VestingLocked (max_values: None, max_size: None, mode: Measured)
+    // Storage: Vesting VestingGrants (r:0 w:1)
+    // Proof Skipped: Vesting VestingGrants (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `g` is `[1, 100]`.
+    fn create_vesting_grants(g: u32) -> Weight {
+        // Minimum execution time: 181_052 nanoseconds.
+        Weight::from_ref_time(103_614_000)
+            // Standard Error: 21_873
+            .saturating_add(Weight::from_ref_time(97_384_000).saturating_mul(g.into()))
+            .saturating_add(DbWeight::get().reads(8))
+            .saturating_add(DbWeight::get().reads((13_u64).saturating_mul(g.into())))
+            .saturating_add(DbWeight::get().writes(1))
+            .saturating_add(DbWeight::get().writes((10_u64).saturating_mul(g.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Vesting VestingGrants (r:1 w:1)
+    // Proof Skipped: Vesting VestingGrants (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Vesting VestingLocked (r:1 w:1)
+    // Proof Skipped: Vesting VestingLocked (max_values: None, max_size: None, mode: Measured)
+    fn release_vested() -> Weight {
+        // Minimum execution time: 38_413 nanoseconds.
+        Weight::from_ref_time(39_874_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Vesting VestingGrants (r:1 w:1)
+    // Proof Skipped: Vesting VestingGrants (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Vesting VestingLocked (r:1 w:1)
+    // Proof Skipped: Vesting VestingLocked (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:2 w:2)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    fn revoke_vesting_grant() -> Weight {
+        // Minimum execution time: 112_648 nanoseconds.
+        Weight::from_ref_time(116_207_000)
+            .saturating_add(DbWeight::get().reads(17))
+            .saturating_add(DbWeight::get().writes(9))
+    }
+}
@@ -21,8 +21,10 @@ use scale_info::TypeInfo;
 use sp_io::hashing::blake2_128;
 use sp_std::prelude::Vec;
 
+use crate::calendar::{CalendarPeriod, CheckpointSchedule};
 use crate::impl_checked_inc;
 use crate::ticker::Ticker;
+use crate::{Balance, Moment, PortfolioId};
 use polymesh_primitives_derive::VecU8StrongTyped;
 
 /// An unique asset identifier.
@@ -172,3 +174,81 @@ impl AssetType {
 #[derive(Decode, Encode, TypeInfo, VecU8StrongTyped)]
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub struct FundingRoundName(pub Vec<u8>);
+
+/// A per-asset issuance schedule ID.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IssuanceScheduleId(pub u64);
+impl_checked_inc!(IssuanceScheduleId);
+
+/// A schedule that mints a fixed amount of an asset into a portfolio on each period.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuanceSchedule {
+    /// The [`PortfolioId`] receiving the issued tokens.
+    pub portfolio: PortfolioId,
+    /// The [`Balance`] issued on each period.
+    pub amount: Balance,
+    /// The maximum [`Balance`] the schedule can issue.
+    pub cap: Balance,
+    /// The time of the first issuance.
+    pub start: Moment,
+    /// The period between two issuances. A non-recurring period issues only once.
+    pub period: CalendarPeriod,
+    /// The time of the next issuance, if any.
+    pub next_at: Option<Moment>,
+    /// The [`Balance`] issued so far.
+    pub issued: Balance,
+}
+
+impl IssuanceSchedule {
+    /// Creates a new [`IssuanceSchedule`] with its first issuance at `start`.
+    pub fn new(
+        portfolio: PortfolioId,
+        amount: Balance,
+        cap: Balance,
+        start: Moment,
+        period: CalendarPeriod,
+    ) -> Self {
+        Self {
+            portfolio,
+            amount,
+            cap,
+            start,
+            period,
+            next_at: Some(start),
+            issued: 0,
+        }
+    }
+
+    /// Returns the [`Balance`] that can still be issued by the schedule.
+    pub fn remaining(&self) -> Balance {
+        self.cap.saturating_sub(self.issued)
+    }
+
+    /// Returns `true` if no more tokens can be issued by the schedule.
+    pub fn is_finished(&self) -> bool {
+        self.remaining() == 0 || self.next_at.is_none()
+    }
+
+    /// Returns `true` if an issuance is due at `now`.
+    pub fn is_due(&self, now: Moment) -> bool {
+        self.remaining() > 0 && self.next_at.map_or(false, |next_at| next_at <= now)
+    }
+
+    /// Records the issuance due at `next_at` and moves `next_at` to the following period.
+    /// Returns the [`Balance`] to be issued.
+    pub fn advance(&mut self) -> Balance {
+        let amount = self.amount.min(self.remaining());
+        self.issued = self.issued.saturating_add(amount);
+        let schedule = CheckpointSchedule {
+            start: self.start,
+            period: self.period,
+        };
+        // A non-recurring schedule is done after its only issuance
+        self.next_at = self
+            .next_at
+            .and_then(|next_at| schedule.next_checkpoint(next_at));
+        amount
+    }
+}
@@ -15,12 +15,25 @@
 
 use crate::impl_checked_inc;
 use crate::Url;
-use codec::{Decode, DecodeAll, Encode};
+use codec::{Compact, Decode, DecodeAll, Encode, Input};
 use polymesh_primitives_derive::VecU8StrongTyped;
-use scale_info::{PortableRegistry, TypeInfo};
+use scale_info::form::PortableForm;
+use scale_info::prelude::string::String;
+use scale_info::{Field, PortableRegistry, Type, TypeDef, TypeDefPrimitive, TypeInfo};
 #[cfg(feature = "std")]
 use sp_runtime::{Deserialize, Serialize};
 use sp_std::prelude::Vec;
+use sp_std::vec;
+
+/// The maximum nesting depth of a value decoded with an [`AssetMetadataTypeDef`].
+const MAX_DECODE_DEPTH: u32 = 32;
+
+/// The number of types that can be visited per byte of a value decoded with an [`AssetMetadataTypeDef`].
+const DECODE_BUDGET_PER_BYTE: usize = 8;
+
+/// The number of types that can be visited to decode a value with an [`AssetMetadataTypeDef`],
+/// on top of [`DECODE_BUDGET_PER_BYTE`] per byte of the value.
+const DECODE_BUDGET_OVERHEAD: usize = 1024;
 
 /// Asset Metadata Name.
 #[derive(Encode, Decode, TypeInfo, VecU8StrongTyped)]
@@ -31,18 +44,21 @@ pub struct AssetMetadataName(#[cfg_attr(feature = "std", serde(with = "serde_byt
 /// Asset Metadata Global Key.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AssetMetadataGlobalKey(pub u64);
 impl_checked_inc!(AssetMetadataGlobalKey);
 
 /// Asset Metadata Local Key.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AssetMetadataLocalKey(pub u64);
 impl_checked_inc!(AssetMetadataLocalKey);
 
 /// Asset Metadata Key.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum AssetMetadataKey {
     /// Global Metadata Key.
     Global(AssetMetadataGlobalKey),
@@ -203,6 +219,309 @@ impl AssetMetadataTypeDef {
             ty,
         }
     }
+
+    /// Decodes the SCALE encoded `value` as the top-level type of this definition.
+    ///
+    /// Fails if `value` doesn't match the type or has trailing bytes, if sequence items are zero sized,
+    /// or if more types than allowed for the length of `value` must be visited.
+    pub fn decode_value(&self, value: &[u8]) -> Result<AssetMetadataDecodedValue, codec::Error> {
+        let input = &mut &value[..];
+        let mut budget = value
+            .len()
+            .saturating_mul(DECODE_BUDGET_PER_BYTE)
+            .saturating_add(DECODE_BUDGET_OVERHEAD);
+        let decoded = self.decode_type(self.ty, input, 0, &mut budget)?;
+        if !input.is_empty() {
+            return Err("Asset metadata value has trailing bytes".into());
+        }
+        Ok(decoded)
+    }
+
+    /// Resolves the type `id` found at `depth`, using up one unit of `budget`.
+    fn resolve(
+        &self,
+        id: u32,
+        depth: u32,
+        budget: &mut usize,
+    ) -> Result<&Type<PortableForm>, codec::Error> {
+        if depth > MAX_DECODE_DEPTH {
+            return Err("Asset metadata value is nested too deeply".into());
+        }
+        *budget = budget
+            .checked_sub(1)
+            .ok_or("Asset metadata value is too complex to decode")?;
+        self.types
+            .resolve(id)
+            .ok_or_else(|| "Unknown asset metadata type id".into())
+    }
+
+    /// Returns `true` if the type `id` found at `depth` is always encoded with zero bytes.
+    fn is_zero_sized(&self, id: u32, depth: u32, budget: &mut usize) -> Result<bool, codec::Error> {
+        let depth = depth + 1;
+        match &self.resolve(id, depth, budget)?.type_def {
+            TypeDef::Composite(composite) => self.are_zero_sized(
+                composite.fields.iter().map(|field| field.ty.id),
+                depth,
+                budget,
+            ),
+            TypeDef::Tuple(tuple) => {
+                self.are_zero_sized(tuple.fields.iter().map(|field| field.id), depth, budget)
+            }
+            TypeDef::Array(array) => {
+                Ok(array.len == 0 || self.is_zero_sized(array.type_param.id, depth, budget)?)
+            }
+            TypeDef::Variant(_)
+            | TypeDef::Sequence(_)
+            | TypeDef::Primitive(_)
+            | TypeDef::Compact(_)
+            | TypeDef::BitSequence(_) => Ok(false),
+        }
+    }
+
+    /// Returns `true` if all the types `ids` found at `depth` are zero sized.
+    fn are_zero_sized(
+        &self,
+        ids: impl Iterator<Item = u32>,
+        depth: u32,
+        budget: &mut usize,
+    ) -> Result<bool, codec::Error> {
+        for id in ids {
+            if !self.is_zero_sized(id, depth, budget)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn decode_type(
+        &self,
+        id: u32,
+        input: &mut &[u8],
+        depth: u32,
+        budget: &mut usize,
+    ) -> Result<AssetMetadataDecodedValue, codec::Error> {
+        let depth = depth + 1;
+        match &self.resolve(id, depth, budget)?.type_def {
+            TypeDef::Composite(composite) => self
+                .decode_fields(&composite.fields, input, depth, budget)
+                .map(AssetMetadataDecodedValue::Composite),
+            TypeDef::Variant(def) => {
+                let index = u8::decode(input)?;
+                let variant = def
+                    .variants
+                    .iter()
+                    .find(|variant| variant.index == index)
+                    .ok_or("Unknown asset metadata variant index")?;
+                let fields = self.decode_fields(&variant.fields, input, depth, budget)?;
+                Ok(AssetMetadataDecodedValue::Variant(
+                    variant.name.clone(),
+                    fields,
+                ))
+            }
+            TypeDef::Sequence(sequence) => {
+                let len = Compact::<u32>::decode(input)?.0;
+                self.decode_items(sequence.type_param.id, len, input, depth, budget)
+            }
+            TypeDef::Array(array) => {
+                self.decode_items(array.type_param.id, array.len, input, depth, budget)
+            }
+            TypeDef::Tuple(tuple) => tuple
+                .fields
+                .iter()
+                .map(|field| self.decode_type(field.id, input, depth, budget))
+                .collect::<Result<_, _>>()
+                .map(AssetMetadataDecodedValue::Sequence),
+            TypeDef::Primitive(primitive) => decode_primitive(primitive, input),
+            TypeDef::Compact(compact) => {
+                self.decode_compact(compact.type_param.id, input, depth, budget)
+            }
+            TypeDef::BitSequence(_) => {
+                Err("Bit sequences are not supported in asset metadata values".into())
+            }
+        }
+    }
+
+    fn decode_fields(
+        &self,
+        fields: &[Field<PortableForm>],
+        input: &mut &[u8],
+        depth: u32,
+        budget: &mut usize,
+    ) -> Result<Vec<(Option<String>, AssetMetadataDecodedValue)>, codec::Error> {
+        fields
+            .iter()
+            .map(|field| {
+                Ok((
+                    field.name.clone(),
+                    self.decode_type(field.ty.id, input, depth, budget)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Decodes `len` items of type `id`, returning sequences of `u8` as raw bytes.
+    fn decode_items(
+        &self,
+        id: u32,
+        len: u32,
+        input: &mut &[u8],
+        depth: u32,
+        budget: &mut usize,
+    ) -> Result<AssetMetadataDecodedValue, codec::Error> {
+        // Every item must take at least one byte, which bounds the work done for a given value.
+        if self.is_zero_sized(id, depth, budget)? {
+            return Err("Asset metadata sequence items must not be zero sized".into());
+        }
+        if len as usize > input.len() {
+            return Err("Asset metadata sequence is longer than its value".into());
+        }
+        if let TypeDef::Primitive(TypeDefPrimitive::U8) = self.resolve(id, depth, budget)?.type_def
+        {
+            let mut bytes = vec![0u8; len as usize];
+            input.read(&mut bytes)?;
+            return Ok(AssetMetadataDecodedValue::Bytes(bytes));
+        }
+        (0..len)
+            .map(|_| self.decode_type(id, input, depth, budget))
+            .collect::<Result<_, _>>()
+            .map(AssetMetadataDecodedValue::Sequence)
+    }
+
+    /// Decodes a compact encoded unsigned integer, or a single field struct wrapping one.
+    fn decode_compact(
+        &self,
+        id: u32,
+        input: &mut &[u8],
+        depth: u32,
+        budget: &mut usize,
+    ) -> Result<AssetMetadataDecodedValue, codec::Error> {
+        let depth = depth + 1;
+        let value = match &self.resolve(id, depth, budget)?.type_def {
+            TypeDef::Primitive(TypeDefPrimitive::U8) => Compact::<u8>::decode(input)?.0.into(),
+            TypeDef::Primitive(TypeDefPrimitive::U16) => Compact::<u16>::decode(input)?.0.into(),
+            TypeDef::Primitive(TypeDefPrimitive::U32) => Compact::<u32>::decode(input)?.0.into(),
+            TypeDef::Primitive(TypeDefPrimitive::U64) => Compact::<u64>::decode(input)?.0.into(),
+            TypeDef::Primitive(TypeDefPrimitive::U128) => Compact::<u128>::decode(input)?.0,
+            TypeDef::Composite(composite) if composite.fields.len() == 1 => {
+                let field = &composite.fields[0];
+                let value = self.decode_compact(field.ty.id, input, depth, budget)?;
+                return Ok(AssetMetadataDecodedValue::Composite(vec![(
+                    field.name.clone(),
+                    value,
+                )]));
+            }
+            _ => return Err("Unsupported compact asset metadata type".into()),
+        };
+        Ok(AssetMetadataDecodedValue::Unsigned(value))
+    }
+}
+
+fn decode_primitive(
+    primitive: &TypeDefPrimitive,
+    input: &mut &[u8],
+) -> Result<AssetMetadataDecodedValue, codec::Error> {
+    use AssetMetadataDecodedValue::*;
+    Ok(match primitive {
+        TypeDefPrimitive::Bool => Bool(bool::decode(input)?),
+        TypeDefPrimitive::Char => {
+            let c = char::from_u32(u32::decode(input)?).ok_or("Invalid asset metadata char")?;
+            Str(c.into())
+        }
+        TypeDefPrimitive::Str => Str(String::decode(input)?),
+        TypeDefPrimitive::U8 => Unsigned(u8::decode(input)?.into()),
+        TypeDefPrimitive::U16 => Unsigned(u16::decode(input)?.into()),
+        TypeDefPrimitive::U32 => Unsigned(u32::decode(input)?.into()),
+        TypeDefPrimitive::U64 => Unsigned(u64::decode(input)?.into()),
+        TypeDefPrimitive::U128 => Unsigned(u128::decode(input)?),
+        TypeDefPrimitive::I8 => Signed(i8::decode(input)?.into()),
+        TypeDefPrimitive::I16 => Signed(i16::decode(input)?.into()),
+        TypeDefPrimitive::I32 => Signed(i32::decode(input)?.into()),
+        TypeDefPrimitive::I64 => Signed(i64::decode(input)?.into()),
+        TypeDefPrimitive::I128 => Signed(i128::decode(input)?),
+        TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => {
+            Bytes(<[u8; 32]>::decode(input)?.to_vec())
+        }
+    })
+}
+
+/// An asset metadata value decoded with its [`AssetMetadataTypeDef`].
+///
+/// When serialized, structs become JSON objects (or arrays, if their fields are unnamed),
+/// enums become either their variant name or an object keyed by it, and bytes become hex strings.
+/// Integers outside of the 64-bit range are serialized as strings, since JSON numbers can't
+/// represent them precisely.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssetMetadataDecodedValue {
+    /// A boolean.
+    Bool(bool),
+    /// A string or a char.
+    Str(String),
+    /// An unsigned integer up to 128 bits.
+    Unsigned(u128),
+    /// A signed integer up to 128 bits.
+    Signed(i128),
+    /// A sequence or array of `u8`, or a 256-bit integer in little endian.
+    Bytes(Vec<u8>),
+    /// A sequence, array or tuple.
+    Sequence(Vec<AssetMetadataDecodedValue>),
+    /// A struct with its (optionally named) fields.
+    Composite(Vec<(Option<String>, AssetMetadataDecodedValue)>),
+    /// An enum variant name with its (optionally named) fields.
+    Variant(String, Vec<(Option<String>, AssetMetadataDecodedValue)>),
+}
+
+#[cfg(feature = "std")]
+impl Serialize for AssetMetadataDecodedValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        match self {
+            Self::Bool(b) => serializer.serialize_bool(*b),
+            Self::Str(s) => serializer.serialize_str(s),
+            Self::Unsigned(n) => match u64::try_from(*n) {
+                Ok(n) => serializer.serialize_u64(n),
+                Err(_) => serializer.serialize_str(&n.to_string()),
+            },
+            Self::Signed(n) => match i64::try_from(*n) {
+                Ok(n) => serializer.serialize_i64(n),
+                Err(_) => serializer.serialize_str(&n.to_string()),
+            },
+            Self::Bytes(bytes) => {
+                let hex: String = rustc_hex::ToHex::to_hex(&bytes[..]);
+                serializer.serialize_str(&format!("0x{}", hex))
+            }
+            Self::Sequence(items) => serializer.collect_seq(items),
+            Self::Composite(fields) => DecodedFields(fields).serialize(serializer),
+            Self::Variant(name, fields) if fields.is_empty() => serializer.serialize_str(name),
+            Self::Variant(name, fields) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(name, &DecodedFields(fields))?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// The fields of a decoded struct or enum variant.
+#[cfg(feature = "std")]
+struct DecodedFields<'a>(&'a [(Option<String>, AssetMetadataDecodedValue)]);
+
+#[cfg(feature = "std")]
+impl Serialize for DecodedFields<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            // Newtypes are serialized as their inner value.
+            [(None, value)] => value.serialize(serializer),
+            fields if !fields.is_empty() && fields.iter().all(|(name, _)| name.is_some()) => {
+                serializer.collect_map(
+                    fields
+                        .iter()
+                        .map(|(name, value)| (name.as_deref().unwrap_or_default(), value)),
+                )
+            }
+            fields => serializer.collect_seq(fields.iter().map(|(_, value)| value)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -234,4 +553,62 @@ mod tests {
             .expect("Missing type def.");
         assert_eq!(type_def, type_def2);
     }
+
+    #[test]
+    fn decode_metadata_value_test() {
+        let type_def = AssetMetadataTypeDef::new_from_type::<AssetMetadataValueDetail<Moment>>();
+        let detail = AssetMetadataValueDetail {
+            expire: Some(1_000u64),
+            lock_status: AssetMetadataLockStatus::LockedUntil(2_000u64),
+        };
+        let value = detail.encode();
+        let decoded = type_def
+            .decode_value(&value)
+            .expect("Failed to decode value.");
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::json!({ "expire": { "Some": 1000 }, "lock_status": { "LockedUntil": 2000 } })
+        );
+
+        // Truncated values and values with trailing bytes don't match the type.
+        assert!(type_def.decode_value(&value[..value.len() - 1]).is_err());
+        assert!(type_def.decode_value(&[&value[..], &[0]].concat()).is_err());
+
+        // Byte sequences are decoded as hex strings.
+        let type_def = AssetMetadataTypeDef::new_from_type::<Vec<u8>>();
+        let decoded = type_def
+            .decode_value(&vec![1u8, 2].encode())
+            .expect("Failed to decode value.");
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::json!("0x0102")
+        );
+    }
+
+    #[test]
+    fn decode_zero_sized_items_test() {
+        // Items of sequences and arrays can't be zero sized.
+        let type_def = AssetMetadataTypeDef::new_from_type::<Vec<()>>();
+        assert!(type_def.decode_value(&vec![(); 3].encode()).is_err());
+        let type_def = AssetMetadataTypeDef::new_from_type::<[((), [u8; 0]); 1_000]>();
+        assert!(type_def.decode_value(&[]).is_err());
+
+        // Zero sized fields are fine.
+        let type_def = AssetMetadataTypeDef::new_from_type::<Vec<((), u8)>>();
+        assert!(type_def.decode_value(&vec![((), 1u8)].encode()).is_ok());
+    }
+
+    #[test]
+    fn decode_budget_test() {
+        type T1 = ((), (), (), (), (), (), (), ());
+        type T2 = (T1, T1, T1, T1, T1, T1, T1, T1);
+        type T3 = (T2, T2, T2, T2, T2, T2, T2, T2);
+        type T4 = (T3, T3, T3, T3, T3, T3, T3, T3);
+
+        // Decoding an empty `T3` visits less types than allowed, but not `T4`.
+        let type_def = AssetMetadataTypeDef::new_from_type::<T3>();
+        assert!(type_def.decode_value(&[]).is_ok());
+        let type_def = AssetMetadataTypeDef::new_from_type::<T4>();
+        assert!(type_def.decode_value(&[]).is_err());
+    }
 }
@@ -2,12 +2,17 @@ use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use codec::{Decode, Encode};
 use core::num::NonZeroU64;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use sp_runtime::{Deserialize, Serialize};
 use sp_std::convert::TryFrom;
 
 use crate::Moment;
 
 /// Calendar units for timing recurring operations.
-#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode, Decode, TypeInfo, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord
+)]
 pub enum CalendarUnit {
     /// A unit of one second.
     Second,
@@ -80,7 +85,10 @@ pub enum FixedOrVariableCalendarUnit {
 }
 
 /// A simple period which is a multiple of a `CalendarUnit`.
-#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode, Decode, TypeInfo, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord
+)]
 pub struct CalendarPeriod {
     /// The base calendar unit.
     pub unit: CalendarUnit,
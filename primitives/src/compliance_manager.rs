@@ -233,6 +233,8 @@ pub struct ConditionReport {
     pub satisfied: bool,
     /// The [`Condition`] assessed.
     pub condition: Condition,
+    /// The result of each node, if the condition is an expression tree.
+    pub expression: Option<ExpressionReport>,
 }
 
 impl ConditionReport {
@@ -241,6 +243,16 @@ impl ConditionReport {
         Self {
             satisfied,
             condition,
+            expression: None,
+        }
+    }
+
+    /// Creates a new [`ConditionReport`] instance for an expression tree condition.
+    pub fn new_with_expression(condition: Condition, expression: ExpressionReport) -> Self {
+        Self {
+            satisfied: expression.satisfied,
+            condition,
+            expression: Some(expression),
         }
     }
 
@@ -254,3 +266,101 @@ impl ConditionReport {
         &self.condition
     }
 }
+
+/// Holds the result of a node of a [`ConditionExpression`](crate::ConditionExpression) tree.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq)]
+pub struct ExpressionReport {
+    /// Set to `true` if the node holds.
+    pub satisfied: bool,
+    /// The reports of the sub-expressions, in the same order as in the node.
+    pub children: Vec<ExpressionReport>,
+}
+
+impl ExpressionReport {
+    /// Creates a new [`ExpressionReport`] instance.
+    pub fn new(satisfied: bool, children: Vec<ExpressionReport>) -> Self {
+        Self {
+            satisfied,
+            children,
+        }
+    }
+}
+
+/// The reports returned by version `2` of the compliance runtime api, before [`ExpressionReport`] was added.
+pub mod v2 {
+    use super::*;
+
+    /// Holds detailed information for all asset's requirements.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+    #[derive(Encode, Decode, TypeInfo)]
+    pub struct ComplianceReport {
+        /// Set to `true` if any requirement is satisfied.
+        any_requirement_satisfied: bool,
+        /// Set to `true` if the asset compliance is paused.
+        paused_compliance: bool,
+        /// All [`RequirementReport`] containg the info for each of the asset's requirement.
+        requirements: Vec<RequirementReport>,
+    }
+
+    /// Holds the information for an individual asset requirement.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+    #[derive(Encode, Decode, TypeInfo)]
+    pub struct RequirementReport {
+        /// Set to `true` if all conditions are satisfied.
+        requirement_satisfied: bool,
+        /// Unique identifier of the compliance requirement.
+        id: u32,
+        /// All sender [`ConditionReport`].
+        sender_conditions: Vec<ConditionReport>,
+        /// All receiver [`ConditionReport`].
+        receiver_conditions: Vec<ConditionReport>,
+    }
+
+    /// Holds the information for an individual condition.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+    #[derive(Encode, Decode, TypeInfo)]
+    pub struct ConditionReport {
+        /// Set to `true` if the condition is satisfied.
+        pub satisfied: bool,
+        /// The [`Condition`] assessed.
+        pub condition: Condition,
+    }
+
+    impl From<ComplianceReport> for super::ComplianceReport {
+        fn from(report: ComplianceReport) -> Self {
+            Self::new(
+                report
+                    .requirements
+                    .into_iter()
+                    .map(super::RequirementReport::from)
+                    .collect(),
+                report.any_requirement_satisfied,
+                report.paused_compliance,
+            )
+        }
+    }
+
+    impl From<RequirementReport> for super::RequirementReport {
+        fn from(report: RequirementReport) -> Self {
+            let convert = |conditions: Vec<ConditionReport>| {
+                conditions
+                    .into_iter()
+                    .map(super::ConditionReport::from)
+                    .collect::<Vec<_>>()
+            };
+            Self::new(
+                convert(report.sender_conditions),
+                convert(report.receiver_conditions),
+                report.id,
+                report.requirement_satisfied,
+            )
+        }
+    }
+
+    impl From<ConditionReport> for super::ConditionReport {
+        fn from(report: ConditionReport) -> Self {
+            Self::new(report.condition, report.satisfied)
+        }
+    }
+}
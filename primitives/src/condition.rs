@@ -13,7 +13,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Claim, ClaimType, IdentityId};
+use crate::{Claim, ClaimType, CountryCode, IdentityClaim, IdentityId, Moment, Scope};
 use codec::{Decode, Encode};
 use core::iter;
 use either::Either;
@@ -48,16 +48,118 @@ pub enum ConditionType {
     IsNoneOf(Vec<Claim>),
     /// Condition to ensure that the sender/receiver is a particular identity or an external agent.
     IsIdentity(TargetIdentity),
+    /// Condition to ensure that a boolean expression of claim checks holds.
+    Expression(ConditionExpression),
+    /// Condition to ensure that claim filter produces one claim whose dates satisfy the predicate.
+    IsPresentWith(Claim, ClaimDatePredicate),
+    /// Condition to ensure that the jurisdiction claim with the given scope is one of the countries.
+    IsJurisdictionIn(Vec<CountryCode>, Scope),
 }
 
 impl ConditionType {
-    /// Return the number of `Claim` or `TargetIdentity`.
+    /// Return the number of `Claim`, `TargetIdentity` or `CountryCode`.
     fn count(&self) -> usize {
         match self {
             ConditionType::IsIdentity(..)
             | ConditionType::IsPresent(..)
-            | ConditionType::IsAbsent(..) => 1,
+            | ConditionType::IsAbsent(..)
+            | ConditionType::IsPresentWith(..) => 1,
             ConditionType::IsNoneOf(claims) | ConditionType::IsAnyOf(claims) => claims.len(),
+            ConditionType::IsJurisdictionIn(countries, _) => countries.len(),
+            ConditionType::Expression(expression) => expression.claims().len(),
+        }
+    }
+}
+
+/// A comparison of the dates of an [`IdentityClaim`] against the current time, in milliseconds.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ClaimDatePredicate {
+    /// The claim was issued at most this long ago.
+    IssuedWithin(Moment),
+    /// The claim was last updated at most this long ago.
+    UpdatedWithin(Moment),
+    /// The claim doesn't expire for at least this long.
+    /// Claims without an expiry always satisfy this predicate.
+    ValidFor(Moment),
+}
+
+impl ClaimDatePredicate {
+    /// Returns `true` if the dates of `claim` satisfy this predicate at `now`.
+    pub fn evaluate(&self, claim: &IdentityClaim, now: Moment) -> bool {
+        match self {
+            Self::IssuedWithin(period) => claim.issuance_date.saturating_add(*period) >= now,
+            Self::UpdatedWithin(period) => claim.last_update_date.saturating_add(*period) >= now,
+            Self::ValidFor(period) => claim
+                .expiry
+                .map_or(true, |expiry| expiry >= now.saturating_add(*period)),
+        }
+    }
+}
+
+/// The maximum depth of a [`ConditionExpression`] tree.
+pub const MAX_CONDITION_EXPRESSION_DEPTH: u32 = 5;
+
+/// A node of a boolean expression tree of claim checks.
+///
+/// For instance, "(Accredited AND Jurisdiction=US) OR (NOT Jurisdiction=US AND KYC)" is encoded as
+/// `Or([And([IsPresent(Accredited), IsPresent(US)]), And([Not(IsPresent(US)), IsPresent(KYC)])])`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ConditionExpression {
+    /// Holds if the claim is present.
+    IsPresent(Claim),
+    /// Holds if at least one of the claims is present.
+    IsAnyOf(Vec<Claim>),
+    /// Holds if all the sub-expressions hold.
+    And(Vec<ConditionExpression>),
+    /// Holds if at least one of the sub-expressions holds.
+    Or(Vec<ConditionExpression>),
+    /// Holds if the sub-expression doesn't hold.
+    Not(Box<ConditionExpression>),
+}
+
+impl ConditionExpression {
+    /// Returns the depth of the expression tree, where a claim check has a depth of `1`.
+    pub fn depth(&self) -> u32 {
+        match self {
+            Self::IsPresent(..) | Self::IsAnyOf(..) => 1,
+            Self::And(exprs) | Self::Or(exprs) => exprs
+                .iter()
+                .map(Self::depth)
+                .max()
+                .unwrap_or_default()
+                .saturating_add(1),
+            Self::Not(expr) => expr.depth().saturating_add(1),
+        }
+    }
+
+    /// Returns the number of nodes of the expression tree.
+    pub fn node_count(&self) -> usize {
+        match self {
+            Self::IsPresent(..) | Self::IsAnyOf(..) => 1,
+            Self::And(exprs) | Self::Or(exprs) => exprs.iter().fold(1usize, |count, expr| {
+                count.saturating_add(expr.node_count())
+            }),
+            Self::Not(expr) => expr.node_count().saturating_add(1),
+        }
+    }
+
+    /// Returns all the claims checked by the expression.
+    pub fn claims(&self) -> Vec<&Claim> {
+        let mut claims = Vec::new();
+        self.collect_claims(&mut claims);
+        claims
+    }
+
+    fn collect_claims<'a>(&'a self, claims: &mut Vec<&'a Claim>) {
+        match self {
+            Self::IsPresent(claim) => claims.push(claim),
+            Self::IsAnyOf(cs) => claims.extend(cs.iter()),
+            Self::And(exprs) | Self::Or(exprs) => {
+                exprs.iter().for_each(|expr| expr.collect_claims(claims))
+            }
+            Self::Not(expr) => expr.collect_claims(claims),
         }
     }
 }
@@ -152,19 +254,45 @@ impl Condition {
     }
 
     /// Returns worst case complexity of a condition.
+    ///
+    /// The nodes of an expression tree are added to the complexity of its claims.
     pub fn complexity(&self, default_issuer_count: usize) -> u32 {
         let issuers = match self.issuers.len() {
             0 => default_issuer_count,
             count => count,
         };
+        let nodes = match &self.condition_type {
+            ConditionType::Expression(expression) => expression.node_count(),
+            _ => 0,
+        };
         self.condition_type
             .count()
             // NB: `max(1)` makes sure issuer count is not zero.
             .saturating_mul(issuers.max(1))
+            .saturating_add(nodes)
             .try_into()
             .unwrap_or(u32::MAX)
     }
 
+    /// Returns all the scopes of the claims in the condition.
+    pub fn scopes(&self) -> impl Iterator<Item = &Scope> {
+        let jurisdiction_scope = match &self.condition_type {
+            ConditionType::IsJurisdictionIn(_, scope) => Some(scope),
+            _ => None,
+        };
+        self.claims()
+            .filter_map(Claim::as_scope)
+            .chain(jurisdiction_scope)
+    }
+
+    /// Returns the depth of the condition, which is `1` unless it is an expression tree.
+    pub fn depth(&self) -> u32 {
+        match &self.condition_type {
+            ConditionType::Expression(expression) => expression.depth(),
+            _ => 1,
+        }
+    }
+
     /// Return number of claims, issuers, and claim_types.
     ///
     /// This is used for weight calculation.
@@ -188,10 +316,16 @@ impl Condition {
 
     /// Returns all the claims in the condition.
     pub fn claims(&self) -> impl Iterator<Item = &Claim> {
+        use Either::{Left, Right};
         match &self.condition_type {
-            ConditionType::IsPresent(c) | ConditionType::IsAbsent(c) => Either::Left(iter::once(c)),
-            ConditionType::IsAnyOf(cs) | ConditionType::IsNoneOf(cs) => Either::Right(cs.iter()),
-            ConditionType::IsIdentity(_) => Either::Right([].iter()),
+            ConditionType::IsPresent(c)
+            | ConditionType::IsAbsent(c)
+            | ConditionType::IsPresentWith(c, _) => Left(iter::once(c)),
+            ConditionType::IsAnyOf(cs) | ConditionType::IsNoneOf(cs) => Right(Left(cs.iter())),
+            ConditionType::IsIdentity(_) | ConditionType::IsJurisdictionIn(..) => {
+                Right(Left([].iter()))
+            }
+            ConditionType::Expression(expression) => Right(Right(expression.claims().into_iter())),
         }
     }
 }
@@ -19,5 +19,7 @@
 
 /// Prefixes for scheduled actions
 pub const SETTLEMENT_INSTRUCTION_EXECUTION: [u8; 27] = *b"SETTLEMENT_INSTRUCTION_EXEC";
+pub const SETTLEMENT_INSTRUCTION_DEADLINE: [u8; 31] = *b"SETTLEMENT_INSTRUCTION_DEADLINE";
 pub const PIP_EXECUTION: [u8; 8] = *b"PIP_EXEC";
 pub const PIP_EXPIRY: [u8; 10] = *b"PIP_EXPIRY";
+pub const BRIDGE_TX_EXECUTION: [u8; 14] = *b"BRIDGE_TX_EXEC";
@@ -0,0 +1,58 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode};
+use frame_support::weights::Weight;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::DispatchResult;
+use sp_std::vec::Vec;
+
+use crate::{AccountId, Balance, FilteredEvent, IdentityId};
+
+/// The origin a call is dry run with.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DryRunOrigin {
+    /// The call is signed by the given account.
+    Account(AccountId),
+    /// The call is signed by the primary key of the given identity.
+    Identity(IdentityId),
+}
+
+/// The reasons a call can't be dry run.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum DryRunError {
+    /// The call couldn't be decoded as a `RuntimeCall`.
+    InvalidCall,
+    /// The identity of the origin has no primary key.
+    MissingPrimaryKey,
+}
+
+/// The outcome of dry running a call on top of the state of a block.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct DryRunEffects {
+    /// The result of dispatching the call.
+    pub result: DispatchResult,
+    /// The actual weight consumed by the call.
+    pub actual_weight: Weight,
+    /// The fee charged for the actual weight and the length of the call, without any tip.
+    pub partial_fee: Balance,
+    /// The events emitted by the call.
+    ///
+    /// A failed batch item is reported by the events of the utility pallet.
+    pub events: Vec<FilteredEvent>,
+}
@@ -0,0 +1,536 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use codec::{Compact, Decode, Encode};
+use scale_info::form::PortableForm;
+use scale_info::{meta_type, Registry, Type, TypeDef, TypeDefPrimitive, TypeInfo, Variant};
+use sp_std::prelude::*;
+
+use crate::asset::AssetId;
+use crate::asset_metadata::{AssetMetadataDecodedValue, AssetMetadataTypeDef};
+use crate::settlement::InstructionId;
+use crate::{IdentityId, PortfolioId};
+
+/// Selects the events of a block.
+///
+/// An event matches the filter when it satisfies all of the given criteria.
+/// Identities, assets, portfolios and instructions are only matched against the event fields of the same type,
+/// which are found using the type information of the runtime event. A portfolio also matches the identity of its owner.
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+pub struct EventFilter {
+    /// The name of the pallet that emitted the event, e.g. `Settlement`.
+    pub pallet: Option<Vec<u8>>,
+    /// The name of the event, e.g. `InstructionExecuted`.
+    pub event: Option<Vec<u8>>,
+    /// An [`IdentityId`] the event must contain.
+    pub identity: Option<IdentityId>,
+    /// An [`AssetId`] the event must contain.
+    pub asset_id: Option<AssetId>,
+    /// A [`PortfolioId`] the event must contain.
+    pub portfolio: Option<PortfolioId>,
+    /// An [`InstructionId`] the event must contain.
+    pub instruction_id: Option<InstructionId>,
+}
+
+/// An event that matched an [`EventFilter`].
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct FilteredEvent {
+    /// The index of the event in the block.
+    pub index: u32,
+    /// The name of the pallet that emitted the event, empty if the event couldn't be decoded.
+    pub pallet: Vec<u8>,
+    /// The name of the event, empty if the event couldn't be decoded.
+    pub event: Vec<u8>,
+    /// The SCALE encoded runtime event.
+    pub data: Vec<u8>,
+    /// The fields of the event, decoded as an [`AssetMetadataDecodedValue::Composite`],
+    /// or `None` if they couldn't be decoded.
+    pub fields: Option<AssetMetadataDecodedValue>,
+}
+
+impl EventFilter {
+    /// Returns all `events` matching this filter, along with their index in `events`.
+    ///
+    /// `E` must be the outer runtime event, i.e. an enum with one variant per pallet,
+    /// each containing the event enum of that pallet.
+    pub fn filter_events<E: Encode + TypeInfo + 'static>(
+        &self,
+        events: impl IntoIterator<Item = E>,
+    ) -> Vec<FilteredEvent> {
+        self.filter_encoded_events(
+            &EventTypes::new::<E>(),
+            events.into_iter().map(|event| event.encode()),
+        )
+    }
+
+    /// Returns all SCALE encoded `events` matching this filter, along with their index in `events`.
+    ///
+    /// An event that can't be decoded with `types` can't be ruled out either,
+    /// so it is returned with its raw data only, as long as the criteria that could be checked are met.
+    pub fn filter_encoded_events(
+        &self,
+        types: &EventTypes,
+        events: impl IntoIterator<Item = Vec<u8>>,
+    ) -> Vec<FilteredEvent> {
+        events
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, data)| {
+                let input = &mut &data[..];
+                let (pallet, event, fields) = match types.visit_names(input) {
+                    Ok((pallet, event)) => {
+                        if !self.matches_names(&pallet.name, &event.name) {
+                            return None;
+                        }
+                        let fields = match types.visit_fields(event, input) {
+                            Ok(typed_fields) if !self.matches_fields(&typed_fields) => return None,
+                            Ok(_) => types.decode_fields(&data),
+                            Err(_) => None,
+                        };
+                        (pallet.name.as_bytes(), event.name.as_bytes(), fields)
+                    }
+                    Err(_) => (&[][..], &[][..], None),
+                };
+                Some(FilteredEvent {
+                    index: index as u32,
+                    pallet: pallet.to_vec(),
+                    event: event.to_vec(),
+                    fields,
+                    data,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns `true` if an event of the given names satisfies the name criteria of this filter.
+    fn matches_names(&self, pallet: &str, event: &str) -> bool {
+        self.pallet
+            .as_ref()
+            .map_or(true, |p| p == pallet.as_bytes())
+            && self.event.as_ref().map_or(true, |e| e == event.as_bytes())
+    }
+
+    /// Returns `true` if an event with the given typed fields satisfies the field criteria of this filter.
+    fn matches_fields(&self, fields: &TypedFields) -> bool {
+        self.identity
+            .map_or(true, |did| fields.identities.contains(&did))
+            && self
+                .asset_id
+                .map_or(true, |asset_id| fields.asset_ids.contains(&asset_id))
+            && self
+                .portfolio
+                .map_or(true, |portfolio| fields.portfolios.contains(&portfolio))
+            && self
+                .instruction_id
+                .map_or(true, |id| fields.instruction_ids.contains(&id))
+    }
+}
+
+/// The fields of an event whose types can be matched by an [`EventFilter`].
+#[derive(Default)]
+struct TypedFields {
+    identities: Vec<IdentityId>,
+    asset_ids: Vec<AssetId>,
+    portfolios: Vec<PortfolioId>,
+    instruction_ids: Vec<InstructionId>,
+}
+
+/// The type information of an outer runtime event, along with the ids of the types matched by an [`EventFilter`].
+///
+/// Building it registers the whole type graph of the runtime event, so it should be reused for all events
+/// emitted by the same runtime.
+#[derive(Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventTypes {
+    type_def: AssetMetadataTypeDef,
+    #[codec(compact)]
+    identity: u32,
+    #[codec(compact)]
+    asset_id: u32,
+    #[codec(compact)]
+    portfolio: u32,
+    #[codec(compact)]
+    instruction_id: u32,
+}
+
+impl EventTypes {
+    /// Creates the type information of the outer runtime event `E`.
+    pub fn new<E: TypeInfo + 'static>() -> Self {
+        let mut registry = Registry::new();
+        let ty = registry.register_type(&meta_type::<E>()).id;
+        let identity = registry.register_type(&meta_type::<IdentityId>()).id;
+        let asset_id = registry.register_type(&meta_type::<AssetId>()).id;
+        let portfolio = registry.register_type(&meta_type::<PortfolioId>()).id;
+        let instruction_id = registry.register_type(&meta_type::<InstructionId>()).id;
+        Self {
+            type_def: AssetMetadataTypeDef {
+                types: registry.into(),
+                ty,
+            },
+            identity,
+            asset_id,
+            portfolio,
+            instruction_id,
+        }
+    }
+
+    fn resolve(&self, id: u32) -> Result<&Type<PortableForm>, codec::Error> {
+        self.type_def
+            .types
+            .resolve(id)
+            .ok_or_else(|| "Unknown event type id".into())
+    }
+
+    /// Decodes the pallet and event variants of the SCALE encoded runtime event in `input`.
+    fn visit_names(
+        &self,
+        input: &mut &[u8],
+    ) -> Result<(&Variant<PortableForm>, &Variant<PortableForm>), codec::Error> {
+        let pallet = self.visit_variant(self.type_def.ty, input)?;
+        let pallet_event = pallet.fields.first().ok_or("Missing pallet event")?;
+        let event = self.visit_variant(pallet_event.ty.id, input)?;
+        Ok((pallet, event))
+    }
+
+    /// Decodes the fields of the `event` variant in `input`, returning those that can be matched by an [`EventFilter`].
+    fn visit_fields(
+        &self,
+        event: &Variant<PortableForm>,
+        input: &mut &[u8],
+    ) -> Result<TypedFields, codec::Error> {
+        let mut typed_fields = TypedFields::default();
+        for field in &event.fields {
+            self.visit(field.ty.id, input, &mut typed_fields)?;
+        }
+        Ok(typed_fields)
+    }
+
+    /// Decodes the index of a variant of the enum `id`, returning the variant.
+    fn visit_variant(
+        &self,
+        id: u32,
+        input: &mut &[u8],
+    ) -> Result<&Variant<PortableForm>, codec::Error> {
+        let variants = match &self.resolve(id)?.type_def {
+            TypeDef::Variant(def) => &def.variants,
+            _ => return Err("Event type is not an enum".into()),
+        };
+        let index = u8::decode(input)?;
+        variants
+            .iter()
+            .find(|variant| variant.index == index)
+            .ok_or_else(|| "Unknown event variant index".into())
+    }
+
+    /// Decodes a value of the type `id`, collecting its fields whose types can be matched by an [`EventFilter`].
+    fn visit(
+        &self,
+        id: u32,
+        input: &mut &[u8],
+        typed_fields: &mut TypedFields,
+    ) -> Result<(), codec::Error> {
+        if id == self.identity {
+            typed_fields.identities.push(IdentityId::decode(input)?);
+            return Ok(());
+        }
+        if id == self.asset_id {
+            typed_fields.asset_ids.push(AssetId::decode(input)?);
+            return Ok(());
+        }
+        if id == self.portfolio {
+            let portfolio = PortfolioId::decode(input)?;
+            typed_fields.identities.push(portfolio.did);
+            typed_fields.portfolios.push(portfolio);
+            return Ok(());
+        }
+        if id == self.instruction_id {
+            typed_fields
+                .instruction_ids
+                .push(InstructionId::decode(input)?);
+            return Ok(());
+        }
+        match &self.resolve(id)?.type_def {
+            TypeDef::Composite(composite) => composite
+                .fields
+                .iter()
+                .try_for_each(|field| self.visit(field.ty.id, input, typed_fields)),
+            TypeDef::Variant(_) => self
+                .visit_variant(id, input)?
+                .fields
+                .iter()
+                .try_for_each(|field| self.visit(field.ty.id, input, typed_fields)),
+            TypeDef::Sequence(sequence) => {
+                let len = Compact::<u32>::decode(input)?.0;
+                self.visit_items(sequence.type_param.id, len, input, typed_fields)
+            }
+            TypeDef::Array(array) => {
+                self.visit_items(array.type_param.id, array.len, input, typed_fields)
+            }
+            TypeDef::Tuple(tuple) => tuple
+                .fields
+                .iter()
+                .try_for_each(|field| self.visit(field.id, input, typed_fields)),
+            TypeDef::Primitive(primitive) => skip_primitive(primitive, input),
+            // Compact integers of any width share the same encoding.
+            TypeDef::Compact(_) => Compact::<u128>::decode(input).map(|_| ()),
+            TypeDef::BitSequence(_) => Err("Bit sequences are not supported in events".into()),
+        }
+    }
+
+    /// Decodes `len` items of type `id`, skipping sequences of `u8` at once.
+    fn visit_items(
+        &self,
+        id: u32,
+        len: u32,
+        input: &mut &[u8],
+        typed_fields: &mut TypedFields,
+    ) -> Result<(), codec::Error> {
+        if len as usize > input.len() {
+            return Err("Event sequence is longer than its value".into());
+        }
+        if let TypeDef::Primitive(TypeDefPrimitive::U8) = self.resolve(id)?.type_def {
+            return skip(input, len as usize);
+        }
+        (0..len).try_for_each(|_| self.visit(id, input, typed_fields))
+    }
+
+    /// Decodes the fields of the SCALE encoded runtime event `data`.
+    fn decode_fields(&self, data: &[u8]) -> Option<AssetMetadataDecodedValue> {
+        // The runtime event is a pallet variant wrapping the event variant of that pallet.
+        match self.type_def.decode_value(data).ok()? {
+            AssetMetadataDecodedValue::Variant(_, mut pallet_event) => match pallet_event.pop()? {
+                (_, AssetMetadataDecodedValue::Variant(_, fields)) => {
+                    Some(AssetMetadataDecodedValue::Composite(fields))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Skips the SCALE encoded `primitive` in `input`.
+fn skip_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<(), codec::Error> {
+    let len = match primitive {
+        TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+        TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+        TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+        TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+        TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+        TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+        TypeDefPrimitive::Str => Compact::<u32>::decode(input)?.0 as usize,
+    };
+    skip(input, len)
+}
+
+/// Skips `len` bytes of `input`.
+fn skip(input: &mut &[u8], len: usize) -> Result<(), codec::Error> {
+    if input.len() < len {
+        return Err("Not enough data to decode the event".into());
+    }
+    *input = &input[len..];
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PortfolioKind, PortfolioNumber};
+
+    #[derive(Encode, TypeInfo)]
+    enum AssetEvent {
+        Issued(IdentityId, AssetId, u128),
+        Frozen(AssetId),
+    }
+
+    #[derive(Encode, TypeInfo)]
+    enum SettlementEvent {
+        InstructionExecuted(IdentityId, InstructionId),
+        Moved(PortfolioId, PortfolioId),
+        Memo([u8; 32], u64),
+    }
+
+    #[derive(Encode, TypeInfo)]
+    enum RuntimeEvent {
+        #[codec(index = 3)]
+        Asset(AssetEvent),
+        #[codec(index = 7)]
+        Settlement(SettlementEvent),
+    }
+
+    fn events() -> Vec<RuntimeEvent> {
+        let asset_id = AssetId::new([1; 16]);
+        vec![
+            RuntimeEvent::Asset(AssetEvent::Issued(IdentityId::from(1), asset_id, 100)),
+            RuntimeEvent::Asset(AssetEvent::Frozen(asset_id)),
+            RuntimeEvent::Settlement(SettlementEvent::InstructionExecuted(
+                IdentityId::from(2),
+                InstructionId(9),
+            )),
+            RuntimeEvent::Settlement(SettlementEvent::Moved(
+                PortfolioId::default_portfolio(IdentityId::from(1)),
+                PortfolioId::user_portfolio(IdentityId::from(2), PortfolioNumber(1)),
+            )),
+        ]
+    }
+
+    fn matched(filter: EventFilter) -> Vec<u32> {
+        filter
+            .filter_events(events())
+            .into_iter()
+            .map(|e| e.index)
+            .collect()
+    }
+
+    #[test]
+    fn filter_by_names() {
+        assert_eq!(matched(EventFilter::default()), vec![0, 1, 2, 3]);
+
+        let filter = EventFilter {
+            pallet: Some(b"Asset".to_vec()),
+            ..Default::default()
+        };
+        assert_eq!(matched(filter), vec![0, 1]);
+
+        let filter = EventFilter {
+            pallet: Some(b"Settlement".to_vec()),
+            event: Some(b"Moved".to_vec()),
+            ..Default::default()
+        };
+        let filtered = filter.filter_events(events());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].index, 3);
+        assert_eq!(filtered[0].pallet, b"Settlement".to_vec());
+        assert_eq!(filtered[0].event, b"Moved".to_vec());
+
+        let filter = EventFilter {
+            event: Some(b"Frozen".to_vec()),
+            ..Default::default()
+        };
+        let filtered = filter.filter_events(events());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].data, events()[1].encode());
+        assert_eq!(
+            filtered[0].fields,
+            Some(AssetMetadataDecodedValue::Composite(vec![(
+                None,
+                AssetMetadataDecodedValue::Composite(vec![(
+                    None,
+                    AssetMetadataDecodedValue::Bytes(vec![1; 16])
+                )])
+            )]))
+        );
+    }
+
+    #[test]
+    fn filter_by_fields() {
+        assert_eq!(
+            matched(EventFilter {
+                identity: Some(IdentityId::from(2)),
+                ..Default::default()
+            }),
+            vec![2, 3]
+        );
+        assert_eq!(
+            matched(EventFilter {
+                identity: Some(IdentityId::from(1)),
+                asset_id: Some(AssetId::new([1; 16])),
+                ..Default::default()
+            }),
+            vec![0]
+        );
+        assert_eq!(
+            matched(EventFilter {
+                portfolio: Some(PortfolioId::new(
+                    IdentityId::from(2),
+                    PortfolioKind::User(PortfolioNumber(1))
+                )),
+                ..Default::default()
+            }),
+            vec![3]
+        );
+        assert_eq!(
+            matched(EventFilter {
+                instruction_id: Some(InstructionId(9)),
+                ..Default::default()
+            }),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn filter_ignores_untyped_fields() {
+        // The same bytes as an identity, an asset and an instruction, in fields of other types
+        let mut memo = [0; 32];
+        memo[..16].copy_from_slice(&[1; 16]);
+        let events = || {
+            vec![
+                RuntimeEvent::Settlement(SettlementEvent::Memo(IdentityId::from(5).0, 9)),
+                RuntimeEvent::Settlement(SettlementEvent::Memo(memo, 0)),
+            ]
+        };
+        let matched = |filter: EventFilter| filter.filter_events(events()).len();
+        assert_eq!(
+            matched(EventFilter {
+                identity: Some(IdentityId::from(5)),
+                ..Default::default()
+            }),
+            0
+        );
+        assert_eq!(
+            matched(EventFilter {
+                asset_id: Some(AssetId::new([1; 16])),
+                ..Default::default()
+            }),
+            0
+        );
+        assert_eq!(
+            matched(EventFilter {
+                instruction_id: Some(InstructionId(9)),
+                ..Default::default()
+            }),
+            0
+        );
+        assert_eq!(matched(EventFilter::default()), 2);
+    }
+
+    #[test]
+    fn filter_keeps_undecodable_events() {
+        let types = EventTypes::new::<RuntimeEvent>();
+        // An unknown event of the asset pallet and a `Frozen` event missing its asset
+        let events = || vec![vec![3, 9], vec![3, 1]];
+        let filter = EventFilter {
+            identity: Some(IdentityId::from(1)),
+            ..Default::default()
+        };
+        let filtered = filter.filter_encoded_events(&types, events());
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[0].pallet, Vec::<u8>::new());
+        assert_eq!(filtered[0].data, vec![3, 9]);
+        assert_eq!(filtered[0].fields, None);
+        assert_eq!(filtered[1].pallet, b"Asset".to_vec());
+        assert_eq!(filtered[1].event, b"Frozen".to_vec());
+        assert_eq!(filtered[1].fields, None);
+
+        // The names of an event are still matched when its fields can't be decoded
+        let filter = EventFilter {
+            pallet: Some(b"Settlement".to_vec()),
+            ..Default::default()
+        };
+        let filtered = filter.filter_encoded_events(&types, events());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].index, 0);
+    }
+}
@@ -182,7 +182,10 @@ pub use document::{Document, DocumentId, DocumentName, DocumentUri};
 
 /// Rules for claims.
 pub mod condition;
-pub use condition::{Condition, ConditionType, TargetIdentity, TrustedFor, TrustedIssuer};
+pub use condition::{
+    ClaimDatePredicate, Condition, ConditionExpression, ConditionType, TargetIdentity, TrustedFor,
+    TrustedIssuer,
+};
 
 /// Predicate calculation for Claims.
 pub mod proposition;
@@ -216,11 +219,24 @@ pub mod ethereum;
 
 /// NFT type definitions.
 pub mod nft;
-pub use nft::{NFTCollectionId, NFTCollectionKeys, NFTId, NFTMetadataAttribute, NFTs};
+pub use nft::{
+    FractionalizedNFT, NFTCollectionId, NFTCollectionKeys, NFTId, NFTMetadataAttribute, NFTs,
+};
+
+/// Event filter definitions.
+pub mod event_filter;
+pub use event_filter::{EventFilter, EventTypes, FilteredEvent};
+
+/// Dry run definitions.
+pub mod dry_run;
+pub use dry_run::{DryRunEffects, DryRunError, DryRunOrigin};
 
 /// Portfolio type definitions.
 pub mod portfolio;
-pub use portfolio::{Fund, FundDescription, PortfolioUpdateReason};
+pub use portfolio::{
+    Fund, FundDescription, PortfolioAssetBalance, PortfolioHoldings, PortfolioHoldingsPage,
+    PortfolioNFTHolding, PortfolioUpdateReason,
+};
 
 /// Custom WeightMeter definitions.
 pub mod weight_meter;
@@ -10,6 +10,7 @@ use sp_std::vec::Vec;
 use crate::asset::AssetId;
 use crate::asset_metadata::{AssetMetadataKey, AssetMetadataValue};
 use crate::impl_checked_inc;
+use crate::PortfolioId;
 
 /// Controls the total number of NFTs per identity.
 pub type NFTCount = u64;
@@ -125,3 +126,15 @@ pub struct NFTMetadataAttribute {
     /// The metadata value.
     pub value: AssetMetadataValue,
 }
+
+/// Links a locked NFT to the fungible asset representing its fractions.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FractionalizedNFT {
+    /// The [`AssetId`] of the NFT collection.
+    pub collection_asset_id: AssetId,
+    /// The id of the locked NFT.
+    pub nft_id: NFTId,
+    /// The portfolio holding the NFT until all its fractions are redeemed.
+    pub custody_portfolio: PortfolioId,
+}
@@ -15,10 +15,13 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
 
 use crate::asset::{AssetId, FundingRoundName};
 use crate::settlement::InstructionId;
-use crate::{Balance, Memo, NFTs};
+use crate::{Balance, IdentityId, Memo, NFTId, NFTs, PortfolioId, PortfolioKind, PortfolioName};
 
 /// Describes what should be moved between portfolios. It can be either fungible or non-fungible tokens.
 #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
@@ -63,3 +66,57 @@ pub enum PortfolioUpdateReason {
     /// Tokens were transferred via a controller call.
     ControllerTransfer,
 }
+
+/// The balance of an asset held in a portfolio.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct PortfolioAssetBalance {
+    /// The [`AssetId`] of the fungible asset.
+    pub asset_id: AssetId,
+    /// The amount that can be moved or transferred.
+    pub free: Balance,
+    /// The amount locked, e.g. by pending instructions or distributions.
+    pub locked: Balance,
+}
+
+/// A non-fungible token held in a portfolio.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct PortfolioNFTHolding {
+    /// The [`AssetId`] of the NFT collection.
+    pub asset_id: AssetId,
+    /// The [`NFTId`] of the token.
+    pub nft_id: NFTId,
+    /// Whether the token is locked.
+    pub locked: bool,
+}
+
+/// A snapshot of everything held in a portfolio.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct PortfolioHoldings {
+    /// The [`PortfolioId`] of the portfolio.
+    pub portfolio_id: PortfolioId,
+    /// The name of the portfolio. Default portfolios are nameless.
+    pub name: Option<PortfolioName>,
+    /// The identity with custody of the portfolio.
+    pub custodian: IdentityId,
+    /// The balances of all fungible assets in the portfolio.
+    pub assets: Vec<PortfolioAssetBalance>,
+    /// All non-fungible tokens in the portfolio.
+    pub nfts: Vec<PortfolioNFTHolding>,
+    /// The assets the portfolio is pre-approved to receive without affirming.
+    pub pre_approved_assets: Vec<AssetId>,
+    /// Whether any of the lists above was capped to the maximum number of items returned per portfolio.
+    pub truncated: bool,
+}
+
+/// A page of [`PortfolioHoldings`] of an identity.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct PortfolioHoldingsPage {
+    /// The holdings of the portfolios in this page.
+    pub holdings: Vec<PortfolioHoldings>,
+    /// The [`PortfolioKind`] to start the next page from, if there are more portfolios.
+    pub next: Option<PortfolioKind>,
+}
@@ -1,6 +1,6 @@
 use crate::{
-    proposition::{Context, Proposition},
-    Claim, IdentityId,
+    proposition::{self, Context, Proposition},
+    Claim, ConditionExpression, CountryCode, IdentityId,
 };
 use codec::{Decode, Encode};
 
@@ -163,11 +163,63 @@ impl<C: Iterator<Item = Claim>> Proposition<C> for AnyProposition<'_> {
     }
 }
 
+// JurisdictionProposition
+// =========================================================
+
+/// Proposition that checks if a jurisdiction claim of any of its countries exists in context.
+#[derive(Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct JurisdictionProposition<'a> {
+    /// List of countries to find in context.
+    pub countries: &'a [CountryCode],
+}
+
+impl<C: Iterator<Item = Claim>> Proposition<C> for JurisdictionProposition<'_> {
+    /// Evaluate proposition against `context`.
+    fn evaluate(&self, mut context: Context<C>) -> bool {
+        context.claims.any(|ctx_claim| match ctx_claim {
+            Claim::Jurisdiction(country, _) => self.countries.contains(&country),
+            _ => false,
+        })
+    }
+}
+
+// ExpressionProposition
+// =========================================================
+
+/// Proposition that evaluates a [`ConditionExpression`] tree.
+#[derive(Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ExpressionProposition<'a> {
+    /// Root node of the expression.
+    pub expression: &'a ConditionExpression,
+}
+
+impl<C: Iterator<Item = Claim> + Clone> Proposition<C> for ExpressionProposition<'_> {
+    /// Evaluate proposition against `context`.
+    fn evaluate(&self, context: Context<C>) -> bool {
+        match self.expression {
+            ConditionExpression::IsPresent(claim) => proposition::exists(claim).evaluate(context),
+            ConditionExpression::IsAnyOf(claims) => proposition::any(claims).evaluate(context),
+            ConditionExpression::And(exprs) => exprs
+                .iter()
+                .all(|expression| ExpressionProposition { expression }.evaluate(context.clone())),
+            ConditionExpression::Or(exprs) => exprs
+                .iter()
+                .any(|expression| ExpressionProposition { expression }.evaluate(context.clone())),
+            ConditionExpression::Not(expression) => {
+                proposition::not::<_, C>(ExpressionProposition { expression }).evaluate(context)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         proposition::{self, Context, Proposition},
-        CddId, Claim, Condition, ConditionType, CountryCode, IdentityId, Scope, TargetIdentity,
+        CddId, Claim, Condition, ConditionExpression, ConditionType, CountryCode, IdentityId,
+        Scope, TargetIdentity,
     };
     use std::convert::From;
     use std::vec::IntoIter;
@@ -301,4 +353,56 @@ mod tests {
             |_| false,
         ));
     }
+
+    #[test]
+    fn run_expression() {
+        let scope = Scope::Identity(IdentityId::from(0));
+        let accredited = Claim::Accredited(scope.clone());
+        let kyc = Claim::KnowYourCustomer(scope.clone());
+        let us = Claim::Jurisdiction(CountryCode::US, scope.clone());
+
+        // (Accredited AND Jurisdiction=US) OR (Jurisdiction!=US AND KYC)
+        let expression = ConditionExpression::Or(vec![
+            ConditionExpression::And(vec![
+                ConditionExpression::IsPresent(accredited.clone()),
+                ConditionExpression::IsPresent(us.clone()),
+            ]),
+            ConditionExpression::And(vec![
+                ConditionExpression::Not(Box::new(ConditionExpression::IsPresent(us.clone()))),
+                ConditionExpression::IsPresent(kyc.clone()),
+            ]),
+        ]);
+        let condition = ConditionType::Expression(expression.clone()).into();
+        let check = |expected, claims: Vec<Claim>| {
+            assert_eq!(
+                proposition::run(&condition, mk_ctx(claims), |_| false),
+                expected
+            );
+        };
+
+        check(true, vec![accredited.clone(), us.clone()]);
+        check(true, vec![kyc.clone()]);
+        check(false, vec![kyc.clone(), us.clone()]);
+        check(false, vec![accredited.clone()]);
+
+        // The report contains the result of each node.
+        let report = proposition::report(&expression, mk_ctx(vec![kyc, us]));
+        assert!(!report.satisfied);
+        assert_eq!(
+            report
+                .children
+                .iter()
+                .map(|child| child.satisfied)
+                .collect::<Vec<_>>(),
+            vec![false, false]
+        );
+        assert_eq!(
+            report.children[1]
+                .children
+                .iter()
+                .map(|child| child.satisfied)
+                .collect::<Vec<_>>(),
+            vec![false, true]
+        );
+    }
 }
@@ -13,10 +13,13 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Claim, Condition, ConditionType, IdentityId, TargetIdentity};
+use crate::compliance_manager::ExpressionReport;
+use crate::{
+    Claim, Condition, ConditionExpression, ConditionType, CountryCode, IdentityId, TargetIdentity,
+};
 use codec::{Decode, Encode};
 
-use sp_std::prelude::*;
+use sp_std::{prelude::*, vec};
 
 /// Context using during an `Proposition` evaluation.
 #[derive(Encode, Decode, Clone, Default)]
@@ -78,8 +81,8 @@ impl<C, F: Fn(Context<C>) -> bool> Proposition<C> for F {
 /// Base and simple propositions
 pub mod base;
 pub use base::{
-    AndProposition, AnyProposition, ExistentialProposition, IsIdentityProposition, NotProposition,
-    OrProposition,
+    AndProposition, AnyProposition, ExistentialProposition, ExpressionProposition,
+    IsIdentityProposition, JurisdictionProposition, NotProposition, OrProposition,
 };
 
 // Helper functions
@@ -97,6 +100,12 @@ pub fn any(claims: &[Claim]) -> AnyProposition<'_> {
     AnyProposition { claims }
 }
 
+/// It creates a proposition to evaluate if a jurisdiction claim of any of `countries` is found in the context.
+#[inline]
+pub fn jurisdiction_in(countries: &[CountryCode]) -> JurisdictionProposition<'_> {
+    JurisdictionProposition { countries }
+}
+
 /// It create a negate proposition of `proposition`.
 #[inline]
 pub fn not<P: Proposition<C>, C>(proposition: P) -> NotProposition<P> {
@@ -104,6 +113,8 @@ pub fn not<P: Proposition<C>, C>(proposition: P) -> NotProposition<P> {
 }
 
 /// Helper function to run propositions from a context.
+///
+/// For [`ConditionType::IsPresentWith`], the context must only contain claims satisfying its date predicate.
 pub fn run<C: Iterator<Item = Claim>, E: Proposition<C>>(
     condition: &Condition,
     context: Context<C>,
@@ -118,5 +129,54 @@ pub fn run<C: Iterator<Item = Claim>, E: Proposition<C>>(
             IsIdentityProposition { identity: *id }.evaluate(context)
         }
         ConditionType::IsIdentity(TargetIdentity::ExternalAgent) => ea_prop.evaluate(context),
+        ConditionType::Expression(expression) => {
+            ExpressionProposition { expression }.evaluate(collect_context(context))
+        }
+        ConditionType::IsPresentWith(claim, _) => exists(claim).evaluate(context),
+        ConditionType::IsJurisdictionIn(countries, _) => {
+            jurisdiction_in(countries).evaluate(context)
+        }
     }
 }
+
+/// Collects the claims of `context`, so it can be evaluated by each node of an expression tree.
+fn collect_context<C: Iterator<Item = Claim>>(
+    context: Context<C>,
+) -> Context<vec::IntoIter<Claim>> {
+    Context {
+        claims: context.claims.collect::<Vec<_>>().into_iter(),
+        id: context.id,
+    }
+}
+
+/// Evaluates every node of `expression` against `context`.
+///
+/// Unlike [`ExpressionProposition`], sub-expressions are always evaluated,
+/// so the report contains the result of each node.
+pub fn report<C: Iterator<Item = Claim>>(
+    expression: &ConditionExpression,
+    context: Context<C>,
+) -> ExpressionReport {
+    report_node(expression, &collect_context(context))
+}
+
+fn report_node(
+    expression: &ConditionExpression,
+    context: &Context<vec::IntoIter<Claim>>,
+) -> ExpressionReport {
+    let children = match expression {
+        ConditionExpression::IsPresent(..) | ConditionExpression::IsAnyOf(..) => Vec::new(),
+        ConditionExpression::And(exprs) | ConditionExpression::Or(exprs) => exprs
+            .iter()
+            .map(|expr| report_node(expr, context))
+            .collect(),
+        ConditionExpression::Not(expr) => vec![report_node(expr, context)],
+    };
+    let satisfied = match expression {
+        ConditionExpression::And(..) => children.iter().all(|child| child.satisfied),
+        ConditionExpression::Or(..) => children.iter().any(|child| child.satisfied),
+        ConditionExpression::Not(..) => children.iter().all(|child| !child.satisfied),
+        _ => ExpressionProposition { expression }.evaluate(context.clone()),
+    };
+    ExpressionReport::new(satisfied, children)
+}
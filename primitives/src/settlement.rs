@@ -23,16 +23,18 @@ use codec::{Decode, Encode};
 use frame_support::weights::Weight;
 use scale_info::prelude::string::String;
 use scale_info::TypeInfo;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
 use polymesh_primitives_derive::{SliceU8StrongTyped, VecU8StrongTyped};
 
 use crate::asset::AssetId;
-use crate::constants::SETTLEMENT_INSTRUCTION_EXECUTION;
-use crate::{impl_checked_inc, Balance, IdentityId, NFTs, PortfolioId, Ticker};
+use crate::constants::{SETTLEMENT_INSTRUCTION_DEADLINE, SETTLEMENT_INSTRUCTION_EXECUTION};
+use crate::{impl_checked_inc, Balance, IdentityId, Moment, NFTs, PortfolioId, Ticker};
 
 /// A global and unique venue ID.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
 pub struct VenueId(pub u64);
@@ -120,6 +122,7 @@ pub enum SettlementType<BlockNumber> {
 }
 
 /// A per-Instruction leg ID.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
 pub struct LegId(pub u64);
@@ -137,6 +140,11 @@ impl InstructionId {
     pub fn execution_name(&self) -> Vec<u8> {
         (SETTLEMENT_INSTRUCTION_EXECUTION, self.0).encode()
     }
+
+    /// Converts an instruction id into a scheduler name for its affirmation deadline.
+    pub fn deadline_name(&self) -> Vec<u8> {
+        (SETTLEMENT_INSTRUCTION_DEADLINE, self.0).encode()
+    }
 }
 
 /// Details about an instruction.
@@ -192,15 +200,30 @@ pub enum Leg {
         /// The amount transferred.
         amount: Balance,
     },
+    /// Assets that don't settle on-chain, affirmed by revealing the preimage of `hash_lock` before `expiry`.
+    HashLocked {
+        /// The [`IdentityId`] of the sender.
+        sender_identity: IdentityId,
+        /// The [`IdentityId`] of the receiver.
+        receiver_identity: IdentityId,
+        /// The [`Ticker`] for the off-chain asset.
+        ticker: Ticker,
+        /// The amount transferred.
+        amount: Balance,
+        /// The sha2-256 hash of the secret that affirms the leg.
+        hash_lock: [u8; 32],
+        /// The unix timestamp (in milliseconds) after which the secret can no longer be revealed.
+        expiry: Moment,
+    },
 }
 
 impl Leg {
-    /// Returns `true` if it's an [`Leg::OffChain`] leg, otherwise returns `false`.
+    /// Returns `true` if it's an [`Leg::OffChain`] or [`Leg::HashLocked`] leg, otherwise returns `false`.
     pub fn is_off_chain(&self) -> bool {
-        if let Leg::OffChain { .. } = self {
-            return true;
+        match self {
+            Leg::OffChain { .. } | Leg::HashLocked { .. } => true,
+            Leg::Fungible { .. } | Leg::NonFungible { .. } => false,
         }
-        false
     }
 
     /// Returns the [`AssetId`] of the asset in the given leg.
@@ -208,7 +231,94 @@ impl Leg {
         match self {
             Leg::Fungible { asset_id, .. } => Some(asset_id),
             Leg::NonFungible { nfts, .. } => Some(nfts.asset_id()),
-            Leg::OffChain { .. } => None,
+            Leg::OffChain { .. } | Leg::HashLocked { .. } => None,
+        }
+    }
+
+    /// Returns the sender and receiver [`PortfolioId`] of an on-chain leg, or `None` for off-chain legs.
+    pub fn portfolios(&self) -> Option<(&PortfolioId, &PortfolioId)> {
+        match self {
+            Leg::Fungible {
+                sender, receiver, ..
+            }
+            | Leg::NonFungible {
+                sender, receiver, ..
+            } => Some((sender, receiver)),
+            Leg::OffChain { .. } | Leg::HashLocked { .. } => None,
+        }
+    }
+
+    /// Nets all opposing [`Leg::Fungible`] legs that move the same asset between the same pair of portfolios.
+    /// Each netted leg takes the position of the first leg of its pair and pairs that net to zero are dropped.
+    /// [`Leg::NonFungible`], [`Leg::OffChain`] and [`Leg::HashLocked`] legs are kept unchanged.
+    ///
+    /// Returns `None` if the total amount for a pair of portfolios overflows.
+    pub fn net_fungible_legs(legs: &[Leg]) -> Option<Vec<Leg>> {
+        // (asset_id, lowest portfolio, highest portfolio) -> (lowest to highest amount, highest to lowest amount)
+        let mut net_positions: BTreeMap<(AssetId, PortfolioId, PortfolioId), (Balance, Balance)> =
+            BTreeMap::new();
+        for leg in legs {
+            if let Leg::Fungible {
+                sender,
+                receiver,
+                asset_id,
+                amount,
+            } = leg
+            {
+                let (low, high) = Self::ordered_pair(*sender, *receiver);
+                let position = net_positions.entry((*asset_id, low, high)).or_default();
+                if *sender == low {
+                    position.0 = position.0.checked_add(*amount)?;
+                } else {
+                    position.1 = position.1.checked_add(*amount)?;
+                }
+            }
+        }
+
+        let mut netted_legs = Vec::with_capacity(legs.len());
+        for leg in legs {
+            match leg {
+                Leg::Fungible {
+                    sender,
+                    receiver,
+                    asset_id,
+                    ..
+                } => {
+                    let (low, high) = Self::ordered_pair(*sender, *receiver);
+                    // The pair has already been netted if it's no longer in the map
+                    if let Some((low_to_high, high_to_low)) =
+                        net_positions.remove(&(*asset_id, low, high))
+                    {
+                        if low_to_high > high_to_low {
+                            netted_legs.push(Leg::Fungible {
+                                sender: low,
+                                receiver: high,
+                                asset_id: *asset_id,
+                                amount: low_to_high - high_to_low,
+                            });
+                        } else if high_to_low > low_to_high {
+                            netted_legs.push(Leg::Fungible {
+                                sender: high,
+                                receiver: low,
+                                asset_id: *asset_id,
+                                amount: high_to_low - low_to_high,
+                            });
+                        }
+                    }
+                }
+                Leg::NonFungible { .. } | Leg::OffChain { .. } | Leg::HashLocked { .. } => {
+                    netted_legs.push(leg.clone())
+                }
+            }
+        }
+        Some(netted_legs)
+    }
+
+    fn ordered_pair(a: PortfolioId, b: PortfolioId) -> (PortfolioId, PortfolioId) {
+        if a <= b {
+            (a, b)
+        } else {
+            (b, a)
         }
     }
 }
@@ -433,7 +543,7 @@ impl AssetCount {
             match &leg {
                 Leg::Fungible { .. } => asset_count.try_add_fungible()?,
                 Leg::NonFungible { nfts, .. } => asset_count.try_add_non_fungible(&nfts)?,
-                Leg::OffChain { .. } => asset_count.try_add_off_chain()?,
+                Leg::OffChain { .. } | Leg::HashLocked { .. } => asset_count.try_add_off_chain()?,
             }
         }
         Ok(asset_count)
@@ -447,7 +557,7 @@ impl AssetCount {
             match &leg {
                 Leg::Fungible { .. } => asset_count.add_fungible(),
                 Leg::NonFungible { nfts, .. } => asset_count.add_non_fungible(&nfts),
-                Leg::OffChain { .. } => asset_count.add_off_chain(),
+                Leg::OffChain { .. } | Leg::HashLocked { .. } => asset_count.add_off_chain(),
             }
         }
         asset_count
@@ -515,6 +625,11 @@ impl InstructionInfo {
             + self.mediators.len() as u64
     }
 
+    /// Returns the [`AssetCount`] for the instruction.
+    pub fn asset_count(&self) -> AssetCount {
+        self.instruction_asset_count
+    }
+
     /// Returns the number of fungible transfers.
     pub fn fungible_transfers(&self) -> u32 {
         self.instruction_asset_count.fungible()
@@ -599,7 +714,7 @@ impl FilteredLegs {
                         receiver_asset_count.add_non_fungible(&nfts);
                     }
                 }
-                Leg::OffChain { .. } => continue,
+                Leg::OffChain { .. } | Leg::HashLocked { .. } => continue,
             }
         }
 
@@ -730,3 +845,114 @@ pub enum MediatorAffirmationStatus<T> {
         expiry: Option<T>,
     },
 }
+
+/// The number of milliseconds in a day, used to reset the daily limit of an [`AutoAffirmRule`].
+const MILLISECONDS_PER_DAY: Moment = 86_400_000;
+
+/// A per-portfolio auto-affirm rule ID.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+pub struct AutoAffirmRuleId(pub u64);
+impl_checked_inc!(AutoAffirmRuleId);
+
+/// The side of a leg a portfolio is on.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum AffirmationSide {
+    /// The portfolio sends the assets of the leg.
+    Sender,
+    /// The portfolio receives the assets of the leg.
+    Receiver,
+}
+
+/// A standing settlement instruction, that automatically affirms new instructions on behalf of a portfolio.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct AutoAffirmRule {
+    /// The side of the legs the rule applies to.
+    pub side: AffirmationSide,
+    /// The [`VenueId`] of the venue that must have created the instruction.
+    pub venue_id: VenueId,
+    /// The asset the rule applies to. If `None`, the rule applies to all assets.
+    pub asset_id: Option<AssetId>,
+    /// The counterparties the rule applies to. If empty, the rule applies to all counterparties.
+    pub counterparties: BTreeSet<IdentityId>,
+    /// The maximum amount that can be affirmed per day (number of NFTs for non-fungible legs). If `None`, there's no limit.
+    pub daily_limit: Option<Balance>,
+}
+
+impl AutoAffirmRule {
+    /// Returns `true` if the rule applies to a leg on `side`, created by `venue_id`, moving `asset_id` with `counterparty`.
+    /// The daily limit is not taken into account.
+    pub fn matches(
+        &self,
+        side: AffirmationSide,
+        venue_id: &VenueId,
+        asset_id: &AssetId,
+        counterparty: &IdentityId,
+    ) -> bool {
+        self.side == side
+            && &self.venue_id == venue_id
+            && self.asset_id.as_ref().map_or(true, |id| id == asset_id)
+            && (self.counterparties.is_empty() || self.counterparties.contains(counterparty))
+    }
+}
+
+/// The usage of an [`AutoAffirmRule`].
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+pub struct AutoAffirmRuleUsage {
+    /// The day, since the unix epoch, `daily_amount` refers to.
+    pub day: u64,
+    /// The amount affirmed by the rule during `day`.
+    pub daily_amount: Balance,
+    /// The total number of instructions affirmed by the rule.
+    pub instructions: u64,
+}
+
+impl AutoAffirmRuleUsage {
+    /// Returns the day, since the unix epoch, of the unix timestamp `now` (in milliseconds).
+    pub fn day_of(now: Moment) -> u64 {
+        now / MILLISECONDS_PER_DAY
+    }
+
+    /// Returns the amount affirmed by the rule during `day`.
+    pub fn used_on(&self, day: u64) -> Balance {
+        if self.day == day {
+            self.daily_amount
+        } else {
+            0
+        }
+    }
+
+    /// Records that the rule affirmed `amount` during `day`.
+    pub fn record(&mut self, day: u64, amount: Balance) {
+        if self.day != day {
+            self.day = day;
+            self.daily_amount = 0;
+        }
+        self.daily_amount = self.daily_amount.saturating_add(amount);
+    }
+
+    /// Refunds the `amount` recorded during `day` for an instruction that was not executed.
+    pub fn refund(&mut self, day: u64, amount: Balance) {
+        if self.day == day {
+            self.daily_amount = self.daily_amount.saturating_sub(amount);
+        }
+        self.instructions = self.instructions.saturating_sub(1);
+    }
+}
+
+/// Explains which [`AutoAffirmRule`] matches a leg a portfolio is a party in.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct AutoAffirmRuleMatch {
+    /// The [`LegId`] of the leg.
+    pub leg_id: LegId,
+    /// The side of the leg the portfolio is on.
+    pub side: AffirmationSide,
+    /// The amount transferred in the leg (number of NFTs for non-fungible legs).
+    pub amount: Balance,
+    /// The first rule that matches the leg, if any.
+    pub rule_id: Option<AutoAffirmRuleId>,
+}
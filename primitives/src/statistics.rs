@@ -14,7 +14,8 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::asset::AssetId;
-use crate::{Claim, ClaimType, CountryCode, IdentityId, Scope};
+use crate::calendar::{CalendarPeriod, FixedOrVariableCalendarUnit};
+use crate::{Balance, Claim, ClaimType, CountryCode, CustomClaimTypeId, IdentityId, Moment, Scope};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
@@ -33,6 +34,8 @@ pub enum StatOpType {
     Count,
     /// Balance - Balance stat can be used for Percentage rules, since the `total_supply` of an asset can change (burn/mint)
     Balance,
+    /// Volume - Amount transferred over a rolling `CalendarPeriod`, per sender and for the whole asset.
+    Volume(CalendarPeriod),
 }
 
 /// The statistic type.
@@ -92,6 +95,7 @@ impl Stat1stKey {
 }
 
 /// Second stats key in double map.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Decode, Encode, TypeInfo)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Stat2ndKey {
@@ -123,6 +127,18 @@ impl Stat2ndKey {
             }
             // No Jurisdiction claim.
             (ClaimType::Jurisdiction, None) => Self::Claim(StatClaim::Jurisdiction(None)),
+            // Has KnowYourCustomer claim.
+            (ClaimType::KnowYourCustomer, Some(Claim::KnowYourCustomer(..))) => {
+                Self::Claim(StatClaim::KnowYourCustomer(true))
+            }
+            // No KnowYourCustomer claim.
+            (ClaimType::KnowYourCustomer, None) => Self::Claim(StatClaim::KnowYourCustomer(false)),
+            // Has Custom claim of the same type.
+            (ClaimType::Custom(id), Some(Claim::Custom(claim_id, _))) if *id == claim_id => {
+                Self::Claim(StatClaim::Custom(*id, true))
+            }
+            // No Custom claim.
+            (ClaimType::Custom(id), None) => Self::Claim(StatClaim::Custom(*id, false)),
             // Unsupported claim type, just map it to `NoClaimStat` variant.
             _ => Self::NoClaimStat,
         }
@@ -168,6 +184,10 @@ pub enum StatClaim {
     Affiliate(bool),
     /// This claim contains a string that represents the jurisdiction of the user.
     Jurisdiction(Option<CountryCode>),
+    /// User has or doesn't have a KnowYourCustomer claim.
+    KnowYourCustomer(bool),
+    /// User has or doesn't have a Custom claim of the given type.
+    Custom(CustomClaimTypeId, bool),
 }
 
 impl StatClaim {
@@ -178,6 +198,8 @@ impl StatClaim {
             (Claim::Affiliate(..), has) => Some(StatClaim::Affiliate(has)),
             (Claim::Jurisdiction(cc, _), true) => Some(StatClaim::Jurisdiction(Some(*cc))),
             (Claim::Jurisdiction(..), false) => Some(StatClaim::Jurisdiction(None)),
+            (Claim::KnowYourCustomer(..), has) => Some(StatClaim::KnowYourCustomer(has)),
+            (Claim::Custom(id, _), has) => Some(StatClaim::Custom(*id, has)),
             _ => None,
         }
     }
@@ -188,8 +210,75 @@ impl StatClaim {
             StatClaim::Accredited(_) => ClaimType::Accredited,
             StatClaim::Affiliate(_) => ClaimType::Affiliate,
             StatClaim::Jurisdiction(..) => ClaimType::Jurisdiction,
+            StatClaim::KnowYourCustomer(_) => ClaimType::KnowYourCustomer,
+            StatClaim::Custom(id, _) => ClaimType::Custom(*id),
+        }
+    }
+}
+
+/// Number of buckets tracking the volume of a rolling period.
+pub const VOLUME_BUCKETS: u64 = 24;
+
+/// Amount transferred over a rolling period.
+///
+/// The period is split into [`VOLUME_BUCKETS`] buckets of equal length, so the volume of the
+/// rolling period is accurate to within one bucket.
+#[derive(Decode, Encode, TypeInfo)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RollingVolume {
+    /// Volume of the buckets still in the rolling period, as `(bucket, amount)` ordered by bucket.
+    pub buckets: Vec<(u64, Balance)>,
+}
+
+impl RollingVolume {
+    /// Returns the bucket of the rolling `period` containing `now`, in milli-seconds Unix time.
+    ///
+    /// Returns `None` if `period` isn't recurring or doesn't have a fixed length.
+    pub fn bucket(period: &CalendarPeriod, now: Moment) -> Option<u64> {
+        match period.to_recurring()?.as_fixed_or_variable() {
+            FixedOrVariableCalendarUnit::Fixed(period_as_secs) => {
+                let bucket_as_ms = (period_as_secs.saturating_mul(1000) / VOLUME_BUCKETS).max(1);
+                Some(now / bucket_as_ms)
+            }
+            FixedOrVariableCalendarUnit::Variable(_) => None,
         }
     }
+
+    /// Returns the volume of the rolling period ending with `bucket`.
+    pub fn total(&self, bucket: u64) -> Balance {
+        self.buckets
+            .iter()
+            .filter(|(b, _)| Self::in_period(*b, bucket))
+            .fold(0, |total, (_, amount)| total.saturating_add(*amount))
+    }
+
+    /// Adds `amount` to `bucket`, dropping the buckets that are no longer in the rolling period.
+    pub fn add(&mut self, bucket: u64, amount: Balance) {
+        self.buckets.retain(|(b, _)| Self::in_period(*b, bucket));
+        match self.buckets.last_mut() {
+            Some((last, volume)) if *last == bucket => *volume = volume.saturating_add(amount),
+            _ => self.buckets.push((bucket, amount)),
+        }
+    }
+
+    fn in_period(bucket: u64, last_bucket: u64) -> bool {
+        bucket.saturating_add(VOLUME_BUCKETS) > last_bucket
+    }
+}
+
+/// The value of an active statistic of an asset, as returned by the statistics RPC.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Decode, Encode, TypeInfo)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetStatValue {
+    /// The [`StatType`] of the statistic.
+    pub stat_type: StatType,
+    /// The [`Stat2ndKey`] the value is tracked under.
+    pub key2: Stat2ndKey,
+    /// The investor count, balance or rolling volume of the statistic.
+    pub value: u128,
+    /// For [`StatOpType::Balance`] statistics, the share of the total supply held by the investors under `key2`.
+    pub ownership: Option<Percentage>,
 }
 
 /// Stats update.
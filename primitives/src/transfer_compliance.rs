@@ -14,8 +14,9 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::asset::AssetId;
+use crate::calendar::CalendarPeriod;
 use crate::statistics::{v1, Percentage, StatClaim, StatOpType, StatType};
-use crate::{ClaimType, IdentityId};
+use crate::{Balance, ClaimType, IdentityId};
 use codec::{Decode, Encode};
 use frame_support::{pallet_prelude::Get, BoundedBTreeSet};
 use scale_info::TypeInfo;
@@ -45,6 +46,15 @@ pub enum TransferCondition {
     /// * min/max % ownership for Accredited/non-accredited.
     /// (StatClaim, Issuer, Min, Max)
     ClaimOwnership(StatClaim, IdentityId, Percentage, Percentage),
+
+    /// Maximum amount a single investor can transfer over a rolling period.
+    /// (Period, Max)
+    MaxInvestorVolume(CalendarPeriod, Balance),
+
+    /// Maximum amount of the asset transferred by all investors over a rolling period.
+    /// Can be used for limiting the daily turnover of an asset.
+    /// (Period, Max)
+    MaxAssetVolume(CalendarPeriod, Balance),
 }
 
 impl TransferCondition {
@@ -59,6 +69,9 @@ impl TransferCondition {
             Self::ClaimOwnership(claim, issuer, _, _) => {
                 (StatOpType::Balance, Some((claim.claim_type(), *issuer)))
             }
+            Self::MaxInvestorVolume(period, _) | Self::MaxAssetVolume(period, _) => {
+                (StatOpType::Volume(*period), None)
+            }
         };
         StatType {
             operation_type,
@@ -73,6 +86,9 @@ impl TransferCondition {
             Self::MaxInvestorOwnership(_) => (StatOpType::Balance, None),
             Self::ClaimCount(claim, _, _, _) => (StatOpType::Count, Some(claim.claim_type())),
             Self::ClaimOwnership(claim, _, _, _) => (StatOpType::Balance, Some(claim.claim_type())),
+            Self::MaxInvestorVolume(period, _) | Self::MaxAssetVolume(period, _) => {
+                (StatOpType::Volume(*period), None)
+            }
         };
         TransferConditionExemptKey {
             asset_id,
@@ -18,7 +18,8 @@ use frame_support::pallet_prelude::DispatchError;
 use sp_std::vec::Vec;
 
 use polymesh_primitives::asset::AssetId;
-use polymesh_primitives::{Balance, PortfolioId};
+use polymesh_primitives::asset_metadata::{AssetMetadataDecodedValue, AssetMetadataKey};
+use polymesh_primitives::{Balance, IdentityId, PortfolioId};
 
 /// The maximum number of DIDs allowed in a `balance_at` RPC query.
 pub const MAX_BALANCE_AT_QUERY_SIZE: usize = 100;
@@ -26,7 +27,7 @@ pub const MAX_BALANCE_AT_QUERY_SIZE: usize = 100;
 pub type Error = Vec<u8>;
 
 sp_api::decl_runtime_apis! {
-    #[api_version(4)]
+    #[api_version(6)]
     pub trait AssetApi {
 
         /// Returns a vector containing all errors for the transfer. An empty vec means there's no error.
@@ -52,5 +53,40 @@ sp_api::decl_runtime_apis! {
             transfer_value: Balance,
             skip_locked_check: bool,
         ) -> Vec<DispatchError>;
+
+        /// Returns the metadata value of `key` for `asset_id`, decoded with the type definition of its spec.
+        /// `None` means the key has no value.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "asset_metadataValue",
+        ///     "params": [
+        ///        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ///        { "Global": 1 }
+        ///     ]
+        /// }'
+        /// ```
+        fn metadata_value(
+            asset_id: AssetId,
+            key: AssetMetadataKey,
+        ) -> Result<Option<AssetMetadataDecodedValue>, DispatchError>;
+
+        /// Returns the [`Balance`] of `asset_id` that `spender` is allowed to transfer out of `portfolio`.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "asset_allowance",
+        ///     "params": [
+        ///        { "did": "0x0100000000000000000000000000000000000000000000000000000000000000", "kind": "Default"},
+        ///        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ///        "0x0200000000000000000000000000000000000000000000000000000000000000"
+        ///     ]
+        /// }'
+        /// ```
+        fn allowance(portfolio: PortfolioId, asset_id: AssetId, spender: IdentityId) -> Balance;
     }
 }
@@ -0,0 +1,38 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for Corporate Actions module.
+
+use frame_support::dispatch::DispatchError;
+use sp_std::vec::Vec;
+
+use pallet_corporate_actions::distribution::DistributionEntitlement;
+use pallet_corporate_actions::CAId;
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::IdentityId;
+
+sp_api::decl_runtime_apis! {
+    #[api_version(0)]
+    pub trait CorporateActionsApi {
+        /// Returns the [`DistributionEntitlement`] of `holder` in the capital distribution attached to `ca_id`.
+        /// This includes the balance at the record date, the benefit before and after withholding tax,
+        /// and whether `holder` has already been paid.
+        fn distribution_entitlement(holder: IdentityId, ca_id: CAId) -> Result<DistributionEntitlement, DispatchError>;
+
+        /// Returns the [`DistributionEntitlement`] of `holder` in every capital distribution of `asset_id` that hasn't expired,
+        /// and for which `holder` is owed a benefit it hasn't been paid yet.
+        fn unclaimed_distributions(holder: IdentityId, asset_id: AssetId) -> Vec<(CAId, DistributionEntitlement)>;
+    }
+}
@@ -0,0 +1,35 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for dry running calls.
+
+use sp_std::vec::Vec;
+
+use polymesh_primitives::{DryRunEffects, DryRunError, DryRunOrigin};
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(0)]
+    pub trait DryRunApi {
+        /// Dispatches the SCALE encoded `RuntimeCall` with `origin` and returns its effects.
+        ///
+        /// The call is executed on top of the state of the block without signed extensions,
+        /// i.e. the nonce is not checked and no fee is withdrawn.
+        /// The storage changes are discarded by the caller of the runtime api.
+        ///
+        /// Returns an error if `call` can't be decoded or if an identity origin has no primary key.
+        fn dry_run_call(origin: DryRunOrigin, call: Vec<u8>) -> Result<DryRunEffects, DryRunError>;
+    }
+}
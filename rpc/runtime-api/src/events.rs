@@ -0,0 +1,36 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for filtering the events of a block.
+
+use sp_std::vec::Vec;
+
+use polymesh_primitives::EventTypes;
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(0)]
+    pub trait EventsApi {
+        /// Returns the type information of the runtime event, used to filter the events of all blocks
+        /// with the same runtime.
+        fn event_types() -> EventTypes;
+
+        /// Returns the SCALE encoded events of the block.
+        ///
+        /// The events are available at the hash of the block that emitted them.
+        /// They are filtered and streamed to clients by the `polymesh_subscribeEvents` subscription.
+        fn encoded_events() -> Vec<Vec<u8>>;
+    }
+}
@@ -0,0 +1,47 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for Portfolio module.
+
+use polymesh_primitives::{IdentityId, PortfolioHoldingsPage, PortfolioKind};
+
+sp_api::decl_runtime_apis! {
+
+    #[api_version(0)]
+    pub trait PortfolioApi {
+        /// Returns the holdings of up to `limit` portfolios owned by `did`, starting from the portfolio of kind `start`.
+        /// At most 10 portfolios are returned, each with at most 100 assets, NFTs and pre-approved assets.
+        /// The default portfolio comes first, followed by the user portfolios in ascending order of their numbers.
+        /// The returned page contains the [`PortfolioKind`] to start the next page from, if any.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "portfolio_getPortfolioHoldings",
+        ///     "params": [
+        ///        "0x0100000000000000000000000000000000000000000000000000000000000000",
+        ///        null,
+        ///        10
+        ///     ]
+        /// }'
+        /// ```
+        fn portfolio_holdings(
+            did: IdentityId,
+            start: Option<PortfolioKind>,
+            limit: u32,
+        ) -> PortfolioHoldingsPage;
+    }
+}
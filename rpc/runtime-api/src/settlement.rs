@@ -19,12 +19,12 @@ use frame_support::dispatch::DispatchError;
 use sp_std::vec::Vec;
 
 use polymesh_primitives::settlement::{
-    AffirmationCount, ExecuteInstructionInfo, InstructionId, Leg,
+    AffirmationCount, AutoAffirmRuleMatch, ExecuteInstructionInfo, InstructionId, Leg, VenueId,
 };
 use polymesh_primitives::PortfolioId;
 
 sp_api::decl_runtime_apis! {
-    #[api_version(2)]
+    #[api_version(3)]
     pub trait SettlementApi {
         /// Returns an [`ExecuteInstructionInfo`] instance containing the consumed weight and the number of fungible and non fungible
         /// tokens in the instruction. Executing an instruction includes verifying the compliance and transfer restrictions of all assets
@@ -86,5 +86,32 @@ sp_api::decl_runtime_apis! {
         ///   }'
         /// ```
         fn get_execute_instruction_report(instruction_id: InstructionId) -> Vec<DispatchError>;
+
+        /// Returns, for every leg in `legs` that `portfolio` is a party in, which of its auto-affirm rules would affirm the leg
+        /// if an instruction with these legs was created by `venue_id`. A leg without a matching rule has no `rule_id`.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "settlement_getAutoAffirmRuleMatches",
+        ///     "params": [
+        ///         { "did": "0x0100000000000000000000000000000000000000000000000000000000000000", "kind": "Default"},
+        ///         1,
+        ///         [
+        ///             {
+        ///                 "Fungible":
+        ///                     {
+        ///                         "sender": { "did": "0x0200000000000000000000000000000000000000000000000000000000000000", "kind": "Default"},
+        ///                         "receiver": { "did": "0x0100000000000000000000000000000000000000000000000000000000000000", "kind": "Default"},
+        ///                         "asset_id": [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ///                         "amount": 1000000
+        ///                     }
+        ///             }
+        ///         ]
+        ///     ]
+        /// }'
+        /// ```
+        fn get_auto_affirm_rule_matches(portfolio: PortfolioId, venue_id: VenueId, legs: Vec<Leg>) -> Vec<AutoAffirmRuleMatch>;
     }
 }
@@ -19,18 +19,58 @@ use frame_support::dispatch::DispatchError;
 use sp_std::vec::Vec;
 
 use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::statistics::{AssetStatValue, StatType};
 use polymesh_primitives::transfer_compliance::TransferCondition;
 use polymesh_primitives::{Balance, IdentityId};
 
 sp_api::decl_runtime_apis! {
-    #[api_version(0)]
+    #[api_version(1)]
     pub trait StatisticsApi {
         /// Returns a vector containing all [`TransferCondition`] that are not being respected for the transfer. An empty vec means there's no error.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "statistics_transferRestrictionsReport",
+        ///     "params": [
+        ///         [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ///         "0x0100000000000000000000000000000000000000000000000000000000000000",
+        ///         "0x0200000000000000000000000000000000000000000000000000000000000000",
+        ///         1000000
+        ///     ]
+        /// }'
+        /// ```
         fn transfer_restrictions_report(
             asset_id: AssetId,
             sender_did: &IdentityId,
             receiver_did: &IdentityId,
             transfer_amount: Balance,
         ) -> Result<Vec<TransferCondition>, DispatchError>;
+
+        /// Returns all active [`StatType`] of `asset_id`.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "statistics_activeAssetStats",
+        ///     "params": [[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]]
+        /// }'
+        /// ```
+        fn active_asset_stats(asset_id: AssetId) -> Vec<StatType>;
+
+        /// Returns an [`AssetStatValue`] for every active [`StatType`] of `asset_id` and each of its `Stat2ndKey`.
+        /// Balance statistics also contain the share of the total supply held under each key.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "statistics_assetStats",
+        ///     "params": [[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]]
+        /// }'
+        /// ```
+        fn asset_stats(asset_id: AssetId) -> Result<Vec<AssetStatValue>, DispatchError>;
     }
 }
@@ -25,7 +25,8 @@ use sp_runtime::traits::Block as BlockT;
 
 pub use node_rpc_runtime_api::asset::AssetApi as AssetRuntimeApi;
 use polymesh_primitives::asset::AssetId;
-use polymesh_primitives::{Balance, PortfolioId};
+use polymesh_primitives::asset_metadata::AssetMetadataKey;
+use polymesh_primitives::{Balance, IdentityId, PortfolioId};
 
 use crate::Error;
 
@@ -41,6 +42,23 @@ pub trait AssetApi<BlockHash> {
         skip_locked_check: bool,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<DispatchError>>;
+
+    #[method(name = "asset_metadataValue")]
+    fn metadata_value(
+        &self,
+        asset_id: AssetId,
+        key: AssetMetadataKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Result<Option<serde_json::Value>, DispatchError>>;
+
+    #[method(name = "asset_allowance")]
+    fn allowance(
+        &self,
+        portfolio: PortfolioId,
+        asset_id: AssetId,
+        spender: IdentityId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Balance>;
 }
 
 /// An implementation of asset specific RPC methods.
@@ -95,4 +113,60 @@ where
             .into()
         })
     }
+
+    fn metadata_value(
+        &self,
+        asset_id: AssetId,
+        key: AssetMetadataKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Result<Option<serde_json::Value>, DispatchError>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let decoded = match api.metadata_value(at_hash, asset_id, key).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to call asset_metadata_value runtime",
+                Some(e.to_string()),
+            ))
+        })? {
+            Ok(decoded) => decoded,
+            Err(e) => return Ok(Err(e)),
+        };
+        decoded
+            .map(serde_json::to_value)
+            .transpose()
+            .map(Ok)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::DecodeError.into(),
+                    "Unable to serialize the asset metadata value",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
+
+    fn allowance(
+        &self,
+        portfolio: PortfolioId,
+        asset_id: AssetId,
+        spender: IdentityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Balance> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.allowance(at_hash, portfolio, asset_id, spender)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call asset_allowance runtime",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
 }
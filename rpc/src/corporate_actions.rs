@@ -0,0 +1,115 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+use std::sync::Arc;
+
+use frame_support::dispatch::DispatchError;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use node_rpc_runtime_api::corporate_actions::CorporateActionsApi as CorporateActionsRuntimeApi;
+use pallet_corporate_actions::distribution::DistributionEntitlement;
+use pallet_corporate_actions::CAId;
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::IdentityId;
+
+use crate::Error;
+
+#[rpc(client, server)]
+pub trait CorporateActionsApi<BlockHash> {
+    #[method(name = "corporateActions_getDistributionEntitlement")]
+    fn get_distribution_entitlement(
+        &self,
+        holder: IdentityId,
+        ca_id: CAId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Result<DistributionEntitlement, DispatchError>>;
+
+    #[method(name = "corporateActions_getUnclaimedDistributions")]
+    fn get_unclaimed_distributions(
+        &self,
+        holder: IdentityId,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(CAId, DistributionEntitlement)>>;
+}
+
+/// An implementation of Corporate Actions specific RPC methods.
+pub struct CorporateActions<T, U> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> CorporateActions<T, U> {
+    /// Creates a new `CorporateActions` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<T, Block> CorporateActionsApiServer<<Block as BlockT>::Hash> for CorporateActions<T, Block>
+where
+    Block: BlockT,
+    T: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    T::Api: CorporateActionsRuntimeApi<Block>,
+{
+    fn get_distribution_entitlement(
+        &self,
+        holder: IdentityId,
+        ca_id: CAId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Result<DistributionEntitlement, DispatchError>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.distribution_entitlement(at_hash, holder, ca_id)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call distribution_entitlement runtime",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
+
+    fn get_unclaimed_distributions(
+        &self,
+        holder: IdentityId,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(CAId, DistributionEntitlement)>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.unclaimed_distributions(at_hash, holder, asset_id)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call unclaimed_distributions runtime",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
+}
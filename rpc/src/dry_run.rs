@@ -0,0 +1,188 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use jsonrpsee::core::{Error as JsonRpseeError, RpcResult};
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::error::{CallError, ErrorCode, ErrorObject};
+use sc_client_api::Backend;
+use sc_rpc::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::{StorageData, StorageKey};
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+use sp_runtime::DispatchResult;
+use sp_weights::Weight;
+
+pub use node_rpc_runtime_api::dry_run::DryRunApi as DryRunRuntimeApi;
+use polymesh_primitives::{DryRunError, DryRunOrigin};
+
+use crate::events::Event;
+use crate::Error;
+
+/// The new value of a storage key written by a dry run, `None` if it was removed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StorageChange {
+    /// The storage key.
+    pub key: StorageKey,
+    /// The value of the key after the dry run.
+    pub value: Option<StorageData>,
+}
+
+/// The outcome of `polymesh_dryRun`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DryRunResult {
+    /// The result of dispatching the call.
+    pub result: DispatchResult,
+    /// The actual weight consumed by the call.
+    pub actual_weight: Weight,
+    /// The fee charged for the actual weight and the length of the call, without any tip.
+    pub partial_fee: NumberOrHex,
+    /// The events emitted by the call, including the errors of failed batch items.
+    pub events: Vec<Event>,
+    /// The storage keys written by the call.
+    pub storage_changes: Vec<StorageChange>,
+    /// The child storage keys written by the call, grouped by child trie.
+    pub child_storage_changes: Vec<(StorageKey, Vec<StorageChange>)>,
+}
+
+#[rpc(client, server)]
+pub trait DryRunApi<BlockHash> {
+    /// Dispatches the SCALE encoded `RuntimeCall` with `origin` on top of the state of the block,
+    /// without committing any of its changes.
+    ///
+    /// Signed extensions are not applied, i.e. the nonce is not checked and no fee is withdrawn.
+    /// The storage changes include the bookkeeping of the `System` pallet, e.g. its events.
+    #[method(name = "polymesh_dryRun")]
+    fn dry_run(
+        &self,
+        origin: DryRunOrigin,
+        call: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<DryRunResult>;
+}
+
+/// An implementation of the dry run RPC methods.
+pub struct DryRun<T, B, U> {
+    client: Arc<T>,
+    backend: Arc<B>,
+    deny_unsafe: DenyUnsafe,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, B, U> DryRun<T, B, U> {
+    /// Creates a new `DryRun` with the given references to the client and its backend.
+    pub fn new(client: Arc<T>, backend: Arc<B>, deny_unsafe: DenyUnsafe) -> Self {
+        Self {
+            client,
+            backend,
+            deny_unsafe,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn runtime_error(error: impl ToString, desc: &'static str) -> CallError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        desc,
+        Some(error.to_string()),
+    ))
+}
+
+impl<T, B, Block> DryRunApiServer<<Block as BlockT>::Hash> for DryRun<T, B, Block>
+where
+    Block: BlockT,
+    B: Backend<Block> + Send + Sync + 'static,
+    T: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    T::Api: DryRunRuntimeApi<Block> + ApiExt<Block, StateBackend = B::State>,
+{
+    fn dry_run(
+        &self,
+        origin: DryRunOrigin,
+        call: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<DryRunResult> {
+        // Executing arbitrary calls is expensive.
+        self.deny_unsafe.check_if_safe()?;
+
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let effects = api
+            .dry_run_call(at_hash, origin, call.0)
+            .map_err(|e| runtime_error(e, "Unable to call dry_run_call runtime"))?
+            .map_err(|e| match e {
+                DryRunError::InvalidCall => CallError::Custom(ErrorObject::owned(
+                    Error::DecodeError.into(),
+                    "Unable to decode call",
+                    None::<()>,
+                )),
+                DryRunError::MissingPrimaryKey => CallError::Custom(ErrorObject::owned(
+                    ErrorCode::InvalidParams.code(),
+                    "The identity has no primary key",
+                    None::<()>,
+                )),
+            })?;
+
+        // The changes of the call are kept by `api` and dropped with it.
+        let state = self
+            .backend
+            .state_at(at_hash)
+            .map_err(|e| runtime_error(e, "Unable to get the state of the block"))?;
+        let changes = api
+            .into_storage_changes(&state, at_hash)
+            .map_err(|e| runtime_error(e, "Unable to collect the storage changes"))?;
+        let into_changes = |collection: Vec<(Vec<u8>, Option<Vec<u8>>)>| {
+            collection
+                .into_iter()
+                .map(|(key, value)| StorageChange {
+                    key: StorageKey(key),
+                    value: value.map(StorageData),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let partial_fee = effects.partial_fee.try_into().map_err(|_| {
+            JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+                ErrorCode::InvalidParams.code(),
+                format!(
+                    "{} doesn't fit in NumberOrHex representation",
+                    effects.partial_fee
+                ),
+                None::<()>,
+            )))
+        })?;
+
+        Ok(DryRunResult {
+            result: effects.result,
+            actual_weight: effects.actual_weight,
+            partial_fee,
+            events: effects.events.into_iter().map(Event::from).collect(),
+            storage_changes: into_changes(changes.main_storage_changes),
+            child_storage_changes: changes
+                .child_storage_changes
+                .into_iter()
+                .map(|(child, collection)| (StorageKey(child), into_changes(collection)))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,193 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::iter;
+use std::sync::Arc;
+
+use futures::{FutureExt, StreamExt};
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::SubscriptionResult;
+use jsonrpsee::SubscriptionSink;
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use node_rpc_runtime_api::events::EventsApi as EventsRuntimeApi;
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::settlement::InstructionId;
+use polymesh_primitives::{EventTypes, FilteredEvent, IdentityId, PortfolioId};
+
+/// Selects the events streamed by `polymesh_subscribeEvents`.
+///
+/// An event is streamed when it satisfies all of the given criteria.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct EventFilter {
+    /// The name of the pallet that emitted the event, e.g. `Settlement`.
+    pub pallet: Option<String>,
+    /// The name of the event, e.g. `InstructionExecuted`.
+    pub event: Option<String>,
+    /// An [`IdentityId`] the event must contain.
+    pub identity: Option<IdentityId>,
+    /// An [`AssetId`] the event must contain.
+    pub asset_id: Option<AssetId>,
+    /// A [`PortfolioId`] the event must contain.
+    pub portfolio: Option<PortfolioId>,
+    /// An [`InstructionId`] the event must contain.
+    pub instruction_id: Option<InstructionId>,
+}
+
+impl From<EventFilter> for polymesh_primitives::EventFilter {
+    fn from(filter: EventFilter) -> Self {
+        Self {
+            pallet: filter.pallet.map(String::into_bytes),
+            event: filter.event.map(String::into_bytes),
+            identity: filter.identity,
+            asset_id: filter.asset_id,
+            portfolio: filter.portfolio,
+            instruction_id: filter.instruction_id,
+        }
+    }
+}
+
+/// An event matching an [`EventFilter`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Event {
+    /// The index of the event in the block.
+    pub index: u32,
+    /// The name of the pallet that emitted the event, empty if the event couldn't be decoded.
+    pub pallet: String,
+    /// The name of the event, empty if the event couldn't be decoded.
+    pub event: String,
+    /// The SCALE encoded runtime event.
+    pub data: Bytes,
+    /// The decoded fields of the event, `None` if they couldn't be decoded.
+    pub fields: Option<serde_json::Value>,
+}
+
+impl From<FilteredEvent> for Event {
+    fn from(event: FilteredEvent) -> Self {
+        Self {
+            index: event.index,
+            pallet: String::from_utf8_lossy(&event.pallet).into_owned(),
+            event: String::from_utf8_lossy(&event.event).into_owned(),
+            data: event.data.into(),
+            fields: event
+                .fields
+                .and_then(|fields| serde_json::to_value(fields).ok()),
+        }
+    }
+}
+
+/// The events of a finalized block matching an [`EventFilter`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BlockEvents<BlockHash> {
+    /// The hash of the finalized block.
+    pub block_hash: BlockHash,
+    /// The matching events of the block.
+    pub events: Vec<Event>,
+}
+
+#[rpc(client, server)]
+pub trait EventsApi<BlockHash> {
+    /// Streams the events of every finalized block matching `filter`.
+    /// Blocks without matching events are skipped.
+    #[subscription(
+        name = "polymesh_subscribeEvents" => "polymesh_events",
+        unsubscribe = "polymesh_unsubscribeEvents",
+        item = BlockEvents<BlockHash>
+    )]
+    fn subscribe_events(&self, filter: EventFilter);
+}
+
+/// An implementation of the event subscription RPC methods.
+pub struct Events<T, U> {
+    client: Arc<T>,
+    executor: SubscriptionTaskExecutor,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> Events<T, U> {
+    /// Creates a new `Events` with the given reference to the client
+    /// and the executor driving the subscriptions.
+    pub fn new(client: Arc<T>, executor: SubscriptionTaskExecutor) -> Self {
+        Self {
+            client,
+            executor,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<T, Block> EventsApiServer<<Block as BlockT>::Hash> for Events<T, Block>
+where
+    Block: BlockT,
+    T: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+    T::Api: EventsRuntimeApi<Block> + Core<Block>,
+{
+    fn subscribe_events(&self, sink: SubscriptionSink, filter: EventFilter) -> SubscriptionResult {
+        let client = self.client.clone();
+        let filter: polymesh_primitives::EventFilter = filter.into();
+        // The type information of the runtime event, along with the spec version of the runtime it belongs to.
+        let mut event_types: Option<(u32, EventTypes)> = None;
+
+        let stream = self
+            .client
+            .finality_notification_stream()
+            .flat_map(move |notification| {
+                // Blocks finalized implicitly by the notified one come first.
+                let hashes = notification
+                    .tree_route
+                    .iter()
+                    .copied()
+                    .chain(iter::once(notification.hash));
+                let blocks: Vec<_> = hashes
+                    .filter_map(|hash| {
+                        // Blocks whose runtime can't filter their events are skipped.
+                        let api = client.runtime_api();
+                        let spec_version = api.version(hash).ok()?.spec_version;
+                        if event_types
+                            .as_ref()
+                            .map_or(true, |(version, _)| *version != spec_version)
+                        {
+                            event_types = Some((spec_version, api.event_types(hash).ok()?));
+                        }
+                        let (_, types) = event_types.as_ref()?;
+                        let encoded_events = api.encoded_events(hash).ok()?;
+                        let events: Vec<Event> = filter
+                            .filter_encoded_events(types, encoded_events)
+                            .into_iter()
+                            .map(Event::from)
+                            .collect();
+                        (!events.is_empty()).then(|| BlockEvents {
+                            block_hash: hash,
+                            events,
+                        })
+                    })
+                    .collect();
+                futures::stream::iter(blocks)
+            });
+
+        let fut = async move {
+            sink.pipe_from_stream(stream).await;
+        };
+        self.executor
+            .spawn("polymesh-rpc-subscription", Some("rpc"), fut.boxed());
+        Ok(())
+    }
+}
@@ -51,8 +51,13 @@ macro_rules! rpc_forward_call {
 
 pub mod asset;
 pub mod compliance;
+pub mod corporate_actions;
+pub mod dry_run;
+pub mod events;
 pub mod identity;
 pub mod nft;
 pub mod pips;
+pub mod portfolio;
 pub mod settlement;
+pub mod statistics;
 pub mod transaction_payment;
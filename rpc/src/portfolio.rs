@@ -0,0 +1,85 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::sync::Arc;
+
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use node_rpc_runtime_api::portfolio::PortfolioApi as PortfolioRuntimeApi;
+use polymesh_primitives::{IdentityId, PortfolioHoldingsPage, PortfolioKind};
+
+use crate::Error;
+
+#[rpc(client, server)]
+pub trait PortfolioApi<BlockHash> {
+    #[method(name = "portfolio_getPortfolioHoldings")]
+    fn get_portfolio_holdings(
+        &self,
+        did: IdentityId,
+        start: Option<PortfolioKind>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<PortfolioHoldingsPage>;
+}
+
+/// An implementation of Portfolio specific RPC methods.
+pub struct Portfolio<T, U> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> Portfolio<T, U> {
+    /// Creates a new `Portfolio` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<T, Block> PortfolioApiServer<<Block as BlockT>::Hash> for Portfolio<T, Block>
+where
+    Block: BlockT,
+    T: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    T::Api: PortfolioRuntimeApi<Block>,
+{
+    fn get_portfolio_holdings(
+        &self,
+        did: IdentityId,
+        start: Option<PortfolioKind>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<PortfolioHoldingsPage> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.portfolio_holdings(at_hash, did, start, limit)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call portfolio_holdings runtime",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
+}
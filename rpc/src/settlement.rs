@@ -26,7 +26,7 @@ use sp_runtime::traits::Block as BlockT;
 
 pub use node_rpc_runtime_api::settlement::SettlementApi as SettlementRuntimeApi;
 use polymesh_primitives::settlement::{
-    AffirmationCount, ExecuteInstructionInfo, InstructionId, Leg,
+    AffirmationCount, AutoAffirmRuleMatch, ExecuteInstructionInfo, InstructionId, Leg, VenueId,
 };
 use polymesh_primitives::PortfolioId;
 
@@ -63,6 +63,15 @@ pub trait SettlementApi<BlockHash> {
         instruction_id: InstructionId,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<DispatchError>>;
+
+    #[method(name = "settlement_getAutoAffirmRuleMatches")]
+    fn get_auto_affirm_rule_matches(
+        &self,
+        portfolio: PortfolioId,
+        venue_id: VenueId,
+        legs: Vec<Leg>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AutoAffirmRuleMatch>>;
 }
 
 /// An implementation of Settlement specific RPC methods.
@@ -168,4 +177,25 @@ where
                 .into()
             })
     }
+    fn get_auto_affirm_rule_matches(
+        &self,
+        portfolio: PortfolioId,
+        venue_id: VenueId,
+        legs: Vec<Leg>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AutoAffirmRuleMatch>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_auto_affirm_rule_matches(at_hash, portfolio, venue_id, legs)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call get_auto_affirm_rule_matches runtime",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
 }
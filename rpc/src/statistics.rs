@@ -0,0 +1,149 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::sync::Arc;
+
+use frame_support::dispatch::DispatchError;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use node_rpc_runtime_api::statistics::StatisticsApi as StatisticsRuntimeApi;
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::statistics::{AssetStatValue, StatType};
+use polymesh_primitives::transfer_compliance::TransferCondition;
+use polymesh_primitives::{Balance, IdentityId};
+
+use crate::Error;
+
+#[rpc(client, server)]
+pub trait StatisticsApi<BlockHash> {
+    #[method(name = "statistics_transferRestrictionsReport")]
+    fn transfer_restrictions_report(
+        &self,
+        asset_id: AssetId,
+        sender_did: IdentityId,
+        receiver_did: IdentityId,
+        transfer_amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Result<Vec<TransferCondition>, DispatchError>>;
+
+    #[method(name = "statistics_activeAssetStats")]
+    fn active_asset_stats(
+        &self,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<StatType>>;
+
+    #[method(name = "statistics_assetStats")]
+    fn asset_stats(
+        &self,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Result<Vec<AssetStatValue>, DispatchError>>;
+}
+
+/// An implementation of Statistics specific RPC methods.
+pub struct Statistics<T, U> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> Statistics<T, U> {
+    /// Creates a new `Statistics` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<T, Block> StatisticsApiServer<<Block as BlockT>::Hash> for Statistics<T, Block>
+where
+    Block: BlockT,
+    T: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    T::Api: StatisticsRuntimeApi<Block>,
+{
+    fn transfer_restrictions_report(
+        &self,
+        asset_id: AssetId,
+        sender_did: IdentityId,
+        receiver_did: IdentityId,
+        transfer_amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Result<Vec<TransferCondition>, DispatchError>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.transfer_restrictions_report(
+            at_hash,
+            asset_id,
+            &sender_did,
+            &receiver_did,
+            transfer_amount,
+        )
+        .map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to call transfer_restrictions_report runtime",
+                Some(e.to_string()),
+            ))
+            .into()
+        })
+    }
+
+    fn active_asset_stats(
+        &self,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<StatType>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.active_asset_stats(at_hash, asset_id).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to call active_asset_stats runtime",
+                Some(e.to_string()),
+            ))
+            .into()
+        })
+    }
+
+    fn asset_stats(
+        &self,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Result<Vec<AssetStatValue>, DispatchError>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.asset_stats(at_hash, asset_id).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to call asset_stats runtime",
+                Some(e.to_string()),
+            ))
+            .into()
+        })
+    }
+}
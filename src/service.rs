@@ -100,6 +100,11 @@ pub trait RuntimeApiCollection:
     + pallet_group_rpc_runtime_api::GroupApi<Block>
     + node_rpc_runtime_api::nft::NFTApi<Block>
     + node_rpc_runtime_api::settlement::SettlementApi<Block>
+    + node_rpc_runtime_api::statistics::StatisticsApi<Block>
+    + node_rpc_runtime_api::corporate_actions::CorporateActionsApi<Block>
+    + node_rpc_runtime_api::portfolio::PortfolioApi<Block>
+    + node_rpc_runtime_api::events::EventsApi<Block>
+    + node_rpc_runtime_api::dry_run::DryRunApi<Block>
 where
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
@@ -125,7 +130,12 @@ where
         + node_rpc_runtime_api::asset::AssetApi<Block>
         + pallet_group_rpc_runtime_api::GroupApi<Block>
         + node_rpc_runtime_api::nft::NFTApi<Block>
-        + node_rpc_runtime_api::settlement::SettlementApi<Block>,
+        + node_rpc_runtime_api::settlement::SettlementApi<Block>
+        + node_rpc_runtime_api::statistics::StatisticsApi<Block>
+        + node_rpc_runtime_api::corporate_actions::CorporateActionsApi<Block>
+        + node_rpc_runtime_api::portfolio::PortfolioApi<Block>
+        + node_rpc_runtime_api::events::EventsApi<Block>
+        + node_rpc_runtime_api::dry_run::DryRunApi<Block>,
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }